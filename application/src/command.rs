@@ -0,0 +1,80 @@
+use shared_kernel::{Entity, EntityKind, Id, Repository};
+use task::domain::{
+    list::List,
+    net::{Net, RelationType, Status},
+    task::Task,
+};
+
+use crate::error::ApplicationError;
+
+/// Handles commands of type `C`.
+///
+/// Handlers load the aggregates a command names from their repositories, invoke the domain
+/// operations and save the changed aggregates. The events the aggregates raise are kept
+/// until the caller takes them for publishing.
+pub trait CommandHandler<C> {
+    /// The result of a handled command.
+    type Output;
+    /// The error a command fails with.
+    type Error;
+
+    /// Handles a command.
+    fn handle(&mut self, command: C) -> Result<Self::Output, Self::Error>;
+}
+
+/// Creates a task at the end of a list, yielding the id of the new task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateTask {
+    pub name: String,
+    pub list: Id<List>,
+}
+
+/// Renames a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameTask {
+    pub task: Id<Task>,
+    pub name: String,
+}
+
+/// Moves a task to the end of another list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoveTaskToList {
+    pub task: Id<Task>,
+    pub list: Id<List>,
+}
+
+/// Adds a task to a net on its default status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddTaskToNet {
+    pub net: Id<Net>,
+    pub task: Id<Task>,
+}
+
+/// Moves a task of a net to a status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangeTaskStatus {
+    pub net: Id<Net>,
+    pub task: Id<Task>,
+    pub status: Id<Status>,
+}
+
+/// Relates two tasks of a net.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddRelation {
+    pub net: Id<Net>,
+    pub from: Id<Task>,
+    pub to: Id<Task>,
+    pub relation_type: RelationType,
+}
+
+/// Loads an aggregate, failing if it is not in the repository.
+pub(crate) fn load<T, R>(repository: &R, id: Id<T>) -> Result<Entity<T>, ApplicationError<R::Error>>
+where
+    T: EntityKind,
+    R: Repository<T>,
+{
+    repository
+        .get(id)
+        .map_err(ApplicationError::Repository)?
+        .ok_or(ApplicationError::NotFound { id: id.into() })
+}
@@ -0,0 +1,19 @@
+use shared_kernel::AnyId;
+use task::domain::error::TaskDomainError;
+use thiserror::Error;
+
+/// Represents errors that can occur while handling a command.
+#[derive(Debug, Error)]
+pub enum ApplicationError<E> {
+    /// Error indicating that an aggregate named by the command was not found.
+    #[error("{id} not found")]
+    NotFound { id: AnyId },
+
+    /// Error indicating that the domain rejected the command.
+    #[error(transparent)]
+    Domain(#[from] TaskDomainError),
+
+    /// Error indicating that the underlying storage failed.
+    #[error("repository failed: {0}")]
+    Repository(#[source] E),
+}
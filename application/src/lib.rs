@@ -0,0 +1,13 @@
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+/// The `command` module contains the commands the application accepts and the trait their
+/// handlers implement.
+pub mod command;
+/// The `error` module contains the errors command handlers fail with.
+pub mod error;
+/// The `net` module contains the handler of commands on nets.
+pub mod net;
+/// The `query` module contains the read models projected from domain events.
+pub mod query;
+/// The `task` module contains the handler of commands on tasks and lists.
+pub mod task;
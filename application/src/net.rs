@@ -0,0 +1,201 @@
+use shared_kernel::{AggregateRoot, Clock, Entity, Id, Repository};
+use task::domain::{
+    error::TaskDomainError,
+    event::TaskDomainEvent,
+    net::{Net, NetAggregateRoot},
+    task::Task,
+};
+
+use crate::{
+    command::{load, AddRelation, AddTaskToNet, ChangeTaskStatus, CommandHandler},
+    error::ApplicationError,
+};
+
+/// Handles commands on nets.
+#[derive(Debug)]
+pub struct NetCommandHandler<N, T, C> {
+    nets: N,
+    tasks: T,
+    clock: C,
+    events: Vec<TaskDomainEvent>,
+}
+
+impl<N, T, C> NetCommandHandler<N, T, C>
+where
+    N: Repository<Net>,
+    T: Repository<Task, Error = N::Error>,
+    C: Clock,
+{
+    /// Creates a handler over the repositories of nets and tasks, timing changes with the
+    /// clock.
+    pub fn new(nets: N, tasks: T, clock: C) -> Self {
+        Self {
+            nets,
+            tasks,
+            clock,
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns the repository of nets.
+    pub fn nets(&self) -> &N {
+        &self.nets
+    }
+
+    /// Takes the events raised by the handled commands, oldest first.
+    pub fn take_events(&mut self) -> Vec<TaskDomainEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Loads a net, changes it and saves it, keeping its events.
+    fn update(
+        &mut self,
+        net: Id<Net>,
+        change: impl FnOnce(&mut Entity<Net>, &C) -> Result<(), TaskDomainError>,
+    ) -> Result<(), ApplicationError<N::Error>> {
+        let mut net = load(&self.nets, net)?;
+        change(&mut net, &self.clock)?;
+
+        self.nets.save(&net).map_err(ApplicationError::Repository)?;
+        self.events.extend(net.data.take_events());
+        Ok(())
+    }
+}
+
+impl<N, T, C> CommandHandler<AddTaskToNet> for NetCommandHandler<N, T, C>
+where
+    N: Repository<Net>,
+    T: Repository<Task, Error = N::Error>,
+    C: Clock,
+{
+    type Output = ();
+    type Error = ApplicationError<N::Error>;
+
+    fn handle(&mut self, command: AddTaskToNet) -> Result<Self::Output, Self::Error> {
+        load(&self.tasks, command.task)?;
+        self.update(command.net, |net, _| net.add_task(command.task))
+    }
+}
+
+impl<N, T, C> CommandHandler<ChangeTaskStatus> for NetCommandHandler<N, T, C>
+where
+    N: Repository<Net>,
+    T: Repository<Task, Error = N::Error>,
+    C: Clock,
+{
+    type Output = ();
+    type Error = ApplicationError<N::Error>;
+
+    fn handle(&mut self, command: ChangeTaskStatus) -> Result<Self::Output, Self::Error> {
+        self.update(command.net, |net, clock| {
+            net.change_task_status(command.task, command.status, clock)
+        })
+    }
+}
+
+impl<N, T, C> CommandHandler<AddRelation> for NetCommandHandler<N, T, C>
+where
+    N: Repository<Net>,
+    T: Repository<Task, Error = N::Error>,
+    C: Clock,
+{
+    type Output = ();
+    type Error = ApplicationError<N::Error>;
+
+    fn handle(&mut self, command: AddRelation) -> Result<Self::Output, Self::Error> {
+        self.update(command.net, |net, clock| {
+            net.new_relation(command.from, command.to, command.relation_type, clock)
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{InMemoryRepository, SystemClock};
+    use task::domain::{
+        name::{StatusName, TaskName},
+        net::{NetQuery, RelationType},
+        task::TaskAggregateRoot,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_net_commands() {
+        let net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let mut nets = InMemoryRepository::new();
+        nets.save(&net).unwrap();
+        let mut tasks = InMemoryRepository::new();
+        let [epic, story] = ["Epic", "Story"].map(|name| {
+            let task: Entity<Task> =
+                TaskAggregateRoot::new(TaskName::new(name).unwrap(), Id::new());
+            tasks.save(&task).unwrap();
+            task.id
+        });
+        let mut handler = NetCommandHandler::new(nets, tasks, SystemClock);
+
+        for task in [epic, story] {
+            handler.handle(AddTaskToNet { net: net.id, task }).unwrap();
+        }
+        handler
+            .handle(AddRelation {
+                net: net.id,
+                from: story,
+                to: epic,
+                relation_type: RelationType::Compose,
+            })
+            .unwrap();
+        handler
+            .handle(ChangeTaskStatus {
+                net: net.id,
+                task: story,
+                status: net.accepted_status(),
+            })
+            .unwrap();
+
+        let stored = handler.nets().get(net.id).unwrap().unwrap();
+        assert_eq!(stored.status_of(epic), Some(net.accepted_status()));
+        assert!(handler.take_events().iter().any(
+            |event| matches!(event, TaskDomainEvent::StatusPropagated { task, .. } if *task == epic)
+        ));
+        assert!(matches!(
+            handler.handle(AddRelation {
+                net: net.id,
+                from: epic,
+                to: story,
+                relation_type: RelationType::Compose,
+            }),
+            Err(ApplicationError::Domain(
+                TaskDomainError::CycleNotAllowedInNet { .. }
+            ))
+        ));
+        assert!(matches!(
+            handler.handle(AddTaskToNet {
+                net: Id::new(),
+                task: epic,
+            }),
+            Err(ApplicationError::NotFound { .. })
+        ));
+        let missing = Id::new();
+        assert!(matches!(
+            handler.handle(AddRelation {
+                net: net.id,
+                from: epic,
+                to: missing,
+                relation_type: RelationType::RelatesTo,
+            }),
+            Err(ApplicationError::Domain(TaskDomainError::TaskNotFoundInNet { task, .. })) if task == missing
+        ));
+        assert!(matches!(
+            handler.handle(AddTaskToNet {
+                net: net.id,
+                task: missing,
+            }),
+            Err(ApplicationError::NotFound { id }) if id == missing.into()
+        ));
+    }
+}
@@ -0,0 +1,646 @@
+use std::collections::HashMap;
+
+use shared_kernel::{Id, Timestamp};
+use task::domain::{
+    event::TaskDomainEvent,
+    list::List,
+    name::{LabelName, ListTitle, StatusName, TaskName},
+    net::{Net, NetChange, Status},
+    task::Task,
+};
+
+/// Represents a task as shown on a board, joined with the title of its list and the name of
+/// its status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskCard {
+    /// The task the card shows.
+    pub task: Id<Task>,
+    /// The name of the task.
+    pub name: TaskName,
+    /// The list the task is categorized to.
+    pub list: Id<List>,
+    /// The title of the list, if its creation has been projected.
+    pub list_title: Option<ListTitle>,
+    /// The net the status is read from: the one the task was last placed or moved in.
+    pub net: Option<Id<Net>>,
+    /// The name of the status of the task in that net.
+    pub status: Option<StatusName>,
+    /// The labels of the task in the order they were added.
+    pub labels: Vec<LabelName>,
+    /// When the task is due, if it is.
+    pub due_date: Option<Timestamp>,
+}
+
+/// Represents a column of a board: a status with the cards of the tasks on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardColumn {
+    /// The status the column shows.
+    pub status: Id<Status>,
+    /// The name of the status.
+    pub name: StatusName,
+    /// The cards of the visible tasks on the status, in the order they were placed.
+    pub cards: Vec<TaskCard>,
+}
+
+/// Represents a net laid out as a board, with one column per status in board order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardView {
+    /// The net the board shows.
+    pub net: Id<Net>,
+    /// The columns of the board.
+    pub columns: Vec<BoardColumn>,
+}
+
+/// Represents a task placed on a board.
+#[derive(Debug, Clone, Copy)]
+struct Placement {
+    task: Id<Task>,
+    status: Id<Status>,
+    /// Whether the task is archived or soft-removed, keeping its status but leaving the board.
+    hidden: bool,
+}
+
+/// Represents the projected state of a net.
+#[derive(Debug, Clone)]
+struct Board {
+    columns: Vec<(Id<Status>, StatusName)>,
+    default: Id<Status>,
+    accepted: Id<Status>,
+    placements: Vec<Placement>,
+}
+
+impl Board {
+    fn name_of(&self, status: Id<Status>) -> Option<&StatusName> {
+        self.columns
+            .iter()
+            .find(|(id, _)| *id == status)
+            .map(|(_, name)| name)
+    }
+
+    fn placement_mut(&mut self, task: Id<Task>) -> Option<&mut Placement> {
+        self.placements
+            .iter_mut()
+            .find(|placement| placement.task == task)
+    }
+}
+
+/// Maintains the task cards and boards by projecting the events of the task context, so
+/// readers get them without loading tasks, lists and nets.
+///
+/// Events may arrive in any order across aggregates: a task placed on a net before its
+/// creation is projected gets its status once it is created, and a list titled after its
+/// tasks are created backfills their cards.
+#[derive(Debug, Clone, Default)]
+pub struct TaskBoardProjection {
+    cards: HashMap<Id<Task>, TaskCard>,
+    list_titles: HashMap<Id<List>, ListTitle>,
+    boards: HashMap<Id<Net>, Board>,
+}
+
+impl TaskBoardProjection {
+    /// Creates an empty projection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the card of a task, if its creation has been projected.
+    pub fn card(&self, task: Id<Task>) -> Option<&TaskCard> {
+        self.cards.get(&task)
+    }
+
+    /// Lays out a net as a board, if its creation has been projected.
+    ///
+    /// Tasks whose creation has not been projected yet are left out.
+    pub fn board(&self, net: Id<Net>) -> Option<BoardView> {
+        let board = self.boards.get(&net)?;
+        let columns = board
+            .columns
+            .iter()
+            .map(|(status, name)| BoardColumn {
+                status: *status,
+                name: name.clone(),
+                cards: board
+                    .placements
+                    .iter()
+                    .filter(|placement| placement.status == *status && !placement.hidden)
+                    .filter_map(|placement| self.cards.get(&placement.task).cloned())
+                    .collect(),
+            })
+            .collect();
+
+        Some(BoardView { net, columns })
+    }
+
+    /// Projects an event; events the read models do not show are ignored.
+    pub fn apply(&mut self, event: &TaskDomainEvent) {
+        match event {
+            TaskDomainEvent::ListCreated { list, title }
+            | TaskDomainEvent::ListRenamed { list, title } => {
+                self.list_titles.insert(*list, title.clone());
+                for card in self.cards.values_mut().filter(|card| card.list == *list) {
+                    card.list_title = Some(title.clone());
+                }
+            }
+            TaskDomainEvent::TaskCreated { task, name, list } => {
+                let placed = self.boards.iter().find_map(|(net, board)| {
+                    let placement = board.placements.iter().find(|p| p.task == *task)?;
+                    Some((*net, board.name_of(placement.status).cloned()))
+                });
+                self.cards.insert(
+                    *task,
+                    TaskCard {
+                        task: *task,
+                        name: name.clone(),
+                        list: *list,
+                        list_title: self.list_titles.get(list).cloned(),
+                        net: placed.as_ref().map(|(net, _)| *net),
+                        status: placed.and_then(|(_, status)| status),
+                        labels: Vec::new(),
+                        due_date: None,
+                    },
+                );
+            }
+            TaskDomainEvent::TaskRenamed { task, name } => {
+                if let Some(card) = self.cards.get_mut(task) {
+                    card.name = name.clone();
+                }
+            }
+            TaskDomainEvent::TaskRecategorized { task, to, .. } => {
+                if let Some(card) = self.cards.get_mut(task) {
+                    card.list = *to;
+                    card.list_title = self.list_titles.get(to).cloned();
+                }
+            }
+            TaskDomainEvent::TaskLabeled { task, label } => {
+                if let Some(card) = self.cards.get_mut(task) {
+                    if !card.labels.contains(label) {
+                        card.labels.push(label.clone());
+                    }
+                }
+            }
+            TaskDomainEvent::TaskUnlabeled { task, label } => {
+                if let Some(card) = self.cards.get_mut(task) {
+                    card.labels.retain(|l| l != label);
+                }
+            }
+            TaskDomainEvent::TaskScheduled { task, schedule } => {
+                if let Some(card) = self.cards.get_mut(task) {
+                    card.due_date = schedule.as_ref().and_then(|s| s.due_date());
+                }
+            }
+            TaskDomainEvent::NetCreated {
+                net,
+                statuses,
+                default,
+                accepted,
+            } => {
+                self.boards.insert(
+                    *net,
+                    Board {
+                        columns: statuses.clone(),
+                        default: *default,
+                        accepted: *accepted,
+                        placements: Vec::new(),
+                    },
+                );
+            }
+            TaskDomainEvent::SchemaReplaced {
+                net,
+                schema,
+                status_mapping,
+                ..
+            } => {
+                let Some(board) = self.boards.get_mut(net) else {
+                    return;
+                };
+                board.columns = schema
+                    .statuses
+                    .iter()
+                    .map(|status| (status.id, status.name.clone()))
+                    .collect();
+                board.default = schema.default;
+                board.accepted = schema.accepted;
+                for placement in &mut board.placements {
+                    if let Some(status) = status_mapping.get(&placement.status) {
+                        placement.status = *status;
+                    }
+                }
+                self.refresh_net(*net);
+            }
+            TaskDomainEvent::TaskAddedToNet { net, task } => {
+                let Some(board) = self.boards.get_mut(net) else {
+                    return;
+                };
+                let status = board.default;
+                board.placements.push(Placement {
+                    task: *task,
+                    status,
+                    hidden: false,
+                });
+                self.place(*net, *task, status);
+            }
+            TaskDomainEvent::TaskRemovedFromNet { net, task } => {
+                if let Some(board) = self.boards.get_mut(net) {
+                    board.placements.retain(|placement| placement.task != *task);
+                }
+                if let Some(card) = self.cards.get_mut(task).filter(|c| c.net == Some(*net)) {
+                    card.net = None;
+                    card.status = None;
+                }
+            }
+            TaskDomainEvent::TaskSoftRemovedFromNet { net, task, .. }
+            | TaskDomainEvent::TaskArchived { net, task } => self.hide(*net, *task, true),
+            TaskDomainEvent::TaskRestoredToNet { net, task }
+            | TaskDomainEvent::TaskUnarchived { net, task } => self.hide(*net, *task, false),
+            TaskDomainEvent::TaskStatusChanged { net, task, to, .. }
+            | TaskDomainEvent::StatusPropagated { net, task, to, .. } => {
+                let Some(placement) = self
+                    .boards
+                    .get_mut(net)
+                    .and_then(|board| board.placement_mut(*task))
+                else {
+                    return;
+                };
+                placement.status = *to;
+                self.place(*net, *task, *to);
+            }
+            TaskDomainEvent::StatusAdded { net, status, name } => {
+                if let Some(board) = self.boards.get_mut(net) {
+                    board.columns.push((*status, name.clone()));
+                }
+            }
+            TaskDomainEvent::StatusRenamed { net, status, name } => {
+                let Some(board) = self.boards.get_mut(net) else {
+                    return;
+                };
+                if let Some(column) = board.columns.iter_mut().find(|(id, _)| id == status) {
+                    column.1 = name.clone();
+                }
+                self.refresh_net(*net);
+            }
+            TaskDomainEvent::StatusRemoved { net, status } => {
+                let Some(board) = self.boards.get_mut(net) else {
+                    return;
+                };
+                board.columns.retain(|(id, _)| id != status);
+                let default = board.default;
+                for placement in &mut board.placements {
+                    if placement.status == *status {
+                        placement.status = default;
+                    }
+                }
+                self.refresh_net(*net);
+            }
+            TaskDomainEvent::StatusMoved {
+                net,
+                status,
+                position,
+            } => {
+                let Some(board) = self.boards.get_mut(net) else {
+                    return;
+                };
+                if let Some(index) = board.columns.iter().position(|(id, _)| id == status) {
+                    let column = board.columns.remove(index);
+                    let position = (*position).min(board.columns.len());
+                    board.columns.insert(position, column);
+                }
+            }
+            TaskDomainEvent::DefaultStatusChanged { net, status } => {
+                if let Some(board) = self.boards.get_mut(net) {
+                    board.default = *status;
+                }
+            }
+            TaskDomainEvent::AcceptedStatusChanged { net, status } => {
+                let Some(board) = self.boards.get_mut(net) else {
+                    return;
+                };
+                // Soft-removed tasks move along without an event of their own.
+                let old = std::mem::replace(&mut board.accepted, *status);
+                for placement in &mut board.placements {
+                    if placement.status == old {
+                        placement.status = *status;
+                    }
+                }
+                self.refresh_net(*net);
+            }
+            TaskDomainEvent::NetEditUndone { net, change }
+            | TaskDomainEvent::NetEditRedone { net, change } => self.restore(*net, change),
+            _ => {}
+        }
+    }
+
+    /// Brings a board in line with the change an undo or redo applied to its net. Tasks keep
+    /// their place on the board; tasks the net regained are placed after them.
+    fn restore(&mut self, net: Id<Net>, change: &NetChange) {
+        let Some(board) = self.boards.get_mut(&net) else {
+            return;
+        };
+        let tasks: HashMap<_, _> = change
+            .tasks
+            .iter()
+            .filter_map(|entry| {
+                let task = entry.before.or(entry.after)?.0;
+                Some((
+                    task,
+                    (
+                        entry.before.is_some(),
+                        entry.after.map(|(_, status)| status),
+                    ),
+                ))
+            })
+            .collect();
+        let removed: HashMap<_, _> = change
+            .removed_tasks
+            .iter()
+            .filter_map(|entry| {
+                let task = entry.before.as_ref().or(entry.after.as_ref())?.task;
+                Some((task, entry.after.as_ref().map(|removed| removed.status)))
+            })
+            .collect();
+        let archived: HashMap<_, _> = change
+            .archived
+            .iter()
+            .filter_map(|entry| Some((entry.before.or(entry.after)?, entry.after.is_some())))
+            .collect();
+        let mut touched: Vec<_> = tasks
+            .keys()
+            .chain(removed.keys())
+            .chain(archived.keys())
+            .copied()
+            .collect();
+        touched.sort();
+        touched.dedup();
+
+        let mut added = Vec::new();
+        let mut dropped = Vec::new();
+        for task in touched {
+            let current = board.placements.iter().position(|p| p.task == task);
+            let hidden = current.map(|index| board.placements[index].hidden);
+            let state = match (tasks.get(&task), removed.get(&task)) {
+                (Some((was_active, Some(status))), _) => {
+                    let kept = *was_active && hidden.unwrap_or(false);
+                    Some((*status, archived.get(&task).copied().unwrap_or(kept)))
+                }
+                (_, Some(Some(status))) => Some((*status, true)),
+                // The task left the net, or its soft removal was purged.
+                (Some(_), _) | (_, Some(None)) => None,
+                (None, None) => current.map(|index| {
+                    let placement = board.placements[index];
+                    let hidden = archived.get(&task).copied().unwrap_or(placement.hidden);
+                    (placement.status, hidden)
+                }),
+            };
+            match (current, state) {
+                (Some(index), Some((status, hidden))) => {
+                    board.placements[index].status = status;
+                    board.placements[index].hidden = hidden;
+                }
+                (Some(index), None) => {
+                    board.placements.remove(index);
+                    dropped.push(task);
+                }
+                (None, Some((status, hidden))) => added.push(Placement {
+                    task,
+                    status,
+                    hidden,
+                }),
+                (None, None) => {}
+            }
+        }
+        board.placements.extend(added.iter().copied());
+        if let Some(schema) = &change.schema {
+            let schema = &schema.after;
+            board.columns = schema
+                .statuses
+                .iter()
+                .map(|status| (status.id, status.name.clone()))
+                .collect();
+            board.default = schema.default;
+            board.accepted = schema.accepted;
+        }
+
+        for task in dropped {
+            if let Some(card) = self
+                .cards
+                .get_mut(&task)
+                .filter(|card| card.net == Some(net))
+            {
+                card.net = None;
+                card.status = None;
+            }
+        }
+        for placement in added {
+            self.place(net, placement.task, placement.status);
+        }
+        self.refresh_net(net);
+    }
+
+    /// Shows or hides a task on a board, keeping its status.
+    fn hide(&mut self, net: Id<Net>, task: Id<Task>, hidden: bool) {
+        if let Some(placement) = self
+            .boards
+            .get_mut(&net)
+            .and_then(|board| board.placement_mut(task))
+        {
+            placement.hidden = hidden;
+        }
+    }
+
+    /// Makes the status of a task in a net the one its card shows.
+    fn place(&mut self, net: Id<Net>, task: Id<Task>, status: Id<Status>) {
+        let name = self
+            .boards
+            .get(&net)
+            .and_then(|board| board.name_of(status))
+            .cloned();
+        if let Some(card) = self.cards.get_mut(&task) {
+            card.net = Some(net);
+            card.status = name;
+        }
+    }
+
+    /// Refreshes the status names on the cards showing their status in a net.
+    fn refresh_net(&mut self, net: Id<Net>) {
+        let Some(board) = self.boards.get(&net) else {
+            return;
+        };
+        for placement in &board.placements {
+            if let Some(card) = self
+                .cards
+                .get_mut(&placement.task)
+                .filter(|card| card.net == Some(net))
+            {
+                card.status = board.name_of(placement.status).cloned();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{AggregateRoot, Clock, Entity, SystemClock};
+    use task::domain::{
+        list::{Label, ListAggregateRoot},
+        net::{Color, NetAggregateRoot, NetHistory, NetQuery},
+        task::{Schedule, TaskAggregateRoot},
+    };
+
+    use super::*;
+
+    fn status(name: &str) -> StatusName {
+        StatusName::new(name).unwrap()
+    }
+
+    fn task(projection: &mut TaskBoardProjection, name: &str) -> Id<Task> {
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new(name).unwrap(), Id::new());
+        apply(projection, task.data.take_events());
+        task.id
+    }
+
+    fn apply(projection: &mut TaskBoardProjection, events: Vec<TaskDomainEvent>) {
+        for event in events {
+            projection.apply(&event);
+        }
+    }
+
+    fn column(projection: &TaskBoardProjection, net: Id<Net>, name: &str) -> Vec<Id<Task>> {
+        let board = projection.board(net).unwrap();
+        let column = board
+            .columns
+            .iter()
+            .find(|c| c.name == status(name))
+            .unwrap();
+        column.cards.iter().map(|card| card.task).collect()
+    }
+
+    #[test]
+    fn test_board_projection() {
+        let mut projection = TaskBoardProjection::new();
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let docs = LabelName::new("docs").unwrap();
+        let color = Color {
+            red: 0,
+            green: 0,
+            blue: 255,
+        };
+        list.define_label(Label::new(docs.clone(), color)).unwrap();
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Write docs").unwrap(), list.id);
+        let due = SystemClock.now();
+        task.add_label(&list, docs).unwrap();
+        task.schedule(Schedule::due(due)).unwrap();
+        net.add_task(task.id).unwrap();
+
+        // The net and the task are projected before the list is.
+        for event in [net.data.take_events(), task.data.take_events()].concat() {
+            projection.apply(&event);
+        }
+        let card = projection.card(task.id).unwrap();
+        assert_eq!(card.status, Some(StatusName::new("Todo").unwrap()));
+        assert_eq!(card.list_title, None);
+        assert_eq!(card.labels, [LabelName::new("docs").unwrap()]);
+        assert_eq!(card.due_date, Some(due));
+
+        list.append_task(task.id).unwrap();
+        list.rename(ListTitle::new("Backlog").unwrap()).unwrap();
+        net.change_task_status(task.id, net.accepted_status(), &SystemClock)
+            .unwrap();
+        net.change_status_name(net.accepted_status(), StatusName::new("Shipped").unwrap())
+            .unwrap();
+        for event in [list.data.take_events(), net.data.take_events()].concat() {
+            projection.apply(&event);
+        }
+        let card = projection.card(task.id).unwrap();
+        assert_eq!(card.list_title, Some(ListTitle::new("Backlog").unwrap()));
+        assert_eq!(card.status, Some(StatusName::new("Shipped").unwrap()));
+
+        let board = projection.board(net.id).unwrap();
+        assert_eq!(board.columns.len(), 2);
+        assert!(board.columns[0].cards.is_empty());
+        assert_eq!(board.columns[1].name, StatusName::new("Shipped").unwrap());
+        assert_eq!(board.columns[1].cards, std::slice::from_ref(card));
+        assert!(projection.board(Id::new()).is_none());
+    }
+
+    #[test]
+    fn test_board_projection_follows_accepted_status() {
+        let mut projection = TaskBoardProjection::new();
+        let (kept, removed) = (
+            task(&mut projection, "Kept"),
+            task(&mut projection, "Removed"),
+        );
+        let mut net: Entity<Net> = NetAggregateRoot::with_statuses(
+            status("Todo"),
+            vec![status("Shipped")],
+            status("Done"),
+        );
+        let shipped = net
+            .statuses()
+            .find(|s| s.data.name() == &status("Shipped"))
+            .unwrap()
+            .id;
+        for task in [kept, removed] {
+            net.add_task(task).unwrap();
+            net.change_task_status(task, net.accepted_status(), &SystemClock)
+                .unwrap();
+        }
+        net.soft_remove_task(removed, &SystemClock).unwrap();
+        net.change_accepted(shipped, &SystemClock).unwrap();
+        net.restore_task(removed, &SystemClock).unwrap();
+        apply(&mut projection, net.data.take_events());
+
+        // The soft-removed task moved along with the accepted status while hidden.
+        assert!(column(&projection, net.id, "Done").is_empty());
+        assert_eq!(column(&projection, net.id, "Shipped"), [kept, removed]);
+        assert_eq!(
+            projection.card(removed).unwrap().status,
+            Some(status("Shipped"))
+        );
+    }
+
+    #[test]
+    fn test_board_projection_follows_undo_and_redo() {
+        let mut projection = TaskBoardProjection::new();
+        let (first, second) = (
+            task(&mut projection, "First"),
+            task(&mut projection, "Second"),
+        );
+        let mut net: Entity<Net> = NetAggregateRoot::new(status("Todo"), status("Done"));
+        net.add_task(first).unwrap();
+        let accepted = net.accepted_status();
+        let mut history = NetHistory::new(net);
+        history
+            .edit(|net| net.change_task_status(first, accepted, &SystemClock))
+            .unwrap();
+        history.edit(|net| net.add_task(second)).unwrap();
+        let net = history.net().id;
+        apply(&mut projection, history.take_events());
+        assert_eq!(column(&projection, net, "Todo"), [second]);
+        assert_eq!(column(&projection, net, "Done"), [first]);
+
+        assert!(history.undo());
+        apply(&mut projection, history.take_events());
+        assert!(column(&projection, net, "Todo").is_empty());
+        assert_eq!(projection.card(second).unwrap().status, None);
+        assert_eq!(projection.card(second).unwrap().net, None);
+
+        assert!(history.undo());
+        apply(&mut projection, history.take_events());
+        assert_eq!(column(&projection, net, "Todo"), [first]);
+        assert_eq!(projection.card(first).unwrap().status, Some(status("Todo")));
+
+        assert!(history.redo());
+        assert!(history.redo());
+        apply(&mut projection, history.take_events());
+        assert_eq!(column(&projection, net, "Todo"), [second]);
+        assert_eq!(column(&projection, net, "Done"), [first]);
+        assert_eq!(projection.card(second).unwrap().net, Some(net));
+        assert_eq!(projection.card(first).unwrap().status, Some(status("Done")));
+    }
+}
@@ -0,0 +1,403 @@
+use shared_kernel::{AggregateRoot, Entity, Id, Repository, RepositoryProvider, Store, UnitOfWork};
+use task::domain::{
+    error::TaskDomainError,
+    event::TaskDomainEvent,
+    list::{List, ListAggregateRoot},
+    name::TaskName,
+    task::{Task, TaskAggregateRoot},
+};
+
+use crate::{
+    command::{load, CommandHandler, CreateTask, MoveTaskToList, RenameTask},
+    error::ApplicationError,
+};
+
+/// Handles commands on tasks and the lists holding them.
+///
+/// Every command runs in a unit of work of its own, so the aggregates it changes are saved
+/// together or not at all.
+#[derive(Debug)]
+pub struct TaskCommandHandler<S> {
+    store: S,
+    events: Vec<TaskDomainEvent>,
+}
+
+impl<S> TaskCommandHandler<S>
+where
+    S: Store,
+    for<'a> S::UnitOfWork<'a>: RepositoryProvider<Task> + RepositoryProvider<List>,
+{
+    /// Creates a handler over the store of tasks and lists.
+    pub fn new(store: S) -> Self {
+        Self {
+            store,
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns the store of tasks and lists.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Takes the events raised by the handled commands, oldest first.
+    pub fn take_events(&mut self) -> Vec<TaskDomainEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Runs a command in a new unit of work. The unit of work is committed and the events
+    /// of the saved aggregates are kept if the command succeeds; otherwise it is rolled back.
+    fn run<R>(
+        &mut self,
+        command: impl FnOnce(
+            &mut S::UnitOfWork<'_>,
+            &mut Vec<TaskDomainEvent>,
+        ) -> Result<R, ApplicationError<S::Error>>,
+    ) -> Result<R, ApplicationError<S::Error>> {
+        let mut unit = self.store.begin().map_err(ApplicationError::Repository)?;
+        let mut events = Vec::new();
+        match command(&mut unit, &mut events) {
+            Ok(output) => {
+                unit.commit().map_err(ApplicationError::Repository)?;
+                self.events.extend(events);
+                Ok(output)
+            }
+            Err(error) => {
+                unit.rollback().map_err(ApplicationError::Repository)?;
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Loads a list in a unit of work, failing if it is archived.
+fn open_list<U: RepositoryProvider<List>>(
+    unit: &mut U,
+    list: Id<List>,
+) -> Result<Entity<List>, ApplicationError<U::Error>> {
+    let list = load(&RepositoryProvider::<List>::repository(unit), list)?;
+    if list.data.is_archived() {
+        return Err(TaskDomainError::ListArchived { list: list.id }.into());
+    }
+
+    Ok(list)
+}
+
+/// Saves an aggregate in a unit of work and keeps its events.
+fn save<T, U>(
+    unit: &mut U,
+    entity: &mut Entity<T>,
+    events: &mut Vec<TaskDomainEvent>,
+) -> Result<(), ApplicationError<U::Error>>
+where
+    T: AggregateRoot<Event = TaskDomainEvent>,
+    U: RepositoryProvider<T>,
+{
+    unit.repository()
+        .save(entity)
+        .map_err(ApplicationError::Repository)?;
+    events.extend(entity.take_events());
+    Ok(())
+}
+
+impl<S> CommandHandler<CreateTask> for TaskCommandHandler<S>
+where
+    S: Store,
+    for<'a> S::UnitOfWork<'a>: RepositoryProvider<Task> + RepositoryProvider<List>,
+{
+    type Output = Id<Task>;
+    type Error = ApplicationError<S::Error>;
+
+    fn handle(&mut self, command: CreateTask) -> Result<Self::Output, Self::Error> {
+        self.run(|unit, events| {
+            let mut list = open_list(unit, command.list)?;
+            let mut task: Entity<Task> =
+                TaskAggregateRoot::new(TaskName::new_trimmed(command.name)?, list.id);
+            list.append_task(task.id)?;
+
+            save(unit, &mut task, events)?;
+            save(unit, &mut list, events)?;
+            Ok(task.id)
+        })
+    }
+}
+
+impl<S> CommandHandler<RenameTask> for TaskCommandHandler<S>
+where
+    S: Store,
+    for<'a> S::UnitOfWork<'a>: RepositoryProvider<Task> + RepositoryProvider<List>,
+{
+    type Output = ();
+    type Error = ApplicationError<S::Error>;
+
+    fn handle(&mut self, command: RenameTask) -> Result<Self::Output, Self::Error> {
+        self.run(|unit, events| {
+            let mut task = load(&RepositoryProvider::<Task>::repository(unit), command.task)?;
+            task.rename(command.name)?;
+
+            save(unit, &mut task, events)
+        })
+    }
+}
+
+impl<S> CommandHandler<MoveTaskToList> for TaskCommandHandler<S>
+where
+    S: Store,
+    for<'a> S::UnitOfWork<'a>: RepositoryProvider<Task> + RepositoryProvider<List>,
+{
+    type Output = ();
+    type Error = ApplicationError<S::Error>;
+
+    fn handle(&mut self, command: MoveTaskToList) -> Result<Self::Output, Self::Error> {
+        self.run(|unit, events| {
+            let mut task = load(&RepositoryProvider::<Task>::repository(unit), command.task)?;
+            if task.data.list() == command.list {
+                return Ok(());
+            }
+            let mut target = open_list(unit, command.list)?;
+            let source = RepositoryProvider::<List>::repository(unit)
+                .get(task.data.list())
+                .map_err(ApplicationError::Repository)?;
+
+            task.categorize_to(target.id);
+            target.append_task(task.id)?;
+
+            save(unit, &mut task, events)?;
+            if let Some(mut source) = source.filter(|list| list.data.position_of(task.id).is_some())
+            {
+                source.remove_task(task.id)?;
+                save(unit, &mut source, events)?;
+            }
+            save(unit, &mut target, events)
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{InMemoryRepository, Page, PageRequest};
+    use task::domain::name::ListTitle;
+    use thiserror::Error;
+
+    use super::*;
+
+    #[derive(Debug, Error)]
+    #[error("storage failed")]
+    struct StorageFailed;
+
+    /// A store keeping tasks and lists in memory, whose units of work change copies of them.
+    #[derive(Debug, Default)]
+    struct MemoryStore {
+        tasks: InMemoryRepository<Task>,
+        lists: InMemoryRepository<List>,
+        failing_list_saves: bool,
+    }
+
+    struct MemoryUnitOfWork<'a> {
+        store: &'a mut MemoryStore,
+        tasks: InMemoryRepository<Task>,
+        lists: InMemoryRepository<List>,
+    }
+
+    struct MemoryRepository<'a, T> {
+        entities: &'a mut InMemoryRepository<T>,
+        failing_saves: bool,
+    }
+
+    impl<T: Clone> Repository<T> for MemoryRepository<'_, T> {
+        type Error = StorageFailed;
+
+        fn get(&self, id: Id<T>) -> Result<Option<Entity<T>>, Self::Error> {
+            Ok(self.entities.get(id).unwrap())
+        }
+
+        fn save(&mut self, entity: &Entity<T>) -> Result<(), Self::Error> {
+            if self.failing_saves {
+                return Err(StorageFailed);
+            }
+            self.entities.save(entity).unwrap();
+            Ok(())
+        }
+
+        fn delete(&mut self, id: Id<T>) -> Result<(), Self::Error> {
+            self.entities.delete(id).unwrap();
+            Ok(())
+        }
+
+        fn list(&self, page: PageRequest) -> Result<Page<Entity<T>>, Self::Error> {
+            Ok(self.entities.list(page).unwrap())
+        }
+    }
+
+    impl Store for MemoryStore {
+        type Error = StorageFailed;
+        type UnitOfWork<'a> = MemoryUnitOfWork<'a>;
+
+        fn begin(&mut self) -> Result<Self::UnitOfWork<'_>, Self::Error> {
+            Ok(MemoryUnitOfWork {
+                tasks: copy(&self.tasks),
+                lists: copy(&self.lists),
+                store: self,
+            })
+        }
+    }
+
+    impl UnitOfWork for MemoryUnitOfWork<'_> {
+        type Error = StorageFailed;
+
+        fn commit(self) -> Result<(), Self::Error> {
+            self.store.tasks = self.tasks;
+            self.store.lists = self.lists;
+            Ok(())
+        }
+
+        fn rollback(self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    impl RepositoryProvider<Task> for MemoryUnitOfWork<'_> {
+        fn repository(&mut self) -> impl Repository<Task, Error = Self::Error> + '_ {
+            MemoryRepository {
+                entities: &mut self.tasks,
+                failing_saves: false,
+            }
+        }
+    }
+
+    impl RepositoryProvider<List> for MemoryUnitOfWork<'_> {
+        fn repository(&mut self) -> impl Repository<List, Error = Self::Error> + '_ {
+            MemoryRepository {
+                entities: &mut self.lists,
+                failing_saves: self.store.failing_list_saves,
+            }
+        }
+    }
+
+    fn copy<T: Clone>(repository: &InMemoryRepository<T>) -> InMemoryRepository<T> {
+        let mut copy = InMemoryRepository::new();
+        for entity in repository
+            .list(PageRequest::first(usize::MAX))
+            .unwrap()
+            .items
+        {
+            copy.save(&entity).unwrap();
+        }
+        copy
+    }
+
+    fn list(title: &str) -> Entity<List> {
+        ListAggregateRoot::new(ListTitle::new(title).unwrap())
+    }
+
+    fn store(lists: &[&Entity<List>]) -> MemoryStore {
+        let mut store = MemoryStore::default();
+        for list in lists {
+            store.lists.save(list).unwrap();
+        }
+        store
+    }
+
+    #[test]
+    fn test_task_commands() {
+        let (mut inbox, mut done) = (list("Inbox"), list("Done"));
+        let mut handler = TaskCommandHandler::new(store(&[&inbox, &done]));
+
+        let task = handler
+            .handle(CreateTask {
+                name: "  Write docs ".to_owned(),
+                list: inbox.id,
+            })
+            .unwrap();
+        assert_eq!(
+            handler
+                .store()
+                .tasks
+                .get(task)
+                .unwrap()
+                .unwrap()
+                .data
+                .name()
+                .as_str(),
+            "Write docs"
+        );
+        handler
+            .handle(RenameTask {
+                task,
+                name: "Write the docs".to_owned(),
+            })
+            .unwrap();
+        handler
+            .handle(MoveTaskToList {
+                task,
+                list: done.id,
+            })
+            .unwrap();
+
+        let stored = handler.store().tasks.get(task).unwrap().unwrap();
+        assert_eq!(stored.data.name().as_str(), "Write the docs");
+        assert_eq!(stored.data.list(), done.id);
+        inbox = handler.store().lists.get(inbox.id).unwrap().unwrap();
+        done = handler.store().lists.get(done.id).unwrap().unwrap();
+        assert_eq!(inbox.data.position_of(task), None);
+        assert_eq!(done.data.position_of(task), Some(0));
+        assert!(matches!(
+            handler.take_events().first(),
+            Some(TaskDomainEvent::TaskCreated { .. })
+        ));
+        assert!(handler.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_task_command_failures() {
+        let mut archived = list("Old");
+        archived.archive();
+        let inbox = list("Inbox");
+        let mut handler = TaskCommandHandler::new(store(&[&archived, &inbox]));
+
+        assert!(matches!(
+            handler.handle(CreateTask {
+                name: "Task".to_owned(),
+                list: Id::new(),
+            }),
+            Err(ApplicationError::NotFound { .. })
+        ));
+        assert!(matches!(
+            handler.handle(CreateTask {
+                name: "Task".to_owned(),
+                list: archived.id,
+            }),
+            Err(ApplicationError::Domain(
+                TaskDomainError::ListArchived { .. }
+            ))
+        ));
+        assert!(matches!(
+            handler.handle(RenameTask {
+                task: Id::new(),
+                name: "Task".to_owned(),
+            }),
+            Err(ApplicationError::NotFound { .. })
+        ));
+
+        // The task saved before the list failed to save is rolled back with it.
+        handler.store.failing_list_saves = true;
+        assert!(matches!(
+            handler.handle(CreateTask {
+                name: "Task".to_owned(),
+                list: inbox.id,
+            }),
+            Err(ApplicationError::Repository(StorageFailed))
+        ));
+        assert_eq!(
+            handler
+                .store()
+                .tasks
+                .list(PageRequest::first(10))
+                .unwrap()
+                .total,
+            Some(0)
+        );
+        assert!(handler.take_events().is_empty());
+    }
+}
@@ -0,0 +1,19 @@
+use shared_kernel::{Id, NameViolation};
+use task::domain::{error::TaskDomainError, task::Task};
+use thiserror::Error;
+
+/// Represents errors that can occur in the automation domain.
+#[derive(Debug, Error)]
+pub enum AutomationDomainError {
+    /// Error indicating that a rule name failed validation.
+    #[error("invalid rule name: {reason}")]
+    InvalidName { reason: NameViolation },
+
+    /// Error indicating that a command targets a task the command bus was not given.
+    #[error("task {task:?} is not loaded")]
+    TaskNotLoaded { task: Id<Task> },
+
+    /// Error indicating that the task domain rejected a command.
+    #[error(transparent)]
+    Task(#[from] TaskDomainError),
+}
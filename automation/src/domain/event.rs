@@ -0,0 +1,28 @@
+use shared_kernel::Id;
+
+use super::rule::{Condition, Rule, RuleAction, RuleName, Target, Trigger};
+
+/// Represents a domain event raised by an aggregate of the automation context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutomationDomainEvent {
+    /// A rule was created, enabled and without conditions or actions.
+    RuleCreated {
+        rule: Id<Rule>,
+        name: RuleName,
+        trigger: Trigger,
+        target: Target,
+    },
+    /// A rule was renamed.
+    RuleRenamed { rule: Id<Rule>, name: RuleName },
+    /// A condition was added to a rule.
+    ConditionAdded {
+        rule: Id<Rule>,
+        condition: Condition,
+    },
+    /// An action was added to a rule.
+    ActionAdded { rule: Id<Rule>, action: RuleAction },
+    /// A rule was enabled.
+    RuleEnabled { rule: Id<Rule> },
+    /// A rule was disabled.
+    RuleDisabled { rule: Id<Rule> },
+}
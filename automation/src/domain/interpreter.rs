@@ -0,0 +1,436 @@
+use shared_kernel::{Clock, Entity, Id, User};
+use task::domain::{
+    error::TaskDomainError,
+    event::TaskDomainEvent,
+    list::List,
+    name::{LabelName, TaskName},
+    net::{Net, NetAggregateRoot, NetQuery, RelationType, Status},
+    task::{Task, TaskAggregateRoot},
+};
+
+use super::{
+    error::AutomationDomainError,
+    rule::{Rule, RuleAction},
+};
+
+/// A change to the task aggregates requested by a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Move a task of a net to a status.
+    ChangeStatus {
+        net: Id<Net>,
+        task: Id<Task>,
+        status: Id<Status>,
+    },
+    /// Assign a task to a user.
+    Assign { task: Id<Task>, user: Id<User> },
+    /// Add a label to a task.
+    AddLabel { task: Id<Task>, label: LabelName },
+    /// Create a task in a list as a subtask of a task of a net.
+    CreateSubtask {
+        net: Id<Net>,
+        parent: Id<Task>,
+        list: Id<List>,
+        name: TaskName,
+    },
+}
+
+/// Carries out commands requested by rules.
+pub trait CommandBus {
+    /// The error a command fails with.
+    type Error;
+
+    /// Carries out a command.
+    fn dispatch(&mut self, command: Command) -> Result<(), Self::Error>;
+}
+
+/// Works out the commands the rules request in reaction to an event of a net.
+///
+/// Rules are evaluated in order against the state before any of their commands run. Actions
+/// that would change nothing, like moving a task to the status it already has, request no
+/// command, so rules reacting to the changes they make themselves settle instead of looping.
+pub fn plan(
+    rules: &[Entity<Rule>],
+    event: &TaskDomainEvent,
+    net: &Entity<Net>,
+    tasks: &[Entity<Task>],
+) -> Vec<Command> {
+    let mut commands = Vec::new();
+    for rule in rules {
+        let Some(target) = rule.data.target_of(event, net, tasks) else {
+            continue;
+        };
+
+        let task = target.task;
+        for action in rule.data.actions() {
+            let command = match action {
+                RuleAction::ChangeStatus(status) => (net.status_of(task.id) != Some(*status))
+                    .then_some(Command::ChangeStatus {
+                        net: net.id,
+                        task: task.id,
+                        status: *status,
+                    }),
+                RuleAction::Assign(user) => {
+                    (!task.data.assignees().contains(user)).then_some(Command::Assign {
+                        task: task.id,
+                        user: *user,
+                    })
+                }
+                RuleAction::AddLabel(label) => {
+                    (!task.data.labels().contains(label)).then(|| Command::AddLabel {
+                        task: task.id,
+                        label: label.clone(),
+                    })
+                }
+                RuleAction::CreateSubtask(name) => (!has_subtask_named(net, tasks, task.id, name))
+                    .then(|| Command::CreateSubtask {
+                        net: net.id,
+                        parent: task.id,
+                        list: task.data.list(),
+                        name: name.clone(),
+                    }),
+            };
+            commands.extend(command);
+        }
+    }
+
+    commands
+}
+
+/// Checks whether a task of a net already has a direct subtask with the given name.
+fn has_subtask_named(
+    net: &Entity<Net>,
+    tasks: &[Entity<Task>],
+    parent: Id<Task>,
+    name: &TaskName,
+) -> bool {
+    net.direct_subtasks_of(parent).into_iter().any(|subtask| {
+        tasks
+            .iter()
+            .any(|task| task.id == subtask && task.data.name() == name)
+    })
+}
+
+/// Dispatches the commands the rules request in reaction to an event, stopping at the first
+/// one that fails.
+///
+/// The bus usually holds the aggregates the rules read, so the commands are planned against
+/// them first and passed here once they are free to change.
+pub fn execute<B: CommandBus>(
+    commands: impl IntoIterator<Item = Command>,
+    bus: &mut B,
+) -> Result<(), B::Error> {
+    commands
+        .into_iter()
+        .try_for_each(|command| bus.dispatch(command))
+}
+
+/// A bus carrying out commands directly on a net and its tasks, checking labels against the
+/// lists of the tasks.
+///
+/// The events the commands raise stay pending on the aggregates; feeding them back through
+/// [`plan`] lets rules react to each other.
+pub struct AggregateBus<'a, C> {
+    net: &'a mut Entity<Net>,
+    lists: &'a [Entity<List>],
+    tasks: &'a mut Vec<Entity<Task>>,
+    clock: &'a C,
+}
+
+impl<'a, C: Clock> AggregateBus<'a, C> {
+    /// Creates a bus changing the given net and tasks, with new tasks added to the tasks.
+    pub fn new(
+        net: &'a mut Entity<Net>,
+        lists: &'a [Entity<List>],
+        tasks: &'a mut Vec<Entity<Task>>,
+        clock: &'a C,
+    ) -> Self {
+        Self {
+            net,
+            lists,
+            tasks,
+            clock,
+        }
+    }
+
+    /// Finds a task, failing if it is not among the tasks of the bus.
+    fn task_mut(&mut self, task: Id<Task>) -> Result<&mut Entity<Task>, AutomationDomainError> {
+        self.tasks
+            .iter_mut()
+            .find(|candidate| candidate.id == task)
+            .ok_or(AutomationDomainError::TaskNotLoaded { task })
+    }
+}
+
+impl<C: Clock> CommandBus for AggregateBus<'_, C> {
+    type Error = AutomationDomainError;
+
+    /// Carries out a command; creating a subtask the parent already has under the same name
+    /// does nothing, so redelivered commands are harmless.
+    fn dispatch(&mut self, command: Command) -> Result<(), Self::Error> {
+        match command {
+            Command::ChangeStatus { task, status, .. } => {
+                self.net.change_task_status(task, status, self.clock)?;
+            }
+            Command::Assign { task, user } => self.task_mut(task)?.assign(user)?,
+            Command::AddLabel { task, label } => {
+                let lists = self.lists;
+                let task = self.task_mut(task)?;
+                let list = task.data.list();
+                let list = lists
+                    .iter()
+                    .find(|candidate| candidate.id == list)
+                    .ok_or(TaskDomainError::ListNotFound { list })?;
+                task.add_label(list, label)?;
+            }
+            Command::CreateSubtask {
+                parent, list, name, ..
+            } => {
+                if has_subtask_named(self.net, self.tasks, parent, &name) {
+                    return Ok(());
+                }
+                let subtask: Entity<Task> = TaskAggregateRoot::new(name, list);
+                self.net.add_task(subtask.id)?;
+                self.net
+                    .new_relation(subtask.id, parent, RelationType::Compose, self.clock)?;
+                self.tasks.push(subtask);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{AggregateRoot, SystemClock};
+    use task::domain::{
+        list::{Label, ListAggregateRoot},
+        name::{ListTitle, StatusName},
+        net::Color,
+    };
+
+    use super::*;
+    use crate::domain::rule::{Condition, RuleAggregateRoot, RuleName, Target, Trigger};
+
+    fn task(name: &str, list: Id<List>) -> Entity<Task> {
+        TaskAggregateRoot::new(TaskName::new(name).unwrap(), list)
+    }
+
+    fn list(labels: &[&LabelName]) -> Entity<List> {
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let color = Color {
+            red: 255,
+            green: 0,
+            blue: 0,
+        };
+        for label in labels {
+            list.define_label(Label::new((*label).clone(), color))
+                .unwrap();
+        }
+        list
+    }
+
+    #[test]
+    fn test_hand_finished_epics_over_for_review() {
+        let review = LabelName::new("review").unwrap();
+        let lists = [list(&[&review])];
+        let list = lists[0].id;
+        let mut tasks = vec![
+            task("Epic", list),
+            task("First", list),
+            task("Second", list),
+        ];
+        let [epic, first, second] = [tasks[0].id, tasks[1].id, tasks[2].id];
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        for task in [epic, first, second] {
+            net.add_task(task).unwrap();
+        }
+        for child in [first, second] {
+            net.new_relation(child, epic, RelationType::Compose, &SystemClock)
+                .unwrap();
+        }
+
+        let reviewer = Id::new();
+        let mut rule: Entity<Rule> = RuleAggregateRoot::new(
+            RuleName::new("Review finished epics").unwrap(),
+            Trigger::StatusChanged {
+                to: Some(net.accepted_status()),
+            },
+            Target::Parent,
+        );
+        rule.add_condition(Condition::AllSubtasksAccepted);
+        rule.add_action(RuleAction::AddLabel(review.clone()));
+        rule.add_action(RuleAction::Assign(reviewer));
+        let rules = [rule];
+
+        let react = |net: &mut Entity<Net>, tasks: &mut Vec<Entity<Task>>, task| {
+            net.change_task_status(task, net.accepted_status(), &SystemClock)
+                .unwrap();
+            let events = net.data.take_events();
+            let commands: Vec<_> = events
+                .iter()
+                .flat_map(|event| plan(&rules, event, net, tasks))
+                .collect();
+            execute(
+                commands,
+                &mut AggregateBus::new(net, &lists, tasks, &SystemClock),
+            )
+            .unwrap();
+        };
+
+        react(&mut net, &mut tasks, first);
+        assert!(tasks[0].data.labels().is_empty());
+        react(&mut net, &mut tasks, second);
+        assert_eq!(tasks[0].data.labels(), [review]);
+        assert_eq!(tasks[0].data.assignees(), [reviewer]);
+    }
+
+    #[test]
+    fn test_rules_react_to_each_other() {
+        let label = LabelName::new("bug").unwrap();
+        let lists = [list(&[&label])];
+        let mut tasks = vec![task("Bug", lists[0].id)];
+        let bug = tasks[0].id;
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        let doing = net
+            .statuses()
+            .find(|status| status.data.name() == &StatusName::new("Doing").unwrap())
+            .unwrap()
+            .id;
+        net.add_task(bug).unwrap();
+        let user = Id::new();
+
+        let mut triage: Entity<Rule> = RuleAggregateRoot::new(
+            RuleName::new("Triage bugs").unwrap(),
+            Trigger::TaskLabeled {
+                label: Some(label.clone()),
+            },
+            Target::Subject,
+        );
+        triage.add_action(RuleAction::Assign(user));
+        triage.add_action(RuleAction::AddLabel(label.clone()));
+        let mut start: Entity<Rule> = RuleAggregateRoot::new(
+            RuleName::new("Start assigned tasks").unwrap(),
+            Trigger::TaskAssigned { user: None },
+            Target::Subject,
+        );
+        start.add_action(RuleAction::ChangeStatus(doing));
+        let rules = [triage, start];
+
+        tasks[0].add_label(&lists[0], label.clone()).unwrap();
+        let event = tasks[0].data.take_events().pop().unwrap();
+        let commands = plan(&rules, &event, &net, &tasks);
+        assert_eq!(commands, [Command::Assign { task: bug, user }]);
+        execute(
+            commands,
+            &mut AggregateBus::new(&mut net, &lists, &mut tasks, &SystemClock),
+        )
+        .unwrap();
+
+        let event = tasks[0].data.take_events().pop().unwrap();
+        let commands = plan(&rules, &event, &net, &tasks);
+        execute(
+            commands,
+            &mut AggregateBus::new(&mut net, &lists, &mut tasks, &SystemClock),
+        )
+        .unwrap();
+        assert_eq!(net.status_of(bug), Some(doing));
+        assert!(plan(&rules, &event, &net, &tasks).is_empty());
+    }
+
+    #[test]
+    fn test_create_subtask() {
+        let bug_label = LabelName::new("bug").unwrap();
+        let lists = [list(&[&bug_label])];
+        let list = lists[0].id;
+        let mut tasks = vec![task("Bug", list)];
+        let bug = tasks[0].id;
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.add_task(bug).unwrap();
+
+        let mut rule: Entity<Rule> = RuleAggregateRoot::new(
+            RuleName::new("Reproduce bugs").unwrap(),
+            Trigger::TaskLabeled { label: None },
+            Target::Subject,
+        );
+        rule.add_action(RuleAction::CreateSubtask(
+            TaskName::new("Reproduce").unwrap(),
+        ));
+        let rules = [rule];
+
+        tasks[0].add_label(&lists[0], bug_label).unwrap();
+        let event = tasks[0].data.take_events().pop().unwrap();
+        let commands = plan(&rules, &event, &net, &tasks);
+        execute(
+            commands,
+            &mut AggregateBus::new(&mut net, &lists, &mut tasks, &SystemClock),
+        )
+        .unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[1].data.list(), list);
+        assert_eq!(net.direct_subtasks_of(bug), vec![tasks[1].id]);
+
+        // The subtask is there already, so the rule requests nothing and a redelivered
+        // command changes nothing.
+        assert!(plan(&rules, &event, &net, &tasks).is_empty());
+        let redelivered = Command::CreateSubtask {
+            net: net.id,
+            parent: bug,
+            list,
+            name: TaskName::new("Reproduce").unwrap(),
+        };
+        execute(
+            [redelivered],
+            &mut AggregateBus::new(&mut net, &lists, &mut tasks, &SystemClock),
+        )
+        .unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(net.direct_subtasks_of(bug), vec![tasks[1].id]);
+        assert!(matches!(
+            execute(
+                [Command::Assign {
+                    task: Id::new(),
+                    user: Id::new()
+                }],
+                &mut AggregateBus::new(&mut net, &lists, &mut tasks, &SystemClock),
+            ),
+            Err(AutomationDomainError::TaskNotLoaded { .. })
+        ));
+        assert!(matches!(
+            execute(
+                [Command::AddLabel {
+                    task: bug,
+                    label: LabelName::new("docs").unwrap(),
+                }],
+                &mut AggregateBus::new(&mut net, &lists, &mut tasks, &SystemClock),
+            ),
+            Err(AutomationDomainError::Task(
+                TaskDomainError::LabelNotDefined { .. }
+            ))
+        ));
+        assert!(matches!(
+            execute(
+                [Command::AddLabel {
+                    task: bug,
+                    label: LabelName::new("bug").unwrap(),
+                }],
+                &mut AggregateBus::new(&mut net, &[], &mut tasks, &SystemClock),
+            ),
+            Err(AutomationDomainError::Task(
+                TaskDomainError::ListNotFound { .. }
+            ))
+        ));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod error;
+pub mod event;
+pub mod interpreter;
+pub mod rule;
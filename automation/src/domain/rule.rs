@@ -0,0 +1,448 @@
+use shared_kernel::{AggregateRoot, Entity, EntityKind, Id, Specification, User};
+use task::domain::{
+    event::TaskDomainEvent,
+    name::{LabelName, TaskName},
+    net::{Net, NetQuery, Status},
+    smart_list::TaskFilter,
+    specification::TaskCandidate,
+    task::Task,
+};
+
+use super::{error::AutomationDomainError, event::AutomationDomainEvent};
+
+shared_kernel::validated_text!(
+    /// The validated name of a rule.
+    RuleName,
+    128,
+    AutomationDomainError,
+    |reason| AutomationDomainError::InvalidName { reason }
+);
+
+/// A pattern over task domain events that sets a rule off.
+///
+/// Fields left as `None` match any value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Trigger {
+    /// A task was created.
+    TaskCreated,
+    /// A task of the net moved to a status, directly or by propagation.
+    StatusChanged { to: Option<Id<Status>> },
+    /// A task was assigned to a user.
+    TaskAssigned { user: Option<Id<User>> },
+    /// A label was added to a task.
+    TaskLabeled { label: Option<LabelName> },
+}
+
+/// Checks whether a value matches a trigger field, `None` matching any value.
+fn matches<T: PartialEq>(pattern: &Option<T>, value: &T) -> bool {
+    pattern.as_ref().is_none_or(|pattern| pattern == value)
+}
+
+impl Trigger {
+    /// Returns the task an event is about if it matches the trigger in the given net.
+    pub fn subject_of(&self, event: &TaskDomainEvent, net: Id<Net>) -> Option<Id<Task>> {
+        match (self, event) {
+            (Trigger::TaskCreated, TaskDomainEvent::TaskCreated { task, .. }) => Some(*task),
+            (
+                Trigger::StatusChanged { to: pattern },
+                TaskDomainEvent::TaskStatusChanged {
+                    net: event_net,
+                    task,
+                    to,
+                    ..
+                }
+                | TaskDomainEvent::StatusPropagated {
+                    net: event_net,
+                    task,
+                    to,
+                    ..
+                },
+            ) if *event_net == net && matches(pattern, to) => Some(*task),
+            (
+                Trigger::TaskAssigned { user: pattern },
+                TaskDomainEvent::TaskAssigned { task, user },
+            ) if matches(pattern, user) => Some(*task),
+            (
+                Trigger::TaskLabeled { label: pattern },
+                TaskDomainEvent::TaskLabeled { task, label },
+            ) if matches(pattern, label) => Some(*task),
+            _ => None,
+        }
+    }
+}
+
+/// The task a rule checks its conditions against and acts upon, relative to the task its
+/// trigger matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Target {
+    /// The task the event is about.
+    Subject,
+    /// The parent of that task in the net.
+    Parent,
+}
+
+/// A condition the target of a rule must satisfy for the rule to act.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Condition {
+    /// Satisfied by tasks matching a filter.
+    Matches(TaskFilter),
+    /// Satisfied by tasks with subtasks, all of them accepted.
+    AllSubtasksAccepted,
+}
+
+impl Specification<TaskCandidate<'_>> for Condition {
+    fn is_satisfied_by(&self, candidate: &TaskCandidate<'_>) -> bool {
+        match self {
+            Condition::Matches(filter) => filter.is_satisfied_by(candidate),
+            Condition::AllSubtasksAccepted => {
+                let net = candidate.net;
+                let subtasks = net.direct_subtasks_of(candidate.task.id);
+                !subtasks.is_empty()
+                    && subtasks.into_iter().all(|subtask| {
+                        net.status_of(subtask)
+                            .is_some_and(|status| net.accepted_statuses().any(|a| a == status))
+                    })
+            }
+        }
+    }
+}
+
+/// Something a rule does to its target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RuleAction {
+    /// Moves the target to a status of the net.
+    ChangeStatus(Id<Status>),
+    /// Assigns the target to a user.
+    Assign(Id<User>),
+    /// Adds a label to the target.
+    AddLabel(LabelName),
+    /// Creates a subtask of the target in its list.
+    CreateSubtask(TaskName),
+}
+
+/// Represents an automation rule: when its trigger matches an event and its conditions hold
+/// for its target, its actions are carried out on that target.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "RuleRecord", from = "RuleRecord")
+)]
+pub struct Rule {
+    name: RuleName,
+    trigger: Trigger,
+    target: Target,
+    conditions: Vec<Condition>,
+    actions: Vec<RuleAction>,
+    enabled: bool,
+    events: Vec<AutomationDomainEvent>,
+}
+
+/// The versioned on-disk form of a rule; pending events are not persisted.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+enum RuleRecord {
+    #[serde(rename = "1")]
+    V1 {
+        name: RuleName,
+        trigger: Trigger,
+        target: Target,
+        conditions: Vec<Condition>,
+        actions: Vec<RuleAction>,
+        enabled: bool,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<Rule> for RuleRecord {
+    fn from(rule: Rule) -> Self {
+        RuleRecord::V1 {
+            name: rule.name,
+            trigger: rule.trigger,
+            target: rule.target,
+            conditions: rule.conditions,
+            actions: rule.actions,
+            enabled: rule.enabled,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<RuleRecord> for Rule {
+    fn from(record: RuleRecord) -> Self {
+        match record {
+            RuleRecord::V1 {
+                name,
+                trigger,
+                target,
+                conditions,
+                actions,
+                enabled,
+            } => Rule {
+                name,
+                trigger,
+                target,
+                conditions,
+                actions,
+                enabled,
+                events: Vec::new(),
+            },
+        }
+    }
+}
+
+impl EntityKind for Rule {
+    const KIND: &'static str = "rule";
+}
+
+impl Rule {
+    /// Returns the name of the rule.
+    pub fn name(&self) -> &RuleName {
+        &self.name
+    }
+
+    /// Returns the trigger of the rule.
+    pub fn trigger(&self) -> &Trigger {
+        &self.trigger
+    }
+
+    /// Returns the target of the rule.
+    pub fn target(&self) -> Target {
+        self.target
+    }
+
+    /// Lists the conditions of the rule, all of which must hold.
+    pub fn conditions(&self) -> &[Condition] {
+        &self.conditions
+    }
+
+    /// Lists the actions of the rule in the order they are carried out.
+    pub fn actions(&self) -> &[RuleAction] {
+        &self.actions
+    }
+
+    /// Checks whether the rule reacts to events.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the task the rule acts upon in reaction to an event, if it is enabled, its
+    /// trigger matches and its conditions hold.
+    ///
+    /// Tasks missing from the given tasks are never acted upon.
+    pub fn target_of<'a>(
+        &self,
+        event: &TaskDomainEvent,
+        net: &'a Entity<Net>,
+        tasks: &'a [Entity<Task>],
+    ) -> Option<TaskCandidate<'a>> {
+        if !self.enabled {
+            return None;
+        }
+
+        let subject = self.trigger.subject_of(event, net.id)?;
+        let target = match self.target {
+            Target::Subject => subject,
+            Target::Parent => net.parent_of(subject)?,
+        };
+        let task = tasks.iter().find(|task| task.id == target)?;
+        let candidate = TaskCandidate { task, net };
+
+        self.conditions
+            .iter()
+            .all(|condition| condition.is_satisfied_by(&candidate))
+            .then_some(candidate)
+    }
+}
+
+/// Trait for aggregate root operations on a `Rule`.
+pub trait RuleAggregateRoot {
+    /// Creates an enabled rule without conditions or actions.
+    fn new(name: RuleName, trigger: Trigger, target: Target) -> Self;
+
+    /// Gives the rule a new name.
+    fn rename(&mut self, name: RuleName);
+
+    /// Adds a condition the target must satisfy.
+    fn add_condition(&mut self, condition: Condition);
+
+    /// Adds an action carried out after the existing ones.
+    fn add_action(&mut self, action: RuleAction);
+
+    /// Lets the rule react to events again; does nothing if it already does.
+    fn enable(&mut self);
+
+    /// Stops the rule from reacting to events; does nothing if it already has.
+    fn disable(&mut self);
+}
+
+impl RuleAggregateRoot for Entity<Rule> {
+    fn new(name: RuleName, trigger: Trigger, target: Target) -> Self {
+        let id = Id::new();
+        Entity {
+            id,
+            data: Rule {
+                name: name.clone(),
+                trigger: trigger.clone(),
+                target,
+                conditions: Vec::new(),
+                actions: Vec::new(),
+                enabled: true,
+                events: vec![AutomationDomainEvent::RuleCreated {
+                    rule: id,
+                    name,
+                    trigger,
+                    target,
+                }],
+            },
+        }
+    }
+
+    fn rename(&mut self, name: RuleName) {
+        self.data.name = name.clone();
+        self.data.events.push(AutomationDomainEvent::RuleRenamed {
+            rule: self.id,
+            name,
+        });
+    }
+
+    fn add_condition(&mut self, condition: Condition) {
+        self.data.conditions.push(condition.clone());
+        self.data
+            .events
+            .push(AutomationDomainEvent::ConditionAdded {
+                rule: self.id,
+                condition,
+            });
+    }
+
+    fn add_action(&mut self, action: RuleAction) {
+        self.data.actions.push(action.clone());
+        self.data.events.push(AutomationDomainEvent::ActionAdded {
+            rule: self.id,
+            action,
+        });
+    }
+
+    fn enable(&mut self) {
+        if self.data.enabled {
+            return;
+        }
+
+        self.data.enabled = true;
+        self.data
+            .events
+            .push(AutomationDomainEvent::RuleEnabled { rule: self.id });
+    }
+
+    fn disable(&mut self) {
+        if !self.data.enabled {
+            return;
+        }
+
+        self.data.enabled = false;
+        self.data
+            .events
+            .push(AutomationDomainEvent::RuleDisabled { rule: self.id });
+    }
+}
+
+impl AggregateRoot for Rule {
+    type Event = AutomationDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::NameViolation;
+    use task::domain::{name::StatusName, net::NetAggregateRoot};
+
+    use super::*;
+
+    #[test]
+    fn test_trigger_patterns() {
+        let net = Id::new();
+        let (task, status) = (Id::new(), Id::new());
+        let changed = TaskDomainEvent::TaskStatusChanged {
+            net,
+            task,
+            from: Id::new(),
+            to: status,
+            reason: None,
+        };
+
+        assert_eq!(
+            Trigger::StatusChanged { to: None }.subject_of(&changed, net),
+            Some(task)
+        );
+        assert_eq!(
+            Trigger::StatusChanged { to: Some(status) }.subject_of(&changed, net),
+            Some(task)
+        );
+        assert_eq!(
+            Trigger::StatusChanged {
+                to: Some(Id::new())
+            }
+            .subject_of(&changed, net),
+            None
+        );
+        assert_eq!(
+            Trigger::StatusChanged { to: None }.subject_of(&changed, Id::new()),
+            None
+        );
+        assert_eq!(Trigger::TaskCreated.subject_of(&changed, net), None);
+
+        let label = LabelName::new("bug").unwrap();
+        let labeled = TaskDomainEvent::TaskLabeled {
+            task,
+            label: label.clone(),
+        };
+        assert_eq!(
+            Trigger::TaskLabeled { label: Some(label) }.subject_of(&labeled, net),
+            Some(task)
+        );
+    }
+
+    #[test]
+    fn test_rule_lifecycle() {
+        let net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let mut rule: Entity<Rule> = RuleAggregateRoot::new(
+            RuleName::new("Triage").unwrap(),
+            Trigger::TaskCreated,
+            Target::Subject,
+        );
+        rule.add_action(RuleAction::AddLabel(LabelName::new("triage").unwrap()));
+        rule.disable();
+        rule.disable();
+
+        let event = TaskDomainEvent::TaskCreated {
+            task: Id::new(),
+            name: TaskName::new("Task").unwrap(),
+            list: Id::new(),
+        };
+        assert!(rule.data.target_of(&event, &net, &[]).is_none());
+        rule.enable();
+        rule.rename(RuleName::new("Label new tasks").unwrap());
+        assert!(rule.data.is_enabled());
+        assert_eq!(rule.data.name(), &"Label new tasks");
+        assert!(matches!(
+            RuleName::new(" "),
+            Err(AutomationDomainError::InvalidName {
+                reason: NameViolation::WhitespaceOnly
+            })
+        ));
+        assert_eq!(rule.data.take_events().len(), 5);
+    }
+}
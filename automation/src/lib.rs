@@ -0,0 +1,4 @@
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+/// The `domain` module contains the core domain logic for rules automating work on tasks.
+pub mod domain;
@@ -0,0 +1,514 @@
+use shared_kernel::{AggregateRoot, Clock, Entity, EntityKind, Id, Timestamp, User};
+use task::domain::task::Task;
+
+use super::{error::CommentDomainError, event::CommentDomainEvent};
+
+type CommentDomainResult<T> = Result<T, CommentDomainError>;
+
+shared_kernel::validated_text!(
+    /// The validated Markdown body of a comment.
+    CommentBody,
+    10_000,
+    CommentDomainError,
+    |reason| CommentDomainError::InvalidBody { reason }
+);
+
+/// A short reaction to a comment, such as an emoji or a shortcode like `:+1:`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+pub struct Reaction(String);
+
+impl Reaction {
+    /// The maximum number of characters.
+    pub const MAX_LEN: usize = 32;
+
+    /// Validates and wraps a reaction, which must not be blank or contain whitespace.
+    pub fn new(value: impl Into<String>) -> CommentDomainResult<Self> {
+        let value = value.into();
+        if value.is_empty()
+            || value.chars().any(char::is_whitespace)
+            || value.chars().count() > Self::MAX_LEN
+        {
+            return Err(CommentDomainError::InvalidReaction { reaction: value });
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Returns the reaction as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Reaction> for String {
+    fn from(value: Reaction) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<String> for Reaction {
+    type Error = CommentDomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+/// Represents a comment on a task, possibly replying to another comment of the same task.
+///
+/// Deleting a comment keeps it as a tombstone without a body, so replies stay in their thread.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "CommentRecord", from = "CommentRecord")
+)]
+pub struct Comment {
+    task: Id<Task>,
+    author: Id<User>,
+    parent: Option<Id<Comment>>,
+    body: Option<CommentBody>,
+    posted_at: Timestamp,
+    edited_at: Option<Timestamp>,
+    deleted_at: Option<Timestamp>,
+    reactions: Vec<(Id<User>, Reaction)>,
+    events: Vec<CommentDomainEvent>,
+}
+
+/// The versioned on-disk form of a comment; pending events are not persisted.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+enum CommentRecord {
+    #[serde(rename = "1")]
+    V1 {
+        task: Id<Task>,
+        author: Id<User>,
+        parent: Option<Id<Comment>>,
+        body: Option<CommentBody>,
+        posted_at: Timestamp,
+        edited_at: Option<Timestamp>,
+        deleted_at: Option<Timestamp>,
+        reactions: Vec<(Id<User>, Reaction)>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<Comment> for CommentRecord {
+    fn from(comment: Comment) -> Self {
+        CommentRecord::V1 {
+            task: comment.task,
+            author: comment.author,
+            parent: comment.parent,
+            body: comment.body,
+            posted_at: comment.posted_at,
+            edited_at: comment.edited_at,
+            deleted_at: comment.deleted_at,
+            reactions: comment.reactions,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<CommentRecord> for Comment {
+    fn from(record: CommentRecord) -> Self {
+        match record {
+            CommentRecord::V1 {
+                task,
+                author,
+                parent,
+                body,
+                posted_at,
+                edited_at,
+                deleted_at,
+                reactions,
+            } => Comment {
+                task,
+                author,
+                parent,
+                body,
+                posted_at,
+                edited_at,
+                deleted_at,
+                reactions,
+                events: Vec::new(),
+            },
+        }
+    }
+}
+
+impl EntityKind for Comment {
+    const KIND: &'static str = "comment";
+}
+
+impl Comment {
+    /// Returns the task the comment is about.
+    pub fn task(&self) -> Id<Task> {
+        self.task
+    }
+
+    /// Returns the user who wrote the comment.
+    pub fn author(&self) -> Id<User> {
+        self.author
+    }
+
+    /// Returns the comment this one replies to, if any.
+    pub fn parent(&self) -> Option<Id<Comment>> {
+        self.parent
+    }
+
+    /// Returns the body of the comment, or `None` once it is deleted.
+    pub fn body(&self) -> Option<&CommentBody> {
+        self.body.as_ref()
+    }
+
+    /// Returns when the comment was posted.
+    pub fn posted_at(&self) -> Timestamp {
+        self.posted_at
+    }
+
+    /// Returns when the comment was last edited, if it was.
+    pub fn edited_at(&self) -> Option<Timestamp> {
+        self.edited_at
+    }
+
+    /// Returns when the comment was deleted, if it was.
+    pub fn deleted_at(&self) -> Option<Timestamp> {
+        self.deleted_at
+    }
+
+    /// Checks whether the comment is deleted.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Lists the reactions to the comment with the users who reacted, oldest first.
+    pub fn reactions(&self) -> &[(Id<User>, Reaction)] {
+        &self.reactions
+    }
+
+    /// Fails if the comment is deleted.
+    fn ensure_not_deleted(&self, comment: Id<Comment>) -> CommentDomainResult<()> {
+        if self.is_deleted() {
+            return Err(CommentDomainError::CommentDeleted { comment });
+        }
+
+        Ok(())
+    }
+}
+
+/// Trait for aggregate root operations on a `Comment`.
+pub trait CommentAggregateRoot {
+    /// Posts a new comment on a task.
+    fn new(task: Id<Task>, author: Id<User>, body: CommentBody, clock: &impl Clock) -> Self;
+
+    /// Posts a reply to the comment on the same task.
+    fn reply(
+        &self,
+        author: Id<User>,
+        body: CommentBody,
+        clock: &impl Clock,
+    ) -> CommentDomainResult<Self>
+    where
+        Self: Sized;
+
+    /// Replaces the body of the comment on behalf of its author.
+    fn edit(
+        &mut self,
+        editor: Id<User>,
+        body: CommentBody,
+        clock: &impl Clock,
+    ) -> CommentDomainResult<()>;
+
+    /// Deletes the comment on behalf of its author, keeping a tombstone without a body in its
+    /// place.
+    fn delete(&mut self, actor: Id<User>, clock: &impl Clock) -> CommentDomainResult<()>;
+
+    /// Adds a reaction of a user to the comment.
+    fn react(&mut self, user: Id<User>, reaction: Reaction) -> CommentDomainResult<()>;
+
+    /// Takes back a reaction of a user to the comment.
+    fn unreact(&mut self, user: Id<User>, reaction: &Reaction) -> CommentDomainResult<()>;
+}
+
+impl CommentAggregateRoot for Entity<Comment> {
+    fn new(task: Id<Task>, author: Id<User>, body: CommentBody, clock: &impl Clock) -> Self {
+        post(task, author, None, body, clock)
+    }
+
+    fn reply(
+        &self,
+        author: Id<User>,
+        body: CommentBody,
+        clock: &impl Clock,
+    ) -> CommentDomainResult<Self> {
+        self.data.ensure_not_deleted(self.id)?;
+
+        Ok(post(self.data.task, author, Some(self.id), body, clock))
+    }
+
+    fn edit(
+        &mut self,
+        editor: Id<User>,
+        body: CommentBody,
+        clock: &impl Clock,
+    ) -> CommentDomainResult<()> {
+        self.data.ensure_not_deleted(self.id)?;
+        if editor != self.data.author {
+            return Err(CommentDomainError::NotAuthor {
+                comment: self.id,
+                user: editor,
+            });
+        }
+
+        let edited_at = clock.now();
+        self.data.body = Some(body.clone());
+        self.data.edited_at = Some(edited_at);
+        self.data.events.push(CommentDomainEvent::CommentEdited {
+            comment: self.id,
+            body,
+            edited_at,
+        });
+
+        Ok(())
+    }
+
+    fn delete(&mut self, actor: Id<User>, clock: &impl Clock) -> CommentDomainResult<()> {
+        self.data.ensure_not_deleted(self.id)?;
+        if actor != self.data.author {
+            return Err(CommentDomainError::NotAuthor {
+                comment: self.id,
+                user: actor,
+            });
+        }
+
+        let deleted_at = clock.now();
+        self.data.body = None;
+        self.data.deleted_at = Some(deleted_at);
+        self.data.events.push(CommentDomainEvent::CommentDeleted {
+            comment: self.id,
+            deleted_at,
+        });
+
+        Ok(())
+    }
+
+    fn react(&mut self, user: Id<User>, reaction: Reaction) -> CommentDomainResult<()> {
+        self.data.ensure_not_deleted(self.id)?;
+        if self
+            .data
+            .reactions
+            .iter()
+            .any(|(u, r)| *u == user && *r == reaction)
+        {
+            return Err(CommentDomainError::AlreadyReacted {
+                comment: self.id,
+                user,
+                reaction,
+            });
+        }
+
+        self.data.reactions.push((user, reaction.clone()));
+        self.data.events.push(CommentDomainEvent::ReactionAdded {
+            comment: self.id,
+            user,
+            reaction,
+        });
+
+        Ok(())
+    }
+
+    fn unreact(&mut self, user: Id<User>, reaction: &Reaction) -> CommentDomainResult<()> {
+        self.data.ensure_not_deleted(self.id)?;
+        let Some(index) = self
+            .data
+            .reactions
+            .iter()
+            .position(|(u, r)| *u == user && r == reaction)
+        else {
+            return Err(CommentDomainError::NotReacted {
+                comment: self.id,
+                user,
+                reaction: reaction.clone(),
+            });
+        };
+
+        self.data.reactions.remove(index);
+        self.data.events.push(CommentDomainEvent::ReactionRemoved {
+            comment: self.id,
+            user,
+            reaction: reaction.clone(),
+        });
+
+        Ok(())
+    }
+}
+
+/// Creates a comment and records that it was posted.
+fn post(
+    task: Id<Task>,
+    author: Id<User>,
+    parent: Option<Id<Comment>>,
+    body: CommentBody,
+    clock: &impl Clock,
+) -> Entity<Comment> {
+    let id = Id::new();
+    let posted_at = clock.now();
+    Entity {
+        id,
+        data: Comment {
+            task,
+            author,
+            parent,
+            body: Some(body.clone()),
+            posted_at,
+            edited_at: None,
+            deleted_at: None,
+            reactions: Vec::new(),
+            events: vec![CommentDomainEvent::CommentPosted {
+                comment: id,
+                task,
+                author,
+                parent,
+                body,
+                posted_at,
+            }],
+        },
+    }
+}
+
+impl AggregateRoot for Comment {
+    type Event = CommentDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{NameViolation, SystemClock};
+
+    use super::*;
+
+    fn body(text: &str) -> CommentBody {
+        CommentBody::new(text).unwrap()
+    }
+
+    #[test]
+    fn test_body_validation() {
+        assert!(matches!(
+            CommentBody::new(""),
+            Err(CommentDomainError::InvalidBody {
+                reason: NameViolation::Empty
+            })
+        ));
+        assert!(matches!(
+            CommentBody::new(" \n"),
+            Err(CommentDomainError::InvalidBody {
+                reason: NameViolation::WhitespaceOnly
+            })
+        ));
+        assert!(CommentBody::new("x".repeat(CommentBody::MAX_LEN + 1)).is_err());
+        assert!(Reaction::new(":+1:").is_ok());
+        assert!(Reaction::new("thumbs up").is_err());
+    }
+
+    #[test]
+    fn test_thread() {
+        let task = Id::new();
+        let alice = Id::new();
+        let bob = Id::new();
+        let comment: Entity<Comment> =
+            CommentAggregateRoot::new(task, alice, body("Ready for review?"), &SystemClock);
+        let reply = comment
+            .reply(bob, body("Looks good"), &SystemClock)
+            .unwrap();
+
+        assert_eq!(reply.data.task(), task);
+        assert_eq!(reply.data.parent(), Some(comment.id));
+        assert_eq!(reply.data.author(), bob);
+        assert_eq!(comment.data.parent(), None);
+    }
+
+    #[test]
+    fn test_edit_and_delete() {
+        let alice = Id::new();
+        let mut comment: Entity<Comment> =
+            CommentAggregateRoot::new(Id::new(), alice, body("Draft"), &SystemClock);
+
+        assert!(matches!(
+            comment.edit(Id::new(), body("Hijacked"), &SystemClock),
+            Err(CommentDomainError::NotAuthor { .. })
+        ));
+        comment.edit(alice, body("Final"), &SystemClock).unwrap();
+        assert_eq!(comment.data.body().unwrap(), &"Final");
+        assert!(comment.data.edited_at().is_some());
+
+        assert!(matches!(
+            comment.delete(Id::new(), &SystemClock),
+            Err(CommentDomainError::NotAuthor { .. })
+        ));
+        assert!(!comment.data.is_deleted());
+        comment.delete(alice, &SystemClock).unwrap();
+        assert!(comment.data.is_deleted());
+        assert_eq!(comment.data.body(), None);
+        assert!(matches!(
+            comment.delete(alice, &SystemClock),
+            Err(CommentDomainError::CommentDeleted { .. })
+        ));
+        assert!(matches!(
+            comment.reply(alice, body("Anyone?"), &SystemClock),
+            Err(CommentDomainError::CommentDeleted { .. })
+        ));
+        assert_eq!(comment.take_events().len(), 3);
+    }
+
+    #[test]
+    fn test_reactions() {
+        let alice = Id::new();
+        let bob = Id::new();
+        let thumbs_up = Reaction::new(":+1:").unwrap();
+        let mut comment: Entity<Comment> =
+            CommentAggregateRoot::new(Id::new(), alice, body("Shipped"), &SystemClock);
+
+        comment.react(alice, thumbs_up.clone()).unwrap();
+        comment.react(bob, thumbs_up.clone()).unwrap();
+        assert!(matches!(
+            comment.react(bob, thumbs_up.clone()),
+            Err(CommentDomainError::AlreadyReacted { .. })
+        ));
+        assert_eq!(comment.data.reactions().len(), 2);
+
+        comment.unreact(alice, &thumbs_up).unwrap();
+        assert!(matches!(
+            comment.unreact(alice, &thumbs_up),
+            Err(CommentDomainError::NotReacted { .. })
+        ));
+        assert_eq!(comment.data.reactions(), [(bob, thumbs_up)]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let author = Id::new();
+        let mut comment: Entity<Comment> =
+            CommentAggregateRoot::new(Id::new(), author, body("Noted"), &SystemClock);
+        comment.delete(author, &SystemClock).unwrap();
+        let json = serde_json::to_value(&comment).unwrap();
+
+        assert_eq!(json["data"]["version"], "1");
+        assert_eq!(json["data"]["body"], serde_json::Value::Null);
+
+        let mut restored: Entity<Comment> = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.id, comment.id);
+        assert!(restored.data.is_deleted());
+        assert!(restored.data.take_events().is_empty());
+    }
+}
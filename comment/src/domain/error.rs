@@ -0,0 +1,43 @@
+use shared_kernel::{Id, NameViolation, User};
+use thiserror::Error;
+
+use super::comment::{Comment, Reaction};
+
+/// Represents errors that can occur in the comment domain.
+#[derive(Debug, Error)]
+pub enum CommentDomainError {
+    /// Error indicating that a comment body failed validation.
+    #[error("invalid comment body: {reason}")]
+    InvalidBody { reason: NameViolation },
+
+    /// Error indicating that a reaction is empty or too long.
+    #[error("invalid reaction {reaction:?}")]
+    InvalidReaction { reaction: String },
+
+    /// Error indicating that a deleted comment cannot be changed or replied to.
+    #[error("comment {comment:?} is deleted")]
+    CommentDeleted { comment: Id<Comment> },
+
+    /// Error indicating that only the author of a comment may edit or delete it.
+    #[error("user {user:?} is not the author of comment {comment:?}")]
+    NotAuthor {
+        comment: Id<Comment>,
+        user: Id<User>,
+    },
+
+    /// Error indicating that a user already reacted to a comment in the same way.
+    #[error("user {user:?} already reacted with {reaction:?} to comment {comment:?}")]
+    AlreadyReacted {
+        comment: Id<Comment>,
+        user: Id<User>,
+        reaction: Reaction,
+    },
+
+    /// Error indicating that a user did not react to a comment in a given way.
+    #[error("user {user:?} did not react with {reaction:?} to comment {comment:?}")]
+    NotReacted {
+        comment: Id<Comment>,
+        user: Id<User>,
+        reaction: Reaction,
+    },
+}
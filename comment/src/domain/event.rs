@@ -0,0 +1,41 @@
+use shared_kernel::{Id, Timestamp, User};
+use task::domain::task::Task;
+
+use super::comment::{Comment, CommentBody, Reaction};
+
+/// Represents a domain event raised by an aggregate of the comment context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommentDomainEvent {
+    /// A comment was posted on a task, possibly in reply to another comment.
+    CommentPosted {
+        comment: Id<Comment>,
+        task: Id<Task>,
+        author: Id<User>,
+        parent: Option<Id<Comment>>,
+        body: CommentBody,
+        posted_at: Timestamp,
+    },
+    /// The body of a comment was edited.
+    CommentEdited {
+        comment: Id<Comment>,
+        body: CommentBody,
+        edited_at: Timestamp,
+    },
+    /// A comment was deleted, leaving a tombstone in its thread.
+    CommentDeleted {
+        comment: Id<Comment>,
+        deleted_at: Timestamp,
+    },
+    /// A user reacted to a comment.
+    ReactionAdded {
+        comment: Id<Comment>,
+        user: Id<User>,
+        reaction: Reaction,
+    },
+    /// A user took back a reaction to a comment.
+    ReactionRemoved {
+        comment: Id<Comment>,
+        user: Id<User>,
+        reaction: Reaction,
+    },
+}
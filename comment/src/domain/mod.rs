@@ -0,0 +1,3 @@
+pub mod comment;
+pub mod error;
+pub mod event;
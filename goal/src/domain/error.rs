@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+use shared_kernel::{Id, NameViolation};
+
+use super::goal::{Goal, KeyResult};
+
+/// Represents errors that can occur in the goal domain.
+#[derive(Debug, Error)]
+pub enum GoalDomainError {
+    /// Error indicating that a goal or key result title failed validation.
+    #[error("invalid title: {reason}")]
+    InvalidTitle { reason: NameViolation },
+
+    /// Error indicating that a percentage is above one hundred.
+    #[error("percentage {percent} exceeds 100")]
+    InvalidPercentage { percent: u8 },
+
+    /// Error indicating that a key result was not found in a goal.
+    #[error("key result {key_result:?} not found in goal {goal:?}")]
+    KeyResultNotFound {
+        goal: Id<Goal>,
+        key_result: Id<KeyResult>,
+    },
+
+    /// Error indicating that progress was recorded by hand on a key result bound to a net.
+    #[error("key result {key_result:?} of goal {goal:?} follows a net")]
+    KeyResultNotManual {
+        goal: Id<Goal>,
+        key_result: Id<KeyResult>,
+    },
+}
@@ -0,0 +1,30 @@
+use shared_kernel::Id;
+
+use super::goal::{Goal, GoalTitle, KeyResult, KeyResultSource};
+
+/// Represents a domain event raised by an aggregate of the goal context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GoalDomainEvent {
+    /// A goal was set.
+    GoalCreated { goal: Id<Goal>, title: GoalTitle },
+    /// A goal was retitled.
+    GoalRetitled { goal: Id<Goal>, title: GoalTitle },
+    /// A key result was added to a goal.
+    KeyResultAdded {
+        goal: Id<Goal>,
+        key_result: Id<KeyResult>,
+        title: GoalTitle,
+        source: KeyResultSource,
+    },
+    /// A key result was removed from a goal.
+    KeyResultRemoved {
+        goal: Id<Goal>,
+        key_result: Id<KeyResult>,
+    },
+    /// Progress on a manually tracked key result was recorded.
+    KeyResultProgressed {
+        goal: Id<Goal>,
+        key_result: Id<KeyResult>,
+        percent: u8,
+    },
+}
@@ -0,0 +1,406 @@
+use shared_kernel::{AggregateRoot, Entity, EntityKind, Id};
+use task::domain::{
+    net::{Net, NetQuery},
+    task::Task,
+};
+
+use super::{error::GoalDomainError, event::GoalDomainEvent};
+
+type GoalDomainResult<T> = Result<T, GoalDomainError>;
+
+shared_kernel::validated_text!(
+    /// The validated title of a goal or key result.
+    GoalTitle,
+    256,
+    GoalDomainError,
+    |reason| GoalDomainError::InvalidTitle { reason }
+);
+
+/// Where the progress of a key result comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyResultSource {
+    /// Progress is recorded by hand, as a percentage.
+    Manual { percent: u8 },
+    /// The key result is met once a task of a net is accepted.
+    Task { net: Id<Net>, task: Id<Task> },
+    /// Progress follows the share of accepted subtasks of a task of a net.
+    Subtree { net: Id<Net>, root: Id<Task> },
+}
+
+impl KeyResultSource {
+    /// Returns the net the key result follows, if it is not tracked by hand.
+    pub fn net(&self) -> Option<Id<Net>> {
+        match self {
+            KeyResultSource::Manual { .. } => None,
+            KeyResultSource::Task { net, .. } | KeyResultSource::Subtree { net, .. } => Some(*net),
+        }
+    }
+}
+
+/// Represents a measurable outcome a goal is judged by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyResult {
+    title: GoalTitle,
+    source: KeyResultSource,
+}
+
+impl EntityKind for KeyResult {
+    const KIND: &'static str = "key_result";
+}
+
+impl KeyResult {
+    /// Returns the title of the key result.
+    pub fn title(&self) -> &GoalTitle {
+        &self.title
+    }
+
+    /// Returns where the progress of the key result comes from.
+    pub fn source(&self) -> KeyResultSource {
+        self.source
+    }
+
+    /// Returns the progress of the key result as a value between `0.0` and `1.0`.
+    ///
+    /// A key result bound to a net that is not given, or to a task missing from it, has made
+    /// no progress.
+    pub fn progress(&self, net: Option<&Entity<Net>>) -> f64 {
+        let net = net.filter(|net| Some(net.id) == self.source.net());
+        match (self.source, net) {
+            (KeyResultSource::Manual { percent }, _) => f64::from(percent) / 100.0,
+            (KeyResultSource::Task { task, .. }, Some(net)) => {
+                let accepted = net
+                    .status_of(task)
+                    .is_some_and(|status| net.accepted_statuses().any(|a| a == status));
+                if accepted {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            (KeyResultSource::Subtree { root, .. }, Some(net)) => {
+                if net.status_of(root).is_some() {
+                    net.progress_of(root)
+                } else {
+                    0.0
+                }
+            }
+            (_, None) => 0.0,
+        }
+    }
+}
+
+/// Represents a goal, measured by the progress of its key results.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "GoalRecord", from = "GoalRecord")
+)]
+pub struct Goal {
+    title: GoalTitle,
+    key_results: Vec<Entity<KeyResult>>,
+    events: Vec<GoalDomainEvent>,
+}
+
+/// The versioned on-disk form of a goal; pending events are not persisted.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+enum GoalRecord {
+    #[serde(rename = "1")]
+    V1 {
+        title: GoalTitle,
+        key_results: Vec<Entity<KeyResult>>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<Goal> for GoalRecord {
+    fn from(goal: Goal) -> Self {
+        GoalRecord::V1 {
+            title: goal.title,
+            key_results: goal.key_results,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<GoalRecord> for Goal {
+    fn from(record: GoalRecord) -> Self {
+        match record {
+            GoalRecord::V1 { title, key_results } => Goal {
+                title,
+                key_results,
+                events: Vec::new(),
+            },
+        }
+    }
+}
+
+impl EntityKind for Goal {
+    const KIND: &'static str = "goal";
+}
+
+impl Goal {
+    /// Returns the title of the goal.
+    pub fn title(&self) -> &GoalTitle {
+        &self.title
+    }
+
+    /// Lists the key results of the goal in the order they were added.
+    pub fn key_results(&self) -> &[Entity<KeyResult>] {
+        &self.key_results
+    }
+
+    /// Looks up a key result of the goal.
+    pub fn key_result(&self, key_result: Id<KeyResult>) -> Option<&Entity<KeyResult>> {
+        self.key_results.iter().find(|kr| kr.id == key_result)
+    }
+
+    /// Returns the progress of the goal as the mean progress of its key results, between
+    /// `0.0` and `1.0`, reading bound key results from the given nets.
+    ///
+    /// A goal without key results has made no progress.
+    pub fn progress<'a>(&self, nets: impl IntoIterator<Item = &'a Entity<Net>>) -> f64 {
+        if self.key_results.is_empty() {
+            return 0.0;
+        }
+
+        let nets: Vec<_> = nets.into_iter().collect();
+        let total: f64 = self
+            .key_results
+            .iter()
+            .map(|kr| {
+                let net = nets
+                    .iter()
+                    .copied()
+                    .find(|net| Some(net.id) == kr.data.source.net());
+                kr.data.progress(net)
+            })
+            .sum();
+        total / self.key_results.len() as f64
+    }
+
+    /// Returns the index of a key result, failing if it is not in the goal.
+    fn index_of(&self, goal: Id<Goal>, key_result: Id<KeyResult>) -> GoalDomainResult<usize> {
+        self.key_results
+            .iter()
+            .position(|kr| kr.id == key_result)
+            .ok_or(GoalDomainError::KeyResultNotFound { goal, key_result })
+    }
+}
+
+/// Trait for aggregate root operations on a `Goal`.
+pub trait GoalAggregateRoot {
+    /// Sets a new goal without key results.
+    fn new(title: GoalTitle) -> Self;
+
+    /// Gives the goal a new title.
+    fn retitle(&mut self, title: GoalTitle);
+
+    /// Adds a key result to the goal, returning its id.
+    fn add_key_result(
+        &mut self,
+        title: GoalTitle,
+        source: KeyResultSource,
+    ) -> GoalDomainResult<Id<KeyResult>>;
+
+    /// Removes a key result from the goal.
+    fn remove_key_result(&mut self, key_result: Id<KeyResult>) -> GoalDomainResult<()>;
+
+    /// Records the progress of a manually tracked key result, as a percentage.
+    fn record_progress(&mut self, key_result: Id<KeyResult>, percent: u8) -> GoalDomainResult<()>;
+}
+
+/// Fails if a percentage is above one hundred.
+fn ensure_percentage(percent: u8) -> GoalDomainResult<()> {
+    if percent > 100 {
+        return Err(GoalDomainError::InvalidPercentage { percent });
+    }
+
+    Ok(())
+}
+
+impl GoalAggregateRoot for Entity<Goal> {
+    fn new(title: GoalTitle) -> Self {
+        let id = Id::new();
+        Entity {
+            id,
+            data: Goal {
+                title: title.clone(),
+                key_results: Vec::new(),
+                events: vec![GoalDomainEvent::GoalCreated { goal: id, title }],
+            },
+        }
+    }
+
+    fn retitle(&mut self, title: GoalTitle) {
+        self.data.title = title.clone();
+        self.data.events.push(GoalDomainEvent::GoalRetitled {
+            goal: self.id,
+            title,
+        });
+    }
+
+    fn add_key_result(
+        &mut self,
+        title: GoalTitle,
+        source: KeyResultSource,
+    ) -> GoalDomainResult<Id<KeyResult>> {
+        if let KeyResultSource::Manual { percent } = source {
+            ensure_percentage(percent)?;
+        }
+
+        let key_result = Entity::from(KeyResult {
+            title: title.clone(),
+            source,
+        });
+        let id = key_result.id;
+        self.data.key_results.push(key_result);
+        self.data.events.push(GoalDomainEvent::KeyResultAdded {
+            goal: self.id,
+            key_result: id,
+            title,
+            source,
+        });
+
+        Ok(id)
+    }
+
+    fn remove_key_result(&mut self, key_result: Id<KeyResult>) -> GoalDomainResult<()> {
+        let index = self.data.index_of(self.id, key_result)?;
+
+        self.data.key_results.remove(index);
+        self.data.events.push(GoalDomainEvent::KeyResultRemoved {
+            goal: self.id,
+            key_result,
+        });
+
+        Ok(())
+    }
+
+    fn record_progress(&mut self, key_result: Id<KeyResult>, percent: u8) -> GoalDomainResult<()> {
+        let index = self.data.index_of(self.id, key_result)?;
+        ensure_percentage(percent)?;
+        let source = &mut self.data.key_results[index].data.source;
+        let KeyResultSource::Manual { percent: current } = source else {
+            return Err(GoalDomainError::KeyResultNotManual {
+                goal: self.id,
+                key_result,
+            });
+        };
+
+        *current = percent;
+        self.data.events.push(GoalDomainEvent::KeyResultProgressed {
+            goal: self.id,
+            key_result,
+            percent,
+        });
+
+        Ok(())
+    }
+}
+
+impl AggregateRoot for Goal {
+    type Event = GoalDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::SystemClock;
+    use task::domain::{
+        name::StatusName,
+        net::{NetAggregateRoot, RelationType},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_progress_follows_nets() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let [epic, first, second, launch] = [Id::new(), Id::new(), Id::new(), Id::new()];
+        for task in [epic, first, second, launch] {
+            net.add_task(task).unwrap();
+        }
+        for child in [first, second] {
+            net.new_relation(child, epic, RelationType::Compose, &SystemClock)
+                .unwrap();
+        }
+
+        let mut goal: Entity<Goal> = GoalAggregateRoot::new(GoalTitle::new("Ship v2").unwrap());
+        let title = |t: &str| GoalTitle::new(t).unwrap();
+        goal.add_key_result(
+            title("Finish the epic"),
+            KeyResultSource::Subtree {
+                net: net.id,
+                root: epic,
+            },
+        )
+        .unwrap();
+        goal.add_key_result(
+            title("Launch"),
+            KeyResultSource::Task {
+                net: net.id,
+                task: launch,
+            },
+        )
+        .unwrap();
+        let signups = goal
+            .add_key_result(title("Sign-ups"), KeyResultSource::Manual { percent: 0 })
+            .unwrap();
+        assert_eq!(goal.data.progress([&net]), 0.0);
+
+        net.change_task_status(first, net.accepted_status(), &SystemClock)
+            .unwrap();
+        net.change_task_status(launch, net.accepted_status(), &SystemClock)
+            .unwrap();
+        goal.record_progress(signups, 25).unwrap();
+        assert_eq!(goal.data.progress([&net]), (0.5 + 1.0 + 0.25) / 3.0);
+        assert_eq!(goal.data.progress([]), 0.25 / 3.0);
+    }
+
+    #[test]
+    fn test_key_results() {
+        let mut goal: Entity<Goal> = GoalAggregateRoot::new(GoalTitle::new("Grow").unwrap());
+        assert_eq!(goal.data.progress([]), 0.0);
+        assert!(matches!(
+            goal.add_key_result(
+                GoalTitle::new("Reach").unwrap(),
+                KeyResultSource::Manual { percent: 101 }
+            ),
+            Err(GoalDomainError::InvalidPercentage { percent: 101 })
+        ));
+        let bound = goal
+            .add_key_result(
+                GoalTitle::new("Ship").unwrap(),
+                KeyResultSource::Task {
+                    net: Id::new(),
+                    task: Id::new(),
+                },
+            )
+            .unwrap();
+        assert!(matches!(
+            goal.record_progress(bound, 50),
+            Err(GoalDomainError::KeyResultNotManual { .. })
+        ));
+
+        goal.remove_key_result(bound).unwrap();
+        assert!(matches!(
+            goal.remove_key_result(bound),
+            Err(GoalDomainError::KeyResultNotFound { .. })
+        ));
+        assert!(goal.data.key_results().is_empty());
+        goal.retitle(GoalTitle::new("Grow faster").unwrap());
+        assert_eq!(goal.data.title(), &"Grow faster");
+    }
+}
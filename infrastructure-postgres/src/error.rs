@@ -0,0 +1,18 @@
+use infrastructure_sql::error::MappingError;
+use thiserror::Error;
+
+/// Represents errors that can occur while storing aggregates in PostgreSQL.
+#[derive(Debug, Error)]
+pub enum PostgresError {
+    /// Error indicating that the database rejected a query or could not be reached.
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    /// Error indicating that an aggregate could not be mapped to or from its rows.
+    #[error(transparent)]
+    Mapping(#[from] MappingError),
+
+    /// Error indicating that the runtime driving the queries could not be started.
+    #[error("failed to start the runtime: {0}")]
+    Runtime(#[from] std::io::Error),
+}
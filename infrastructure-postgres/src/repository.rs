@@ -0,0 +1,369 @@
+use std::marker::PhantomData;
+
+use infrastructure_sql::mapping::{
+    decode, decode_option, decode_text, encode, encode_option, NetRows,
+};
+use serde::de::DeserializeOwned;
+use shared_kernel::{Entity, Id, Page, PageRequest, Repository};
+use sqlx::{PgConnection, Row};
+use task::domain::{
+    list::List,
+    net::{Net, NetQuery, RelationParts, StatusParts},
+    task::Task,
+};
+
+use crate::{error::PostgresError, store::PostgresUnitOfWork};
+
+/// A repository of aggregates of type `T` working in a unit of work.
+///
+/// Lists and tasks are stored as JSON documents next to the columns they are looked up by;
+/// nets are split into rows of statuses, tasks and relations. Relation times are stored to the
+/// microsecond, the precision of PostgreSQL timestamps.
+#[derive(Debug)]
+pub struct PostgresRepository<'a, T> {
+    unit: &'a PostgresUnitOfWork<'a>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> PostgresRepository<'a, T> {
+    pub(crate) fn new(unit: &'a PostgresUnitOfWork<'a>) -> Self {
+        Self {
+            unit,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Loads the document stored with an id in a table.
+async fn get_document<T: DeserializeOwned>(
+    connection: &mut PgConnection,
+    table: &str,
+    id: Id<T>,
+) -> Result<Option<Entity<T>>, PostgresError> {
+    let row = sqlx::query(&format!("SELECT data FROM {table} WHERE id = $1"))
+        .bind(id.id)
+        .fetch_optional(connection)
+        .await?;
+    row.map(|row| {
+        Ok(Entity {
+            id,
+            data: decode(row.try_get("data")?)?,
+        })
+    })
+    .transpose()
+}
+
+/// Loads one page of the documents stored in a table, ordered by id.
+async fn page_documents<T: DeserializeOwned>(
+    connection: &mut PgConnection,
+    table: &str,
+    page: PageRequest,
+) -> Result<Page<Entity<T>>, PostgresError> {
+    let total = count(connection, table).await?;
+    let rows = sqlx::query(&format!(
+        "SELECT id, data FROM {table} ORDER BY id LIMIT $1 OFFSET $2"
+    ))
+    .bind(page.limit() as i64)
+    .bind(page.offset() as i64)
+    .fetch_all(connection)
+    .await?;
+    let items = rows
+        .into_iter()
+        .map(|row| {
+            Ok(Entity {
+                id: Id::from_uuid(row.try_get("id")?),
+                data: decode(row.try_get("data")?)?,
+            })
+        })
+        .collect::<Result<_, PostgresError>>()?;
+
+    Ok(Page {
+        items,
+        total: Some(total),
+        next_cursor: None,
+    })
+}
+
+/// Counts the rows of a table.
+async fn count(connection: &mut PgConnection, table: &str) -> Result<usize, PostgresError> {
+    let total: i64 = sqlx::query(&format!("SELECT count(*) AS total FROM {table}"))
+        .fetch_one(connection)
+        .await?
+        .try_get("total")?;
+    Ok(total as usize)
+}
+
+/// Deletes the row stored with an id in a table, along with the rows referencing it.
+async fn delete_row<T>(
+    connection: &mut PgConnection,
+    table: &str,
+    id: Id<T>,
+) -> Result<(), PostgresError> {
+    sqlx::query(&format!("DELETE FROM {table} WHERE id = $1"))
+        .bind(id.id)
+        .execute(connection)
+        .await?;
+    Ok(())
+}
+
+impl Repository<List> for PostgresRepository<'_, List> {
+    type Error = PostgresError;
+
+    fn get(&self, id: Id<List>) -> Result<Option<Entity<List>>, Self::Error> {
+        self.unit
+            .run(async |connection| get_document(connection, "lists", id).await)
+    }
+
+    fn save(&mut self, entity: &Entity<List>) -> Result<(), Self::Error> {
+        let data = encode(&entity.data)?;
+        self.unit.run(async |connection| {
+            sqlx::query(
+                "INSERT INTO lists (id, title, archived, data) VALUES ($1, $2, $3, $4)
+                 ON CONFLICT (id) DO UPDATE
+                 SET title = excluded.title, archived = excluded.archived, data = excluded.data",
+            )
+            .bind(entity.id.id)
+            .bind(entity.data.title().as_str())
+            .bind(entity.data.is_archived())
+            .bind(data)
+            .execute(connection)
+            .await?;
+            Ok(())
+        })
+    }
+
+    fn delete(&mut self, id: Id<List>) -> Result<(), Self::Error> {
+        self.unit
+            .run(async |connection| delete_row(connection, "lists", id).await)
+    }
+
+    fn list(&self, page: PageRequest) -> Result<Page<Entity<List>>, Self::Error> {
+        self.unit
+            .run(async |connection| page_documents(connection, "lists", page).await)
+    }
+}
+
+impl Repository<Task> for PostgresRepository<'_, Task> {
+    type Error = PostgresError;
+
+    fn get(&self, id: Id<Task>) -> Result<Option<Entity<Task>>, Self::Error> {
+        self.unit
+            .run(async |connection| get_document(connection, "tasks", id).await)
+    }
+
+    fn save(&mut self, entity: &Entity<Task>) -> Result<(), Self::Error> {
+        let data = encode(&entity.data)?;
+        self.unit.run(async |connection| {
+            sqlx::query(
+                "INSERT INTO tasks (id, list_id, name, data) VALUES ($1, $2, $3, $4)
+                 ON CONFLICT (id) DO UPDATE
+                 SET list_id = excluded.list_id, name = excluded.name, data = excluded.data",
+            )
+            .bind(entity.id.id)
+            .bind(entity.data.list().id)
+            .bind(entity.data.name().as_str())
+            .bind(data)
+            .execute(connection)
+            .await?;
+            Ok(())
+        })
+    }
+
+    fn delete(&mut self, id: Id<Task>) -> Result<(), Self::Error> {
+        self.unit
+            .run(async |connection| delete_row(connection, "tasks", id).await)
+    }
+
+    fn list(&self, page: PageRequest) -> Result<Page<Entity<Task>>, Self::Error> {
+        self.unit
+            .run(async |connection| page_documents(connection, "tasks", page).await)
+    }
+}
+
+/// Loads the net stored with an id from its rows.
+async fn get_net(
+    connection: &mut PgConnection,
+    id: Id<Net>,
+) -> Result<Option<Entity<Net>>, PostgresError> {
+    let Some(row) = sqlx::query("SELECT details FROM nets WHERE id = $1")
+        .bind(id.id)
+        .fetch_optional(&mut *connection)
+        .await?
+    else {
+        return Ok(None);
+    };
+    let details = row.try_get("details")?;
+
+    let statuses = sqlx::query(
+        "SELECT id, name, category, color, description, wip_limit FROM net_statuses
+         WHERE net_id = $1 ORDER BY position",
+    )
+    .bind(id.id)
+    .fetch_all(&mut *connection)
+    .await?
+    .into_iter()
+    .map(|row| {
+        Ok(StatusParts {
+            id: Id::from_uuid(row.try_get("id")?),
+            name: decode_text(row.try_get("name")?)?,
+            category: decode(row.try_get("category")?)?,
+            color: decode_option(row.try_get("color")?)?,
+            description: row.try_get("description")?,
+            wip_limit: decode_option(row.try_get("wip_limit")?)?,
+        })
+    })
+    .collect::<Result<_, PostgresError>>()?;
+
+    let tasks =
+        sqlx::query("SELECT task_id, status_id FROM net_tasks WHERE net_id = $1 ORDER BY task_id")
+            .bind(id.id)
+            .fetch_all(&mut *connection)
+            .await?
+            .into_iter()
+            .map(|row| {
+                Ok((
+                    Id::from_uuid(row.try_get("task_id")?),
+                    Id::from_uuid(row.try_get("status_id")?),
+                ))
+            })
+            .collect::<Result<_, PostgresError>>()?;
+
+    let relations = sqlx::query(
+        "SELECT from_task, to_task, relation_type, strength, note, created_at FROM net_relations
+         WHERE net_id = $1 ORDER BY from_task, to_task",
+    )
+    .bind(id.id)
+    .fetch_all(&mut *connection)
+    .await?
+    .into_iter()
+    .map(|row| {
+        Ok(RelationParts {
+            from: Id::from_uuid(row.try_get("from_task")?),
+            to: Id::from_uuid(row.try_get("to_task")?),
+            relation_type: decode(row.try_get("relation_type")?)?,
+            strength: decode(row.try_get("strength")?)?,
+            note: row.try_get("note")?,
+            created_at: row.try_get("created_at")?,
+        })
+    })
+    .collect::<Result<_, PostgresError>>()?;
+
+    let net = NetRows {
+        details,
+        statuses,
+        tasks,
+        relations,
+    }
+    .into_net(id)?;
+    Ok(Some(net))
+}
+
+/// Stores a net as rows, replacing the rows stored for it before.
+async fn save_net(
+    connection: &mut PgConnection,
+    id: Id<Net>,
+    rows: NetRows,
+) -> Result<(), PostgresError> {
+    sqlx::query(
+        "INSERT INTO nets (id, details) VALUES ($1, $2)
+         ON CONFLICT (id) DO UPDATE SET details = excluded.details",
+    )
+    .bind(id.id)
+    .bind(rows.details)
+    .execute(&mut *connection)
+    .await?;
+    for table in ["net_statuses", "net_tasks", "net_relations"] {
+        sqlx::query(&format!("DELETE FROM {table} WHERE net_id = $1"))
+            .bind(id.id)
+            .execute(&mut *connection)
+            .await?;
+    }
+
+    for (position, status) in rows.statuses.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO net_statuses
+             (net_id, id, position, name, category, color, description, wip_limit)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(id.id)
+        .bind(status.id.id)
+        .bind(position as i32)
+        .bind(status.name.as_str())
+        .bind(encode(&status.category)?)
+        .bind(encode_option(&status.color)?)
+        .bind(&status.description)
+        .bind(encode_option(&status.wip_limit)?)
+        .execute(&mut *connection)
+        .await?;
+    }
+    for (task, status) in &rows.tasks {
+        sqlx::query("INSERT INTO net_tasks (net_id, task_id, status_id) VALUES ($1, $2, $3)")
+            .bind(id.id)
+            .bind(task.id)
+            .bind(status.id)
+            .execute(&mut *connection)
+            .await?;
+    }
+    for relation in &rows.relations {
+        sqlx::query(
+            "INSERT INTO net_relations
+             (net_id, from_task, to_task, relation_type, strength, note, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(id.id)
+        .bind(relation.from.id)
+        .bind(relation.to.id)
+        .bind(encode(&relation.relation_type)?)
+        .bind(encode(&relation.strength)?)
+        .bind(&relation.note)
+        .bind(relation.created_at)
+        .execute(&mut *connection)
+        .await?;
+    }
+
+    Ok(())
+}
+
+impl Repository<Net> for PostgresRepository<'_, Net> {
+    type Error = PostgresError;
+
+    fn get(&self, id: Id<Net>) -> Result<Option<Entity<Net>>, Self::Error> {
+        self.unit
+            .run(async |connection| get_net(connection, id).await)
+    }
+
+    fn save(&mut self, entity: &Entity<Net>) -> Result<(), Self::Error> {
+        let rows = NetRows::from_parts(entity.to_parts())?;
+        self.unit
+            .run(async |connection| save_net(connection, entity.id, rows).await)
+    }
+
+    fn delete(&mut self, id: Id<Net>) -> Result<(), Self::Error> {
+        self.unit
+            .run(async |connection| delete_row(connection, "nets", id).await)
+    }
+
+    fn list(&self, page: PageRequest) -> Result<Page<Entity<Net>>, Self::Error> {
+        self.unit.run(async |connection| {
+            let total = count(connection, "nets").await?;
+            let ids: Vec<_> = sqlx::query("SELECT id FROM nets ORDER BY id LIMIT $1 OFFSET $2")
+                .bind(page.limit() as i64)
+                .bind(page.offset() as i64)
+                .fetch_all(&mut *connection)
+                .await?
+                .into_iter()
+                .map(|row| row.try_get("id").map(Id::from_uuid))
+                .collect::<Result<_, _>>()?;
+
+            let mut items = Vec::with_capacity(ids.len());
+            for id in ids {
+                items.extend(get_net(connection, id).await?);
+            }
+            Ok(Page {
+                items,
+                total: Some(total),
+                next_cursor: None,
+            })
+        })
+    }
+}
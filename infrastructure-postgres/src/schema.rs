@@ -0,0 +1,42 @@
+use infrastructure_sql::schema::MIGRATIONS;
+use sqlx::{Connection, PgConnection, Row};
+
+use crate::error::PostgresError;
+
+/// Applies the migrations the database has not seen yet, each in its own transaction, and
+/// returns how many were applied. Applied migrations are recorded by version in the
+/// `schema_migrations` table.
+pub(crate) async fn migrate(connection: &mut PgConnection) -> Result<usize, PostgresError> {
+    sqlx::raw_sql(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            version integer PRIMARY KEY,
+            applied_at timestamptz NOT NULL DEFAULT now()
+        )",
+    )
+    .execute(&mut *connection)
+    .await?;
+    let current: i32 =
+        sqlx::query("SELECT coalesce(max(version), 0) AS version FROM schema_migrations")
+            .fetch_one(&mut *connection)
+            .await?
+            .try_get("version")?;
+
+    let mut applied = 0;
+    for migration in MIGRATIONS
+        .iter()
+        .filter(|migration| migration.version > current)
+    {
+        let mut transaction = connection.begin().await?;
+        sqlx::raw_sql(migration.postgres)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query("INSERT INTO schema_migrations (version) VALUES ($1)")
+            .bind(migration.version)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+        applied += 1;
+    }
+
+    Ok(applied)
+}
@@ -0,0 +1,135 @@
+use std::cell::RefCell;
+
+use shared_kernel::{Repository, RepositoryProvider, Store, UnitOfWork};
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use task::domain::{list::List, net::Net, task::Task};
+use tokio::runtime::Runtime;
+
+use crate::{error::PostgresError, repository::PostgresRepository, schema};
+
+/// Invariant of `PostgresUnitOfWork`: its transaction is only taken when it is consumed.
+const OPEN: &str = "the transaction stays open until the unit of work is consumed";
+
+/// Represents a PostgreSQL database holding the aggregates of the task context.
+///
+/// The repositories are synchronous, so the store drives the asynchronous driver on a
+/// runtime of its own; it must not be used from within another asynchronous runtime.
+#[derive(Debug)]
+pub struct PostgresStore {
+    runtime: Runtime,
+    pool: PgPool,
+}
+
+impl PostgresStore {
+    /// Connects to the database at a URL such as `postgres://user@localhost/just_dev`.
+    pub fn connect(url: &str) -> Result<Self, PostgresError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let pool = runtime.block_on(PgPool::connect(url))?;
+        Ok(Self { runtime, pool })
+    }
+
+    /// Applies the migrations the database has not seen yet, returning how many were applied.
+    pub fn migrate(&self) -> Result<usize, PostgresError> {
+        self.runtime.block_on(async {
+            let mut connection = self.pool.acquire().await?;
+            schema::migrate(&mut connection).await
+        })
+    }
+
+    /// Opens a unit of work in a new transaction.
+    pub fn begin(&self) -> Result<PostgresUnitOfWork<'_>, PostgresError> {
+        let transaction = self.runtime.block_on(self.pool.begin())?;
+        Ok(PostgresUnitOfWork {
+            runtime: &self.runtime,
+            transaction: RefCell::new(Some(transaction)),
+        })
+    }
+}
+
+/// Represents a database transaction the repositories of a store work in.
+///
+/// Changes are only visible to other units of work once committed; dropping the unit of work
+/// without committing rolls them back.
+#[derive(Debug)]
+pub struct PostgresUnitOfWork<'a> {
+    runtime: &'a Runtime,
+    transaction: RefCell<Option<Transaction<'static, Postgres>>>,
+}
+
+impl PostgresUnitOfWork<'_> {
+    /// Returns the repository of lists.
+    pub fn lists(&self) -> PostgresRepository<'_, List> {
+        PostgresRepository::new(self)
+    }
+
+    /// Returns the repository of tasks.
+    pub fn tasks(&self) -> PostgresRepository<'_, Task> {
+        PostgresRepository::new(self)
+    }
+
+    /// Returns the repository of nets.
+    pub fn nets(&self) -> PostgresRepository<'_, Net> {
+        PostgresRepository::new(self)
+    }
+
+    /// Runs a database operation in the transaction, blocking until it completes.
+    pub(crate) fn run<R>(
+        &self,
+        operation: impl AsyncFnOnce(&mut PgConnection) -> Result<R, PostgresError>,
+    ) -> Result<R, PostgresError> {
+        let mut transaction = self.transaction.borrow_mut();
+        let connection: &mut PgConnection = transaction.as_mut().expect(OPEN);
+        self.runtime.block_on(operation(connection))
+    }
+}
+
+impl Store for PostgresStore {
+    type Error = PostgresError;
+    type UnitOfWork<'a> = PostgresUnitOfWork<'a>;
+
+    fn begin(&mut self) -> Result<Self::UnitOfWork<'_>, Self::Error> {
+        PostgresStore::begin(self)
+    }
+}
+
+impl UnitOfWork for PostgresUnitOfWork<'_> {
+    type Error = PostgresError;
+
+    fn commit(self) -> Result<(), Self::Error> {
+        let transaction = self.transaction.borrow_mut().take().expect(OPEN);
+        Ok(self.runtime.block_on(transaction.commit())?)
+    }
+
+    fn rollback(self) -> Result<(), Self::Error> {
+        let transaction = self.transaction.borrow_mut().take().expect(OPEN);
+        Ok(self.runtime.block_on(transaction.rollback())?)
+    }
+}
+
+impl RepositoryProvider<List> for PostgresUnitOfWork<'_> {
+    fn repository(&mut self) -> impl Repository<List, Error = Self::Error> + '_ {
+        self.lists()
+    }
+}
+
+impl RepositoryProvider<Task> for PostgresUnitOfWork<'_> {
+    fn repository(&mut self) -> impl Repository<Task, Error = Self::Error> + '_ {
+        self.tasks()
+    }
+}
+
+impl RepositoryProvider<Net> for PostgresUnitOfWork<'_> {
+    fn repository(&mut self) -> impl Repository<Net, Error = Self::Error> + '_ {
+        self.nets()
+    }
+}
+
+impl Drop for PostgresUnitOfWork<'_> {
+    fn drop(&mut self) {
+        // Returning the connection to the pool spawns a task, which needs the runtime.
+        let _context = self.runtime.enter();
+        self.transaction.get_mut().take();
+    }
+}
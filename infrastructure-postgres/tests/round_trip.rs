@@ -0,0 +1,99 @@
+//! Round-trip tests against a live database, run with `--features integration` and
+//! `DATABASE_URL` pointing at a scratch database. Without `DATABASE_URL` they are skipped.
+#![cfg(feature = "integration")]
+
+use infrastructure_postgres::store::PostgresStore;
+use shared_kernel::{Entity, Id, PageRequest, Repository, UnitOfWork};
+use task::domain::{
+    list::{Label, List, ListAggregateRoot},
+    name::{LabelName, ListTitle, StatusName, TaskName},
+    net::{Color, Net, NetAggregateRoot, NetQuery, RelationType},
+    task::{Task, TaskAggregateRoot},
+};
+
+fn store() -> Option<PostgresStore> {
+    let Ok(url) = std::env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL is not set, skipping");
+        return None;
+    };
+    let store = PostgresStore::connect(&url).unwrap();
+    store.migrate().unwrap();
+    Some(store)
+}
+
+#[test]
+fn test_list_and_task_round_trip() {
+    let Some(store) = store() else { return };
+    let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+    let docs = LabelName::new("docs").unwrap();
+    let color = Color {
+        red: 0,
+        green: 0,
+        blue: 255,
+    };
+    list.define_label(Label::new(docs.clone(), color)).unwrap();
+    let mut task: Entity<Task> =
+        TaskAggregateRoot::new(TaskName::new("Write docs").unwrap(), list.id);
+    task.add_label(&list, docs).unwrap();
+    list.append_task(task.id).unwrap();
+
+    let unit = store.begin().unwrap();
+    unit.lists().save(&list).unwrap();
+    unit.tasks().save(&task).unwrap();
+    unit.commit().unwrap();
+
+    let unit = store.begin().unwrap();
+    let stored = unit.tasks().get(task.id).unwrap().unwrap();
+    assert_eq!(stored.data.name(), task.data.name());
+    assert_eq!(stored.data.labels(), task.data.labels());
+    let stored = unit.lists().get(list.id).unwrap().unwrap();
+    assert_eq!(stored.data.title(), list.data.title());
+    assert!(unit
+        .tasks()
+        .list(PageRequest::first(usize::MAX >> 1))
+        .unwrap()
+        .items
+        .iter()
+        .any(|stored| stored.id == task.id));
+
+    unit.tasks().delete(task.id).unwrap();
+    unit.lists().delete(list.id).unwrap();
+    unit.commit().unwrap();
+    let unit = store.begin().unwrap();
+    assert!(unit.tasks().get(task.id).unwrap().is_none());
+}
+
+#[test]
+fn test_net_round_trip() {
+    let Some(store) = store() else { return };
+    // PostgreSQL keeps timestamps to the microsecond, so the clock stays on whole seconds.
+    let now = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let clock = &|| now;
+    let mut net: Entity<Net> = NetAggregateRoot::new(
+        StatusName::new("Todo").unwrap(),
+        StatusName::new("Done").unwrap(),
+    );
+    net.new_status(StatusName::new("Doing").unwrap()).unwrap();
+    let (epic, story) = (Id::new(), Id::new());
+    net.add_task(epic).unwrap();
+    net.add_task(story).unwrap();
+    net.new_relation(story, epic, RelationType::Compose, clock)
+        .unwrap();
+    net.change_task_status(story, net.accepted_status(), clock)
+        .unwrap();
+
+    let unit = store.begin().unwrap();
+    unit.nets().save(&net).unwrap();
+    let stored = unit.nets().get(net.id).unwrap().unwrap();
+    assert_eq!(stored.to_parts(), net.to_parts());
+
+    // Saving again replaces the rows of the net.
+    net.remove_task(story, clock).unwrap();
+    unit.nets().save(&net).unwrap();
+    let stored = unit.nets().get(net.id).unwrap().unwrap();
+    assert_eq!(stored.to_parts(), net.to_parts());
+    unit.rollback().unwrap();
+
+    let unit = store.begin().unwrap();
+    assert!(unit.nets().get(net.id).unwrap().is_none());
+}
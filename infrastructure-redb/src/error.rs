@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+/// Represents errors that can occur while storing aggregates in a redb database.
+#[derive(Debug, Error)]
+pub enum RedbError {
+    /// Error indicating that the database could not be opened, read or written.
+    #[error("storage error: {0}")]
+    Storage(#[source] Box<redb::Error>),
+
+    /// Error indicating that a stored aggregate could not be encoded or decoded.
+    #[error("invalid stored value: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    /// Error indicating that a stored key is not the id of an aggregate.
+    #[error("invalid stored key {key:?}")]
+    InvalidKey { key: String },
+}
+
+/// Converts any of the errors of redb operations into a storage error.
+pub(crate) fn storage(error: impl Into<redb::Error>) -> RedbError {
+    RedbError::Storage(Box::new(error.into()))
+}
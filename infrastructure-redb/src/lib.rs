@@ -0,0 +1,8 @@
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+/// The `error` module contains the errors the key-value adapter fails with.
+pub mod error;
+/// The `repository` module contains the repository of serialized aggregates.
+pub mod repository;
+/// The `store` module contains the database file and its units of work.
+pub mod store;
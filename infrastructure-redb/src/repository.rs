@@ -0,0 +1,172 @@
+use std::marker::PhantomData;
+
+use redb::{ReadableTable, TableDefinition, WriteTransaction};
+use serde::{de::DeserializeOwned, Serialize};
+use shared_kernel::{AnyId, Entity, EntityKind, Id, Page, PageRequest, Repository, Uuid};
+
+use crate::error::{storage, RedbError};
+
+/// The table holding every aggregate, keyed by its kind-qualified id such as `task:<uuid>`.
+///
+/// Keys of the same kind share a prefix and sort by id, so listing is a prefix scan.
+const AGGREGATES: TableDefinition<&str, &[u8]> = TableDefinition::new("aggregates");
+
+/// A repository of aggregates of type `T` working in a unit of work.
+///
+/// Aggregates are stored as JSON through their versioned serde records, so any aggregate
+/// with serde support can be stored without a schema.
+pub struct RedbRepository<'a, T> {
+    transaction: &'a WriteTransaction,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> RedbRepository<'a, T> {
+    pub(crate) fn new(transaction: &'a WriteTransaction) -> Self {
+        Self {
+            transaction,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Returns the key an aggregate is stored under.
+fn key<T: EntityKind>(id: Id<T>) -> String {
+    AnyId::from(id).to_string()
+}
+
+/// Returns the prefix of the keys of the aggregates of a kind.
+fn prefix<T: EntityKind>() -> String {
+    format!("{}:", T::KIND)
+}
+
+impl<T> Repository<T> for RedbRepository<'_, T>
+where
+    T: EntityKind + Serialize + DeserializeOwned,
+{
+    type Error = RedbError;
+
+    fn get(&self, id: Id<T>) -> Result<Option<Entity<T>>, Self::Error> {
+        let table = self.transaction.open_table(AGGREGATES).map_err(storage)?;
+        let value = table.get(key(id).as_str()).map_err(storage)?;
+        value
+            .map(|value| {
+                Ok(Entity {
+                    id,
+                    data: serde_json::from_slice(value.value())?,
+                })
+            })
+            .transpose()
+    }
+
+    fn save(&mut self, entity: &Entity<T>) -> Result<(), Self::Error> {
+        let value = serde_json::to_vec(&entity.data)?;
+        let mut table = self.transaction.open_table(AGGREGATES).map_err(storage)?;
+        table
+            .insert(key(entity.id).as_str(), value.as_slice())
+            .map_err(storage)?;
+        Ok(())
+    }
+
+    fn delete(&mut self, id: Id<T>) -> Result<(), Self::Error> {
+        let mut table = self.transaction.open_table(AGGREGATES).map_err(storage)?;
+        table.remove(key(id).as_str()).map_err(storage)?;
+        Ok(())
+    }
+
+    fn list(&self, page: PageRequest) -> Result<Page<Entity<T>>, Self::Error> {
+        let prefix = prefix::<T>();
+        let table = self.transaction.open_table(AGGREGATES).map_err(storage)?;
+        let mut items = Vec::new();
+        let mut total = 0;
+        for entry in table.range(prefix.as_str()..).map_err(storage)? {
+            let (key, value) = entry.map_err(storage)?;
+            let Some(uuid) = key.value().strip_prefix(&prefix) else {
+                break;
+            };
+            if total >= page.offset() && items.len() < page.limit() {
+                let uuid = Uuid::parse_str(uuid).map_err(|_| RedbError::InvalidKey {
+                    key: key.value().to_owned(),
+                })?;
+                items.push(Entity {
+                    id: Id::from_uuid(uuid),
+                    data: serde_json::from_slice(value.value())?,
+                });
+            }
+            total += 1;
+        }
+
+        Ok(Page {
+            items,
+            total: Some(total),
+            next_cursor: None,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{SystemClock, UnitOfWork};
+    use task::domain::{
+        list::{List, ListAggregateRoot},
+        name::{ListTitle, StatusName, TaskName},
+        net::{Net, NetAggregateRoot, NetQuery, RelationType},
+        task::{Task, TaskAggregateRoot},
+    };
+
+    use super::*;
+    use crate::store::RedbStore;
+
+    #[test]
+    fn test_round_trip() {
+        let store = RedbStore::open_in_memory().unwrap();
+        let list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let tasks: Vec<Entity<Task>> = ["Plan", "Build", "Ship"]
+            .into_iter()
+            .map(|name| TaskAggregateRoot::new(TaskName::new(name).unwrap(), list.id))
+            .collect();
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.add_task(tasks[0].id).unwrap();
+        net.add_task(tasks[1].id).unwrap();
+        net.new_relation(
+            tasks[1].id,
+            tasks[0].id,
+            RelationType::Compose,
+            &SystemClock,
+        )
+        .unwrap();
+
+        let unit = store.begin().unwrap();
+        unit.repository().save(&list).unwrap();
+        for task in &tasks {
+            unit.repository().save(task).unwrap();
+        }
+        unit.repository().save(&net).unwrap();
+        unit.commit().unwrap();
+
+        let unit = store.begin().unwrap();
+        let stored: Entity<Net> = unit.repository().get(net.id).unwrap().unwrap();
+        assert_eq!(stored.to_parts(), net.to_parts());
+        let stored: Entity<List> = unit.repository().get(list.id).unwrap().unwrap();
+        assert_eq!(stored.data.title(), list.data.title());
+
+        // Listing scans the tasks only, in id order.
+        let mut ids: Vec<_> = tasks.iter().map(|task| task.id).collect();
+        ids.sort();
+        let page: Page<Entity<Task>> = unit.repository().list(PageRequest::new(1, 5)).unwrap();
+        assert_eq!(page.total, Some(3));
+        assert_eq!(
+            page.items.iter().map(|task| task.id).collect::<Vec<_>>(),
+            ids[1..]
+        );
+
+        Repository::<Task>::delete(&mut unit.repository(), ids[0]).unwrap();
+        unit.rollback().unwrap();
+        let unit = store.begin().unwrap();
+        let task: Option<Entity<Task>> = unit.repository().get(ids[0]).unwrap();
+        assert!(task.is_some());
+    }
+}
@@ -0,0 +1,87 @@
+use std::path::Path;
+
+use redb::{backends::InMemoryBackend, Database, WriteTransaction};
+use serde::{de::DeserializeOwned, Serialize};
+use shared_kernel::{EntityKind, Repository, RepositoryProvider, Store, UnitOfWork};
+
+use crate::{
+    error::{storage, RedbError},
+    repository::RedbRepository,
+};
+
+/// Represents an embedded key-value database holding serialized aggregates, for deployments
+/// without a database server.
+#[derive(Debug)]
+pub struct RedbStore {
+    database: Database,
+}
+
+impl RedbStore {
+    /// Opens the database file at a path, creating it if it does not exist.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, RedbError> {
+        Ok(Self {
+            database: Database::create(path).map_err(storage)?,
+        })
+    }
+
+    /// Opens a database living in memory until the store is dropped.
+    pub fn open_in_memory() -> Result<Self, RedbError> {
+        let database = Database::builder()
+            .create_with_backend(InMemoryBackend::new())
+            .map_err(storage)?;
+        Ok(Self { database })
+    }
+
+    /// Opens a unit of work in a new write transaction.
+    ///
+    /// Write transactions are serialized: this blocks while another unit of work is open.
+    pub fn begin(&self) -> Result<RedbUnitOfWork, RedbError> {
+        Ok(RedbUnitOfWork {
+            transaction: self.database.begin_write().map_err(storage)?,
+        })
+    }
+}
+
+/// Represents a write transaction the repositories of a store work in.
+///
+/// Dropping the unit of work without committing discards its changes.
+pub struct RedbUnitOfWork {
+    transaction: WriteTransaction,
+}
+
+impl RedbUnitOfWork {
+    /// Returns the repository of the aggregates of type `T`.
+    pub fn repository<T>(&self) -> RedbRepository<'_, T> {
+        RedbRepository::new(&self.transaction)
+    }
+}
+
+impl Store for RedbStore {
+    type Error = RedbError;
+    type UnitOfWork<'a> = RedbUnitOfWork;
+
+    fn begin(&mut self) -> Result<Self::UnitOfWork<'_>, Self::Error> {
+        RedbStore::begin(self)
+    }
+}
+
+impl UnitOfWork for RedbUnitOfWork {
+    type Error = RedbError;
+
+    fn commit(self) -> Result<(), Self::Error> {
+        self.transaction.commit().map_err(storage)
+    }
+
+    fn rollback(self) -> Result<(), Self::Error> {
+        self.transaction.abort().map_err(storage)
+    }
+}
+
+impl<T> RepositoryProvider<T> for RedbUnitOfWork
+where
+    T: EntityKind + Serialize + DeserializeOwned + 'static,
+{
+    fn repository(&mut self) -> impl Repository<T, Error = Self::Error> + '_ {
+        RedbUnitOfWork::repository(self)
+    }
+}
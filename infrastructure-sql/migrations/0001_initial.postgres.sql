@@ -0,0 +1,52 @@
+CREATE TABLE lists (
+    id uuid PRIMARY KEY,
+    title text NOT NULL,
+    archived boolean NOT NULL,
+    data jsonb NOT NULL
+);
+
+CREATE TABLE tasks (
+    id uuid PRIMARY KEY,
+    list_id uuid NOT NULL,
+    name text NOT NULL,
+    data jsonb NOT NULL
+);
+
+CREATE INDEX tasks_list_id ON tasks (list_id);
+
+CREATE TABLE nets (
+    id uuid PRIMARY KEY,
+    details jsonb NOT NULL
+);
+
+CREATE TABLE net_statuses (
+    net_id uuid NOT NULL REFERENCES nets (id) ON DELETE CASCADE,
+    id uuid NOT NULL,
+    position integer NOT NULL,
+    name text NOT NULL,
+    category jsonb NOT NULL,
+    color jsonb,
+    description text,
+    wip_limit jsonb,
+    PRIMARY KEY (net_id, id)
+);
+
+CREATE TABLE net_tasks (
+    net_id uuid NOT NULL REFERENCES nets (id) ON DELETE CASCADE,
+    task_id uuid NOT NULL,
+    status_id uuid NOT NULL,
+    PRIMARY KEY (net_id, task_id)
+);
+
+CREATE INDEX net_tasks_task_id ON net_tasks (task_id);
+
+CREATE TABLE net_relations (
+    net_id uuid NOT NULL REFERENCES nets (id) ON DELETE CASCADE,
+    from_task uuid NOT NULL,
+    to_task uuid NOT NULL,
+    relation_type jsonb NOT NULL,
+    strength jsonb NOT NULL,
+    note text,
+    created_at timestamptz NOT NULL,
+    PRIMARY KEY (net_id, from_task, to_task)
+);
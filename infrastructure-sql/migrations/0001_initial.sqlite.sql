@@ -0,0 +1,52 @@
+CREATE TABLE lists (
+    id BLOB PRIMARY KEY,
+    title TEXT NOT NULL,
+    archived INTEGER NOT NULL,
+    data TEXT NOT NULL
+);
+
+CREATE TABLE tasks (
+    id BLOB PRIMARY KEY,
+    list_id BLOB NOT NULL,
+    name TEXT NOT NULL,
+    data TEXT NOT NULL
+);
+
+CREATE INDEX tasks_list_id ON tasks (list_id);
+
+CREATE TABLE nets (
+    id BLOB PRIMARY KEY,
+    details TEXT NOT NULL
+);
+
+CREATE TABLE net_statuses (
+    net_id BLOB NOT NULL REFERENCES nets (id) ON DELETE CASCADE,
+    id BLOB NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    category TEXT NOT NULL,
+    color TEXT,
+    description TEXT,
+    wip_limit TEXT,
+    PRIMARY KEY (net_id, id)
+);
+
+CREATE TABLE net_tasks (
+    net_id BLOB NOT NULL REFERENCES nets (id) ON DELETE CASCADE,
+    task_id BLOB NOT NULL,
+    status_id BLOB NOT NULL,
+    PRIMARY KEY (net_id, task_id)
+);
+
+CREATE INDEX net_tasks_task_id ON net_tasks (task_id);
+
+CREATE TABLE net_relations (
+    net_id BLOB NOT NULL REFERENCES nets (id) ON DELETE CASCADE,
+    from_task BLOB NOT NULL,
+    to_task BLOB NOT NULL,
+    relation_type TEXT NOT NULL,
+    strength TEXT NOT NULL,
+    note TEXT,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (net_id, from_task, to_task)
+);
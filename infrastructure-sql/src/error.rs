@@ -0,0 +1,19 @@
+use shared_kernel::Id;
+use task::domain::{error::TaskDomainError, net::Net};
+use thiserror::Error;
+
+/// Represents errors that can occur while mapping aggregates to and from table rows.
+#[derive(Debug, Error)]
+pub enum MappingError {
+    /// Error indicating that a stored value could not be encoded or decoded.
+    #[error("invalid stored value: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    /// Error indicating that the stored rows of a net do not form a valid net.
+    #[error("stored net {net:?} is invalid: {source}")]
+    InvalidNet {
+        net: Id<Net>,
+        #[source]
+        source: TaskDomainError,
+    },
+}
@@ -0,0 +1,8 @@
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+/// The `error` module contains the errors mapping aggregates to rows fails with.
+pub mod error;
+/// The `mapping` module contains the conversion of aggregates to and from table rows.
+pub mod mapping;
+/// The `schema` module contains the migrations creating the tables, in every SQL dialect.
+pub mod schema;
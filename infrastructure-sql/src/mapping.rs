@@ -0,0 +1,116 @@
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use shared_kernel::{Entity, Id};
+use task::domain::{
+    net::{Net, NetAggregateRoot, NetParts, RelationParts, Status, StatusParts},
+    task::Task,
+};
+
+use crate::error::MappingError;
+
+/// Encodes a value as JSON for a JSON column.
+pub fn encode<T: Serialize>(value: &T) -> Result<Value, MappingError> {
+    Ok(serde_json::to_value(value)?)
+}
+
+/// Decodes a value from a JSON column.
+pub fn decode<T: DeserializeOwned>(value: Value) -> Result<T, MappingError> {
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Encodes an optional value for a nullable JSON column.
+pub fn encode_option<T: Serialize>(value: &Option<T>) -> Result<Option<Value>, MappingError> {
+    value.as_ref().map(encode).transpose()
+}
+
+/// Decodes an optional value from a nullable JSON column.
+pub fn decode_option<T: DeserializeOwned>(value: Option<Value>) -> Result<Option<T>, MappingError> {
+    value.map(decode).transpose()
+}
+
+/// Decodes a validated name from a text column, checking it like a deserialized one.
+pub fn decode_text<T: DeserializeOwned>(text: String) -> Result<T, MappingError> {
+    decode(Value::String(text))
+}
+
+/// Represents a net split into the rows it is stored as: a row per status, task and
+/// relation, and the rest of the net as a JSON document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetRows {
+    pub details: Value,
+    pub statuses: Vec<StatusParts>,
+    pub tasks: Vec<(Id<Task>, Id<Status>)>,
+    pub relations: Vec<RelationParts>,
+}
+
+impl NetRows {
+    /// Splits the parts of a net into rows.
+    pub fn from_parts(mut parts: NetParts) -> Result<Self, MappingError> {
+        let statuses = std::mem::take(&mut parts.schema.statuses);
+        let tasks = std::mem::take(&mut parts.tasks);
+        let relations = std::mem::take(&mut parts.relations);
+        Ok(Self {
+            details: encode(&parts)?,
+            statuses,
+            tasks,
+            relations,
+        })
+    }
+
+    /// Joins rows back into the parts of a net; the rows must be in the order the parts
+    /// list them.
+    pub fn into_parts(self) -> Result<NetParts, MappingError> {
+        let mut parts: NetParts = decode(self.details)?;
+        parts.schema.statuses = self.statuses;
+        parts.tasks = self.tasks;
+        parts.relations = self.relations;
+        Ok(parts)
+    }
+
+    /// Joins rows back into the net stored with an id, checking that they form a valid net.
+    pub fn into_net(self, id: Id<Net>) -> Result<Entity<Net>, MappingError> {
+        <Entity<Net> as NetAggregateRoot>::from_parts(id, self.into_parts()?)
+            .map_err(|source| MappingError::InvalidNet { net: id, source })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::SystemClock;
+    use task::domain::{
+        name::StatusName,
+        net::{NetAggregateRoot, NetQuery, RelationType},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_net_rows() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let (epic, story) = (Id::new(), Id::new());
+        net.add_task(epic).unwrap();
+        net.add_task(story).unwrap();
+        net.new_relation(story, epic, RelationType::Compose, &SystemClock)
+            .unwrap();
+        let parts = net.to_parts();
+
+        let rows = NetRows::from_parts(parts.clone()).unwrap();
+        assert_eq!(rows.statuses.len(), 2);
+        assert_eq!(rows.tasks.len(), 2);
+        assert_eq!(rows.relations.len(), 1);
+        assert_eq!(rows.details["schema"]["statuses"], Value::Array(Vec::new()));
+        assert_eq!(rows.clone().into_parts().unwrap(), parts);
+        assert_eq!(rows.clone().into_net(net.id).unwrap().to_parts(), parts);
+
+        let mut invalid = rows;
+        invalid.tasks[0].1 = Id::new();
+        assert!(matches!(
+            invalid.into_net(net.id),
+            Err(MappingError::InvalidNet { net: id, .. }) if id == net.id
+        ));
+    }
+}
@@ -0,0 +1,34 @@
+/// Represents a migration, written once per SQL dialect so the adapters keep the same
+/// tables and columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Migration {
+    /// The version the migration brings the schema to, counting from one.
+    pub version: i32,
+    /// The statements of the migration for SQLite.
+    pub sqlite: &'static str,
+    /// The statements of the migration for PostgreSQL.
+    pub postgres: &'static str,
+}
+
+/// The migrations creating the schema, in the order they are applied.
+///
+/// New migrations are appended here with the next version, and existing ones are never
+/// edited.
+pub const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    sqlite: include_str!("../migrations/0001_initial.sqlite.sql"),
+    postgres: include_str!("../migrations/0001_initial.postgres.sql"),
+}];
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migration_versions() {
+        for (index, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.version, index as i32 + 1);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use infrastructure_sql::error::MappingError;
+use thiserror::Error;
+
+/// Represents errors that can occur while storing aggregates in SQLite.
+#[derive(Debug, Error)]
+pub enum SqliteError {
+    /// Error indicating that the database rejected a statement or could not be opened.
+    #[error("database error: {0}")]
+    Database(#[from] rusqlite::Error),
+
+    /// Error indicating that an aggregate could not be mapped to or from its rows.
+    #[error(transparent)]
+    Mapping(#[from] MappingError),
+
+    /// Error indicating that the database was written by a newer version of the adapter.
+    #[error("database schema version {found} is newer than the supported version {supported}")]
+    UnsupportedSchema { found: i64, supported: i64 },
+}
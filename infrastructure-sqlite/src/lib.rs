@@ -0,0 +1,10 @@
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+/// The `error` module contains the errors the SQLite adapter fails with.
+pub mod error;
+/// The `repository` module contains the repositories of lists, tasks and nets.
+pub mod repository;
+/// The `schema` module contains the migrations creating the tables.
+pub mod schema;
+/// The `store` module contains the database file and its units of work.
+pub mod store;
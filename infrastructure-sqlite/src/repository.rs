@@ -0,0 +1,455 @@
+use std::marker::PhantomData;
+
+use infrastructure_sql::mapping::{
+    decode, decode_option, decode_text, encode, encode_option, NetRows,
+};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use shared_kernel::{Entity, Id, Page, PageRequest, Repository, Uuid};
+use task::domain::{
+    list::List,
+    net::{Net, NetQuery, RelationParts, StatusParts},
+    task::Task,
+};
+
+use crate::error::SqliteError;
+
+/// A repository of aggregates of type `T` working in a unit of work.
+///
+/// Lists and tasks are stored as JSON documents next to the columns they are looked up by;
+/// nets are split into rows of statuses, tasks and relations.
+#[derive(Debug)]
+pub struct SqliteRepository<'a, T> {
+    connection: &'a Connection,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> SqliteRepository<'a, T> {
+    pub(crate) fn new(connection: &'a Connection) -> Self {
+        Self {
+            connection,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Loads the document stored with an id in a table.
+fn get_document<T: DeserializeOwned>(
+    connection: &Connection,
+    table: &str,
+    id: Id<T>,
+) -> Result<Option<Entity<T>>, SqliteError> {
+    let data: Option<Value> = connection
+        .query_row(
+            &format!("SELECT data FROM {table} WHERE id = ?1"),
+            [id.id],
+            |row| row.get("data"),
+        )
+        .optional()?;
+    data.map(|data| {
+        Ok(Entity {
+            id,
+            data: decode(data)?,
+        })
+    })
+    .transpose()
+}
+
+/// Loads one page of the documents stored in a table, ordered by id.
+fn page_documents<T: DeserializeOwned>(
+    connection: &Connection,
+    table: &str,
+    page: PageRequest,
+) -> Result<Page<Entity<T>>, SqliteError> {
+    let rows = connection
+        .prepare(&format!(
+            "SELECT id, data FROM {table} ORDER BY id LIMIT ?1 OFFSET ?2"
+        ))?
+        .query_map(params![limit(page), page.offset() as i64], |row| {
+            Ok((row.get::<_, Uuid>("id")?, row.get::<_, Value>("data")?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    let items = rows
+        .into_iter()
+        .map(|(id, data)| {
+            Ok(Entity {
+                id: Id::from_uuid(id),
+                data: decode(data)?,
+            })
+        })
+        .collect::<Result<_, SqliteError>>()?;
+
+    Ok(Page {
+        items,
+        total: Some(count(connection, table)?),
+        next_cursor: None,
+    })
+}
+
+/// Returns the row limit of a page request; limits past the range of SQLite integers mean
+/// no limit.
+fn limit(page: PageRequest) -> i64 {
+    i64::try_from(page.limit()).unwrap_or(-1)
+}
+
+/// Counts the rows of a table.
+fn count(connection: &Connection, table: &str) -> Result<usize, SqliteError> {
+    let total: i64 = connection.query_row(&format!("SELECT count(*) FROM {table}"), [], |row| {
+        row.get(0)
+    })?;
+    Ok(total as usize)
+}
+
+/// Deletes the row stored with an id in a table, along with the rows referencing it.
+fn delete_row<T>(connection: &Connection, table: &str, id: Id<T>) -> Result<(), SqliteError> {
+    connection.execute(&format!("DELETE FROM {table} WHERE id = ?1"), [id.id])?;
+    Ok(())
+}
+
+impl Repository<List> for SqliteRepository<'_, List> {
+    type Error = SqliteError;
+
+    fn get(&self, id: Id<List>) -> Result<Option<Entity<List>>, Self::Error> {
+        get_document(self.connection, "lists", id)
+    }
+
+    fn save(&mut self, entity: &Entity<List>) -> Result<(), Self::Error> {
+        self.connection.execute(
+            "INSERT INTO lists (id, title, archived, data) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (id) DO UPDATE
+             SET title = excluded.title, archived = excluded.archived, data = excluded.data",
+            params![
+                entity.id.id,
+                entity.data.title().as_str(),
+                entity.data.is_archived(),
+                encode(&entity.data)?,
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn delete(&mut self, id: Id<List>) -> Result<(), Self::Error> {
+        delete_row(self.connection, "lists", id)
+    }
+
+    fn list(&self, page: PageRequest) -> Result<Page<Entity<List>>, Self::Error> {
+        page_documents(self.connection, "lists", page)
+    }
+}
+
+impl Repository<Task> for SqliteRepository<'_, Task> {
+    type Error = SqliteError;
+
+    fn get(&self, id: Id<Task>) -> Result<Option<Entity<Task>>, Self::Error> {
+        get_document(self.connection, "tasks", id)
+    }
+
+    fn save(&mut self, entity: &Entity<Task>) -> Result<(), Self::Error> {
+        self.connection.execute(
+            "INSERT INTO tasks (id, list_id, name, data) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (id) DO UPDATE
+             SET list_id = excluded.list_id, name = excluded.name, data = excluded.data",
+            params![
+                entity.id.id,
+                entity.data.list().id,
+                entity.data.name().as_str(),
+                encode(&entity.data)?,
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn delete(&mut self, id: Id<Task>) -> Result<(), Self::Error> {
+        delete_row(self.connection, "tasks", id)
+    }
+
+    fn list(&self, page: PageRequest) -> Result<Page<Entity<Task>>, Self::Error> {
+        page_documents(self.connection, "tasks", page)
+    }
+}
+
+/// Loads the net stored with an id from its rows.
+fn get_net(connection: &Connection, id: Id<Net>) -> Result<Option<Entity<Net>>, SqliteError> {
+    let Some(details) = connection
+        .query_row("SELECT details FROM nets WHERE id = ?1", [id.id], |row| {
+            row.get::<_, Value>("details")
+        })
+        .optional()?
+    else {
+        return Ok(None);
+    };
+
+    type StatusRow = (
+        Uuid,
+        String,
+        Value,
+        Option<Value>,
+        Option<String>,
+        Option<Value>,
+    );
+    let statuses = connection
+        .prepare(
+            "SELECT id, name, category, color, description, wip_limit FROM net_statuses
+             WHERE net_id = ?1 ORDER BY position",
+        )?
+        .query_map([id.id], |row| {
+            Ok((
+                row.get("id")?,
+                row.get("name")?,
+                row.get("category")?,
+                row.get("color")?,
+                row.get("description")?,
+                row.get("wip_limit")?,
+            ))
+        })?
+        .collect::<Result<Vec<StatusRow>, _>>()?
+        .into_iter()
+        .map(|(status, name, category, color, description, wip_limit)| {
+            Ok(StatusParts {
+                id: Id::from_uuid(status),
+                name: decode_text(name)?,
+                category: decode(category)?,
+                color: decode_option(color)?,
+                description,
+                wip_limit: decode_option(wip_limit)?,
+            })
+        })
+        .collect::<Result<_, SqliteError>>()?;
+
+    let tasks = connection
+        .prepare("SELECT task_id, status_id FROM net_tasks WHERE net_id = ?1 ORDER BY task_id")?
+        .query_map([id.id], |row| {
+            Ok((
+                Id::from_uuid(row.get("task_id")?),
+                Id::from_uuid(row.get("status_id")?),
+            ))
+        })?
+        .collect::<Result<_, _>>()?;
+
+    type RelationRow = (
+        Uuid,
+        Uuid,
+        Value,
+        Value,
+        Option<String>,
+        shared_kernel::Timestamp,
+    );
+    let relations = connection
+        .prepare(
+            "SELECT from_task, to_task, relation_type, strength, note, created_at
+             FROM net_relations WHERE net_id = ?1 ORDER BY from_task, to_task",
+        )?
+        .query_map([id.id], |row| {
+            Ok((
+                row.get("from_task")?,
+                row.get("to_task")?,
+                row.get("relation_type")?,
+                row.get("strength")?,
+                row.get("note")?,
+                row.get("created_at")?,
+            ))
+        })?
+        .collect::<Result<Vec<RelationRow>, _>>()?
+        .into_iter()
+        .map(|(from, to, relation_type, strength, note, created_at)| {
+            Ok(RelationParts {
+                from: Id::from_uuid(from),
+                to: Id::from_uuid(to),
+                relation_type: decode(relation_type)?,
+                strength: decode(strength)?,
+                note,
+                created_at,
+            })
+        })
+        .collect::<Result<_, SqliteError>>()?;
+
+    let net = NetRows {
+        details,
+        statuses,
+        tasks,
+        relations,
+    }
+    .into_net(id)?;
+    Ok(Some(net))
+}
+
+/// Stores a net as rows, replacing the rows stored for it before.
+fn save_net(connection: &Connection, id: Id<Net>, rows: NetRows) -> Result<(), SqliteError> {
+    connection.execute(
+        "INSERT INTO nets (id, details) VALUES (?1, ?2)
+         ON CONFLICT (id) DO UPDATE SET details = excluded.details",
+        params![id.id, rows.details],
+    )?;
+    for table in ["net_statuses", "net_tasks", "net_relations"] {
+        connection.execute(&format!("DELETE FROM {table} WHERE net_id = ?1"), [id.id])?;
+    }
+
+    let mut insert = connection.prepare(
+        "INSERT INTO net_statuses
+         (net_id, id, position, name, category, color, description, wip_limit)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+    )?;
+    for (position, status) in rows.statuses.iter().enumerate() {
+        insert.execute(params![
+            id.id,
+            status.id.id,
+            position as i64,
+            status.name.as_str(),
+            encode(&status.category)?,
+            encode_option(&status.color)?,
+            status.description,
+            encode_option(&status.wip_limit)?,
+        ])?;
+    }
+    let mut insert = connection
+        .prepare("INSERT INTO net_tasks (net_id, task_id, status_id) VALUES (?1, ?2, ?3)")?;
+    for (task, status) in &rows.tasks {
+        insert.execute(params![id.id, task.id, status.id])?;
+    }
+    let mut insert = connection.prepare(
+        "INSERT INTO net_relations
+         (net_id, from_task, to_task, relation_type, strength, note, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )?;
+    for relation in &rows.relations {
+        insert.execute(params![
+            id.id,
+            relation.from.id,
+            relation.to.id,
+            encode(&relation.relation_type)?,
+            encode(&relation.strength)?,
+            relation.note,
+            relation.created_at,
+        ])?;
+    }
+
+    Ok(())
+}
+
+impl Repository<Net> for SqliteRepository<'_, Net> {
+    type Error = SqliteError;
+
+    fn get(&self, id: Id<Net>) -> Result<Option<Entity<Net>>, Self::Error> {
+        get_net(self.connection, id)
+    }
+
+    fn save(&mut self, entity: &Entity<Net>) -> Result<(), Self::Error> {
+        save_net(
+            self.connection,
+            entity.id,
+            NetRows::from_parts(entity.to_parts())?,
+        )
+    }
+
+    fn delete(&mut self, id: Id<Net>) -> Result<(), Self::Error> {
+        delete_row(self.connection, "nets", id)
+    }
+
+    fn list(&self, page: PageRequest) -> Result<Page<Entity<Net>>, Self::Error> {
+        let ids = self
+            .connection
+            .prepare("SELECT id FROM nets ORDER BY id LIMIT ?1 OFFSET ?2")?
+            .query_map(params![limit(page), page.offset() as i64], |row| {
+                row.get("id").map(Id::from_uuid)
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut items = Vec::with_capacity(ids.len());
+        for id in ids {
+            items.extend(get_net(self.connection, id)?);
+        }
+
+        Ok(Page {
+            items,
+            total: Some(count(self.connection, "nets")?),
+            next_cursor: None,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{SystemClock, UnitOfWork};
+    use task::domain::{
+        list::{Label, ListAggregateRoot},
+        name::{LabelName, ListTitle, StatusName, TaskName},
+        net::{Color, NetAggregateRoot, RelationType},
+        task::TaskAggregateRoot,
+    };
+
+    use super::*;
+    use crate::store::SqliteStore;
+
+    #[test]
+    fn test_list_and_task_round_trip() {
+        let mut store = SqliteStore::open_in_memory().unwrap();
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let docs = LabelName::new("docs").unwrap();
+        let color = Color {
+            red: 0,
+            green: 0,
+            blue: 255,
+        };
+        list.define_label(Label::new(docs.clone(), color)).unwrap();
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Write docs").unwrap(), list.id);
+        task.add_label(&list, docs).unwrap();
+        list.append_task(task.id).unwrap();
+
+        let unit = store.begin().unwrap();
+        unit.lists().save(&list).unwrap();
+        unit.tasks().save(&task).unwrap();
+        unit.commit().unwrap();
+
+        let unit = store.begin().unwrap();
+        let stored = unit.tasks().get(task.id).unwrap().unwrap();
+        assert_eq!(stored.data.labels(), task.data.labels());
+        let stored = unit.lists().get(list.id).unwrap().unwrap();
+        assert_eq!(stored.data.title(), list.data.title());
+        let page = unit.tasks().list(PageRequest::first(usize::MAX)).unwrap();
+        assert_eq!(page.total, Some(1));
+        assert_eq!(page.items[0].id, task.id);
+
+        unit.tasks().delete(task.id).unwrap();
+        unit.rollback().unwrap();
+        let unit = store.begin().unwrap();
+        assert!(unit.tasks().get(task.id).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_net_round_trip() {
+        let mut store = SqliteStore::open_in_memory().unwrap();
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        let (epic, story) = (Id::new(), Id::new());
+        net.add_task(epic).unwrap();
+        net.add_task(story).unwrap();
+        net.new_relation(story, epic, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.change_task_status(story, net.accepted_status(), &SystemClock)
+            .unwrap();
+
+        let unit = store.begin().unwrap();
+        unit.nets().save(&net).unwrap();
+        let stored = unit.nets().get(net.id).unwrap().unwrap();
+        assert_eq!(stored.to_parts(), net.to_parts());
+
+        net.remove_task(story, &SystemClock).unwrap();
+        unit.nets().save(&net).unwrap();
+        let page = unit.nets().list(PageRequest::first(10)).unwrap();
+        assert_eq!(page.items[0].to_parts(), net.to_parts());
+
+        unit.nets().delete(net.id).unwrap();
+        assert!(unit.nets().get(net.id).unwrap().is_none());
+        let rows: i64 = unit
+            .nets()
+            .connection
+            .query_row("SELECT count(*) FROM net_tasks", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(rows, 0);
+    }
+}
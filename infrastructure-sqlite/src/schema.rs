@@ -0,0 +1,34 @@
+use infrastructure_sql::schema::MIGRATIONS;
+use rusqlite::Connection;
+
+use crate::error::SqliteError;
+
+/// Returns the number of migrations applied to the database.
+pub(crate) fn version(connection: &Connection) -> Result<i64, SqliteError> {
+    Ok(connection.pragma_query_value(None, "user_version", |row| row.get(0))?)
+}
+
+/// Applies the migrations the database has not seen yet, each in its own transaction, and
+/// returns how many were applied.
+///
+/// The number of applied migrations is kept in the `user_version` pragma of the database
+/// file. Fails without changing anything if the database was migrated by a newer version.
+pub(crate) fn migrate(connection: &mut Connection) -> Result<usize, SqliteError> {
+    let current = version(connection)?;
+    let supported = MIGRATIONS.len() as i64;
+    if current > supported {
+        return Err(SqliteError::UnsupportedSchema {
+            found: current,
+            supported,
+        });
+    }
+
+    for migration in MIGRATIONS.iter().skip(current as usize) {
+        let transaction = connection.transaction()?;
+        transaction.execute_batch(migration.sqlite)?;
+        transaction.pragma_update(None, "user_version", migration.version)?;
+        transaction.commit()?;
+    }
+
+    Ok((supported - current) as usize)
+}
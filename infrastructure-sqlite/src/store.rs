@@ -0,0 +1,138 @@
+use std::path::Path;
+
+use rusqlite::{Connection, Transaction};
+use shared_kernel::{Repository, RepositoryProvider, Store, UnitOfWork};
+use task::domain::{list::List, net::Net, task::Task};
+
+use crate::{error::SqliteError, repository::SqliteRepository, schema};
+
+/// Represents a SQLite database holding the aggregates of the task context, for single-user
+/// deployments keeping their data in a local file.
+#[derive(Debug)]
+pub struct SqliteStore {
+    connection: Connection,
+}
+
+impl SqliteStore {
+    /// Opens the database file at a path, creating it if it does not exist, and brings its
+    /// schema up to date.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, SqliteError> {
+        Self::init(Connection::open(path)?)
+    }
+
+    /// Opens a database living in memory until the store is dropped.
+    pub fn open_in_memory() -> Result<Self, SqliteError> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(mut connection: Connection) -> Result<Self, SqliteError> {
+        connection.pragma_update(None, "foreign_keys", true)?;
+        schema::migrate(&mut connection)?;
+        Ok(Self { connection })
+    }
+
+    /// Returns the number of migrations applied to the database.
+    pub fn schema_version(&self) -> Result<i64, SqliteError> {
+        schema::version(&self.connection)
+    }
+
+    /// Opens a unit of work in a new transaction.
+    pub fn begin(&mut self) -> Result<SqliteUnitOfWork<'_>, SqliteError> {
+        Ok(SqliteUnitOfWork {
+            transaction: self.connection.transaction()?,
+        })
+    }
+}
+
+/// Represents a database transaction the repositories of a store work in.
+///
+/// Dropping the unit of work without committing rolls its changes back.
+#[derive(Debug)]
+pub struct SqliteUnitOfWork<'a> {
+    transaction: Transaction<'a>,
+}
+
+impl SqliteUnitOfWork<'_> {
+    /// Returns the repository of lists.
+    pub fn lists(&self) -> SqliteRepository<'_, List> {
+        SqliteRepository::new(&self.transaction)
+    }
+
+    /// Returns the repository of tasks.
+    pub fn tasks(&self) -> SqliteRepository<'_, Task> {
+        SqliteRepository::new(&self.transaction)
+    }
+
+    /// Returns the repository of nets.
+    pub fn nets(&self) -> SqliteRepository<'_, Net> {
+        SqliteRepository::new(&self.transaction)
+    }
+}
+
+impl Store for SqliteStore {
+    type Error = SqliteError;
+    type UnitOfWork<'a> = SqliteUnitOfWork<'a>;
+
+    fn begin(&mut self) -> Result<Self::UnitOfWork<'_>, Self::Error> {
+        SqliteStore::begin(self)
+    }
+}
+
+impl UnitOfWork for SqliteUnitOfWork<'_> {
+    type Error = SqliteError;
+
+    fn commit(self) -> Result<(), Self::Error> {
+        Ok(self.transaction.commit()?)
+    }
+
+    fn rollback(self) -> Result<(), Self::Error> {
+        Ok(self.transaction.rollback()?)
+    }
+}
+
+impl RepositoryProvider<List> for SqliteUnitOfWork<'_> {
+    fn repository(&mut self) -> impl Repository<List, Error = Self::Error> + '_ {
+        self.lists()
+    }
+}
+
+impl RepositoryProvider<Task> for SqliteUnitOfWork<'_> {
+    fn repository(&mut self) -> impl Repository<Task, Error = Self::Error> + '_ {
+        self.tasks()
+    }
+}
+
+impl RepositoryProvider<Net> for SqliteUnitOfWork<'_> {
+    fn repository(&mut self) -> impl Repository<Net, Error = Self::Error> + '_ {
+        self.nets()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrations_on_disk() {
+        let path =
+            std::env::temp_dir().join(format!("just-dev-{}.sqlite", shared_kernel::Uuid::new_v4()));
+        let store = SqliteStore::open(&path).unwrap();
+        assert_eq!(
+            store.schema_version().unwrap(),
+            infrastructure_sql::schema::MIGRATIONS.len() as i64
+        );
+        drop(store);
+
+        // Reopening leaves an up-to-date schema alone.
+        let mut connection = Connection::open(&path).unwrap();
+        assert_eq!(schema::migrate(&mut connection).unwrap(), 0);
+        connection.pragma_update(None, "user_version", 99).unwrap();
+        drop(connection);
+        assert!(matches!(
+            SqliteStore::open(&path),
+            Err(SqliteError::UnsupportedSchema { found: 99, .. })
+        ));
+        std::fs::remove_file(path).unwrap();
+    }
+}
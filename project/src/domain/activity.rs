@@ -0,0 +1,425 @@
+use std::collections::{BTreeSet, HashMap};
+
+use shared_kernel::{Clock, Id, Page, PageRequest, Timestamp, User};
+use task::domain::{event::TaskDomainEvent, list::List, net::Net, task::Task};
+
+use super::{event::ProjectDomainEvent, project::Project};
+
+/// A domain event the activity feed knows how to file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActivityEvent {
+    /// An event of the task context.
+    Task(TaskDomainEvent),
+    /// An event of the project context.
+    Project(ProjectDomainEvent),
+}
+
+impl From<TaskDomainEvent> for ActivityEvent {
+    fn from(event: TaskDomainEvent) -> Self {
+        ActivityEvent::Task(event)
+    }
+}
+
+impl From<ProjectDomainEvent> for ActivityEvent {
+    fn from(event: ProjectDomainEvent) -> Self {
+        ActivityEvent::Project(event)
+    }
+}
+
+/// Represents something that happened: an event, when it was recorded and who caused it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Activity {
+    /// When the event was recorded.
+    pub at: Timestamp,
+    /// The user who caused the event, if a user did.
+    pub actor: Option<Id<User>>,
+    /// The event.
+    pub event: ActivityEvent,
+}
+
+/// A read model answering "what happened recently" per project, per task and per user.
+///
+/// The feed is fed every event once, in the order the events were raised, and files each
+/// event under the feeds it belongs to at that moment: a task event belongs to the projects
+/// holding the list or a net of the task. Feeds list the newest activity first.
+#[derive(Debug, Default)]
+pub struct ActivityFeed {
+    activities: Vec<Activity>,
+    by_project: HashMap<Id<Project>, Vec<usize>>,
+    by_task: HashMap<Id<Task>, Vec<usize>>,
+    by_user: HashMap<Id<User>, Vec<usize>>,
+    list_of_task: HashMap<Id<Task>, Id<List>>,
+    nets_of_task: HashMap<Id<Task>, BTreeSet<Id<Net>>>,
+    projects_of_list: HashMap<Id<List>, BTreeSet<Id<Project>>>,
+    projects_of_net: HashMap<Id<Net>, BTreeSet<Id<Project>>>,
+}
+
+impl ActivityFeed {
+    /// Creates an empty feed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an event caused by a user, if any, at the current time.
+    pub fn record(
+        &mut self,
+        event: impl Into<ActivityEvent>,
+        actor: Option<Id<User>>,
+        clock: &impl Clock,
+    ) {
+        let event = event.into();
+        self.track_membership(&event);
+
+        let index = self.activities.len();
+        let tasks = match &event {
+            ActivityEvent::Task(event) => tasks_of(event),
+            ActivityEvent::Project(_) => Vec::new(),
+        };
+        for project in self.projects_of(&event, &tasks) {
+            self.by_project.entry(project).or_default().push(index);
+        }
+        for task in tasks {
+            self.by_task.entry(task).or_default().push(index);
+        }
+        if let Some(actor) = actor {
+            self.by_user.entry(actor).or_default().push(index);
+        }
+
+        self.activities.push(Activity {
+            at: clock.now(),
+            actor,
+            event,
+        });
+    }
+
+    /// Lists one page of all activity, newest first.
+    pub fn recent(&self, page: PageRequest) -> Page<&Activity> {
+        page.paginate(self.activities.iter().rev())
+    }
+
+    /// Lists one page of the activity of a project, newest first.
+    pub fn for_project(&self, project: Id<Project>, page: PageRequest) -> Page<&Activity> {
+        self.page_of(self.by_project.get(&project), page)
+    }
+
+    /// Lists one page of the activity of a task, newest first.
+    pub fn for_task(&self, task: Id<Task>, page: PageRequest) -> Page<&Activity> {
+        self.page_of(self.by_task.get(&task), page)
+    }
+
+    /// Lists one page of the activity caused by a user, newest first.
+    pub fn for_user(&self, user: Id<User>, page: PageRequest) -> Page<&Activity> {
+        self.page_of(self.by_user.get(&user), page)
+    }
+
+    /// Cuts a page, newest first, out of the activities at the given indices.
+    fn page_of(&self, indices: Option<&Vec<usize>>, page: PageRequest) -> Page<&Activity> {
+        let indices = indices.map_or(&[][..], Vec::as_slice);
+        page.paginate(indices.iter().rev().map(|&index| &self.activities[index]))
+    }
+
+    /// Keeps track of which list and nets hold each task and which projects hold each list
+    /// and net.
+    fn track_membership(&mut self, event: &ActivityEvent) {
+        match event {
+            ActivityEvent::Task(
+                TaskDomainEvent::TaskCreated { task, list, .. }
+                | TaskDomainEvent::TaskRecategorized { task, to: list, .. },
+            ) => {
+                self.list_of_task.insert(*task, *list);
+            }
+            ActivityEvent::Task(TaskDomainEvent::TaskAddedToNet { net, task }) => {
+                self.nets_of_task.entry(*task).or_default().insert(*net);
+            }
+            ActivityEvent::Task(TaskDomainEvent::TaskRemovedFromNet { net, task }) => {
+                if let Some(nets) = self.nets_of_task.get_mut(task) {
+                    nets.remove(net);
+                }
+            }
+            ActivityEvent::Project(ProjectDomainEvent::ListAddedToProject { project, list }) => {
+                self.projects_of_list
+                    .entry(*list)
+                    .or_default()
+                    .insert(*project);
+            }
+            ActivityEvent::Project(ProjectDomainEvent::ListRemovedFromProject {
+                project,
+                list,
+            }) => {
+                if let Some(projects) = self.projects_of_list.get_mut(list) {
+                    projects.remove(project);
+                }
+            }
+            ActivityEvent::Project(ProjectDomainEvent::NetAddedToProject { project, net }) => {
+                self.projects_of_net
+                    .entry(*net)
+                    .or_default()
+                    .insert(*project);
+            }
+            ActivityEvent::Project(ProjectDomainEvent::NetRemovedFromProject { project, net }) => {
+                if let Some(projects) = self.projects_of_net.get_mut(net) {
+                    projects.remove(project);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the projects an event belongs to, given the tasks it is about.
+    fn projects_of(&self, event: &ActivityEvent, tasks: &[Id<Task>]) -> BTreeSet<Id<Project>> {
+        let event = match event {
+            ActivityEvent::Project(event) => return BTreeSet::from([project_of(event)]),
+            ActivityEvent::Task(event) => event,
+        };
+
+        let lists = lists_of(event).into_iter().chain(
+            tasks
+                .iter()
+                .filter_map(|task| self.list_of_task.get(task).copied()),
+        );
+        let nets = net_of(event).into_iter().chain(
+            tasks
+                .iter()
+                .filter_map(|task| self.nets_of_task.get(task))
+                .flatten()
+                .copied(),
+        );
+
+        lists
+            .filter_map(|list| self.projects_of_list.get(&list))
+            .chain(nets.filter_map(|net| self.projects_of_net.get(&net)))
+            .flatten()
+            .copied()
+            .collect()
+    }
+}
+
+/// Returns the project a project event is about.
+fn project_of(event: &ProjectDomainEvent) -> Id<Project> {
+    match event {
+        ProjectDomainEvent::ProjectCreated { project, .. }
+        | ProjectDomainEvent::ProjectRenamed { project, .. }
+        | ProjectDomainEvent::ProjectDescriptionChanged { project, .. }
+        | ProjectDomainEvent::MemberAdded { project, .. }
+        | ProjectDomainEvent::MemberRemoved { project, .. }
+        | ProjectDomainEvent::ListAddedToProject { project, .. }
+        | ProjectDomainEvent::ListRemovedFromProject { project, .. }
+        | ProjectDomainEvent::NetAddedToProject { project, .. }
+        | ProjectDomainEvent::NetRemovedFromProject { project, .. } => *project,
+    }
+}
+
+/// Returns the tasks a task event is about.
+fn tasks_of(event: &TaskDomainEvent) -> Vec<Id<Task>> {
+    match event {
+        TaskDomainEvent::TaskPositioned { task, .. }
+        | TaskDomainEvent::TaskRemovedFromList { task, .. }
+        | TaskDomainEvent::TaskSectioned { task, .. }
+        | TaskDomainEvent::TaskCreated { task, .. }
+        | TaskDomainEvent::TaskRenamed { task, .. }
+        | TaskDomainEvent::TaskDescriptionChanged { task, .. }
+        | TaskDomainEvent::TaskScheduled { task, .. }
+        | TaskDomainEvent::TaskPrioritized { task, .. }
+        | TaskDomainEvent::TaskAssigned { task, .. }
+        | TaskDomainEvent::TaskUnassigned { task, .. }
+        | TaskDomainEvent::TaskLabeled { task, .. }
+        | TaskDomainEvent::TaskUnlabeled { task, .. }
+        | TaskDomainEvent::WorkLogged { task, .. }
+        | TaskDomainEvent::WorkEntryRemoved { task, .. }
+        | TaskDomainEvent::TaskRecurrenceChanged { task, .. }
+        | TaskDomainEvent::TaskFieldSet { task, .. }
+        | TaskDomainEvent::TaskFieldCleared { task, .. }
+        | TaskDomainEvent::TaskRecategorized { task, .. }
+        | TaskDomainEvent::TaskTemplateInstantiated { task, .. }
+        | TaskDomainEvent::ReminderScheduled { task, .. }
+        | TaskDomainEvent::ReminderFired { task, .. }
+        | TaskDomainEvent::TaskAddedToNet { task, .. }
+        | TaskDomainEvent::TaskRemovedFromNet { task, .. }
+        | TaskDomainEvent::TaskSoftRemovedFromNet { task, .. }
+        | TaskDomainEvent::TaskArchived { task, .. }
+        | TaskDomainEvent::TaskUnarchived { task, .. }
+        | TaskDomainEvent::TaskRestoredToNet { task, .. }
+        | TaskDomainEvent::TaskStatusChanged { task, .. }
+        | TaskDomainEvent::StatusPropagated { task, .. }
+        | TaskDomainEvent::MilestoneSet { task, .. }
+        | TaskDomainEvent::RequirementLinked { task, .. }
+        | TaskDomainEvent::RequirementUnlinked { task, .. }
+        | TaskDomainEvent::TaskEstimated { task, .. } => vec![*task],
+        TaskDomainEvent::RelationAdded { from, to, .. }
+        | TaskDomainEvent::RelationStrengthChanged { from, to, .. }
+        | TaskDomainEvent::RelationRemoved { from, to, .. }
+        | TaskDomainEvent::RelationAnnotated { from, to, .. } => vec![*from, *to],
+        TaskDomainEvent::SubtreeOrphaned { tasks, .. } => tasks.clone(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the lists a task event names directly.
+fn lists_of(event: &TaskDomainEvent) -> Vec<Id<List>> {
+    match event {
+        TaskDomainEvent::ListCreated { list, .. }
+        | TaskDomainEvent::ListRenamed { list, .. }
+        | TaskDomainEvent::ListArchived { list }
+        | TaskDomainEvent::ListUnarchived { list }
+        | TaskDomainEvent::ListMoved { list, .. }
+        | TaskDomainEvent::LabelDefined { list, .. }
+        | TaskDomainEvent::LabelRecolored { list, .. }
+        | TaskDomainEvent::LabelUndefined { list, .. }
+        | TaskDomainEvent::FieldDefined { list, .. }
+        | TaskDomainEvent::FieldRemoved { list, .. }
+        | TaskDomainEvent::TaskPositioned { list, .. }
+        | TaskDomainEvent::TaskRemovedFromList { list, .. }
+        | TaskDomainEvent::SectionAdded { list, .. }
+        | TaskDomainEvent::SectionRenamed { list, .. }
+        | TaskDomainEvent::SectionRemoved { list, .. }
+        | TaskDomainEvent::SectionMoved { list, .. }
+        | TaskDomainEvent::TaskSectioned { list, .. }
+        | TaskDomainEvent::TaskCreated { list, .. } => vec![*list],
+        TaskDomainEvent::ListsMerged { source, target } => vec![*source, *target],
+        TaskDomainEvent::TaskRecategorized { from, to, .. } => vec![*from, *to],
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the net a task event names directly, if any.
+fn net_of(event: &TaskDomainEvent) -> Option<Id<Net>> {
+    match event {
+        TaskDomainEvent::NetCreated { net, .. }
+        | TaskDomainEvent::SchemaReplaced { net, .. }
+        | TaskDomainEvent::TaskAddedToNet { net, .. }
+        | TaskDomainEvent::TaskRemovedFromNet { net, .. }
+        | TaskDomainEvent::TaskSoftRemovedFromNet { net, .. }
+        | TaskDomainEvent::TaskArchived { net, .. }
+        | TaskDomainEvent::TaskUnarchived { net, .. }
+        | TaskDomainEvent::TaskRestoredToNet { net, .. }
+        | TaskDomainEvent::TaskStatusChanged { net, .. }
+        | TaskDomainEvent::StatusPropagated { net, .. }
+        | TaskDomainEvent::MilestoneSet { net, .. }
+        | TaskDomainEvent::RequirementLinked { net, .. }
+        | TaskDomainEvent::RequirementUnlinked { net, .. }
+        | TaskDomainEvent::LinkedRequirementUpdated { net, .. }
+        | TaskDomainEvent::RelationAdded { net, .. }
+        | TaskDomainEvent::RelationStrengthChanged { net, .. }
+        | TaskDomainEvent::RelationRemoved { net, .. }
+        | TaskDomainEvent::StatusAdded { net, .. }
+        | TaskDomainEvent::StatusRemoved { net, .. }
+        | TaskDomainEvent::StatusRenamed { net, .. }
+        | TaskDomainEvent::DefaultStatusChanged { net, .. }
+        | TaskDomainEvent::AcceptedStatusChanged { net, .. }
+        | TaskDomainEvent::StatusColorChanged { net, .. }
+        | TaskDomainEvent::StatusDescriptionChanged { net, .. }
+        | TaskDomainEvent::StatusWipLimitChanged { net, .. }
+        | TaskDomainEvent::WipLimitExceeded { net, .. }
+        | TaskDomainEvent::PropagationPolicyChanged { net, .. }
+        | TaskDomainEvent::SingleParentRequirementChanged { net, .. }
+        | TaskDomainEvent::OrphanWarningsChanged { net, .. }
+        | TaskDomainEvent::SubtreeOrphaned { net, .. }
+        | TaskDomainEvent::RelationAnnotated { net, .. }
+        | TaskDomainEvent::TaskEstimated { net, .. }
+        | TaskDomainEvent::StatusMoved { net, .. }
+        | TaskDomainEvent::TransitionAllowed { net, .. }
+        | TaskDomainEvent::TransitionForbidden { net, .. }
+        | TaskDomainEvent::StatusCategoryChanged { net, .. }
+        | TaskDomainEvent::StatusMarkedAccepted { net, .. }
+        | TaskDomainEvent::StatusUnmarkedAccepted { net, .. }
+        | TaskDomainEvent::NetLocked { net }
+        | TaskDomainEvent::NetUnlocked { net }
+        | TaskDomainEvent::NetEditUndone { net, .. }
+        | TaskDomainEvent::NetEditRedone { net, .. } => Some(*net),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{AggregateRoot, Entity, SystemClock};
+    use task::domain::{
+        name::{StatusName, TaskName},
+        net::{NetAggregateRoot, NetQuery},
+        task::TaskAggregateRoot,
+    };
+
+    use super::*;
+    use crate::domain::project::{ProjectAggregateRoot, ProjectName};
+
+    #[test]
+    fn test_feeds() {
+        let mut time = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let (alice, bob) = (Id::new(), Id::new());
+        let mut feed = ActivityFeed::new();
+        let mut record = |feed: &mut ActivityFeed, events: Vec<ActivityEvent>, actor| {
+            for event in events {
+                time += chrono::Duration::minutes(1);
+                feed.record(event, actor, &|| time);
+            }
+        };
+
+        let list = Id::new();
+        let mut project: Entity<Project> =
+            ProjectAggregateRoot::new(ProjectName::new("Launch").unwrap());
+        project.add_list(list).unwrap();
+        record(
+            &mut feed,
+            into_activity(project.data.take_events()),
+            Some(alice),
+        );
+
+        let mut inside: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Inside").unwrap(), list);
+        let mut outside: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Outside").unwrap(), Id::new());
+        record(
+            &mut feed,
+            into_activity(inside.data.take_events()),
+            Some(bob),
+        );
+        record(
+            &mut feed,
+            into_activity(outside.data.take_events()),
+            Some(bob),
+        );
+
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.add_task(inside.id).unwrap();
+        net.data.take_events();
+        net.change_task_status(inside.id, net.accepted_status(), &SystemClock)
+            .unwrap();
+        record(&mut feed, into_activity(net.data.take_events()), None);
+
+        let page = feed.for_project(project.id, PageRequest::first(10));
+        assert_eq!(page.total, Some(4));
+        assert!(matches!(
+            page.items[0].event,
+            ActivityEvent::Task(TaskDomainEvent::TaskStatusChanged { .. })
+        ));
+        assert!(page.items.windows(2).all(|pair| pair[0].at > pair[1].at));
+
+        assert_eq!(
+            feed.for_task(outside.id, PageRequest::first(10)).total,
+            Some(1)
+        );
+        let page = feed.for_user(bob, PageRequest::first(1));
+        assert_eq!(page.total, Some(2));
+        assert_eq!(
+            page.items[0].event,
+            ActivityEvent::Task(TaskDomainEvent::TaskCreated {
+                task: outside.id,
+                name: TaskName::new("Outside").unwrap(),
+                list: outside.data.list(),
+            })
+        );
+        assert_eq!(feed.recent(PageRequest::first(100)).total, Some(5));
+        assert!(feed
+            .for_user(Id::new(), PageRequest::first(10))
+            .items
+            .is_empty());
+    }
+
+    fn into_activity<E: Into<ActivityEvent>>(events: Vec<E>) -> Vec<ActivityEvent> {
+        events.into_iter().map(Into::into).collect()
+    }
+}
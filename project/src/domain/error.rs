@@ -0,0 +1,61 @@
+use shared_kernel::{Id, User};
+use task::domain::{list::List, name::NameViolation, net::Net, task::Task};
+use thiserror::Error;
+
+use super::project::Project;
+
+/// Represents errors that can occur in the project domain.
+#[derive(Debug, Error)]
+pub enum ProjectDomainError {
+    /// Error indicating that a project name failed validation.
+    #[error("invalid project name: {reason}")]
+    InvalidName { reason: NameViolation },
+
+    /// Error indicating that a project description failed validation.
+    #[error("invalid project description: {reason}")]
+    InvalidDescription { reason: NameViolation },
+
+    /// Error indicating that a user is already a member of a project.
+    #[error("user {user:?} is already a member of project {project:?}")]
+    AlreadyMember {
+        project: Id<Project>,
+        user: Id<User>,
+    },
+
+    /// Error indicating that a user is not a member of a project.
+    #[error("user {user:?} is not a member of project {project:?}")]
+    NotMember {
+        project: Id<Project>,
+        user: Id<User>,
+    },
+
+    /// Error indicating that a list is already part of a project.
+    #[error("list {list:?} is already in project {project:?}")]
+    ListAlreadyInProject {
+        project: Id<Project>,
+        list: Id<List>,
+    },
+
+    /// Error indicating that a list is not part of a project.
+    #[error("list {list:?} not found in project {project:?}")]
+    ListNotInProject {
+        project: Id<Project>,
+        list: Id<List>,
+    },
+
+    /// Error indicating that a net is already part of a project.
+    #[error("net {net:?} is already in project {project:?}")]
+    NetAlreadyInProject { project: Id<Project>, net: Id<Net> },
+
+    /// Error indicating that a net is not part of a project.
+    #[error("net {net:?} not found in project {project:?}")]
+    NetNotInProject { project: Id<Project>, net: Id<Net> },
+
+    /// Error indicating that a net holds tasks categorized to lists outside of a project.
+    #[error("tasks {tasks:?} of net {net:?} are not in the lists of project {project:?}")]
+    TasksOutsideProject {
+        project: Id<Project>,
+        net: Id<Net>,
+        tasks: Vec<Id<Task>>,
+    },
+}
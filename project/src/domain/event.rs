@@ -0,0 +1,48 @@
+use shared_kernel::{Id, User};
+use task::domain::{list::List, net::Net};
+
+use super::project::{Project, ProjectDescription, ProjectName};
+
+/// Represents a domain event raised by an aggregate of the project context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProjectDomainEvent {
+    /// A project was created.
+    ProjectCreated {
+        project: Id<Project>,
+        name: ProjectName,
+    },
+    /// A project was renamed.
+    ProjectRenamed {
+        project: Id<Project>,
+        name: ProjectName,
+    },
+    /// The description of a project was set or cleared.
+    ProjectDescriptionChanged {
+        project: Id<Project>,
+        description: Option<ProjectDescription>,
+    },
+    /// A user joined a project.
+    MemberAdded {
+        project: Id<Project>,
+        user: Id<User>,
+    },
+    /// A user left a project.
+    MemberRemoved {
+        project: Id<Project>,
+        user: Id<User>,
+    },
+    /// A list was made part of a project.
+    ListAddedToProject {
+        project: Id<Project>,
+        list: Id<List>,
+    },
+    /// A list was taken out of a project.
+    ListRemovedFromProject {
+        project: Id<Project>,
+        list: Id<List>,
+    },
+    /// A net was made part of a project.
+    NetAddedToProject { project: Id<Project>, net: Id<Net> },
+    /// A net was taken out of a project.
+    NetRemovedFromProject { project: Id<Project>, net: Id<Net> },
+}
@@ -0,0 +1,4 @@
+pub mod activity;
+pub mod error;
+pub mod event;
+pub mod project;
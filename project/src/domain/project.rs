@@ -0,0 +1,471 @@
+use shared_kernel::{AggregateRoot, Entity, EntityKind, Id, User};
+use task::domain::{
+    list::List,
+    net::{Net, NetQuery},
+    task::Task,
+};
+
+use super::{error::ProjectDomainError, event::ProjectDomainEvent};
+
+type ProjectDomainResult<T> = Result<T, ProjectDomainError>;
+
+shared_kernel::validated_text!(
+    /// The validated name of a project.
+    ProjectName,
+    128,
+    ProjectDomainError,
+    |reason| ProjectDomainError::InvalidName { reason }
+);
+
+shared_kernel::validated_text!(
+    /// The validated Markdown description of a project.
+    ProjectDescription,
+    16_384,
+    ProjectDomainError,
+    |reason| ProjectDomainError::InvalidDescription { reason }
+);
+
+/// Represents a project: the lists and nets of one undertaking, and the users working on it.
+///
+/// Every task of a net of the project must be categorized to one of its lists; the project
+/// checks this whenever a net joins it.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "ProjectRecord", from = "ProjectRecord")
+)]
+pub struct Project {
+    name: ProjectName,
+    description: Option<ProjectDescription>,
+    members: Vec<Id<User>>,
+    lists: Vec<Id<List>>,
+    nets: Vec<Id<Net>>,
+    events: Vec<ProjectDomainEvent>,
+}
+
+/// The versioned on-disk form of a project; pending events are not persisted.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+enum ProjectRecord {
+    #[serde(rename = "1")]
+    V1 {
+        name: ProjectName,
+        description: Option<ProjectDescription>,
+        members: Vec<Id<User>>,
+        lists: Vec<Id<List>>,
+        nets: Vec<Id<Net>>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<Project> for ProjectRecord {
+    fn from(project: Project) -> Self {
+        ProjectRecord::V1 {
+            name: project.name,
+            description: project.description,
+            members: project.members,
+            lists: project.lists,
+            nets: project.nets,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ProjectRecord> for Project {
+    fn from(record: ProjectRecord) -> Self {
+        match record {
+            ProjectRecord::V1 {
+                name,
+                description,
+                members,
+                lists,
+                nets,
+            } => Project {
+                name,
+                description,
+                members,
+                lists,
+                nets,
+                events: Vec::new(),
+            },
+        }
+    }
+}
+
+impl EntityKind for Project {
+    const KIND: &'static str = "project";
+}
+
+impl Project {
+    /// Returns the name of the project.
+    pub fn name(&self) -> &ProjectName {
+        &self.name
+    }
+
+    /// Returns the description of the project, if it has one.
+    pub fn description(&self) -> Option<&ProjectDescription> {
+        self.description.as_ref()
+    }
+
+    /// Lists the members of the project in the order they joined.
+    pub fn members(&self) -> &[Id<User>] {
+        &self.members
+    }
+
+    /// Lists the lists of the project in the order they were added.
+    pub fn lists(&self) -> &[Id<List>] {
+        &self.lists
+    }
+
+    /// Lists the nets of the project in the order they were added.
+    pub fn nets(&self) -> &[Id<Net>] {
+        &self.nets
+    }
+
+    /// Lists the tasks of a net that are not categorized to a list of the project, sorted.
+    ///
+    /// Tasks of the net missing from the given tasks count as outside the project.
+    pub fn tasks_outside<'a>(
+        &self,
+        net: &Entity<Net>,
+        tasks: impl IntoIterator<Item = &'a Entity<Task>>,
+    ) -> Vec<Id<Task>> {
+        let inside: Vec<_> = tasks
+            .into_iter()
+            .filter(|task| self.lists.contains(&task.data.list()))
+            .map(|task| task.id)
+            .collect();
+        let mut outside: Vec<_> = net
+            .tasks()
+            .map(|(task, _)| task)
+            .filter(|task| !inside.contains(task))
+            .collect();
+        outside.sort();
+        outside
+    }
+}
+
+/// Trait for aggregate root operations on a `Project`.
+pub trait ProjectAggregateRoot {
+    /// Creates a new project without members, lists or nets.
+    fn new(name: ProjectName) -> Self;
+
+    /// Renames the project.
+    fn rename(&mut self, name: ProjectName);
+
+    /// Sets or clears the description of the project.
+    fn describe(&mut self, description: Option<ProjectDescription>);
+
+    /// Adds a user to the members of the project.
+    fn add_member(&mut self, user: Id<User>) -> ProjectDomainResult<()>;
+
+    /// Removes a user from the members of the project.
+    fn remove_member(&mut self, user: Id<User>) -> ProjectDomainResult<()>;
+
+    /// Makes a list part of the project.
+    fn add_list(&mut self, list: Id<List>) -> ProjectDomainResult<()>;
+
+    /// Takes a list out of the project.
+    ///
+    /// Nets of the project may still hold tasks of the list; check them with
+    /// [`Project::tasks_outside`].
+    fn remove_list(&mut self, list: Id<List>) -> ProjectDomainResult<()>;
+
+    /// Makes a net part of the project, failing if any of its tasks is not categorized to a
+    /// list of the project.
+    fn add_net<'a>(
+        &mut self,
+        net: &Entity<Net>,
+        tasks: impl IntoIterator<Item = &'a Entity<Task>>,
+    ) -> ProjectDomainResult<()>;
+
+    /// Takes a net out of the project.
+    fn remove_net(&mut self, net: Id<Net>) -> ProjectDomainResult<()>;
+}
+
+impl ProjectAggregateRoot for Entity<Project> {
+    fn new(name: ProjectName) -> Self {
+        let id = Id::new();
+        Entity {
+            id,
+            data: Project {
+                name: name.clone(),
+                description: None,
+                members: Vec::new(),
+                lists: Vec::new(),
+                nets: Vec::new(),
+                events: vec![ProjectDomainEvent::ProjectCreated { project: id, name }],
+            },
+        }
+    }
+
+    fn rename(&mut self, name: ProjectName) {
+        self.data.name = name.clone();
+        self.data.events.push(ProjectDomainEvent::ProjectRenamed {
+            project: self.id,
+            name,
+        });
+    }
+
+    fn describe(&mut self, description: Option<ProjectDescription>) {
+        if self.data.description == description {
+            return;
+        }
+
+        self.data.description = description.clone();
+        self.data
+            .events
+            .push(ProjectDomainEvent::ProjectDescriptionChanged {
+                project: self.id,
+                description,
+            });
+    }
+
+    fn add_member(&mut self, user: Id<User>) -> ProjectDomainResult<()> {
+        if self.data.members.contains(&user) {
+            return Err(ProjectDomainError::AlreadyMember {
+                project: self.id,
+                user,
+            });
+        }
+
+        self.data.members.push(user);
+        self.data.events.push(ProjectDomainEvent::MemberAdded {
+            project: self.id,
+            user,
+        });
+
+        Ok(())
+    }
+
+    fn remove_member(&mut self, user: Id<User>) -> ProjectDomainResult<()> {
+        let Some(index) = self.data.members.iter().position(|u| *u == user) else {
+            return Err(ProjectDomainError::NotMember {
+                project: self.id,
+                user,
+            });
+        };
+
+        self.data.members.remove(index);
+        self.data.events.push(ProjectDomainEvent::MemberRemoved {
+            project: self.id,
+            user,
+        });
+
+        Ok(())
+    }
+
+    fn add_list(&mut self, list: Id<List>) -> ProjectDomainResult<()> {
+        if self.data.lists.contains(&list) {
+            return Err(ProjectDomainError::ListAlreadyInProject {
+                project: self.id,
+                list,
+            });
+        }
+
+        self.data.lists.push(list);
+        self.data
+            .events
+            .push(ProjectDomainEvent::ListAddedToProject {
+                project: self.id,
+                list,
+            });
+
+        Ok(())
+    }
+
+    fn remove_list(&mut self, list: Id<List>) -> ProjectDomainResult<()> {
+        let Some(index) = self.data.lists.iter().position(|l| *l == list) else {
+            return Err(ProjectDomainError::ListNotInProject {
+                project: self.id,
+                list,
+            });
+        };
+
+        self.data.lists.remove(index);
+        self.data
+            .events
+            .push(ProjectDomainEvent::ListRemovedFromProject {
+                project: self.id,
+                list,
+            });
+
+        Ok(())
+    }
+
+    fn add_net<'a>(
+        &mut self,
+        net: &Entity<Net>,
+        tasks: impl IntoIterator<Item = &'a Entity<Task>>,
+    ) -> ProjectDomainResult<()> {
+        if self.data.nets.contains(&net.id) {
+            return Err(ProjectDomainError::NetAlreadyInProject {
+                project: self.id,
+                net: net.id,
+            });
+        }
+        let outside = self.data.tasks_outside(net, tasks);
+        if !outside.is_empty() {
+            return Err(ProjectDomainError::TasksOutsideProject {
+                project: self.id,
+                net: net.id,
+                tasks: outside,
+            });
+        }
+
+        self.data.nets.push(net.id);
+        self.data
+            .events
+            .push(ProjectDomainEvent::NetAddedToProject {
+                project: self.id,
+                net: net.id,
+            });
+
+        Ok(())
+    }
+
+    fn remove_net(&mut self, net: Id<Net>) -> ProjectDomainResult<()> {
+        let Some(index) = self.data.nets.iter().position(|n| *n == net) else {
+            return Err(ProjectDomainError::NetNotInProject {
+                project: self.id,
+                net,
+            });
+        };
+
+        self.data.nets.remove(index);
+        self.data
+            .events
+            .push(ProjectDomainEvent::NetRemovedFromProject {
+                project: self.id,
+                net,
+            });
+
+        Ok(())
+    }
+}
+
+impl AggregateRoot for Project {
+    type Event = ProjectDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::NameViolation;
+    use task::domain::{
+        list::ListAggregateRoot,
+        name::{ListTitle, StatusName, TaskName},
+        net::NetAggregateRoot,
+        task::TaskAggregateRoot,
+    };
+
+    use super::*;
+
+    fn project() -> Entity<Project> {
+        ProjectAggregateRoot::new(ProjectName::new("Launch").unwrap())
+    }
+
+    #[test]
+    fn test_names() {
+        assert!(matches!(
+            ProjectName::new(" "),
+            Err(ProjectDomainError::InvalidName {
+                reason: NameViolation::WhitespaceOnly
+            })
+        ));
+        assert!(matches!(
+            ProjectDescription::new("x".repeat(ProjectDescription::MAX_LEN + 1)),
+            Err(ProjectDomainError::InvalidDescription {
+                reason: NameViolation::TooLong { .. }
+            })
+        ));
+
+        let mut project = project();
+        project.rename(ProjectName::new("Relaunch").unwrap());
+        project.describe(Some(ProjectDescription::new("Second try.").unwrap()));
+        project.describe(Some(ProjectDescription::new("Second try.").unwrap()));
+        assert_eq!(project.data.name(), &"Relaunch");
+        assert_eq!(project.data.description().unwrap(), &"Second try.");
+        assert_eq!(project.take_events().len(), 3);
+    }
+
+    #[test]
+    fn test_members() {
+        let mut project = project();
+        let user = Id::new();
+
+        project.add_member(user).unwrap();
+        assert!(matches!(
+            project.add_member(user),
+            Err(ProjectDomainError::AlreadyMember { .. })
+        ));
+        assert_eq!(project.data.members(), [user]);
+
+        project.remove_member(user).unwrap();
+        assert!(matches!(
+            project.remove_member(user),
+            Err(ProjectDomainError::NotMember { .. })
+        ));
+    }
+
+    #[test]
+    fn test_nets_stay_within_lists() {
+        let mut project = project();
+        let list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Launch").unwrap());
+        project.add_list(list.id).unwrap();
+        assert!(matches!(
+            project.add_list(list.id),
+            Err(ProjectDomainError::ListAlreadyInProject { .. })
+        ));
+
+        let inside: Entity<Task> = TaskAggregateRoot::new(TaskName::new("Ship").unwrap(), list.id);
+        let outside: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Vacation").unwrap(), Id::new());
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.add_task(inside.id).unwrap();
+        net.add_task(outside.id).unwrap();
+
+        assert!(matches!(
+            project.add_net(&net, [&inside, &outside]),
+            Err(ProjectDomainError::TasksOutsideProject { tasks, .. }) if tasks == [outside.id]
+        ));
+        assert_eq!(project.data.tasks_outside(&net, [&inside]), [outside.id]);
+
+        net.remove_task(outside.id, &shared_kernel::SystemClock)
+            .unwrap();
+        project.add_net(&net, [&inside]).unwrap();
+        assert_eq!(project.data.nets(), [net.id]);
+        assert!(matches!(
+            project.add_net(&net, [&inside]),
+            Err(ProjectDomainError::NetAlreadyInProject { .. })
+        ));
+
+        project.remove_list(list.id).unwrap();
+        assert_eq!(project.data.tasks_outside(&net, [&inside]), [inside.id]);
+        project.remove_net(net.id).unwrap();
+        assert!(project.remove_net(net.id).is_err());
+        assert!(project.remove_list(list.id).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut project = project();
+        project.add_member(Id::new()).unwrap();
+
+        let json = serde_json::to_value(&project).unwrap();
+        assert_eq!(json["data"]["version"], "1");
+        let restored: Entity<Project> = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.data.members(), project.data.members());
+        assert!(restored.data.description().is_none());
+    }
+}
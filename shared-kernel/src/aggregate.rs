@@ -0,0 +1,49 @@
+use alloc::vec::Vec;
+
+use crate::{Clock, Entity, SoftDeletable, Timestamped, Versioned};
+
+/// An aggregate root that records the domain events raised by its mutations.
+///
+/// Events accumulate inside the aggregate until the application layer takes them for
+/// publication, typically right after the aggregate has been persisted. Bounded contexts
+/// implement this trait for their data value objects; `Entity<T>` and the wrappers in this
+/// crate forward to it.
+pub trait AggregateRoot {
+    /// The type of domain events raised by the aggregate.
+    type Event;
+
+    /// Takes all events recorded since the last call, leaving none pending.
+    fn take_events(&mut self) -> Vec<Self::Event>;
+}
+
+impl<T: AggregateRoot> AggregateRoot for Entity<T> {
+    type Event = T::Event;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        self.data.take_events()
+    }
+}
+
+impl<T: AggregateRoot, C: Clock> AggregateRoot for Timestamped<T, C> {
+    type Event = T::Event;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        self.inner_mut().take_events()
+    }
+}
+
+impl<T: AggregateRoot> AggregateRoot for Versioned<T> {
+    type Event = T::Event;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        self.inner_mut().take_events()
+    }
+}
+
+impl<T: AggregateRoot> AggregateRoot for SoftDeletable<T> {
+    type Event = T::Event;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        self.inner_mut().take_events()
+    }
+}
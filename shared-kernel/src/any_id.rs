@@ -0,0 +1,109 @@
+use alloc::borrow::Cow;
+use core::fmt;
+
+use crate::{Id, Uuid};
+
+/// Names the kind of entity an identifier belongs to.
+///
+/// The kind is persisted alongside type-erased identifiers, so it must be stable and unique
+/// across all bounded contexts.
+pub trait EntityKind {
+    /// The stable name of the kind.
+    const KIND: &'static str;
+}
+
+/// A type-erased identifier of an entity of any kind.
+///
+/// Heterogeneous collections such as event logs and activity feeds can reference entities of
+/// different kinds through `AnyId` and recover the typed [`Id`] with [`AnyId::downcast`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnyId {
+    kind: Cow<'static, str>,
+    id: Uuid,
+}
+
+impl AnyId {
+    /// Rebuilds a type-erased identifier from a persisted kind and UUID.
+    pub fn from_parts(kind: impl Into<Cow<'static, str>>, id: Uuid) -> Self {
+        Self {
+            kind: kind.into(),
+            id,
+        }
+    }
+
+    /// Returns the kind of the referenced entity.
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// Returns the UUID of the referenced entity.
+    pub fn uuid(&self) -> Uuid {
+        self.id
+    }
+
+    /// Checks whether the identifier references an entity of type `T`.
+    pub fn is<T: EntityKind>(&self) -> bool {
+        self.kind == T::KIND
+    }
+
+    /// Recovers the typed identifier, if it references an entity of type `T`.
+    pub fn downcast<T: EntityKind>(&self) -> Option<Id<T>> {
+        self.is::<T>().then(|| Id::from_uuid(self.id))
+    }
+}
+
+impl<T: EntityKind> From<Id<T>> for AnyId {
+    fn from(value: Id<T>) -> Self {
+        Self::from_parts(T::KIND, value.id)
+    }
+}
+
+impl fmt::Display for AnyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.kind, self.id.hyphenated())
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Apple;
+
+    #[derive(Debug)]
+    struct Pear;
+
+    impl EntityKind for Apple {
+        const KIND: &'static str = "apple";
+    }
+
+    impl EntityKind for Pear {
+        const KIND: &'static str = "pear";
+    }
+
+    #[test]
+    fn test_downcast() {
+        let id = Id::<Apple>::new();
+        let any = AnyId::from(id);
+
+        assert_eq!(any.kind(), "apple");
+        assert!(any.is::<Apple>());
+        assert_eq!(any.downcast::<Apple>(), Some(id));
+        assert_eq!(any.downcast::<Pear>(), None);
+        assert_eq!(any.to_string(), format!("apple:{id}"));
+    }
+
+    #[test]
+    fn test_from_parts() {
+        let id = Id::<Pear>::new();
+
+        assert_eq!(
+            AnyId::from_parts(String::from("pear"), id.id),
+            AnyId::from(id)
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::{AggregateRoot, EntityKind, Id};
+
+/// Represents a user acting on aggregates.
+///
+/// Users belong to their own bounded context; the kernel only shares their identity so that
+/// other contexts can attribute changes to them.
+#[derive(Debug)]
+pub struct User;
+
+impl EntityKind for User {
+    const KIND: &'static str = "user";
+}
+
+/// A value annotated with the users who created and last modified it.
+///
+/// The wrapped value is readable through `Deref`, but can only be mutated through
+/// [`Audited::update_by`] and [`Audited::try_update_by`]. Bounded contexts build the
+/// attributed (`*_by`) variants of their aggregate-root operations on top of them.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Audited<T> {
+    inner: T,
+    created_by: Id<User>,
+    last_modified_by: Id<User>,
+}
+
+impl<T> Audited<T> {
+    /// Wraps a value newly created by `actor`.
+    pub fn new(inner: T, actor: Id<User>) -> Self {
+        Self::from_parts(inner, actor, actor)
+    }
+
+    /// Rebuilds an audited value from previously recorded actors.
+    pub fn from_parts(inner: T, created_by: Id<User>, last_modified_by: Id<User>) -> Self {
+        Self {
+            inner,
+            created_by,
+            last_modified_by,
+        }
+    }
+
+    /// Returns the user who created the value.
+    pub fn created_by(&self) -> Id<User> {
+        self.created_by
+    }
+
+    /// Returns the user who last modified the value.
+    pub fn last_modified_by(&self) -> Id<User> {
+        self.last_modified_by
+    }
+
+    /// Unwraps the value, discarding the audit metadata.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Mutates the value on behalf of `actor`.
+    pub fn update_by<R>(&mut self, actor: Id<User>, f: impl FnOnce(&mut T) -> R) -> R {
+        let result = f(&mut self.inner);
+        self.last_modified_by = actor;
+        result
+    }
+
+    /// Mutates the value with a fallible operation on behalf of `actor`, recording the actor
+    /// only on success.
+    pub fn try_update_by<R, E>(
+        &mut self,
+        actor: Id<User>,
+        f: impl FnOnce(&mut T) -> Result<R, E>,
+    ) -> Result<R, E> {
+        let result = f(&mut self.inner)?;
+        self.last_modified_by = actor;
+        Ok(result)
+    }
+}
+
+impl<T> Deref for Audited<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: AggregateRoot> AggregateRoot for Audited<T> {
+    type Event = T::Event;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        self.inner.take_events()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_update_by_records_actor() {
+        let creator = Id::new();
+        let editor = Id::new();
+        let mut value = Audited::new(1, creator);
+
+        value.update_by(editor, |value| *value += 1);
+
+        assert_eq!(*value, 2);
+        assert_eq!(value.created_by(), creator);
+        assert_eq!(value.last_modified_by(), editor);
+    }
+
+    #[test]
+    fn test_failed_try_update_by_keeps_actor() {
+        let creator = Id::new();
+        let mut value = Audited::new(1, creator);
+
+        let result: Result<(), &str> = value.try_update_by(Id::new(), |_| Err("rejected"));
+
+        assert!(result.is_err());
+        assert_eq!(value.last_modified_by(), creator);
+    }
+}
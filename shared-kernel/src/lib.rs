@@ -1,28 +1,74 @@
-use std::marker::PhantomData;
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use uuid::Uuid;
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, marker::PhantomData, str::FromStr};
+
+use thiserror::Error;
+pub use uuid::Uuid;
+
+mod aggregate;
+mod any_id;
+mod audit;
+mod pagination;
+mod repository;
+mod snapshot;
+mod soft_delete;
+pub mod specification;
+mod text;
+mod time;
+mod version;
+
+pub use aggregate::AggregateRoot;
+pub use any_id::{AnyId, EntityKind};
+pub use audit::{Audited, User};
+pub use pagination::{Cursor, CursorRequest, Page, PageRequest};
+pub use repository::{InMemoryRepository, Repository, RepositoryProvider, Store, UnitOfWork};
+pub use snapshot::{Restorable, Snapshot};
+pub use soft_delete::SoftDeletable;
+pub use specification::Specification;
+pub use text::{name_violation, NameViolation};
+#[cfg(feature = "std")]
+pub use time::SystemClock;
+pub use time::{Clock, ManualClock, Timestamp, Timestamped};
+pub use version::{Version, VersionConflict, Versioned};
 
 /// A unique identifier for a data value object of type `T`.
+///
+/// The type parameter is only a tag: an `Id<T>` is `Send`, `Sync` and `'static` whenever
+/// `T` is `'static`, regardless of the auto traits of `T` itself.
 #[derive(Debug)]
 pub struct Id<T> {
     pub id: Uuid,
-    phantom: PhantomData<T>,
+    phantom: PhantomData<fn() -> T>,
 }
 
-impl<T> std::hash::Hash for Id<T> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Id<alloc::rc::Rc<()>>>();
+    assert_send_sync::<Id<core::cell::Cell<()>>>();
+};
+
+impl<T> core::hash::Hash for Id<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.id.hash(state);
     }
 }
 
 impl<T> Ord for Id<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.id.cmp(&other.id)
     }
 }
 
 impl<T> PartialOrd for Id<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
@@ -51,6 +97,30 @@ impl<T> Id<T> {
             phantom: PhantomData,
         }
     }
+
+    /// Wraps an existing UUID, e.g. one loaded from persistent storage.
+    pub fn from_uuid(id: Uuid) -> Self {
+        Self {
+            id,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a deterministic identifier from a namespace and a name (UUIDv5).
+    ///
+    /// The same namespace and name always yield the same identifier, which lets importers
+    /// derive stable ids from external keys.
+    pub fn new_v5(namespace: &Uuid, name: impl AsRef<[u8]>) -> Self {
+        Self::from_uuid(Uuid::new_v5(namespace, name.as_ref()))
+    }
+
+    /// Creates a new time-ordered identifier (UUIDv7).
+    ///
+    /// Identifiers created later compare greater, so `Ord` roughly follows creation order.
+    #[cfg(feature = "v7")]
+    pub fn new_v7() -> Self {
+        Self::from_uuid(Uuid::now_v7())
+    }
 }
 
 impl<T> Default for Id<T> {
@@ -59,8 +129,106 @@ impl<T> Default for Id<T> {
     }
 }
 
+impl<T> fmt::Display for Id<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.id.hyphenated(), f)
+    }
+}
+
+/// Error returned when a string cannot be parsed into an [`Id`].
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("invalid id {input:?}")]
+pub struct ParseIdError {
+    input: String,
+}
+
+impl ParseIdError {
+    /// Returns the input that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl<T> FromStr for Id<T> {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s)
+            .map(Self::from_uuid)
+            .map_err(|_| ParseIdError {
+                input: s.to_string(),
+            })
+    }
+}
+
+/// The alphabet of the short id encoding, in ascending order.
+const BASE62_ALPHABET: &[u8; 62] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// The maximum length of a short id; 62^22 exceeds 2^128.
+const SHORT_ID_MAX_LEN: usize = 22;
+
+impl<T> Id<T> {
+    /// Encodes the identifier as a compact, URL-safe base62 string of at most 22 characters.
+    pub fn to_short_string(&self) -> String {
+        let mut value = self.id.as_u128();
+        let mut digits = Vec::with_capacity(SHORT_ID_MAX_LEN);
+        loop {
+            digits.push(BASE62_ALPHABET[(value % 62) as usize]);
+            value /= 62;
+            if value == 0 {
+                break;
+            }
+        }
+        digits.reverse();
+        String::from_utf8(digits).expect("base62 alphabet is ASCII")
+    }
+
+    /// Parses an identifier from the encoding produced by [`Id::to_short_string`].
+    pub fn parse_short(s: &str) -> Result<Self, ParseIdError> {
+        let error = || ParseIdError {
+            input: s.to_string(),
+        };
+
+        if s.is_empty() || s.len() > SHORT_ID_MAX_LEN {
+            return Err(error());
+        }
+
+        s.bytes()
+            .try_fold(0u128, |value, byte| {
+                let digit = BASE62_ALPHABET.iter().position(|c| *c == byte)?;
+                value.checked_mul(62)?.checked_add(digit as u128)
+            })
+            .map(|value| Self::from_uuid(Uuid::from_u128(value)))
+            .ok_or_else(error)
+    }
+}
+
+impl<T> TryFrom<&str> for Id<T> {
+    type Error = ParseIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Id<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.id.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Id<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Uuid::deserialize(deserializer).map(Self::from_uuid)
+    }
+}
+
 /// An entity with a unique identifier and associated data value object.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Entity<T> {
     pub id: Id<T>,
     pub data: T,
@@ -74,3 +242,189 @@ impl<T> From<T> for Entity<T> {
         }
     }
 }
+
+/// Marks `Self` as sharing the identity of `T`, so an `Id<T>` may be reused as an `Id<Self>`.
+///
+/// Implement this for representations of the same entity, such as DTOs or read models, to
+/// allow [`Entity::map`] into them while keeping unrelated entity types apart.
+pub trait IdCompatible<T> {}
+
+impl<T> IdCompatible<T> for T {}
+
+impl<T> IdCompatible<T> for &T {}
+
+impl<T> IdCompatible<T> for &mut T {}
+
+impl<T> Id<T> {
+    /// Reinterprets the identifier as the identifier of an id-compatible type.
+    pub fn cast<U: IdCompatible<T>>(self) -> Id<U> {
+        Id::from_uuid(self.id)
+    }
+}
+
+impl<T> Entity<T> {
+    /// Transforms the data of the entity, keeping its identifier.
+    pub fn map<U: IdCompatible<T>>(self, f: impl FnOnce(T) -> U) -> Entity<U> {
+        Entity {
+            id: self.id.cast(),
+            data: f(self.data),
+        }
+    }
+
+    /// Transforms the data of the entity with a fallible conversion, keeping its identifier.
+    pub fn try_map<U: IdCompatible<T>, E>(
+        self,
+        f: impl FnOnce(T) -> Result<U, E>,
+    ) -> Result<Entity<U>, E> {
+        Ok(Entity {
+            id: self.id.cast(),
+            data: f(self.data)?,
+        })
+    }
+
+    /// Borrows the data of the entity.
+    pub fn as_ref(&self) -> Entity<&T> {
+        Entity {
+            id: self.id.cast(),
+            data: &self.data,
+        }
+    }
+
+    /// Mutably borrows the data of the entity.
+    pub fn as_mut(&mut self) -> Entity<&mut T> {
+        Entity {
+            id: self.id.cast(),
+            data: &mut self.data,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_id_from_uuid() {
+        let uuid = Uuid::new_v4();
+
+        assert_eq!(Id::<()>::from_uuid(uuid).id, uuid);
+    }
+
+    #[test]
+    fn test_id_new_v5_deterministic() {
+        let namespace = Uuid::NAMESPACE_URL;
+
+        assert_eq!(
+            Id::<()>::new_v5(&namespace, "JIRA-1"),
+            Id::<()>::new_v5(&namespace, "JIRA-1")
+        );
+        assert_ne!(
+            Id::<()>::new_v5(&namespace, "JIRA-1"),
+            Id::<()>::new_v5(&namespace, "JIRA-2")
+        );
+    }
+
+    #[test]
+    fn test_short_string_round_trip() {
+        for id in [
+            Id::<()>::new(),
+            Id::from_uuid(Uuid::nil()),
+            Id::from_uuid(Uuid::max()),
+        ] {
+            let short = id.to_short_string();
+
+            assert!(short.len() <= 22);
+            assert!(short.bytes().all(|byte| byte.is_ascii_alphanumeric()));
+            assert_eq!(Id::<()>::parse_short(&short).unwrap(), id);
+        }
+
+        assert_eq!(Id::<()>::from_uuid(Uuid::nil()).to_short_string(), "0");
+    }
+
+    #[test]
+    fn test_parse_short_error() {
+        assert!(Id::<()>::parse_short("").is_err());
+        assert!(Id::<()>::parse_short("not-base62").is_err());
+        assert!(Id::<()>::parse_short(&"z".repeat(22)).is_err());
+        assert!(Id::<()>::parse_short(&"1".repeat(23)).is_err());
+    }
+
+    #[test]
+    fn test_entity_map() {
+        struct Dto(String);
+
+        impl IdCompatible<i32> for Dto {}
+
+        let entity = Entity::from(1);
+        let id = entity.id;
+
+        let dto = entity.map(|value| Dto(value.to_string()));
+        assert_eq!(dto.id.id, id.id);
+        assert_eq!(dto.data.0, "1");
+
+        let result: Result<Entity<Dto>, &str> = Entity::from(1).try_map(|_| Err("rejected"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_entity_as_ref_as_mut() {
+        let mut entity = Entity::from(1);
+
+        *entity.as_mut().data += 1;
+
+        assert_eq!(*entity.as_ref().data, 2);
+        assert_eq!(entity.as_ref().id.id, entity.id.id);
+    }
+
+    #[cfg(feature = "v7")]
+    #[test]
+    fn test_id_new_v7_ordered() {
+        let ids: Vec<_> = (0..16).map(|_| Id::<()>::new_v7()).collect();
+
+        assert_eq!(ids[0].id.get_version(), Some(uuid::Version::SortRand));
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_id_display_from_str_round_trip() {
+        let id = Id::<()>::new();
+        let text = id.to_string();
+
+        assert_eq!(text, id.id.hyphenated().to_string());
+        assert_eq!(text.parse::<Id<()>>().unwrap(), id);
+        assert_eq!(Id::<()>::try_from(text.as_str()).unwrap(), id);
+    }
+
+    #[test]
+    fn test_id_from_str_error() {
+        let error = "not-an-id".parse::<Id<()>>().unwrap_err();
+
+        assert_eq!(error.input(), "not-an-id");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_id_serde_round_trip() {
+        let id = Id::<()>::new();
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, format!("\"{}\"", id.id));
+        assert_eq!(serde_json::from_str::<Id<()>>(&json).unwrap(), id);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_entity_serde_round_trip() {
+        let entity = Entity::from("Test".to_string());
+        let json = serde_json::to_value(&entity).unwrap();
+
+        assert_eq!(json["id"], serde_json::json!(entity.id.id));
+        assert_eq!(json["data"], "Test");
+
+        let restored: Entity<String> = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.id, entity.id);
+        assert_eq!(restored.data, entity.data);
+    }
+}
@@ -0,0 +1,169 @@
+use alloc::{string::String, vec::Vec};
+
+/// A request for one page of results, addressed by offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PageRequest {
+    offset: usize,
+    limit: usize,
+}
+
+impl PageRequest {
+    /// Creates a request for at most `limit` items, skipping the first `offset` items.
+    pub fn new(offset: usize, limit: usize) -> Self {
+        Self { offset, limit }
+    }
+
+    /// Creates a request for the first page of at most `limit` items.
+    pub fn first(limit: usize) -> Self {
+        Self::new(0, limit)
+    }
+
+    /// Returns the number of items skipped.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the maximum number of items in the page.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the request for the page following this one.
+    pub fn next(&self) -> Self {
+        Self::new(self.offset + self.limit, self.limit)
+    }
+
+    /// Cuts the requested page out of an in-memory sequence of items.
+    pub fn paginate<T>(&self, items: impl IntoIterator<Item = T>) -> Page<T> {
+        let items: Vec<_> = items.into_iter().collect();
+        let total = items.len();
+        let items = items
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit)
+            .collect();
+        Page {
+            items,
+            total: Some(total),
+            next_cursor: None,
+        }
+    }
+}
+
+/// An opaque position in a result set, produced by a previous page.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// Wraps an encoded position. Only the component producing the cursor knows its format.
+    pub fn new(position: impl Into<String>) -> Self {
+        Self(position.into())
+    }
+
+    /// Returns the encoded position.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A request for one page of results, addressed by cursor.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CursorRequest {
+    after: Option<Cursor>,
+    limit: usize,
+}
+
+impl CursorRequest {
+    /// Creates a request for the first page of at most `limit` items.
+    pub fn first(limit: usize) -> Self {
+        Self { after: None, limit }
+    }
+
+    /// Creates a request for at most `limit` items following `cursor`.
+    pub fn after(cursor: Cursor, limit: usize) -> Self {
+        Self {
+            after: Some(cursor),
+            limit,
+        }
+    }
+
+    /// Returns the cursor after which the page starts, or `None` for the first page.
+    pub fn cursor(&self) -> Option<&Cursor> {
+        self.after.as_ref()
+    }
+
+    /// Returns the maximum number of items in the page.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+/// One page of results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Page<T> {
+    /// The items of the page.
+    pub items: Vec<T>,
+    /// The total number of items across all pages, if known.
+    pub total: Option<usize>,
+    /// The cursor to request the next page with, if there is a next page and the result
+    /// set is cursor-based.
+    pub next_cursor: Option<Cursor>,
+}
+
+impl<T> Page<T> {
+    /// Creates a page of a cursor-based result set.
+    pub fn with_cursor(items: Vec<T>, next_cursor: Option<Cursor>) -> Self {
+        Self {
+            items,
+            total: None,
+            next_cursor,
+        }
+    }
+
+    /// Transforms the items of the page, keeping its position in the result set.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_paginate() {
+        let request = PageRequest::first(2);
+
+        let page = request.paginate(1..=5);
+        assert_eq!(page.items, vec![1, 2]);
+        assert_eq!(page.total, Some(5));
+
+        let page = request.next().next().paginate(1..=5);
+        assert_eq!(page.items, vec![5]);
+        assert_eq!(page.total, Some(5));
+
+        let page = request.next().next().next().paginate(1..=5);
+        assert!(page.items.is_empty());
+    }
+
+    #[test]
+    fn test_page_map() {
+        let page = Page::with_cursor(vec![1, 2], Some(Cursor::new("2")));
+
+        let page = page.map(|item| item * 10);
+
+        assert_eq!(page.items, vec![10, 20]);
+        assert_eq!(page.next_cursor, Some(Cursor::new("2")));
+    }
+}
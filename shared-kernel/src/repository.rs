@@ -0,0 +1,131 @@
+use alloc::collections::BTreeMap;
+use core::convert::Infallible;
+
+use crate::{Entity, Id, Page, PageRequest};
+
+/// A collection-like abstraction over the persistent storage of aggregates of type `T`.
+///
+/// Persistence adapters implement this trait for each aggregate they store, so application
+/// services can load and save aggregates without knowing the storage technology.
+pub trait Repository<T> {
+    /// The error raised by the underlying storage.
+    type Error: core::error::Error;
+
+    /// Loads the aggregate with the given id, if it exists.
+    fn get(&self, id: Id<T>) -> Result<Option<Entity<T>>, Self::Error>;
+
+    /// Inserts the aggregate, or replaces the stored aggregate with the same id.
+    fn save(&mut self, entity: &Entity<T>) -> Result<(), Self::Error>;
+
+    /// Deletes the aggregate with the given id. Deleting a missing aggregate is a no-op.
+    fn delete(&mut self, id: Id<T>) -> Result<(), Self::Error>;
+
+    /// Loads one page of aggregates, ordered by id.
+    fn list(&self, page: PageRequest) -> Result<Page<Entity<T>>, Self::Error>;
+}
+
+/// A set of changes across one or more repositories that is committed or rolled back as a
+/// whole.
+pub trait UnitOfWork {
+    /// The error raised by the underlying storage.
+    type Error: core::error::Error;
+
+    /// Makes all changes of the unit of work durable.
+    fn commit(self) -> Result<(), Self::Error>;
+
+    /// Discards all changes of the unit of work.
+    fn rollback(self) -> Result<(), Self::Error>;
+}
+
+/// A unit of work giving access to the repository of the aggregates of type `T`.
+///
+/// Changes made through the repository only become durable when the unit of work commits.
+pub trait RepositoryProvider<T>: UnitOfWork {
+    /// Returns the repository of the aggregates of type `T`.
+    fn repository(&mut self) -> impl Repository<T, Error = Self::Error> + '_;
+}
+
+/// Storage that opens units of work, so changes to several aggregates are made atomically.
+pub trait Store {
+    /// The error raised by the underlying storage.
+    type Error: core::error::Error;
+    /// The unit of work the store opens.
+    type UnitOfWork<'a>: UnitOfWork<Error = Self::Error>
+    where
+        Self: 'a;
+
+    /// Opens a unit of work.
+    fn begin(&mut self) -> Result<Self::UnitOfWork<'_>, Self::Error>;
+}
+
+/// A repository keeping aggregates in memory, for tests and prototypes.
+#[derive(Debug)]
+pub struct InMemoryRepository<T> {
+    entities: BTreeMap<Id<T>, Entity<T>>,
+}
+
+impl<T> InMemoryRepository<T> {
+    /// Creates an empty repository.
+    pub fn new() -> Self {
+        Self {
+            entities: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> Default for InMemoryRepository<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Repository<T> for InMemoryRepository<T> {
+    type Error = Infallible;
+
+    fn get(&self, id: Id<T>) -> Result<Option<Entity<T>>, Self::Error> {
+        Ok(self.entities.get(&id).cloned())
+    }
+
+    fn save(&mut self, entity: &Entity<T>) -> Result<(), Self::Error> {
+        self.entities.insert(entity.id, entity.clone());
+        Ok(())
+    }
+
+    fn delete(&mut self, id: Id<T>) -> Result<(), Self::Error> {
+        self.entities.remove(&id);
+        Ok(())
+    }
+
+    fn list(&self, page: PageRequest) -> Result<Page<Entity<T>>, Self::Error> {
+        Ok(page.paginate(self.entities.values().cloned()))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_in_memory_repository() {
+        let mut repository = InMemoryRepository::new();
+        let first = Entity::from(1);
+        let second = Entity::from(2);
+
+        repository.save(&first).unwrap();
+        repository.save(&second).unwrap();
+        assert_eq!(repository.get(first.id).unwrap().unwrap().data, 1);
+
+        let page = repository.list(PageRequest::first(10)).unwrap();
+        assert_eq!(page.total, Some(2));
+
+        repository.delete(first.id).unwrap();
+        repository.delete(first.id).unwrap();
+        assert!(repository.get(first.id).unwrap().is_none());
+        assert_eq!(
+            repository.list(PageRequest::first(10)).unwrap().items[0].id,
+            second.id
+        );
+    }
+}
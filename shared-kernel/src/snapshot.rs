@@ -0,0 +1,98 @@
+use crate::Entity;
+
+/// A value whose state can be captured as a checkpoint.
+pub trait Snapshot {
+    /// The captured state.
+    type Snapshot;
+
+    /// Captures the current state.
+    fn snapshot(&self) -> Self::Snapshot;
+}
+
+/// A value that can be rolled back to a previously captured state.
+pub trait Restorable: Snapshot {
+    /// Replaces the current state with the captured one.
+    fn restore(&mut self, snapshot: Self::Snapshot);
+
+    /// Runs a multi-step edit, rolling back to the state before it if any step fails.
+    fn transaction<R, E>(&mut self, edit: impl FnOnce(&mut Self) -> Result<R, E>) -> Result<R, E>
+    where
+        Self: Sized,
+    {
+        let snapshot = self.snapshot();
+        let result = edit(self);
+        if result.is_err() {
+            self.restore(snapshot);
+        }
+        result
+    }
+}
+
+impl<T: Snapshot> Snapshot for Entity<T> {
+    type Snapshot = T::Snapshot;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.data.snapshot()
+    }
+}
+
+impl<T: Restorable> Restorable for Entity<T> {
+    fn restore(&mut self, snapshot: Self::Snapshot) {
+        self.data.restore(snapshot);
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Counter(u32);
+
+    impl Snapshot for Counter {
+        type Snapshot = u32;
+
+        fn snapshot(&self) -> u32 {
+            self.0
+        }
+    }
+
+    impl Restorable for Counter {
+        fn restore(&mut self, snapshot: u32) {
+            self.0 = snapshot;
+        }
+    }
+
+    #[test]
+    fn test_entity_restore_keeps_id() {
+        let mut entity = Entity::from(Counter(1));
+        let id = entity.id;
+        let snapshot = entity.snapshot();
+
+        entity.data.0 = 5;
+        entity.restore(snapshot);
+
+        assert_eq!(entity.data.0, 1);
+        assert_eq!(entity.id, id);
+    }
+
+    #[test]
+    fn test_transaction_rolls_back_on_error() {
+        let mut counter = Counter(1);
+
+        let committed: Result<u32, ()> = counter.transaction(|counter| {
+            counter.0 += 1;
+            Ok(counter.0)
+        });
+        assert_eq!(committed, Ok(2));
+        assert_eq!(counter.0, 2);
+
+        let rolled_back: Result<(), &str> = counter.transaction(|counter| {
+            counter.0 += 1;
+            Err("failed")
+        });
+        assert_eq!(rolled_back, Err("failed"));
+        assert_eq!(counter.0, 2);
+    }
+}
@@ -0,0 +1,118 @@
+use core::ops::Deref;
+
+use crate::{Clock, Timestamp};
+
+/// A value that can be marked as deleted without being destroyed.
+///
+/// A deleted value keeps its contents and can be restored, so accidental deletions can be
+/// undone. Callers decide how deleted values are hidden, e.g. by filtering on
+/// [`SoftDeletable::is_deleted`].
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoftDeletable<T> {
+    inner: T,
+    deleted_at: Option<Timestamp>,
+}
+
+impl<T> SoftDeletable<T> {
+    /// Wraps a value that is not deleted.
+    pub fn new(inner: T) -> Self {
+        Self::from_parts(inner, None)
+    }
+
+    /// Rebuilds a value from a previously recorded deletion time.
+    pub fn from_parts(inner: T, deleted_at: Option<Timestamp>) -> Self {
+        Self { inner, deleted_at }
+    }
+
+    /// Returns the time the value was deleted, if it is deleted.
+    pub fn deleted_at(&self) -> Option<Timestamp> {
+        self.deleted_at
+    }
+
+    /// Returns whether the value is deleted.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Marks the value as deleted now. Deleting an already deleted value keeps the original
+    /// deletion time.
+    pub fn delete(&mut self, clock: &impl Clock) {
+        if self.deleted_at.is_none() {
+            self.deleted_at = Some(clock.now());
+        }
+    }
+
+    /// Restores a deleted value.
+    pub fn restore(&mut self) {
+        self.deleted_at = None;
+    }
+
+    /// Returns the value if it is not deleted.
+    pub fn live(&self) -> Option<&T> {
+        (!self.is_deleted()).then_some(&self.inner)
+    }
+
+    /// Returns mutable access to the value if it is not deleted.
+    pub fn live_mut(&mut self) -> Option<&mut T> {
+        (!self.is_deleted()).then_some(&mut self.inner)
+    }
+
+    /// Gives crate-internal access to the wrapped value regardless of its deletion state.
+    pub(crate) fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwraps the value, discarding the deletion state.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> Deref for SoftDeletable<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+
+    use chrono::{DateTime, TimeDelta};
+
+    use super::*;
+
+    fn now() -> Timestamp {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    #[test]
+    fn test_delete_and_restore() {
+        let now = now();
+        let mut value = SoftDeletable::new(1);
+
+        value.delete(&|| now);
+        value.delete(&|| now + TimeDelta::seconds(1));
+
+        assert!(value.is_deleted());
+        assert_eq!(value.deleted_at(), Some(now));
+        assert_eq!(value.live(), None);
+        assert_eq!(*value, 1);
+
+        value.restore();
+
+        assert!(!value.is_deleted());
+        assert_eq!(value.live(), Some(&1));
+    }
+
+    #[test]
+    fn test_live_mut_on_deleted_value() {
+        let mut value = SoftDeletable::new(1);
+        value.delete(&now);
+
+        assert!(value.live_mut().is_none());
+    }
+}
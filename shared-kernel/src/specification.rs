@@ -0,0 +1,102 @@
+/// A business rule that a candidate of type `T` either satisfies or not.
+///
+/// Specifications can be composed with [`Specification::and`], [`Specification::or`] and
+/// [`Specification::not`], so query layers can build filters declaratively from small rules.
+pub trait Specification<T: ?Sized> {
+    /// Checks whether the candidate satisfies the specification.
+    fn is_satisfied_by(&self, candidate: &T) -> bool;
+
+    /// Combines two specifications that must both be satisfied.
+    fn and<S>(self, other: S) -> And<Self, S>
+    where
+        Self: Sized,
+        S: Specification<T>,
+    {
+        And(self, other)
+    }
+
+    /// Combines two specifications of which at least one must be satisfied.
+    fn or<S>(self, other: S) -> Or<Self, S>
+    where
+        Self: Sized,
+        S: Specification<T>,
+    {
+        Or(self, other)
+    }
+
+    /// Negates the specification.
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self)
+    }
+}
+
+/// A specification satisfied when both inner specifications are satisfied.
+#[derive(Debug, Clone, Copy)]
+pub struct And<A, B>(A, B);
+
+/// A specification satisfied when at least one inner specification is satisfied.
+#[derive(Debug, Clone, Copy)]
+pub struct Or<A, B>(A, B);
+
+/// A specification satisfied when the inner specification is not satisfied.
+#[derive(Debug, Clone, Copy)]
+pub struct Not<A>(A);
+
+impl<T: ?Sized, A: Specification<T>, B: Specification<T>> Specification<T> for And<A, B> {
+    fn is_satisfied_by(&self, candidate: &T) -> bool {
+        self.0.is_satisfied_by(candidate) && self.1.is_satisfied_by(candidate)
+    }
+}
+
+impl<T: ?Sized, A: Specification<T>, B: Specification<T>> Specification<T> for Or<A, B> {
+    fn is_satisfied_by(&self, candidate: &T) -> bool {
+        self.0.is_satisfied_by(candidate) || self.1.is_satisfied_by(candidate)
+    }
+}
+
+impl<T: ?Sized, A: Specification<T>> Specification<T> for Not<A> {
+    fn is_satisfied_by(&self, candidate: &T) -> bool {
+        !self.0.is_satisfied_by(candidate)
+    }
+}
+
+impl<T: ?Sized, F: Fn(&T) -> bool> Specification<T> for F {
+    fn is_satisfied_by(&self, candidate: &T) -> bool {
+        self(candidate)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+
+    use super::*;
+
+    fn even(n: &i32) -> bool {
+        n % 2 == 0
+    }
+
+    fn positive(n: &i32) -> bool {
+        *n > 0
+    }
+
+    #[test]
+    fn test_combinators() {
+        let spec = even.and(positive);
+        assert!(spec.is_satisfied_by(&2));
+        assert!(!spec.is_satisfied_by(&-2));
+        assert!(!spec.is_satisfied_by(&3));
+
+        let spec = even.or(positive);
+        assert!(spec.is_satisfied_by(&-2));
+        assert!(spec.is_satisfied_by(&3));
+        assert!(!spec.is_satisfied_by(&-3));
+
+        let spec = even.not().and(positive.not());
+        assert!(spec.is_satisfied_by(&-3));
+        assert!(!spec.is_satisfied_by(&3));
+    }
+}
@@ -0,0 +1,183 @@
+use core::fmt;
+
+/// Describes why a name or text failed validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameViolation {
+    /// The name is empty.
+    Empty,
+    /// The name consists of whitespace only.
+    WhitespaceOnly,
+    /// The name is longer than the maximum number of characters.
+    TooLong { max: usize },
+}
+
+impl fmt::Display for NameViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NameViolation::Empty => f.write_str("must not be empty"),
+            NameViolation::WhitespaceOnly => f.write_str("must not be whitespace only"),
+            NameViolation::TooLong { max } => write!(f, "must not exceed {max} characters"),
+        }
+    }
+}
+
+/// Checks a text against the rules every name follows, returning what is wrong with it.
+///
+/// A text must not be empty nor whitespace only, and must not exceed `max` characters.
+pub fn name_violation(value: &str, max: usize) -> Option<NameViolation> {
+    if value.is_empty() {
+        Some(NameViolation::Empty)
+    } else if value.trim().is_empty() {
+        Some(NameViolation::WhitespaceOnly)
+    } else if value.chars().count() > max {
+        Some(NameViolation::TooLong { max })
+    } else {
+        None
+    }
+}
+
+/// Defines a string newtype validated with [`name_violation`].
+///
+/// Takes the doc comments of the type, its name, its maximum number of characters, the error
+/// type of its constructors and how to turn a [`NameViolation`] into that error. The `serde`
+/// feature of the calling crate decides whether the type is (de)serialized as a string.
+///
+/// ```
+/// use shared_kernel::NameViolation;
+///
+/// #[derive(Debug)]
+/// pub struct InvalidTitle(NameViolation);
+///
+/// impl std::fmt::Display for InvalidTitle {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "note title {}", self.0)
+///     }
+/// }
+///
+/// shared_kernel::validated_text!(
+///     /// The validated title of a note.
+///     NoteTitle,
+///     64,
+///     InvalidTitle,
+///     |reason| InvalidTitle(reason)
+/// );
+///
+/// assert_eq!(NoteTitle::new_trimmed(" Groceries ").unwrap(), "Groceries");
+/// assert!(matches!(
+///     NoteTitle::new(" "),
+///     Err(InvalidTitle(NameViolation::WhitespaceOnly))
+/// ));
+/// ```
+#[macro_export]
+macro_rules! validated_text {
+    ($(#[$meta:meta])* $name:ident, $max:expr, $error:ty, |$reason:ident| $invalid:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(try_from = "String", into = "String")
+        )]
+        pub struct $name(String);
+
+        impl $name {
+            /// The maximum number of characters.
+            pub const MAX_LEN: usize = $max;
+
+            /// Validates and wraps a text.
+            pub fn new(value: impl Into<String>) -> Result<Self, $error> {
+                let value = value.into();
+                match $crate::name_violation(&value, Self::MAX_LEN) {
+                    Some($reason) => Err($invalid),
+                    None => Ok(Self(value)),
+                }
+            }
+
+            /// Trims leading and trailing whitespace off a text, then validates and wraps
+            /// it. A text of whitespace only is reported as such rather than as empty.
+            pub fn new_trimmed(value: impl Into<String>) -> Result<Self, $error> {
+                let value = value.into();
+                if value.trim().is_empty() {
+                    return Self::new(value);
+                }
+                Self::new(value.trim())
+            }
+
+            /// Returns the text as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Unwraps the text into its string.
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = $error;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = $error;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_violation() {
+        assert_eq!(name_violation("", 3), Some(NameViolation::Empty));
+        assert_eq!(
+            name_violation(" \t", 3),
+            Some(NameViolation::WhitespaceOnly)
+        );
+        assert_eq!(
+            name_violation("four", 3),
+            Some(NameViolation::TooLong { max: 3 })
+        );
+        assert_eq!(name_violation("été", 3), None);
+    }
+}
@@ -0,0 +1,209 @@
+use alloc::rc::Rc;
+use core::{cell::Cell, fmt, ops::Deref};
+
+use chrono::{DateTime, Utc};
+
+/// A point in time, in UTC.
+pub type Timestamp = DateTime<Utc>;
+
+/// A source of the current time.
+///
+/// Domain code asks a `Clock` instead of reading the system time directly, so tests can
+/// supply fixed or manually advanced times. Any `Fn() -> Timestamp` closure is a clock.
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> Timestamp;
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> Timestamp,
+{
+    fn now(&self) -> Timestamp {
+        self()
+    }
+}
+
+/// A clock reading the system time.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> Timestamp {
+        Utc::now()
+    }
+}
+
+/// A clock that only moves when told to, for tests and simulations.
+///
+/// Clones share the same time, so a clone kept aside can advance a clock owned by a
+/// [`Timestamped`] value. The default clock stands at the Unix epoch.
+#[derive(Debug, Default, Clone)]
+pub struct ManualClock(Rc<Cell<Timestamp>>);
+
+impl ManualClock {
+    /// Creates a clock standing at `now`.
+    pub fn new(now: Timestamp) -> Self {
+        Self(Rc::new(Cell::new(now)))
+    }
+
+    /// Moves the clock, and every clone of it, to `now`.
+    pub fn set(&self, now: Timestamp) {
+        self.0.set(now);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Timestamp {
+        self.0.get()
+    }
+}
+
+/// A value annotated with the time it was created and last modified.
+///
+/// The wrapped value is readable through `Deref`, but can only be mutated through
+/// [`Timestamped::update`] and [`Timestamped::try_update`], which bump `updated_at` with the
+/// wrapper's clock. Bounded contexts implement their aggregate-root traits for timestamped
+/// entities on top of them, so every mutation bumps `updated_at`.
+///
+/// The clock is not persisted: a deserialized value starts with the default clock.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timestamped<T, C> {
+    inner: T,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    clock: C,
+}
+
+impl<T, C: Clock> Timestamped<T, C> {
+    /// Wraps a newly created value, stamping both times with the current time of `clock`.
+    pub fn new(inner: T, clock: C) -> Self {
+        let now = clock.now();
+        Self::from_parts(inner, now, now, clock)
+    }
+
+    /// Rebuilds a timestamped value from previously recorded times.
+    pub fn from_parts(inner: T, created_at: Timestamp, updated_at: Timestamp, clock: C) -> Self {
+        Self {
+            inner,
+            created_at,
+            updated_at,
+            clock,
+        }
+    }
+
+    /// Returns the time the value was created.
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    /// Returns the time the value was last modified.
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    /// Returns the clock stamping modifications.
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Gives crate-internal access to the wrapped value without marking it as modified.
+    pub(crate) fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwraps the value, discarding the timestamps.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Marks the value as modified now.
+    pub fn touch(&mut self) {
+        self.updated_at = self.clock.now();
+    }
+
+    /// Mutates the value and marks it as modified.
+    pub fn update<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        let result = f(&mut self.inner);
+        self.touch();
+        result
+    }
+
+    /// Mutates the value with a fallible operation, marking it as modified only on success.
+    pub fn try_update<R, E>(&mut self, f: impl FnOnce(&mut T) -> Result<R, E>) -> Result<R, E> {
+        let result = f(&mut self.inner)?;
+        self.touch();
+        Ok(result)
+    }
+}
+
+impl<T: fmt::Debug, C> fmt::Debug for Timestamped<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timestamped")
+            .field("inner", &self.inner)
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, C> Deref for Timestamped<T, C> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+
+    use chrono::TimeDelta;
+
+    use super::*;
+
+    fn start() -> Timestamp {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    #[test]
+    fn test_new_stamps_both_times() {
+        let start = start();
+        let value = Timestamped::new(1, ManualClock::new(start));
+
+        assert_eq!(value.created_at(), start);
+        assert_eq!(value.updated_at(), start);
+        assert_eq!(*value, 1);
+    }
+
+    #[test]
+    fn test_update_bumps_updated_at() {
+        let start = start();
+        let clock = ManualClock::new(start);
+        let mut value = Timestamped::new(1, clock.clone());
+
+        clock.set(start + TimeDelta::seconds(5));
+        value.update(|value| *value += 1);
+
+        assert_eq!(*value, 2);
+        assert_eq!(value.created_at(), start);
+        assert_eq!(value.updated_at(), start + TimeDelta::seconds(5));
+    }
+
+    #[test]
+    fn test_failed_try_update_keeps_updated_at() {
+        let start = start();
+        let clock = ManualClock::new(start);
+        let mut value = Timestamped::new(1, clock.clone());
+
+        clock.set(start + TimeDelta::seconds(5));
+        let result: Result<(), &str> = value.try_update(|_| Err("rejected"));
+
+        assert!(result.is_err());
+        assert_eq!(value.updated_at(), start);
+    }
+}
@@ -0,0 +1,162 @@
+use core::{fmt, ops::Deref};
+
+use thiserror::Error;
+
+/// The revision number of a versioned value, starting at zero and incremented on every change.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Version(u64);
+
+impl Version {
+    /// The version of a value that has never been modified.
+    pub const INITIAL: Version = Version(0);
+
+    /// Wraps a raw revision number, e.g. one loaded from persistent storage.
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the raw revision number.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the version following this one.
+    pub fn next(self) -> Self {
+        Self(self.0 + 1)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Error indicating that a value was modified concurrently.
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("version conflict: expected version {expected}, found {actual}")]
+pub struct VersionConflict {
+    pub expected: Version,
+    pub actual: Version,
+}
+
+/// A value carrying a [`Version`] for optimistic concurrency control.
+///
+/// The wrapped value is readable through `Deref`, but can only be mutated through
+/// [`Versioned::update`] and [`Versioned::try_update`], so every aggregate-root mutation
+/// increments the version.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Versioned<T> {
+    inner: T,
+    version: Version,
+}
+
+impl<T> Versioned<T> {
+    /// Wraps a newly created value at [`Version::INITIAL`].
+    pub fn new(inner: T) -> Self {
+        Self::from_parts(inner, Version::INITIAL)
+    }
+
+    /// Rebuilds a versioned value from a previously recorded version.
+    pub fn from_parts(inner: T, version: Version) -> Self {
+        Self { inner, version }
+    }
+
+    /// Returns the current version.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Gives crate-internal access to the wrapped value without marking it as modified.
+    pub(crate) fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwraps the value, discarding the version.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Checks that the value is still at the `expected` version.
+    pub fn check_version(&self, expected: Version) -> Result<(), VersionConflict> {
+        if self.version == expected {
+            Ok(())
+        } else {
+            Err(VersionConflict {
+                expected,
+                actual: self.version,
+            })
+        }
+    }
+
+    /// Mutates the value and increments the version.
+    pub fn update<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        let result = f(&mut self.inner);
+        self.version = self.version.next();
+        result
+    }
+
+    /// Mutates the value with a fallible operation, incrementing the version only on success.
+    pub fn try_update<R, E>(&mut self, f: impl FnOnce(&mut T) -> Result<R, E>) -> Result<R, E> {
+        let result = f(&mut self.inner)?;
+        self.version = self.version.next();
+        Ok(result)
+    }
+}
+
+impl<T> Deref for Versioned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_update_increments_version() {
+        let mut value = Versioned::new(1);
+
+        value.update(|value| *value += 1);
+        value.update(|value| *value += 1);
+
+        assert_eq!(*value, 3);
+        assert_eq!(value.version(), Version::new(2));
+    }
+
+    #[test]
+    fn test_failed_try_update_keeps_version() {
+        let mut value = Versioned::new(1);
+
+        let result: Result<(), &str> = value.try_update(|_| Err("rejected"));
+
+        assert!(result.is_err());
+        assert_eq!(value.version(), Version::INITIAL);
+    }
+
+    #[test]
+    fn test_check_version_conflict() {
+        let mut value = Versioned::new(1);
+        let loaded = value.version();
+
+        assert!(value.check_version(loaded).is_ok());
+
+        value.update(|value| *value += 1);
+
+        assert_eq!(
+            value.check_version(loaded),
+            Err(VersionConflict {
+                expected: Version::INITIAL,
+                actual: Version::new(1),
+            })
+        );
+    }
+}
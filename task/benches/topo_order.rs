@@ -0,0 +1,79 @@
+use std::{hint::black_box, time::Duration};
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use shared_kernel::{Entity, Id, SystemClock};
+use task::domain::{
+    name::StatusName,
+    net::{Net, NetAggregateRoot, NetOp, NetQuery, PropagationPolicy, RelationType},
+    task::Task,
+};
+
+const TASKS: usize = 10_000;
+
+/// Builds a net of parallel requirement chains with estimated tasks.
+fn net() -> (Entity<Net>, Vec<Id<Task>>) {
+    let mut net: Entity<Net> = NetAggregateRoot::new(
+        StatusName::new("Todo").unwrap(),
+        StatusName::new("Done").unwrap(),
+    );
+    let tasks: Vec<Id<Task>> = (0..TASKS).map(|_| Id::new()).collect();
+    let mut ops: Vec<_> = tasks
+        .iter()
+        .map(|task| NetOp::AddTask(*task))
+        .chain(tasks.iter().map(|task| NetOp::EstimateTask {
+            task: *task,
+            estimate: Some(Duration::from_secs(60)),
+        }))
+        .collect();
+    ops.extend(
+        tasks
+            .windows(2)
+            .enumerate()
+            .filter(|(index, _)| index % 100 != 99)
+            .map(|(_, pair)| NetOp::NewRelation {
+                from: pair[0],
+                to: pair[1],
+                relation_type: RelationType::Require,
+            }),
+    );
+    net.apply(ops, &SystemClock).unwrap();
+    (net, tasks)
+}
+
+fn topo_order(c: &mut Criterion) {
+    let (net, tasks) = net();
+
+    c.bench_function("critical_path/cached", |b| {
+        let net = net.clone();
+        net.critical_path();
+        b.iter(|| black_box(net.critical_path()))
+    });
+
+    c.bench_function("critical_path/after_relation_change", |b| {
+        b.iter_batched_ref(
+            || net.clone(),
+            |net| {
+                net.remove_relation(tasks[0], tasks[1], &SystemClock)
+                    .unwrap();
+                black_box(net.critical_path())
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    c.bench_function("propagate_all/cached", |b| {
+        let mut net = net.clone();
+        net.critical_path();
+        let mut policy = PropagationPolicy::Strict;
+        b.iter(|| {
+            policy = match policy {
+                PropagationPolicy::Strict => PropagationPolicy::Advisory,
+                PropagationPolicy::Advisory => PropagationPolicy::Strict,
+            };
+            net.change_propagation_policy(policy, &SystemClock).unwrap();
+        })
+    });
+}
+
+criterion_group!(benches, topo_order);
+criterion_main!(benches);
@@ -0,0 +1,209 @@
+use std::fmt::Write;
+
+use shared_kernel::{Clock, Entity, Id, Timestamp};
+
+use super::{
+    recurrence::{Frequency, Recurrence, RecurrenceEnd},
+    task::Task,
+};
+
+/// The identifier of the product producing calendar feeds.
+const PRODUCT_ID: &str = "-//just-dev//task calendar//EN";
+
+/// The maximum length of a content line in octets, excluding the line break.
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Represents a task projected onto a calendar, spanning from its start date, if any, to its
+/// due date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    /// The task the event shows.
+    pub task: Id<Task>,
+    /// The name of the task.
+    pub summary: String,
+    /// The description of the task, if any.
+    pub description: Option<String>,
+    /// When work on the task starts, or its due date if it has no start date.
+    pub start: Timestamp,
+    /// When the task is due.
+    pub end: Timestamp,
+    /// How the task repeats, if it does.
+    pub recurrence: Option<Recurrence>,
+}
+
+/// Projects the tasks with a due date into calendar events, ordered by due date.
+pub fn calendar_events<'a>(
+    tasks: impl IntoIterator<Item = &'a Entity<Task>>,
+) -> Vec<CalendarEvent> {
+    let mut events: Vec<_> = tasks
+        .into_iter()
+        .filter_map(|task| {
+            let schedule = task.data.schedule()?;
+            let end = schedule.due_date()?;
+            Some(CalendarEvent {
+                task: task.id,
+                summary: task.data.name().as_str().to_owned(),
+                description: task
+                    .data
+                    .description()
+                    .map(|description| description.as_str().to_owned()),
+                start: schedule.start_date().unwrap_or(end),
+                end,
+                recurrence: task.data.recurrence(),
+            })
+        })
+        .collect();
+    events.sort_by_key(|event| (event.end, event.task));
+    events
+}
+
+/// Renders calendar events as an iCalendar (RFC 5545) feed, stamped with the current time.
+///
+/// Every event becomes a `VEVENT` whose `UID` is derived from its task, so calendar clients
+/// update events in place when the feed is fetched again.
+pub fn to_ical(events: &[CalendarEvent], clock: &impl Clock) -> String {
+    let stamp = format_time(clock.now());
+    let mut ical = String::new();
+    let mut line = |content: String| push_folded(&mut ical, &content);
+
+    line("BEGIN:VCALENDAR".to_owned());
+    line("VERSION:2.0".to_owned());
+    line(format!("PRODID:{PRODUCT_ID}"));
+    line("CALSCALE:GREGORIAN".to_owned());
+    for event in events {
+        line("BEGIN:VEVENT".to_owned());
+        line(format!("UID:{}@just-dev", event.task));
+        line(format!("DTSTAMP:{stamp}"));
+        line(format!("DTSTART:{}", format_time(event.start)));
+        if event.end != event.start {
+            line(format!("DTEND:{}", format_time(event.end)));
+        }
+        line(format!("SUMMARY:{}", escape_text(&event.summary)));
+        if let Some(description) = &event.description {
+            line(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        if let Some(recurrence) = &event.recurrence {
+            line(format!("RRULE:{}", format_rrule(recurrence)));
+        }
+        line("END:VEVENT".to_owned());
+    }
+    line("END:VCALENDAR".to_owned());
+
+    ical
+}
+
+/// Formats a time as an iCalendar UTC date-time.
+fn format_time(time: Timestamp) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Formats a recurrence as the value of an iCalendar `RRULE` property.
+fn format_rrule(recurrence: &Recurrence) -> String {
+    let frequency = match recurrence.frequency() {
+        Frequency::Daily => "DAILY",
+        Frequency::Weekly => "WEEKLY",
+        Frequency::Monthly => "MONTHLY",
+    };
+    let mut rrule = format!("FREQ={frequency}");
+    if recurrence.interval().get() > 1 {
+        write!(rrule, ";INTERVAL={}", recurrence.interval()).unwrap();
+    }
+    match recurrence.end() {
+        RecurrenceEnd::Never => {}
+        RecurrenceEnd::After(count) => write!(rrule, ";COUNT={count}").unwrap(),
+        RecurrenceEnd::Until(until) => write!(rrule, ";UNTIL={}", format_time(until)).unwrap(),
+    }
+    rrule
+}
+
+/// Escapes a string for use as an iCalendar text value.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Appends a content line ended by CRLF, folding it so no line exceeds the maximum length
+/// and no character is split across lines.
+fn push_folded(ical: &mut String, content: &str) {
+    let mut octets = 0;
+    for c in content.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            ical.push_str("\r\n ");
+            octets = 1;
+        }
+        ical.push(c);
+        octets += c.len_utf8();
+    }
+    ical.push_str("\r\n");
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use super::*;
+    use crate::domain::{
+        name::{TaskDescription, TaskName},
+        task::{Schedule, TaskAggregateRoot},
+    };
+
+    #[test]
+    fn test_calendar_events() {
+        let due = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let list = Id::new();
+        let unscheduled: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Someday").unwrap(), list);
+        let mut later: Entity<Task> = TaskAggregateRoot::new(TaskName::new("Later").unwrap(), list);
+        later
+            .schedule(Schedule::between(due, due + chrono::Duration::days(1)).unwrap())
+            .unwrap();
+        let mut sooner: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Sooner").unwrap(), list);
+        sooner.schedule(Schedule::due(due)).unwrap();
+
+        let events = calendar_events([&unscheduled, &later, &sooner]);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].task, sooner.id);
+        assert_eq!(events[0].start, due);
+        assert_eq!(events[1].start, due);
+        assert_eq!(events[1].end, due + chrono::Duration::days(1));
+    }
+
+    #[test]
+    fn test_to_ical() {
+        let due = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let mut task: Entity<Task> = TaskAggregateRoot::new(
+            TaskName::new("Pay rent, utilities; etc.").unwrap(),
+            Id::new(),
+        );
+        task.schedule(Schedule::due(due)).unwrap();
+        task.set_description(TaskDescription::new("é".repeat(60)).unwrap());
+        task.set_recurrence(Recurrence::new(
+            Frequency::Monthly,
+            NonZeroU32::new(1).unwrap(),
+            RecurrenceEnd::After(NonZeroU32::new(12).unwrap()),
+        ));
+
+        let ical = to_ical(&calendar_events([&task]), &|| due);
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ical.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(ical.contains(&format!("\r\nUID:{}@just-dev\r\n", task.id)));
+        assert!(ical.contains("\r\nDTSTART:20231114T221320Z\r\n"));
+        assert!(!ical.contains("DTEND"));
+        assert!(ical.contains("\r\nSUMMARY:Pay rent\\, utilities\\; etc.\r\n"));
+        assert!(ical.contains("\r\nRRULE:FREQ=MONTHLY;COUNT=12\r\n"));
+        assert!(ical.contains("\r\n é"));
+        assert!(ical.split("\r\n").all(|line| line.len() <= MAX_LINE_OCTETS));
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt;
+
+use shared_kernel::{Entity, Id, Timestamp};
+
+use super::{error::TaskDomainError, list::List, name::FieldName};
+
+type TaskDomainResult<T> = Result<T, TaskDomainError>;
+
+/// Identifies the kind of values a custom field holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FieldKind {
+    /// Free text.
+    Text,
+    /// A finite number.
+    Number,
+    /// A point in time.
+    Date,
+    /// One of a fixed set of options.
+    Enum,
+}
+
+impl fmt::Display for FieldKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldKind::Text => f.write_str("text"),
+            FieldKind::Number => f.write_str("number"),
+            FieldKind::Date => f.write_str("date"),
+            FieldKind::Enum => f.write_str("enum"),
+        }
+    }
+}
+
+/// Represents the type of a custom field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FieldType {
+    /// The field holds free text.
+    Text,
+    /// The field holds a finite number.
+    Number,
+    /// The field holds a point in time.
+    Date,
+    /// The field holds one of the listed options.
+    Enum(Vec<String>),
+}
+
+impl FieldType {
+    /// Returns the kind of values the field holds.
+    pub fn kind(&self) -> FieldKind {
+        match self {
+            FieldType::Text => FieldKind::Text,
+            FieldType::Number => FieldKind::Number,
+            FieldType::Date => FieldKind::Date,
+            FieldType::Enum(_) => FieldKind::Enum,
+        }
+    }
+}
+
+/// Represents a number a number field can hold, which is never infinite nor NaN.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "f64", try_from = "f64")
+)]
+pub struct FiniteNumber(f64);
+
+impl FiniteNumber {
+    /// Creates a number, failing if it is infinite or NaN.
+    pub fn new(value: f64) -> TaskDomainResult<Self> {
+        if !value.is_finite() {
+            return Err(TaskDomainError::NonFiniteNumber { value });
+        }
+
+        Ok(FiniteNumber(value))
+    }
+
+    /// Returns the number.
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
+// Sound since NaN, the only value not equal to itself, is ruled out.
+impl Eq for FiniteNumber {}
+
+impl From<FiniteNumber> for f64 {
+    fn from(number: FiniteNumber) -> Self {
+        number.0
+    }
+}
+
+impl TryFrom<f64> for FiniteNumber {
+    type Error = TaskDomainError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        FiniteNumber::new(value)
+    }
+}
+
+/// Represents the value of a custom field on a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FieldValue {
+    /// Free text.
+    Text(String),
+    /// A number.
+    Number(FiniteNumber),
+    /// A point in time.
+    Date(Timestamp),
+    /// One of the options of an enum field.
+    Enum(String),
+}
+
+impl FieldValue {
+    /// Returns the kind of the value.
+    pub fn kind(&self) -> FieldKind {
+        match self {
+            FieldValue::Text(_) => FieldKind::Text,
+            FieldValue::Number(_) => FieldKind::Number,
+            FieldValue::Date(_) => FieldKind::Date,
+            FieldValue::Enum(_) => FieldKind::Enum,
+        }
+    }
+}
+
+/// Describes a custom field the tasks of a list can fill in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldDefinition {
+    list: Id<List>,
+    name: FieldName,
+    field_type: FieldType,
+}
+
+impl FieldDefinition {
+    /// Creates the definition of a field of a list.
+    pub(crate) fn new(list: Id<List>, name: FieldName, field_type: FieldType) -> Self {
+        FieldDefinition {
+            list,
+            name,
+            field_type,
+        }
+    }
+
+    /// Returns the list owning the field.
+    pub fn list(&self) -> Id<List> {
+        self.list
+    }
+
+    /// Returns the name of the field.
+    pub fn name(&self) -> &FieldName {
+        &self.name
+    }
+
+    /// Returns the type of the field.
+    pub fn field_type(&self) -> &FieldType {
+        &self.field_type
+    }
+}
+
+/// Checks that a value fits the type of a field.
+pub(crate) fn validate(
+    field: &Entity<FieldDefinition>,
+    value: &FieldValue,
+) -> TaskDomainResult<()> {
+    let expected = field.data.field_type.kind();
+    if value.kind() != expected {
+        return Err(TaskDomainError::FieldTypeMismatch {
+            field: field.id,
+            expected,
+            found: value.kind(),
+        });
+    }
+
+    let valid = match (&field.data.field_type, value) {
+        (FieldType::Enum(options), FieldValue::Enum(option)) => options.contains(option),
+        _ => true,
+    };
+    if !valid {
+        return Err(TaskDomainError::InvalidFieldValue {
+            field: field.id,
+            value: value.clone(),
+        });
+    }
+
+    Ok(())
+}
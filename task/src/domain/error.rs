@@ -1,9 +1,17 @@
-use shared_kernel::Id;
+use std::time::Duration;
+
+use shared_kernel::{Id, Timestamp, User};
 use thiserror::Error;
 
 use super::{
+    custom_field::{FieldDefinition, FieldKind, FieldValue},
+    event::TaskDomainEvent,
+    link::TaskRef,
+    list::{List, Section},
+    name::{FieldName, LabelName, NameKind, NameViolation, SectionName, StatusName},
     net::{Net, Status},
-    task::Task,
+    scheduler::Reminder,
+    task::{Task, WorkEntry},
 };
 
 /// Represents errors that can occur in the task domain.
@@ -13,6 +21,10 @@ pub enum TaskDomainError {
     #[error("status {status:?} not found in net {net:?}")]
     StatusNotFoundInNet { net: Id<Net>, status: Id<Status> },
 
+    /// Error indicating that no status of a net has a given name.
+    #[error("no status named {name:?} in net {net:?}")]
+    StatusNameNotFoundInNet { net: Id<Net>, name: StatusName },
+
     /// Error indicating that a task was not found in a net.
     #[error("task {task:?} not found in net {net:?}")]
     TaskNotFoundInNet { net: Id<Net>, task: Id<Task> },
@@ -25,19 +37,264 @@ pub enum TaskDomainError {
         to: Id<Task>,
     },
 
+    /// Error indicating that a relation of a net is not a requirement, e.g. when setting its
+    /// strength.
+    #[error("relation from {from:?} to {to:?} is not a requirement in net {net:?}")]
+    RelationNotRequirement {
+        net: Id<Net>,
+        from: Id<Task>,
+        to: Id<Task>,
+    },
+
     /// Error indicating that relation constraints were not satisfied for a task in a net.
     #[error("relation constraints not satisfied for task {task:?} in net {net:?}")]
     RelationConstraintNotSatisfied { net: Id<Net>, task: Id<Task> },
 
+    /// Error indicating that a task does not require another task through a link.
+    #[error("link from task {task:?} to {required:?} not found in net {net:?}")]
+    LinkNotFoundInNet {
+        net: Id<Net>,
+        task: Id<Task>,
+        required: TaskRef,
+    },
+
+    /// Error indicating that the status of a milestone was changed by hand.
+    #[error("task {task:?} is a milestone in net {net:?} and follows its requirements")]
+    MilestoneNotChangeable { net: Id<Net>, task: Id<Task> },
+
     /// Error indicating that a task is already in a net.
     #[error("task {task:?} already in net {net:?}")]
     TaskAlreadyInNet { task: Id<Task>, net: Id<Net> },
 
+    /// Error indicating that two tasks of a net are already related in the same direction.
+    #[error("relation from task {from:?} to {to:?} already in net {net:?}")]
+    RelationAlreadyInNet {
+        net: Id<Net>,
+        from: Id<Task>,
+        to: Id<Task>,
+    },
+
+    /// Error indicating that a status appears more than once in a net.
+    #[error("status {status:?} already in net {net:?}")]
+    StatusAlreadyInNet { net: Id<Net>, status: Id<Status> },
+
+    /// Error indicating that a task is not soft-removed from a net and cannot be restored.
+    #[error("task {task:?} not removed from net {net:?}")]
+    TaskNotRemovedFromNet { net: Id<Net>, task: Id<Task> },
+
     /// Error indicating that a status is the default status in a net and cannot be removed.
     #[error("status {status:?} is default status in net {net:?}")]
     StatusNotRemovable { net: Id<Net>, status: Id<Status> },
 
+    /// Error indicating that the primary accepted status of a net cannot stop counting as accepted.
+    #[error("status {status:?} is primary accepted status in net {net:?}")]
+    StatusNotUnmarkable { net: Id<Net>, status: Id<Status> },
+
+    /// Error indicating that the default status of a net cannot become its accepted status.
+    #[error("status {status:?} is default status in net {net:?} and cannot be accepted")]
+    DefaultStatusNotAcceptable { net: Id<Net>, status: Id<Status> },
+
+    /// Error indicating that the workflow of a net does not permit moving between two statuses.
+    #[error("transition from status {from:?} to {to:?} not allowed in net {net:?}")]
+    TransitionNotAllowed {
+        net: Id<Net>,
+        from: Id<Status>,
+        to: Id<Status>,
+    },
+
+    /// Error indicating that moving a task would exceed the hard work-in-progress limit of a status.
+    #[error("status {status:?} reached its work-in-progress limit of {limit} in net {net:?}")]
+    WipLimitExceeded {
+        net: Id<Net>,
+        status: Id<Status>,
+        limit: usize,
+    },
+
+    /// Error indicating that a task would get a second composition parent in a net requiring a single one.
+    #[error("task {task:?} already has a parent in net {net:?}")]
+    MultipleParentsNotAllowed { net: Id<Net>, task: Id<Task> },
+
+    /// Error indicating that a status position is past the end of a net's schema.
+    #[error("status position {position} out of range for {len} statuses in net {net:?}")]
+    StatusPositionOutOfRange {
+        net: Id<Net>,
+        position: usize,
+        len: usize,
+    },
+
+    /// Error indicating that a name, title or description failed validation.
+    #[error("invalid {kind}: {reason}")]
+    InvalidName {
+        kind: NameKind,
+        reason: NameViolation,
+    },
+
+    /// Error indicating that a schedule starts after it is due.
+    #[error("schedule starts at {start_date} after its due date {due_date}")]
+    InvertedSchedule {
+        start_date: Timestamp,
+        due_date: Timestamp,
+    },
+
+    /// Error indicating that a schedule has neither a start nor a due date.
+    #[error("schedule has neither a start nor a due date")]
+    EmptySchedule,
+
+    /// Error indicating that a task is scheduled already, so it has to be rescheduled instead.
+    #[error("task {task:?} is already scheduled")]
+    TaskAlreadyScheduled { task: Id<Task> },
+
+    /// Error indicating that a task has no schedule to change.
+    #[error("task {task:?} is not scheduled")]
+    TaskNotScheduled { task: Id<Task> },
+
+    /// Error indicating that a task is assigned to a user already.
+    #[error("task {task:?} is already assigned to user {user:?}")]
+    AlreadyAssigned { task: Id<Task>, user: Id<User> },
+
+    /// Error indicating that a task is not assigned to a user.
+    #[error("task {task:?} is not assigned to user {user:?}")]
+    NotAssigned { task: Id<Task>, user: Id<User> },
+
+    /// Error indicating that a list already has a label with a given name.
+    #[error("label {name:?} is already defined in list {list:?}")]
+    LabelAlreadyDefined { list: Id<List>, name: LabelName },
+
+    /// Error indicating that a list has no label with a given name.
+    #[error("label {name:?} is not defined in list {list:?}")]
+    LabelNotDefined { list: Id<List>, name: LabelName },
+
+    /// Error indicating that logged work took no time.
+    #[error("work logged on task {task:?} must take some time")]
+    EmptyWorkEntry { task: Id<Task> },
+
+    /// Error indicating that the time logged on a task would no longer fit a duration.
+    #[error("work logged on task {task:?} exceeds the longest trackable time")]
+    WorkLogOverflow { task: Id<Task> },
+
+    /// Error indicating that a work entry was not found in the log of a task.
+    #[error("work entry {entry:?} not found on task {task:?}")]
+    WorkEntryNotFound {
+        task: Id<Task>,
+        entry: Id<WorkEntry>,
+    },
+
+    /// Error indicating that a list already has a custom field with a given name.
+    #[error("field {name:?} is already defined in list {list:?}")]
+    FieldAlreadyDefined { list: Id<List>, name: FieldName },
+
+    /// Error indicating that a custom field was not found in a list.
+    #[error("field {field:?} not found in list {list:?}")]
+    FieldNotDefined {
+        list: Id<List>,
+        field: Id<FieldDefinition>,
+    },
+
+    /// Error indicating that a task is already ranked in a list.
+    #[error("task {task:?} is already in list {list:?}")]
+    TaskAlreadyInList { list: Id<List>, task: Id<Task> },
+
+    /// Error indicating that a task is not ranked in a list.
+    #[error("task {task:?} not found in list {list:?}")]
+    TaskNotInList { list: Id<List>, task: Id<Task> },
+
+    /// Error indicating that a task ranked in a list was left out of the tasks given with it.
+    #[error("task {task:?} of list {list:?} not given")]
+    ListTaskNotGiven { list: Id<List>, task: Id<Task> },
+
+    /// Error indicating that a list was not found.
+    #[error("list {list:?} not found")]
+    ListNotFound { list: Id<List> },
+
+    /// Error indicating that an archived list was given a new task.
+    #[error("list {list:?} is archived")]
+    ListArchived { list: Id<List> },
+
+    /// Error indicating that a list was to be merged into itself.
+    #[error("list {list:?} cannot be merged into itself")]
+    ListMergedIntoItself { list: Id<List> },
+
+    /// Error indicating that filing a list under another would make it its own ancestor.
+    #[error("list {list:?} cannot be filed under {parent:?} without forming a cycle")]
+    ListCycleNotAllowed { list: Id<List>, parent: Id<List> },
+
+    /// Error indicating that a list already has a section with a given name.
+    #[error("section {name:?} is already defined in list {list:?}")]
+    SectionAlreadyDefined { list: Id<List>, name: SectionName },
+
+    /// Error indicating that a section was not found in a list.
+    #[error("section {section:?} not found in list {list:?}")]
+    SectionNotFound {
+        list: Id<List>,
+        section: Id<Section>,
+    },
+
+    /// Error indicating that a custom field belongs to another list than the task.
+    #[error("field {field:?} is not defined in list {list:?}")]
+    FieldNotInList {
+        field: Id<FieldDefinition>,
+        list: Id<List>,
+    },
+
+    /// Error indicating that a value has another kind than its custom field.
+    #[error("field {field:?} holds {expected} values, not {found}")]
+    FieldTypeMismatch {
+        field: Id<FieldDefinition>,
+        expected: FieldKind,
+        found: FieldKind,
+    },
+
+    /// Error indicating that a value of the right kind is not allowed in its custom field, e.g.
+    /// an unknown enum option.
+    #[error("value {value:?} is not allowed in field {field:?}")]
+    InvalidFieldValue {
+        field: Id<FieldDefinition>,
+        value: FieldValue,
+    },
+
+    /// Error indicating that a number field was given an infinite number or NaN.
+    #[error("number {value:?} is not finite")]
+    NonFiniteNumber { value: f64 },
+
+    /// Error indicating that a net cannot be changed because it is locked.
+    #[error("net {net:?} is locked")]
+    NetLocked { net: Id<Net> },
+
+    /// Error indicating that an event cannot be applied to a net, e.g. because it belongs to
+    /// another net or records an undo that the stream does not describe.
+    #[error("event {event:?} cannot be applied to net {net:?}")]
+    EventNotApplicable {
+        net: Id<Net>,
+        event: Box<TaskDomainEvent>,
+    },
+
+    /// Error indicating that an event stream does not start with the creation of a net.
+    #[error("event stream does not start with the creation of a net")]
+    NetCreationMissing,
+
     /// Error indicating that a cycle was found in a net, which is not allowed.
-    #[error("cycle found in net {0:?}")]
-    CycleNotAllowedInNet(Id<Net>),
+    ///
+    /// `cycle` lists the tasks along the cycle in relation order; the last task relates back
+    /// to the first.
+    #[error("cycle {cycle:?} found in net {net:?}")]
+    CycleNotAllowedInNet { net: Id<Net>, cycle: Vec<Id<Task>> },
+
+    /// Error indicating that a link would close a cycle running through several nets.
+    ///
+    /// `cycle` lists the tasks along the cycle in relation and link order; the last task
+    /// would be required by the first.
+    #[error("cycle {cycle:?} found across nets")]
+    LinkCycleNotAllowed { cycle: Vec<TaskRef> },
+
+    /// Error indicating that a reminder before the due date was set on a task without one.
+    #[error("task {task:?} has no due date")]
+    TaskWithoutDueDate { task: Id<Task> },
+
+    /// Error indicating that a reminder lead reaches before the earliest representable time.
+    #[error("lead {lead:?} before the due date of task {task:?} is out of range")]
+    ReminderLeadOutOfRange { task: Id<Task>, lead: Duration },
+
+    /// Error indicating that a reminder was fired twice.
+    #[error("reminder {reminder:?} already fired")]
+    ReminderAlreadyFired { reminder: Id<Reminder> },
 }
@@ -0,0 +1,491 @@
+use std::{collections::HashMap, time::Duration};
+
+use shared_kernel::{Id, Timestamp, User};
+
+use super::{
+    custom_field::{FieldDefinition, FieldValue},
+    link::TaskRef,
+    list::{Label, List, Section},
+    name::{LabelName, ListTitle, SectionName, StatusName, TaskDescription, TaskName},
+    net::{
+        Color, Net, NetChange, PropagationPolicy, RelationStrength, RelationType, SchemaParts,
+        Status, StatusCategory, WipLimit,
+    },
+    recurrence::Recurrence,
+    scheduler::{Channel, Reminder},
+    smart_list::{SmartList, TaskFilter},
+    task::{Priority, Schedule, Task, WorkEntry},
+    task_template::{TaskTemplate, TaskTemplateDefaults},
+};
+
+/// Represents a domain event raised by an aggregate of the task context.
+///
+/// Every aggregate-root mutation records one or more of these events, which together form a
+/// machine-readable change stream of the context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TaskDomainEvent {
+    /// A list was created.
+    ListCreated { list: Id<List>, title: ListTitle },
+    /// A list was renamed.
+    ListRenamed { list: Id<List>, title: ListTitle },
+    /// A list was archived.
+    ListArchived { list: Id<List> },
+    /// An archived list was brought back.
+    ListUnarchived { list: Id<List> },
+    /// The tasks of a list were moved to another list, leaving it archived.
+    ListsMerged { source: Id<List>, target: Id<List> },
+    /// A list was filed under another list, or made a root list.
+    ListMoved {
+        list: Id<List>,
+        parent: Option<Id<List>>,
+    },
+    /// A label was added to the registry of a list.
+    LabelDefined { list: Id<List>, label: Label },
+    /// The color of a label of a list changed.
+    LabelRecolored {
+        list: Id<List>,
+        name: LabelName,
+        color: Color,
+    },
+    /// A label was removed from the registry of a list.
+    LabelUndefined { list: Id<List>, name: LabelName },
+    /// A custom field was defined for the tasks of a list.
+    FieldDefined {
+        list: Id<List>,
+        field: Id<FieldDefinition>,
+        definition: FieldDefinition,
+    },
+    /// A custom field was removed from a list.
+    FieldRemoved {
+        list: Id<List>,
+        field: Id<FieldDefinition>,
+    },
+    /// A task was placed at a rank in the manual order of a list.
+    TaskPositioned {
+        list: Id<List>,
+        task: Id<Task>,
+        position: usize,
+    },
+    /// A task was taken out of the manual order of a list.
+    TaskRemovedFromList { list: Id<List>, task: Id<Task> },
+    /// A section was added to a list.
+    SectionAdded {
+        list: Id<List>,
+        section: Id<Section>,
+        name: SectionName,
+    },
+    /// A section of a list was renamed.
+    SectionRenamed {
+        list: Id<List>,
+        section: Id<Section>,
+        name: SectionName,
+    },
+    /// A section was removed from a list.
+    SectionRemoved {
+        list: Id<List>,
+        section: Id<Section>,
+    },
+    /// A section was moved to a rank within its list.
+    SectionMoved {
+        list: Id<List>,
+        section: Id<Section>,
+        position: usize,
+    },
+    /// A task of a list was placed into a section, or into none.
+    TaskSectioned {
+        list: Id<List>,
+        task: Id<Task>,
+        section: Option<Id<Section>>,
+    },
+
+    /// A task was created.
+    TaskCreated {
+        task: Id<Task>,
+        name: TaskName,
+        list: Id<List>,
+    },
+    /// A task was renamed.
+    TaskRenamed { task: Id<Task>, name: TaskName },
+    /// The description of a task was set or cleared.
+    TaskDescriptionChanged {
+        task: Id<Task>,
+        description: Option<TaskDescription>,
+    },
+    /// A task was scheduled, rescheduled, or had its schedule cleared.
+    TaskScheduled {
+        task: Id<Task>,
+        schedule: Option<Schedule>,
+    },
+    /// The priority of a task changed.
+    TaskPrioritized { task: Id<Task>, priority: Priority },
+    /// A task was assigned to a user.
+    TaskAssigned { task: Id<Task>, user: Id<User> },
+    /// A user was removed from the assignees of a task.
+    TaskUnassigned { task: Id<Task>, user: Id<User> },
+    /// A label was added to a task.
+    TaskLabeled { task: Id<Task>, label: LabelName },
+    /// A label was removed from a task.
+    TaskUnlabeled { task: Id<Task>, label: LabelName },
+    /// Work on a task was logged.
+    WorkLogged {
+        task: Id<Task>,
+        entry: Id<WorkEntry>,
+        work: WorkEntry,
+    },
+    /// A work entry was removed from the log of a task.
+    WorkEntryRemoved {
+        task: Id<Task>,
+        entry: Id<WorkEntry>,
+    },
+    /// A task started or stopped recurring, or its recurrence changed.
+    TaskRecurrenceChanged {
+        task: Id<Task>,
+        recurrence: Option<Recurrence>,
+    },
+    /// The value of a custom field of a task was set.
+    TaskFieldSet {
+        task: Id<Task>,
+        field: Id<FieldDefinition>,
+        value: FieldValue,
+    },
+    /// The value of a custom field of a task was cleared.
+    TaskFieldCleared {
+        task: Id<Task>,
+        field: Id<FieldDefinition>,
+    },
+    /// A task was categorized to another list.
+    TaskRecategorized {
+        task: Id<Task>,
+        from: Id<List>,
+        to: Id<List>,
+    },
+
+    /// A task template was created.
+    TaskTemplateCreated {
+        template: Id<TaskTemplate>,
+        name_pattern: TaskName,
+    },
+    /// The name pattern of a task template changed.
+    TaskTemplateNamePatternChanged {
+        template: Id<TaskTemplate>,
+        name_pattern: TaskName,
+    },
+    /// The values tasks of a template start with changed.
+    TaskTemplateDefaultsChanged {
+        template: Id<TaskTemplate>,
+        defaults: TaskTemplateDefaults,
+    },
+    /// A task was created from a template.
+    TaskTemplateInstantiated {
+        template: Id<TaskTemplate>,
+        task: Id<Task>,
+    },
+
+    /// A smart list was created.
+    SmartListCreated {
+        smart_list: Id<SmartList>,
+        title: ListTitle,
+        filter: TaskFilter,
+    },
+    /// A smart list was renamed.
+    SmartListRenamed {
+        smart_list: Id<SmartList>,
+        title: ListTitle,
+    },
+    /// The filter of a smart list was replaced.
+    SmartListFilterChanged {
+        smart_list: Id<SmartList>,
+        filter: TaskFilter,
+    },
+
+    /// A reminder about a task was scheduled.
+    ReminderScheduled {
+        reminder: Id<Reminder>,
+        task: Id<Task>,
+        remind_at: Timestamp,
+        channel: Channel,
+    },
+    /// A reminder was moved to another time.
+    ReminderRescheduled {
+        reminder: Id<Reminder>,
+        remind_at: Timestamp,
+    },
+    /// A reminder fired and is to be delivered through its channel.
+    ReminderFired {
+        reminder: Id<Reminder>,
+        task: Id<Task>,
+        channel: Channel,
+    },
+
+    /// A net was created with its initial statuses.
+    NetCreated {
+        net: Id<Net>,
+        statuses: Vec<(Id<Status>, StatusName)>,
+        default: Id<Status>,
+        accepted: Id<Status>,
+    },
+    /// The status schema of a net was replaced, moving its tasks onto the new statuses.
+    SchemaReplaced {
+        net: Id<Net>,
+        schema: SchemaParts,
+        status_mapping: HashMap<Id<Status>, Id<Status>>,
+        replaced_at: Timestamp,
+    },
+    /// A task was added to a net.
+    TaskAddedToNet { net: Id<Net>, task: Id<Task> },
+    /// A task was removed from a net.
+    TaskRemovedFromNet { net: Id<Net>, task: Id<Task> },
+    /// A task was soft-removed from a net and can be restored.
+    TaskSoftRemovedFromNet {
+        net: Id<Net>,
+        task: Id<Task>,
+        removed_at: Timestamp,
+    },
+    /// A task of a net was archived.
+    TaskArchived { net: Id<Net>, task: Id<Task> },
+    /// An archived task of a net was brought back.
+    TaskUnarchived { net: Id<Net>, task: Id<Task> },
+    /// A soft-removed task was restored to a net.
+    TaskRestoredToNet { net: Id<Net>, task: Id<Task> },
+    /// The status of a task was changed directly, for the given reason if any.
+    TaskStatusChanged {
+        net: Id<Net>,
+        task: Id<Task>,
+        from: Id<Status>,
+        to: Id<Status>,
+        reason: Option<String>,
+    },
+    /// The status of a task was changed by propagation through its relations.
+    StatusPropagated {
+        net: Id<Net>,
+        task: Id<Task>,
+        from: Id<Status>,
+        to: Id<Status>,
+    },
+    /// A task of a net was made a milestone, or a plain task again.
+    MilestoneSet {
+        net: Id<Net>,
+        task: Id<Task>,
+        milestone: bool,
+    },
+    /// A task of a net was made to require a task of another net, which was last seen
+    /// accepted or not.
+    RequirementLinked {
+        net: Id<Net>,
+        task: Id<Task>,
+        required: TaskRef,
+        accepted: bool,
+    },
+    /// A task of a net stopped requiring a task of another net.
+    RequirementUnlinked {
+        net: Id<Net>,
+        task: Id<Task>,
+        required: TaskRef,
+    },
+    /// A net learned that a task of another net its tasks require was accepted or reopened.
+    LinkedRequirementUpdated {
+        net: Id<Net>,
+        required: TaskRef,
+        accepted: bool,
+    },
+    /// A relation was added between two tasks of a net.
+    RelationAdded {
+        net: Id<Net>,
+        from: Id<Task>,
+        to: Id<Task>,
+        relation_type: RelationType,
+        created_at: Timestamp,
+    },
+    /// A requirement relation of a net was made hard or soft.
+    RelationStrengthChanged {
+        net: Id<Net>,
+        from: Id<Task>,
+        to: Id<Task>,
+        strength: RelationStrength,
+    },
+    /// A relation between two tasks of a net was removed.
+    RelationRemoved {
+        net: Id<Net>,
+        from: Id<Task>,
+        to: Id<Task>,
+    },
+    /// A status was added to the schema of a net.
+    StatusAdded {
+        net: Id<Net>,
+        status: Id<Status>,
+        name: StatusName,
+    },
+    /// A status was removed from the schema of a net.
+    StatusRemoved { net: Id<Net>, status: Id<Status> },
+    /// A status of a net was renamed.
+    StatusRenamed {
+        net: Id<Net>,
+        status: Id<Status>,
+        name: StatusName,
+    },
+    /// The default status of a net changed.
+    DefaultStatusChanged { net: Id<Net>, status: Id<Status> },
+    /// The accepted status of a net changed.
+    AcceptedStatusChanged { net: Id<Net>, status: Id<Status> },
+    /// The color of a status of a net changed.
+    StatusColorChanged {
+        net: Id<Net>,
+        status: Id<Status>,
+        color: Option<Color>,
+    },
+    /// The description of a status of a net changed.
+    StatusDescriptionChanged {
+        net: Id<Net>,
+        status: Id<Status>,
+        description: Option<String>,
+    },
+    /// The work-in-progress limit of a status of a net changed.
+    StatusWipLimitChanged {
+        net: Id<Net>,
+        status: Id<Status>,
+        wip_limit: Option<WipLimit>,
+    },
+    /// A task was moved onto a status past its soft work-in-progress limit.
+    WipLimitExceeded {
+        net: Id<Net>,
+        status: Id<Status>,
+        limit: usize,
+    },
+    /// The propagation policy of a net changed.
+    PropagationPolicyChanged {
+        net: Id<Net>,
+        policy: PropagationPolicy,
+    },
+    /// A net started or stopped requiring a single composition parent per task.
+    SingleParentRequirementChanged { net: Id<Net>, enabled: bool },
+    /// A net started or stopped warning when removing a relation splits a subtree off.
+    OrphanWarningsChanged { net: Id<Net>, enabled: bool },
+    /// Removing a relation split a group of tasks off the rest of a net.
+    SubtreeOrphaned { net: Id<Net>, tasks: Vec<Id<Task>> },
+    /// The note of a relation in a net changed.
+    RelationAnnotated {
+        net: Id<Net>,
+        from: Id<Task>,
+        to: Id<Task>,
+        note: Option<String>,
+    },
+    /// The duration estimate of a task in a net changed.
+    TaskEstimated {
+        net: Id<Net>,
+        task: Id<Task>,
+        estimate: Option<Duration>,
+    },
+    /// A status of a net was moved to a new position in the schema.
+    StatusMoved {
+        net: Id<Net>,
+        status: Id<Status>,
+        position: usize,
+    },
+    /// Moving tasks from one status to another was allowed in a net's workflow.
+    TransitionAllowed {
+        net: Id<Net>,
+        from: Id<Status>,
+        to: Id<Status>,
+    },
+    /// Moving tasks from one status to another was forbidden in a net's workflow.
+    TransitionForbidden {
+        net: Id<Net>,
+        from: Id<Status>,
+        to: Id<Status>,
+    },
+    /// The category of a status of a net changed.
+    StatusCategoryChanged {
+        net: Id<Net>,
+        status: Id<Status>,
+        category: StatusCategory,
+    },
+    /// A status of a net started counting as accepted.
+    StatusMarkedAccepted { net: Id<Net>, status: Id<Status> },
+    /// A status of a net stopped counting as accepted.
+    StatusUnmarkedAccepted { net: Id<Net>, status: Id<Status> },
+    /// A net was locked against changes.
+    NetLocked { net: Id<Net> },
+    /// A locked net was unlocked.
+    NetUnlocked { net: Id<Net> },
+    /// The last recorded edit of a net was undone by applying the given change.
+    NetEditUndone {
+        net: Id<Net>,
+        change: Box<NetChange>,
+    },
+    /// The last undone edit of a net was redone by applying the given change.
+    NetEditRedone {
+        net: Id<Net>,
+        change: Box<NetChange>,
+    },
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use shared_kernel::{AggregateRoot, Clock, Entity, SystemClock};
+
+        use super::*;
+        use crate::domain::{
+            list::ListAggregateRoot,
+            net::{NetAggregateRoot, NetQuery},
+            task::TaskAggregateRoot,
+        };
+
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let docs = LabelName::new("docs").unwrap();
+        let color = Color {
+            red: 0,
+            green: 128,
+            blue: 255,
+        };
+        list.define_label(Label::new(docs.clone(), color)).unwrap();
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Write docs").unwrap(), list.id);
+        list.append_task(task.id).unwrap();
+        task.add_label(&list, docs).unwrap();
+        task.schedule(Schedule::due(SystemClock.now())).unwrap();
+
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let (epic, story) = (Id::new(), Id::new());
+        net.add_tasks([epic, story]).unwrap();
+        net.new_relation(story, epic, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.estimate_task(story, Some(Duration::from_secs(90)))
+            .unwrap();
+        let other: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Backlog").unwrap(),
+            StatusName::new("Shipped").unwrap(),
+        );
+        let mapping = HashMap::from([
+            (net.default_status(), other.default_status()),
+            (net.accepted_status(), other.accepted_status()),
+        ]);
+        net.replace_schema(other.schema().export(), mapping, &SystemClock)
+            .unwrap();
+        net.change_task_status(story, other.accepted_status(), &SystemClock)
+            .unwrap();
+
+        let net_events = net.take_events();
+        let events = [list.take_events(), task.take_events(), net_events.clone()].concat();
+        let json = serde_json::to_string(&events).unwrap();
+        let restored: Vec<TaskDomainEvent> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, events);
+
+        // A stored stream replays into the same net.
+        let replayed: Entity<Net> =
+            NetAggregateRoot::replay(restored.into_iter().skip(events.len() - net_events.len()))
+                .unwrap();
+        let (mut replayed_parts, mut parts) = (replayed.to_parts(), net.to_parts());
+        replayed_parts.status_history.clear();
+        parts.status_history.clear();
+        assert_eq!(replayed_parts, parts);
+        assert_eq!(replayed.parent_of(story), Some(epic));
+    }
+}
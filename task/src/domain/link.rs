@@ -0,0 +1,489 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
+
+use shared_kernel::{Clock, Entity, Id};
+
+use super::{
+    error::TaskDomainError,
+    net::{Net, NetAggregateRoot, NetQuery},
+    task::Task,
+};
+
+type TaskDomainResult<T> = Result<T, TaskDomainError>;
+
+/// The tasks each task of a net constrains.
+type Successors = HashMap<Id<Task>, Vec<Id<Task>>>;
+
+/// Refers to a task of a specific net.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaskRef {
+    pub net: Id<Net>,
+    pub task: Id<Task>,
+}
+
+impl TaskRef {
+    /// Refers to a task of a net.
+    pub fn new(net: Id<Net>, task: Id<Task>) -> Self {
+        TaskRef { net, task }
+    }
+}
+
+/// Keeps the links between tasks of different nets and carries acceptance across them.
+///
+/// A linked task requires a task of another net the way a `Require` relation would: while the
+/// required task is not accepted, the linked task is held on its default status. Each net
+/// remembers whether its linked requirements were last seen accepted; the coordinator updates
+/// that knowledge and re-propagates the nets depending on a changed net.
+///
+/// The links themselves are recorded by the nets, so a coordinator can always be rebuilt from
+/// them with [`LinkCoordinator::from_nets`].
+#[derive(Debug, Clone, Default)]
+pub struct LinkCoordinator {
+    dependents: HashMap<TaskRef, HashSet<TaskRef>>,
+}
+
+impl LinkCoordinator {
+    /// Creates a coordinator without links.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rebuilds the coordinator of a set of nets from the links they record, e.g. after
+    /// loading them.
+    pub fn from_nets<'a>(nets: impl IntoIterator<Item = &'a Entity<Net>>) -> Self {
+        let mut coordinator = Self::new();
+        for net in nets {
+            for (task, required, _) in net.linked_requirements() {
+                coordinator
+                    .dependents
+                    .entry(required)
+                    .or_default()
+                    .insert(TaskRef::new(net.id, task));
+            }
+        }
+        coordinator
+    }
+
+    /// Lists the tasks requiring a task through a link.
+    pub fn dependents_of(&self, required: TaskRef) -> impl Iterator<Item = TaskRef> + '_ {
+        self.dependents
+            .get(&required)
+            .into_iter()
+            .flatten()
+            .copied()
+    }
+
+    /// Makes the `dependent` task require the `required` task of another net.
+    ///
+    /// The link is rejected if the required task already depends on the dependent one, through
+    /// the constraining relations and links of all `nets`.
+    pub fn link(
+        &mut self,
+        nets: &mut HashMap<Id<Net>, Entity<Net>>,
+        dependent: TaskRef,
+        required: TaskRef,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        let accepted = nets
+            .get(&required.net)
+            .and_then(|net| is_accepted(net, required.task))
+            .ok_or(TaskDomainError::TaskNotFoundInNet {
+                net: required.net,
+                task: required.task,
+            })?;
+        if let Some(cycle) = self.dependency_path(nets, dependent, required) {
+            return Err(TaskDomainError::LinkCycleNotAllowed { cycle });
+        }
+        nets.get_mut(&dependent.net)
+            .ok_or(TaskDomainError::TaskNotFoundInNet {
+                net: dependent.net,
+                task: dependent.task,
+            })?
+            .link_requirement(dependent.task, required, accepted, clock)?;
+        self.dependents
+            .entry(required)
+            .or_default()
+            .insert(dependent);
+
+        Ok(())
+    }
+
+    /// Removes the link making a task of `dependent_net` require another task.
+    pub fn unlink(
+        &mut self,
+        dependent_net: &mut Entity<Net>,
+        task: Id<Task>,
+        required: TaskRef,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        dependent_net.unlink_requirement(task, required, clock)?;
+        if let Some(dependents) = self.dependents.get_mut(&required) {
+            dependents.remove(&TaskRef::new(dependent_net.id, task));
+            if dependents.is_empty() {
+                self.dependents.remove(&required);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Carries the acceptance of the linked tasks of a changed net over to the nets depending
+    /// on them, and onwards through the nets those changes affect in turn.
+    ///
+    /// Nets missing from `nets` are skipped, as are links to tasks no longer in their net.
+    pub fn propagate(
+        &self,
+        nets: &mut HashMap<Id<Net>, Entity<Net>>,
+        changed: Id<Net>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        let mut pending = vec![changed];
+        while let Some(net_id) = pending.pop() {
+            let Some(net) = nets.get(&net_id) else {
+                continue;
+            };
+            let updates: Vec<_> = self
+                .dependents
+                .iter()
+                .filter(|(required, _)| required.net == net_id)
+                .filter_map(|(required, dependents)| {
+                    is_accepted(net, required.task)
+                        .map(|accepted| (*required, accepted, dependents))
+                })
+                .collect();
+
+            for (required, accepted, dependents) in updates {
+                let dependent_nets: HashSet<_> =
+                    dependents.iter().map(|dependent| dependent.net).collect();
+                for dependent_net in dependent_nets {
+                    let Some(net) = nets.get_mut(&dependent_net) else {
+                        continue;
+                    };
+                    let stale = net
+                        .linked_requirements()
+                        .any(|(_, linked, known)| linked == required && known != accepted);
+                    if stale {
+                        net.update_linked_requirement(required, accepted, clock)?;
+                        pending.push(dependent_net);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds a shortest path from one task to another over the graph joining all nets, whose
+    /// edges are the constraining relations within each net and the links between them.
+    ///
+    /// Nets missing from `nets` are treated as having no relations.
+    fn dependency_path(
+        &self,
+        nets: &HashMap<Id<Net>, Entity<Net>>,
+        from: TaskRef,
+        to: TaskRef,
+    ) -> Option<Vec<TaskRef>> {
+        let mut successors: HashMap<Id<Net>, Successors> = HashMap::new();
+        let mut predecessors = HashMap::from([(from, from)]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(task) = queue.pop_front() {
+            if task == to {
+                let mut path = vec![to];
+                let mut current = to;
+                while current != from {
+                    current = predecessors[&current];
+                    path.push(current);
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            let within = successors.entry(task.net).or_insert_with(|| {
+                let mut within: HashMap<_, Vec<_>> = HashMap::new();
+                for (source, target, relation) in nets
+                    .get(&task.net)
+                    .into_iter()
+                    .flat_map(|net| net.relations())
+                {
+                    if relation.relation_type().is_constraining() {
+                        within.entry(source).or_default().push(target);
+                    }
+                }
+                within
+            });
+            let next = within
+                .get(&task.task)
+                .into_iter()
+                .flatten()
+                .map(|successor| TaskRef::new(task.net, *successor))
+                .chain(self.dependents_of(task))
+                .collect::<Vec<_>>();
+            for successor in next {
+                if let Entry::Vacant(entry) = predecessors.entry(successor) {
+                    entry.insert(task);
+                    queue.push_back(successor);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Checks whether a task of a net is accepted, if it is in the net.
+fn is_accepted(net: &Entity<Net>, task: Id<Task>) -> Option<bool> {
+    let status = net.status_of(task)?;
+    Some(net.accepted_statuses().any(|accepted| accepted == status))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{AggregateRoot, SystemClock};
+
+    use super::*;
+    use crate::domain::{event::TaskDomainEvent, name::StatusName, net::RelationType};
+
+    fn net() -> Entity<Net> {
+        NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        )
+    }
+
+    /// Gathers nets by id, the way the coordinator reaches them.
+    fn nets(nets: impl IntoIterator<Item = Entity<Net>>) -> HashMap<Id<Net>, Entity<Net>> {
+        nets.into_iter().map(|net| (net.id, net)).collect()
+    }
+
+    #[test]
+    fn test_link_holds_dependent_until_required_is_accepted() {
+        let mut backend = net();
+        let mut frontend = net();
+        let api = Id::new();
+        let page = Id::new();
+        let release = Id::new();
+        backend.add_task(api).unwrap();
+        frontend.add_tasks([page, release]).unwrap();
+        frontend
+            .new_relation(page, release, RelationType::Require, &SystemClock)
+            .unwrap();
+        let (backend_id, frontend_id) = (backend.id, frontend.id);
+        let mut nets = nets([backend, frontend]);
+
+        let mut coordinator = LinkCoordinator::new();
+        coordinator
+            .link(
+                &mut nets,
+                TaskRef::new(frontend_id, page),
+                TaskRef::new(backend_id, api),
+                &SystemClock,
+            )
+            .unwrap();
+        let frontend = nets.get_mut(&frontend_id).unwrap();
+        assert!(matches!(
+            frontend.change_task_status(page, frontend.accepted_status(), &SystemClock),
+            Err(TaskDomainError::RelationConstraintNotSatisfied { .. })
+        ));
+
+        let backend = nets.get_mut(&backend_id).unwrap();
+        backend
+            .change_task_status(api, backend.accepted_status(), &SystemClock)
+            .unwrap();
+        coordinator
+            .propagate(&mut nets, backend_id, &SystemClock)
+            .unwrap();
+
+        let frontend = nets.get_mut(&frontend_id).unwrap();
+        frontend
+            .change_task_status(page, frontend.accepted_status(), &SystemClock)
+            .unwrap();
+        frontend
+            .change_task_status(release, frontend.accepted_status(), &SystemClock)
+            .unwrap();
+        frontend.take_events();
+
+        let backend = nets.get_mut(&backend_id).unwrap();
+        backend
+            .change_task_status(api, backend.default_status(), &SystemClock)
+            .unwrap();
+        coordinator
+            .propagate(&mut nets, backend_id, &SystemClock)
+            .unwrap();
+
+        let frontend = nets.get_mut(&frontend_id).unwrap();
+        assert_eq!(frontend.status_of(page), Some(frontend.default_status()));
+        assert_eq!(frontend.status_of(release), Some(frontend.default_status()));
+        assert!(matches!(
+            frontend.take_events()[0],
+            TaskDomainEvent::LinkedRequirementUpdated {
+                accepted: false,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_propagate_through_chained_nets() {
+        let mut first = net();
+        let mut second = net();
+        let mut third = net();
+        let (a, b, c) = (Id::new(), Id::new(), Id::new());
+        first.add_task(a).unwrap();
+        second.add_task(b).unwrap();
+        third.add_task(c).unwrap();
+        let ids = [first.id, second.id, third.id];
+        let mut nets = nets([first, second, third]);
+
+        let mut coordinator = LinkCoordinator::new();
+        coordinator
+            .link(
+                &mut nets,
+                TaskRef::new(ids[1], b),
+                TaskRef::new(ids[0], a),
+                &SystemClock,
+            )
+            .unwrap();
+        coordinator
+            .link(
+                &mut nets,
+                TaskRef::new(ids[2], c),
+                TaskRef::new(ids[1], b),
+                &SystemClock,
+            )
+            .unwrap();
+        for (net_id, task) in ids.into_iter().zip([a, b, c]) {
+            let net = nets.get_mut(&net_id).unwrap();
+            net.change_task_status(task, net.accepted_status(), &SystemClock)
+                .unwrap();
+            coordinator
+                .propagate(&mut nets, net_id, &SystemClock)
+                .unwrap();
+        }
+
+        let first = nets.get_mut(&ids[0]).unwrap();
+        first
+            .change_task_status(a, first.default_status(), &SystemClock)
+            .unwrap();
+        coordinator
+            .propagate(&mut nets, ids[0], &SystemClock)
+            .unwrap();
+
+        let third = &nets[&ids[2]];
+        assert_eq!(third.status_of(c), Some(third.default_status()));
+    }
+
+    #[test]
+    fn test_link_rejects_cycles_across_nets() {
+        let mut first = net();
+        let mut second = net();
+        let (a, b, c) = (Id::new(), Id::new(), Id::new());
+        first.add_task(a).unwrap();
+        second.add_tasks([b, c]).unwrap();
+        second
+            .new_relation(b, c, RelationType::Require, &SystemClock)
+            .unwrap();
+        let (first_id, second_id) = (first.id, second.id);
+        let mut nets = nets([first, second]);
+        let mut coordinator = LinkCoordinator::new();
+        coordinator
+            .link(
+                &mut nets,
+                TaskRef::new(second_id, b),
+                TaskRef::new(first_id, a),
+                &SystemClock,
+            )
+            .unwrap();
+        nets.get_mut(&first_id).unwrap().take_events();
+
+        let closing = coordinator.link(
+            &mut nets,
+            TaskRef::new(first_id, a),
+            TaskRef::new(second_id, c),
+            &SystemClock,
+        );
+
+        assert!(matches!(
+            closing,
+            Err(TaskDomainError::LinkCycleNotAllowed { cycle })
+                if cycle == [
+                    TaskRef::new(first_id, a),
+                    TaskRef::new(second_id, b),
+                    TaskRef::new(second_id, c),
+                ]
+        ));
+        assert_eq!(nets[&first_id].linked_requirements().count(), 0);
+        assert!(nets.get_mut(&first_id).unwrap().take_events().is_empty());
+    }
+
+    #[test]
+    fn test_from_nets() {
+        let mut first = net();
+        let mut second = net();
+        let (a, b, c) = (Id::new(), Id::new(), Id::new());
+        first.add_task(a).unwrap();
+        second.add_tasks([b, c]).unwrap();
+        let (first_id, second_id) = (first.id, second.id);
+        let mut nets = nets([first, second]);
+        let mut coordinator = LinkCoordinator::new();
+        let required = TaskRef::new(first_id, a);
+        for task in [b, c] {
+            coordinator
+                .link(
+                    &mut nets,
+                    TaskRef::new(second_id, task),
+                    required,
+                    &SystemClock,
+                )
+                .unwrap();
+        }
+
+        let rebuilt = LinkCoordinator::from_nets(nets.values());
+
+        let mut dependents: Vec<_> = rebuilt.dependents_of(required).collect();
+        dependents.sort();
+        let mut expected: Vec<_> = coordinator.dependents_of(required).collect();
+        expected.sort();
+        assert_eq!(dependents, expected);
+        assert_eq!(dependents.len(), 2);
+    }
+
+    #[test]
+    fn test_unlink() {
+        let required_net = net();
+        let mut dependent_net = net();
+        let required = Id::new();
+        let dependent = Id::new();
+        dependent_net.add_task(dependent).unwrap();
+        let (required_id, dependent_id) = (required_net.id, dependent_net.id);
+        let mut nets = nets([required_net, dependent_net]);
+        let required = TaskRef::new(required_id, required);
+        let dependent = TaskRef::new(dependent_id, dependent);
+
+        let mut coordinator = LinkCoordinator::new();
+        assert!(matches!(
+            coordinator.link(&mut nets, dependent, required, &SystemClock),
+            Err(TaskDomainError::TaskNotFoundInNet { .. })
+        ));
+
+        nets.get_mut(&required_id)
+            .unwrap()
+            .add_task(required.task)
+            .unwrap();
+        coordinator
+            .link(&mut nets, dependent, required, &SystemClock)
+            .unwrap();
+        assert_eq!(coordinator.dependents_of(required).count(), 1);
+
+        let dependent_net = nets.get_mut(&dependent_id).unwrap();
+        coordinator
+            .unlink(dependent_net, dependent.task, required, &SystemClock)
+            .unwrap();
+        assert_eq!(coordinator.dependents_of(required).count(), 0);
+        assert_eq!(dependent_net.linked_requirements().count(), 0);
+        assert!(matches!(
+            coordinator.unlink(dependent_net, dependent.task, required, &SystemClock),
+            Err(TaskDomainError::LinkNotFoundInNet { .. })
+        ));
+    }
+}
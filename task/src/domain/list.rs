@@ -1,29 +1,1696 @@
-use shared_kernel::{Entity, Id};
+use std::collections::HashMap;
+
+use shared_kernel::{AggregateRoot, Audited, Clock, Entity, EntityKind, Id, Timestamped, User};
+
+use super::{
+    custom_field::{FieldDefinition, FieldType},
+    error::TaskDomainError,
+    event::TaskDomainEvent,
+    name::{FieldName, LabelName, ListTitle, SectionName},
+    net::{self, Color, Net},
+    task::{self, Task, TaskAggregateRoot},
+};
+
+type TaskDomainResult<T> = Result<T, TaskDomainError>;
 
 /// Represents a task list.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "ListRecord", from = "ListRecord")
+)]
 pub struct List {
-    title: String,
+    title: ListTitle,
+    parent: Option<Id<List>>,
+    archived: bool,
+    labels: Vec<Label>,
+    fields: Vec<Entity<FieldDefinition>>,
+    order: Vec<Id<Task>>,
+    sections: Vec<Entity<Section>>,
+    placements: Vec<(Id<Task>, Id<Section>)>,
+    events: Vec<TaskDomainEvent>,
+}
+
+/// Represents a named section grouping tasks within a list.
+///
+/// Sections are ordered within their list; tasks placed into a section keep their rank in
+/// the manual order of the list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Section {
+    name: SectionName,
+}
+
+impl EntityKind for Section {
+    const KIND: &'static str = "section";
+}
+
+impl Section {
+    /// Returns the name of the section.
+    pub fn name(&self) -> &SectionName {
+        &self.name
+    }
+}
+
+/// Represents a label tasks of a list can carry, with the color it is rendered with.
+///
+/// Lists keep a registry of their labels so that every task refers to the same name and
+/// color; tasks only store the label names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Label {
+    name: LabelName,
+    color: Color,
+}
+
+impl Label {
+    /// Creates a label.
+    pub fn new(name: LabelName, color: Color) -> Self {
+        Label { name, color }
+    }
+
+    /// Returns the name of the label.
+    pub fn name(&self) -> &LabelName {
+        &self.name
+    }
+
+    /// Returns the color of the label.
+    pub fn color(&self) -> Color {
+        self.color
+    }
+}
+
+/// The versioned on-disk form of a list; pending events are not persisted.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+enum ListRecord {
+    #[serde(rename = "1")]
+    V1 {
+        title: ListTitle,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        parent: Option<Id<List>>,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        archived: bool,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        labels: Vec<Label>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        fields: Vec<Entity<FieldDefinition>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        order: Vec<Id<Task>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        sections: Vec<Entity<Section>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        placements: Vec<(Id<Task>, Id<Section>)>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<List> for ListRecord {
+    fn from(list: List) -> Self {
+        ListRecord::V1 {
+            title: list.title,
+            parent: list.parent,
+            archived: list.archived,
+            labels: list.labels,
+            fields: list.fields,
+            order: list.order,
+            sections: list.sections,
+            placements: list.placements,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ListRecord> for List {
+    fn from(record: ListRecord) -> Self {
+        match record {
+            ListRecord::V1 {
+                title,
+                parent,
+                archived,
+                labels,
+                fields,
+                order,
+                sections,
+                placements,
+            } => List {
+                title,
+                parent,
+                archived,
+                labels,
+                fields,
+                order,
+                sections,
+                placements,
+                events: Vec::new(),
+            },
+        }
+    }
+}
+
+impl EntityKind for List {
+    const KIND: &'static str = "list";
+}
+
+impl List {
+    /// Returns the title of the list.
+    pub fn title(&self) -> &ListTitle {
+        &self.title
+    }
+
+    /// Returns the list this list is filed under, or `None` for a root list.
+    pub fn parent(&self) -> Option<Id<List>> {
+        self.parent
+    }
+
+    /// Checks whether the list is archived, hiding it without deleting it.
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    /// Lists the labels defined for the list, in the order they were defined.
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    /// Looks up a label of the list by name.
+    pub fn label(&self, name: &LabelName) -> Option<&Label> {
+        self.labels.iter().find(|label| label.name == *name)
+    }
+
+    /// Lists the custom fields defined for the tasks of the list, in the order they were
+    /// defined.
+    pub fn fields(&self) -> &[Entity<FieldDefinition>] {
+        &self.fields
+    }
+
+    /// Looks up a custom field of the list.
+    pub fn field(&self, field: Id<FieldDefinition>) -> Option<&Entity<FieldDefinition>> {
+        self.fields.iter().find(|definition| definition.id == field)
+    }
+
+    /// Lists the tasks of the list in their manual order.
+    pub fn tasks(&self) -> &[Id<Task>] {
+        &self.order
+    }
+
+    /// Returns the rank of a task in the manual order of the list, starting at zero.
+    pub fn position_of(&self, task: Id<Task>) -> Option<usize> {
+        self.order.iter().position(|t| *t == task)
+    }
+
+    /// Lists the sections of the list in order.
+    pub fn sections(&self) -> &[Entity<Section>] {
+        &self.sections
+    }
+
+    /// Looks up a section of the list.
+    pub fn section(&self, section: Id<Section>) -> Option<&Entity<Section>> {
+        self.sections.iter().find(|s| s.id == section)
+    }
+
+    /// Returns the section a task of the list is placed into, if any.
+    pub fn section_of(&self, task: Id<Task>) -> Option<Id<Section>> {
+        self.placements
+            .iter()
+            .find(|(t, _)| *t == task)
+            .map(|(_, section)| *section)
+    }
+
+    /// Lists the tasks placed into a section in their manual order, or the tasks placed into
+    /// no section when given `None`.
+    pub fn tasks_in(&self, section: Option<Id<Section>>) -> Vec<Id<Task>> {
+        self.order
+            .iter()
+            .copied()
+            .filter(|task| self.section_of(*task) == section)
+            .collect()
+    }
 }
 
 /// Trait for aggregate root operations on a `List`.
 pub trait ListAggregateRoot {
-    /// Renames the list with a new title.
-    fn rename(&mut self, title: String);
+    /// Renames the list with a new title, validated and trimmed of surrounding whitespace.
+    fn rename(&mut self, title: impl Into<String>) -> TaskDomainResult<()>;
+
+    /// Adds a label to the registry of the list, failing if its name is taken.
+    fn define_label(&mut self, label: Label) -> TaskDomainResult<()>;
+
+    /// Changes the color of a label of the list.
+    fn recolor_label(&mut self, name: &LabelName, color: Color) -> TaskDomainResult<()>;
+
+    /// Removes a label from the registry of the list.
+    ///
+    /// Tasks keep carrying the label name; it is up to the caller to remove it from them. The label
+    /// can no longer be added to tasks.
+    fn undefine_label(&mut self, name: &LabelName) -> TaskDomainResult<()>;
+
+    /// Defines a custom field for the tasks of the list, failing if its name is taken.
+    fn define_field(
+        &mut self,
+        name: FieldName,
+        field_type: FieldType,
+    ) -> TaskDomainResult<Id<FieldDefinition>>;
+
+    /// Removes a custom field from the list.
+    ///
+    /// Tasks keep their values of the field; it is up to the caller to clear them.
+    fn remove_field(&mut self, field: Id<FieldDefinition>) -> TaskDomainResult<()>;
+
+    /// Places a task last in the manual order of the list, failing if the list is archived.
+    ///
+    /// The list only ranks its tasks; categorizing the task to the list is up to the caller.
+    fn append_task(&mut self, task: Id<Task>) -> TaskDomainResult<()>;
+
+    /// Takes a task out of the manual order of the list.
+    fn remove_task(&mut self, task: Id<Task>) -> TaskDomainResult<()>;
+
+    /// Moves a task of the list right before another one.
+    fn move_task_before(&mut self, task: Id<Task>, anchor: Id<Task>) -> TaskDomainResult<()>;
+
+    /// Moves a task of the list right after another one.
+    fn move_task_after(&mut self, task: Id<Task>, anchor: Id<Task>) -> TaskDomainResult<()>;
+
+    /// Adds a section last in the list, failing if its name is taken.
+    fn add_section(&mut self, name: SectionName) -> TaskDomainResult<Id<Section>>;
+
+    /// Renames a section of the list, failing if the new name is taken by another section.
+    fn rename_section(&mut self, section: Id<Section>, name: SectionName) -> TaskDomainResult<()>;
+
+    /// Removes a section from the list, leaving the tasks placed into it in no section.
+    fn remove_section(&mut self, section: Id<Section>) -> TaskDomainResult<()>;
+
+    /// Moves a section of the list to a rank, or last if the rank is past the end.
+    fn move_section(&mut self, section: Id<Section>, position: usize) -> TaskDomainResult<()>;
+
+    /// Places a task of the list into a section, or into no section when given `None`.
+    fn place_task(&mut self, task: Id<Task>, section: Option<Id<Section>>) -> TaskDomainResult<()>;
+
+    /// Files the list under another list of the tree, failing if that would make the list
+    /// its own ancestor.
+    fn move_under(&mut self, parent: Id<List>, tree: &ListTree) -> TaskDomainResult<()>;
+
+    /// Takes the list out of its parent, making it a root list.
+    fn make_root(&mut self);
+
+    /// Archives the list, keeping it and its tasks while rejecting new tasks.
+    fn archive(&mut self);
+
+    /// Brings an archived list back, letting it take new tasks again.
+    fn unarchive(&mut self);
 
     /// Creates a new list with the given title.
-    fn new(title: String) -> Self;
+    fn new(title: ListTitle) -> Self;
 }
 
 impl ListAggregateRoot for Entity<List> {
-    fn rename(&mut self, title: String) {
-        self.data.title = title;
+    fn rename(&mut self, title: impl Into<String>) -> TaskDomainResult<()> {
+        let title = ListTitle::new_trimmed(title)?;
+        self.data.title = title.clone();
+        self.data.events.push(TaskDomainEvent::ListRenamed {
+            list: self.id,
+            title,
+        });
+
+        Ok(())
+    }
+
+    fn define_label(&mut self, label: Label) -> TaskDomainResult<()> {
+        if self.data.label(&label.name).is_some() {
+            return Err(TaskDomainError::LabelAlreadyDefined {
+                list: self.id,
+                name: label.name,
+            });
+        }
+
+        self.data.labels.push(label.clone());
+        self.data.events.push(TaskDomainEvent::LabelDefined {
+            list: self.id,
+            label,
+        });
+
+        Ok(())
+    }
+
+    fn recolor_label(&mut self, name: &LabelName, color: Color) -> TaskDomainResult<()> {
+        let list = self.id;
+        let label = self
+            .data
+            .labels
+            .iter_mut()
+            .find(|label| label.name == *name)
+            .ok_or_else(|| TaskDomainError::LabelNotDefined {
+                list,
+                name: name.clone(),
+            })?;
+        if label.color == color {
+            return Ok(());
+        }
+
+        label.color = color;
+        self.data.events.push(TaskDomainEvent::LabelRecolored {
+            list,
+            name: name.clone(),
+            color,
+        });
+
+        Ok(())
+    }
+
+    fn undefine_label(&mut self, name: &LabelName) -> TaskDomainResult<()> {
+        let Some(index) = self
+            .data
+            .labels
+            .iter()
+            .position(|label| label.name == *name)
+        else {
+            return Err(TaskDomainError::LabelNotDefined {
+                list: self.id,
+                name: name.clone(),
+            });
+        };
+
+        self.data.labels.remove(index);
+        self.data.events.push(TaskDomainEvent::LabelUndefined {
+            list: self.id,
+            name: name.clone(),
+        });
+
+        Ok(())
+    }
+
+    fn define_field(
+        &mut self,
+        name: FieldName,
+        field_type: FieldType,
+    ) -> TaskDomainResult<Id<FieldDefinition>> {
+        if self
+            .data
+            .fields
+            .iter()
+            .any(|field| *field.data.name() == name)
+        {
+            return Err(TaskDomainError::FieldAlreadyDefined {
+                list: self.id,
+                name,
+            });
+        }
+
+        let field = Entity::from(FieldDefinition::new(self.id, name, field_type));
+        self.data.events.push(TaskDomainEvent::FieldDefined {
+            list: self.id,
+            field: field.id,
+            definition: field.data.clone(),
+        });
+        let id = field.id;
+        self.data.fields.push(field);
+
+        Ok(id)
+    }
+
+    fn remove_field(&mut self, field: Id<FieldDefinition>) -> TaskDomainResult<()> {
+        let Some(index) = self.data.fields.iter().position(|f| f.id == field) else {
+            return Err(TaskDomainError::FieldNotDefined {
+                list: self.id,
+                field,
+            });
+        };
+
+        self.data.fields.remove(index);
+        self.data.events.push(TaskDomainEvent::FieldRemoved {
+            list: self.id,
+            field,
+        });
+
+        Ok(())
+    }
+
+    fn append_task(&mut self, task: Id<Task>) -> TaskDomainResult<()> {
+        if self.data.archived {
+            return Err(TaskDomainError::ListArchived { list: self.id });
+        }
+        if self.data.position_of(task).is_some() {
+            return Err(TaskDomainError::TaskAlreadyInList {
+                list: self.id,
+                task,
+            });
+        }
+
+        self.data.order.push(task);
+        self.data.events.push(TaskDomainEvent::TaskPositioned {
+            list: self.id,
+            task,
+            position: self.data.order.len() - 1,
+        });
+
+        Ok(())
+    }
+
+    fn remove_task(&mut self, task: Id<Task>) -> TaskDomainResult<()> {
+        let index = self.position_in_list(task)?;
+
+        self.data.order.remove(index);
+        self.data.placements.retain(|(t, _)| *t != task);
+        self.data.events.push(TaskDomainEvent::TaskRemovedFromList {
+            list: self.id,
+            task,
+        });
+
+        Ok(())
+    }
+
+    fn move_task_before(&mut self, task: Id<Task>, anchor: Id<Task>) -> TaskDomainResult<()> {
+        self.move_task(task, anchor, 0)
+    }
+
+    fn move_task_after(&mut self, task: Id<Task>, anchor: Id<Task>) -> TaskDomainResult<()> {
+        self.move_task(task, anchor, 1)
+    }
+
+    fn add_section(&mut self, name: SectionName) -> TaskDomainResult<Id<Section>> {
+        if self.data.sections.iter().any(|s| s.data.name == name) {
+            return Err(TaskDomainError::SectionAlreadyDefined {
+                list: self.id,
+                name,
+            });
+        }
+
+        let section = Entity::from(Section { name: name.clone() });
+        self.data.events.push(TaskDomainEvent::SectionAdded {
+            list: self.id,
+            section: section.id,
+            name,
+        });
+        let id = section.id;
+        self.data.sections.push(section);
+
+        Ok(id)
+    }
+
+    fn rename_section(&mut self, section: Id<Section>, name: SectionName) -> TaskDomainResult<()> {
+        if self
+            .data
+            .sections
+            .iter()
+            .any(|s| s.id != section && s.data.name == name)
+        {
+            return Err(TaskDomainError::SectionAlreadyDefined {
+                list: self.id,
+                name,
+            });
+        }
+        let index = self.section_index(section)?;
+
+        self.data.sections[index].data.name = name.clone();
+        self.data.events.push(TaskDomainEvent::SectionRenamed {
+            list: self.id,
+            section,
+            name,
+        });
+
+        Ok(())
     }
 
-    fn new(title: String) -> Self {
+    fn remove_section(&mut self, section: Id<Section>) -> TaskDomainResult<()> {
+        let index = self.section_index(section)?;
+
+        self.data.sections.remove(index);
+        self.data.placements.retain(|(_, s)| *s != section);
+        self.data.events.push(TaskDomainEvent::SectionRemoved {
+            list: self.id,
+            section,
+        });
+
+        Ok(())
+    }
+
+    fn move_section(&mut self, section: Id<Section>, position: usize) -> TaskDomainResult<()> {
+        let from = self.section_index(section)?;
+        let to = position.min(self.data.sections.len() - 1);
+        if from == to {
+            return Ok(());
+        }
+
+        let moved = self.data.sections.remove(from);
+        self.data.sections.insert(to, moved);
+        self.data.events.push(TaskDomainEvent::SectionMoved {
+            list: self.id,
+            section,
+            position: to,
+        });
+
+        Ok(())
+    }
+
+    fn place_task(&mut self, task: Id<Task>, section: Option<Id<Section>>) -> TaskDomainResult<()> {
+        self.position_in_list(task)?;
+        if let Some(section) = section {
+            self.section_index(section)?;
+        }
+        if self.data.section_of(task) == section {
+            return Ok(());
+        }
+
+        self.data.placements.retain(|(t, _)| *t != task);
+        if let Some(section) = section {
+            self.data.placements.push((task, section));
+        }
+        self.data.events.push(TaskDomainEvent::TaskSectioned {
+            list: self.id,
+            task,
+            section,
+        });
+
+        Ok(())
+    }
+
+    fn move_under(&mut self, parent: Id<List>, tree: &ListTree) -> TaskDomainResult<()> {
+        if !tree.contains(parent) {
+            return Err(TaskDomainError::ListNotFound { list: parent });
+        }
+        if parent == self.id || tree.ancestors_of(parent).contains(&self.id) {
+            return Err(TaskDomainError::ListCycleNotAllowed {
+                list: self.id,
+                parent,
+            });
+        }
+        if self.data.parent == Some(parent) {
+            return Ok(());
+        }
+
+        self.data.parent = Some(parent);
+        self.data.events.push(TaskDomainEvent::ListMoved {
+            list: self.id,
+            parent: Some(parent),
+        });
+
+        Ok(())
+    }
+
+    fn make_root(&mut self) {
+        if self.data.parent.take().is_none() {
+            return;
+        }
+
+        self.data.events.push(TaskDomainEvent::ListMoved {
+            list: self.id,
+            parent: None,
+        });
+    }
+
+    fn archive(&mut self) {
+        if std::mem::replace(&mut self.data.archived, true) {
+            return;
+        }
+
+        self.data
+            .events
+            .push(TaskDomainEvent::ListArchived { list: self.id });
+    }
+
+    fn unarchive(&mut self) {
+        if !std::mem::replace(&mut self.data.archived, false) {
+            return;
+        }
+
+        self.data
+            .events
+            .push(TaskDomainEvent::ListUnarchived { list: self.id });
+    }
+
+    fn new(title: ListTitle) -> Self {
+        let id = Id::new();
         Entity {
-            id: Id::new(),
-            data: List { title },
+            id,
+            data: List {
+                title: title.clone(),
+                parent: None,
+                archived: false,
+                labels: Vec::new(),
+                fields: Vec::new(),
+                order: Vec::new(),
+                sections: Vec::new(),
+                placements: Vec::new(),
+                events: vec![TaskDomainEvent::ListCreated { list: id, title }],
+            },
         }
     }
 }
+
+impl<C: Clock + Default> ListAggregateRoot for Timestamped<Entity<List>, C> {
+    fn rename(&mut self, title: impl Into<String>) -> TaskDomainResult<()> {
+        self.try_update(|list| list.rename(title))
+    }
+
+    fn define_label(&mut self, label: Label) -> TaskDomainResult<()> {
+        self.try_update(|list| list.define_label(label))
+    }
+
+    fn recolor_label(&mut self, name: &LabelName, color: Color) -> TaskDomainResult<()> {
+        self.try_update(|list| list.recolor_label(name, color))
+    }
+
+    fn undefine_label(&mut self, name: &LabelName) -> TaskDomainResult<()> {
+        self.try_update(|list| list.undefine_label(name))
+    }
+
+    fn define_field(
+        &mut self,
+        name: FieldName,
+        field_type: FieldType,
+    ) -> TaskDomainResult<Id<FieldDefinition>> {
+        self.try_update(|list| list.define_field(name, field_type))
+    }
+
+    fn remove_field(&mut self, field: Id<FieldDefinition>) -> TaskDomainResult<()> {
+        self.try_update(|list| list.remove_field(field))
+    }
+
+    fn append_task(&mut self, task: Id<Task>) -> TaskDomainResult<()> {
+        self.try_update(|list| list.append_task(task))
+    }
+
+    fn remove_task(&mut self, task: Id<Task>) -> TaskDomainResult<()> {
+        self.try_update(|list| list.remove_task(task))
+    }
+
+    fn move_task_before(&mut self, task: Id<Task>, anchor: Id<Task>) -> TaskDomainResult<()> {
+        self.try_update(|list| list.move_task_before(task, anchor))
+    }
+
+    fn move_task_after(&mut self, task: Id<Task>, anchor: Id<Task>) -> TaskDomainResult<()> {
+        self.try_update(|list| list.move_task_after(task, anchor))
+    }
+
+    fn add_section(&mut self, name: SectionName) -> TaskDomainResult<Id<Section>> {
+        self.try_update(|list| list.add_section(name))
+    }
+
+    fn rename_section(&mut self, section: Id<Section>, name: SectionName) -> TaskDomainResult<()> {
+        self.try_update(|list| list.rename_section(section, name))
+    }
+
+    fn remove_section(&mut self, section: Id<Section>) -> TaskDomainResult<()> {
+        self.try_update(|list| list.remove_section(section))
+    }
+
+    fn move_section(&mut self, section: Id<Section>, position: usize) -> TaskDomainResult<()> {
+        self.try_update(|list| list.move_section(section, position))
+    }
+
+    fn place_task(&mut self, task: Id<Task>, section: Option<Id<Section>>) -> TaskDomainResult<()> {
+        self.try_update(|list| list.place_task(task, section))
+    }
+
+    fn move_under(&mut self, parent: Id<List>, tree: &ListTree) -> TaskDomainResult<()> {
+        self.try_update(|list| list.move_under(parent, tree))
+    }
+
+    fn make_root(&mut self) {
+        self.update(|list| list.make_root());
+    }
+
+    fn archive(&mut self) {
+        self.update(|list| list.archive());
+    }
+
+    fn unarchive(&mut self) {
+        self.update(|list| list.unarchive());
+    }
+
+    fn new(title: ListTitle) -> Self {
+        Timestamped::new(
+            <Entity<List> as ListAggregateRoot>::new(title),
+            C::default(),
+        )
+    }
+}
+
+/// Attributed variants of the [`ListAggregateRoot`] operations, recording the user performing each
+/// one on an [`Audited`] list.
+pub trait AuditedListAggregateRoot {
+    /// Attributed variant of [`ListAggregateRoot::rename`].
+    fn rename_by(&mut self, actor: Id<User>, title: impl Into<String>) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::define_label`].
+    fn define_label_by(&mut self, actor: Id<User>, label: Label) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::recolor_label`].
+    fn recolor_label_by(
+        &mut self,
+        actor: Id<User>,
+        name: &LabelName,
+        color: Color,
+    ) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::undefine_label`].
+    fn undefine_label_by(&mut self, actor: Id<User>, name: &LabelName) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::define_field`].
+    fn define_field_by(
+        &mut self,
+        actor: Id<User>,
+        name: FieldName,
+        field_type: FieldType,
+    ) -> TaskDomainResult<Id<FieldDefinition>>;
+
+    /// Attributed variant of [`ListAggregateRoot::remove_field`].
+    fn remove_field_by(
+        &mut self,
+        actor: Id<User>,
+        field: Id<FieldDefinition>,
+    ) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::append_task`].
+    fn append_task_by(&mut self, actor: Id<User>, task: Id<Task>) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::remove_task`].
+    fn remove_task_by(&mut self, actor: Id<User>, task: Id<Task>) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::move_task_before`].
+    fn move_task_before_by(
+        &mut self,
+        actor: Id<User>,
+        task: Id<Task>,
+        anchor: Id<Task>,
+    ) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::move_task_after`].
+    fn move_task_after_by(
+        &mut self,
+        actor: Id<User>,
+        task: Id<Task>,
+        anchor: Id<Task>,
+    ) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::add_section`].
+    fn add_section_by(
+        &mut self,
+        actor: Id<User>,
+        name: SectionName,
+    ) -> TaskDomainResult<Id<Section>>;
+
+    /// Attributed variant of [`ListAggregateRoot::rename_section`].
+    fn rename_section_by(
+        &mut self,
+        actor: Id<User>,
+        section: Id<Section>,
+        name: SectionName,
+    ) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::remove_section`].
+    fn remove_section_by(&mut self, actor: Id<User>, section: Id<Section>) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::move_section`].
+    fn move_section_by(
+        &mut self,
+        actor: Id<User>,
+        section: Id<Section>,
+        position: usize,
+    ) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::place_task`].
+    fn place_task_by(
+        &mut self,
+        actor: Id<User>,
+        task: Id<Task>,
+        section: Option<Id<Section>>,
+    ) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::move_under`].
+    fn move_under_by(
+        &mut self,
+        actor: Id<User>,
+        parent: Id<List>,
+        tree: &ListTree,
+    ) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`ListAggregateRoot::make_root`].
+    fn make_root_by(&mut self, actor: Id<User>);
+
+    /// Attributed variant of [`ListAggregateRoot::archive`].
+    fn archive_by(&mut self, actor: Id<User>);
+
+    /// Attributed variant of [`ListAggregateRoot::unarchive`].
+    fn unarchive_by(&mut self, actor: Id<User>);
+
+    /// Attributed variant of [`ListAggregateRoot::new`], created by `actor`.
+    fn new_by(actor: Id<User>, title: ListTitle) -> Self;
+}
+
+impl AuditedListAggregateRoot for Audited<Entity<List>> {
+    fn rename_by(&mut self, actor: Id<User>, title: impl Into<String>) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.rename(title))
+    }
+
+    fn define_label_by(&mut self, actor: Id<User>, label: Label) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.define_label(label))
+    }
+
+    fn recolor_label_by(
+        &mut self,
+        actor: Id<User>,
+        name: &LabelName,
+        color: Color,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.recolor_label(name, color))
+    }
+
+    fn undefine_label_by(&mut self, actor: Id<User>, name: &LabelName) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.undefine_label(name))
+    }
+
+    fn define_field_by(
+        &mut self,
+        actor: Id<User>,
+        name: FieldName,
+        field_type: FieldType,
+    ) -> TaskDomainResult<Id<FieldDefinition>> {
+        self.try_update_by(actor, |list| list.define_field(name, field_type))
+    }
+
+    fn remove_field_by(
+        &mut self,
+        actor: Id<User>,
+        field: Id<FieldDefinition>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.remove_field(field))
+    }
+
+    fn append_task_by(&mut self, actor: Id<User>, task: Id<Task>) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.append_task(task))
+    }
+
+    fn remove_task_by(&mut self, actor: Id<User>, task: Id<Task>) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.remove_task(task))
+    }
+
+    fn move_task_before_by(
+        &mut self,
+        actor: Id<User>,
+        task: Id<Task>,
+        anchor: Id<Task>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.move_task_before(task, anchor))
+    }
+
+    fn move_task_after_by(
+        &mut self,
+        actor: Id<User>,
+        task: Id<Task>,
+        anchor: Id<Task>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.move_task_after(task, anchor))
+    }
+
+    fn add_section_by(
+        &mut self,
+        actor: Id<User>,
+        name: SectionName,
+    ) -> TaskDomainResult<Id<Section>> {
+        self.try_update_by(actor, |list| list.add_section(name))
+    }
+
+    fn rename_section_by(
+        &mut self,
+        actor: Id<User>,
+        section: Id<Section>,
+        name: SectionName,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.rename_section(section, name))
+    }
+
+    fn remove_section_by(&mut self, actor: Id<User>, section: Id<Section>) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.remove_section(section))
+    }
+
+    fn move_section_by(
+        &mut self,
+        actor: Id<User>,
+        section: Id<Section>,
+        position: usize,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.move_section(section, position))
+    }
+
+    fn place_task_by(
+        &mut self,
+        actor: Id<User>,
+        task: Id<Task>,
+        section: Option<Id<Section>>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.place_task(task, section))
+    }
+
+    fn move_under_by(
+        &mut self,
+        actor: Id<User>,
+        parent: Id<List>,
+        tree: &ListTree,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |list| list.move_under(parent, tree))
+    }
+
+    fn make_root_by(&mut self, actor: Id<User>) {
+        self.update_by(actor, |list| list.make_root());
+    }
+
+    fn archive_by(&mut self, actor: Id<User>) {
+        self.update_by(actor, |list| list.archive());
+    }
+
+    fn unarchive_by(&mut self, actor: Id<User>) {
+        self.update_by(actor, |list| list.unarchive());
+    }
+
+    fn new_by(actor: Id<User>, title: ListTitle) -> Self {
+        Audited::new(<Entity<List> as ListAggregateRoot>::new(title), actor)
+    }
+}
+
+/// A list copied under fresh ids, with the copies of its tasks.
+#[derive(Debug, Clone)]
+pub struct ListDuplicate {
+    /// The copy of the list.
+    pub list: Entity<List>,
+    /// The copies of the tasks of the list.
+    pub tasks: Vec<Entity<Task>>,
+    /// The id of each task copy by the id of its original.
+    pub copies: HashMap<Id<Task>, Id<Task>>,
+}
+
+/// Copies a list together with its tasks, so recurring projects can start from a prototype.
+///
+/// The copy keeps the title, parent, labels, custom fields, sections and manual order of
+/// the list, under fresh ids; it is never archived. Only the given tasks categorized to the
+/// list are copied, leaving out their work logs. When a net is given, the tasks of the list
+/// in it are copied there too, with the relations among them.
+pub fn duplicate_list(
+    list: &Entity<List>,
+    tasks: &[Entity<Task>],
+    net: Option<&mut Entity<Net>>,
+    clock: &impl Clock,
+) -> TaskDomainResult<ListDuplicate> {
+    let source = &list.data;
+    let mut copy: Entity<List> = ListAggregateRoot::new(source.title.clone());
+    if let Some(parent) = source.parent {
+        copy.data.parent = Some(parent);
+        copy.data.events.push(TaskDomainEvent::ListMoved {
+            list: copy.id,
+            parent: Some(parent),
+        });
+    }
+    for label in &source.labels {
+        copy.define_label(label.clone())?;
+    }
+    let mut fields = HashMap::new();
+    for field in &source.fields {
+        let id = copy.define_field(field.data.name().clone(), field.data.field_type().clone())?;
+        fields.insert(field.id, id);
+    }
+    let mut sections = HashMap::new();
+    for section in &source.sections {
+        sections.insert(section.id, copy.add_section(section.data.name.clone())?);
+    }
+
+    let tasks: Vec<_> = tasks
+        .iter()
+        .filter(|task| task.data.list() == list.id)
+        .map(|task| {
+            (
+                task.id,
+                task::clone_task_into(task, Id::new(), copy.id, &fields),
+            )
+        })
+        .collect();
+    let copies: HashMap<_, _> = tasks
+        .iter()
+        .map(|(original, task)| (*original, task.id))
+        .collect();
+    for task in &source.order {
+        if let Some(task) = copies.get(task) {
+            copy.append_task(*task)?;
+        }
+    }
+    for (task, section) in &source.placements {
+        if let Some(task) = copies.get(task) {
+            copy.place_task(*task, sections.get(section).copied())?;
+        }
+    }
+
+    if let Some(net) = net {
+        let in_net = copies
+            .iter()
+            .filter(|(original, _)| net.data.status_of(**original).is_some())
+            .map(|(original, copy)| (*original, *copy))
+            .collect();
+        net::NetAggregateRoot::copy_tasks(net, &in_net, clock)?;
+    }
+
+    Ok(ListDuplicate {
+        list: copy,
+        tasks: tasks.into_iter().map(|(_, task)| task).collect(),
+        copies,
+    })
+}
+
+/// Moves every task of a list to another one and archives the emptied list.
+///
+/// Every task ranked in the source list must be among the given ones. They are categorized to
+/// the target, keeping their manual order after the tasks already there, and the labels of the
+/// source missing from the target are defined there. Values of custom fields of the source are cleared from
+/// the tasks as they are categorized.
+pub fn merge_lists(
+    source: &mut Entity<List>,
+    target: &mut Entity<List>,
+    tasks: &mut [Entity<Task>],
+) -> TaskDomainResult<()> {
+    if source.id == target.id {
+        return Err(TaskDomainError::ListMergedIntoItself { list: source.id });
+    }
+    if target.data.archived {
+        return Err(TaskDomainError::ListArchived { list: target.id });
+    }
+    if let Some(task) = source.data.order.iter().find(|task| {
+        !tasks
+            .iter()
+            .any(|given| given.id == **task && given.data.list() == source.id)
+    }) {
+        return Err(TaskDomainError::ListTaskNotGiven {
+            list: source.id,
+            task: *task,
+        });
+    }
+
+    // The target takes everything first, so the source and the tasks record nothing unless
+    // it succeeds.
+    for label in &source.data.labels {
+        if target.data.label(&label.name).is_none() {
+            target.define_label(label.clone())?;
+        }
+    }
+    for task in &source.data.order {
+        if target.data.position_of(*task).is_none() {
+            target.append_task(*task)?;
+        }
+    }
+
+    for task in tasks.iter_mut().filter(|t| t.data.list() == source.id) {
+        task.categorize_to(target.id);
+    }
+    for task in std::mem::take(&mut source.data.order) {
+        source
+            .data
+            .events
+            .push(TaskDomainEvent::TaskRemovedFromList {
+                list: source.id,
+                task,
+            });
+    }
+    source.data.placements.clear();
+    source.archive();
+    source.data.events.push(TaskDomainEvent::ListsMerged {
+        source: source.id,
+        target: target.id,
+    });
+
+    Ok(())
+}
+
+/// A read model of how lists are filed under one another.
+///
+/// Lists only know their parent; the tree gathers them to answer questions about the
+/// whole hierarchy. Children are kept in the order the lists were given.
+#[derive(Debug, Clone, Default)]
+pub struct ListTree {
+    parents: HashMap<Id<List>, Option<Id<List>>>,
+    children: HashMap<Option<Id<List>>, Vec<Id<List>>>,
+}
+
+impl ListTree {
+    /// Builds the tree of the given lists.
+    ///
+    /// A list whose parent is not among them is treated as a root.
+    pub fn new<'a>(lists: impl IntoIterator<Item = &'a Entity<List>>) -> Self {
+        let lists: Vec<_> = lists.into_iter().collect();
+        let parents: HashMap<_, _> = lists
+            .iter()
+            .map(|list| (list.id, list.data.parent))
+            .collect();
+        let mut children: HashMap<_, Vec<_>> = HashMap::new();
+        for list in &lists {
+            let parent = list.data.parent.filter(|p| parents.contains_key(p));
+            children.entry(parent).or_default().push(list.id);
+        }
+
+        ListTree { parents, children }
+    }
+
+    /// Checks whether a list is part of the tree.
+    pub fn contains(&self, list: Id<List>) -> bool {
+        self.parents.contains_key(&list)
+    }
+
+    /// Lists the root lists of the tree.
+    pub fn roots(&self) -> &[Id<List>] {
+        self.children.get(&None).map_or(&[], Vec::as_slice)
+    }
+
+    /// Lists the lists filed directly under a list.
+    pub fn children_of(&self, list: Id<List>) -> &[Id<List>] {
+        self.children.get(&Some(list)).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the parent of a list within the tree.
+    pub fn parent_of(&self, list: Id<List>) -> Option<Id<List>> {
+        self.parents
+            .get(&list)
+            .copied()
+            .flatten()
+            .filter(|parent| self.contains(*parent))
+    }
+
+    /// Lists the ancestors of a list, from its parent up to its root.
+    pub fn ancestors_of(&self, list: Id<List>) -> Vec<Id<List>> {
+        let mut ancestors = Vec::new();
+        let mut current = list;
+        while let Some(parent) = self.parent_of(current) {
+            if parent == list || ancestors.contains(&parent) {
+                break;
+            }
+            ancestors.push(parent);
+            current = parent;
+        }
+        ancestors
+    }
+
+    /// Lists the lists filed under a list at any depth, parents before their children.
+    pub fn descendants_of(&self, list: Id<List>) -> Vec<Id<List>> {
+        let mut descendants = Vec::new();
+        let mut pending = vec![list];
+        while let Some(current) = pending.pop() {
+            for child in self.children_of(current).iter().rev() {
+                if *child != list && !descendants.contains(child) {
+                    descendants.push(*child);
+                    pending.push(*child);
+                }
+            }
+        }
+        descendants
+    }
+}
+
+/// Helpers for locating and moving the tasks and sections of a list.
+trait ListLookup {
+    /// Returns the rank of a task, failing if it is not in the list.
+    fn position_in_list(&self, task: Id<Task>) -> TaskDomainResult<usize>;
+
+    /// Moves a task next to an anchor task, `offset` ranks after the slot the anchor holds.
+    fn move_task(
+        &mut self,
+        task: Id<Task>,
+        anchor: Id<Task>,
+        offset: usize,
+    ) -> TaskDomainResult<()>;
+
+    /// Returns the index of a section, failing if it is not in the list.
+    fn section_index(&self, section: Id<Section>) -> TaskDomainResult<usize>;
+}
+
+impl ListLookup for Entity<List> {
+    fn position_in_list(&self, task: Id<Task>) -> TaskDomainResult<usize> {
+        self.data
+            .position_of(task)
+            .ok_or(TaskDomainError::TaskNotInList {
+                list: self.id,
+                task,
+            })
+    }
+
+    fn section_index(&self, section: Id<Section>) -> TaskDomainResult<usize> {
+        self.data
+            .sections
+            .iter()
+            .position(|s| s.id == section)
+            .ok_or(TaskDomainError::SectionNotFound {
+                list: self.id,
+                section,
+            })
+    }
+
+    fn move_task(
+        &mut self,
+        task: Id<Task>,
+        anchor: Id<Task>,
+        offset: usize,
+    ) -> TaskDomainResult<()> {
+        let from = self.position_in_list(task)?;
+        self.position_in_list(anchor)?;
+        if task == anchor {
+            return Ok(());
+        }
+
+        self.data.order.remove(from);
+        let to = self.position_in_list(anchor)? + offset;
+        if to == from {
+            self.data.order.insert(from, task);
+            return Ok(());
+        }
+        self.data.order.insert(to, task);
+        self.data.events.push(TaskDomainEvent::TaskPositioned {
+            list: self.id,
+            task,
+            position: to,
+        });
+
+        Ok(())
+    }
+}
+
+impl AggregateRoot for List {
+    type Event = TaskDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_follows_rename() {
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        assert_eq!(list.data.title(), "Inbox");
+
+        list.rename(ListTitle::new("Backlog").unwrap()).unwrap();
+        assert_eq!(list.data.title(), "Backlog");
+
+        list.rename("  Someday ").unwrap();
+        assert_eq!(list.data.title(), "Someday");
+
+        assert!(list.rename("").is_err());
+        assert!(list.rename("x".repeat(ListTitle::MAX_LEN + 1)).is_err());
+        assert_eq!(list.data.title(), "Someday");
+
+        let longest = "x".repeat(ListTitle::MAX_LEN);
+        list.rename(format!("  {longest}  ")).unwrap();
+        assert_eq!(list.data.title(), longest.as_str());
+    }
+
+    #[test]
+    fn test_label_registry() {
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let bug = LabelName::new("bug").unwrap();
+        let red = Color {
+            red: 255,
+            green: 0,
+            blue: 0,
+        };
+        let green = Color {
+            red: 0,
+            green: 255,
+            blue: 0,
+        };
+
+        list.define_label(Label::new(bug.clone(), red)).unwrap();
+        assert!(matches!(
+            list.define_label(Label::new(bug.clone(), green)),
+            Err(TaskDomainError::LabelAlreadyDefined { .. })
+        ));
+        assert_eq!(list.data.label(&bug).unwrap().color(), red);
+
+        list.recolor_label(&bug, green).unwrap();
+        assert_eq!(list.data.labels()[0].color(), green);
+
+        list.undefine_label(&bug).unwrap();
+        assert!(list.data.label(&bug).is_none());
+        assert!(matches!(
+            list.recolor_label(&bug, red),
+            Err(TaskDomainError::LabelNotDefined { .. })
+        ));
+    }
+
+    #[test]
+    fn test_fields() {
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let points = FieldName::new("Story points").unwrap();
+
+        let field = list
+            .define_field(points.clone(), FieldType::Number)
+            .unwrap();
+        assert!(matches!(
+            list.define_field(points.clone(), FieldType::Text),
+            Err(TaskDomainError::FieldAlreadyDefined { .. })
+        ));
+        assert_eq!(list.data.field(field).unwrap().data.name(), &points);
+        assert_eq!(list.data.field(field).unwrap().data.list(), list.id);
+
+        list.remove_field(field).unwrap();
+        assert!(list.data.fields().is_empty());
+        assert!(matches!(
+            list.remove_field(field),
+            Err(TaskDomainError::FieldNotDefined { .. })
+        ));
+    }
+
+    #[test]
+    fn test_manual_order() {
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let [a, b, c] = [Id::new(), Id::new(), Id::new()];
+        for task in [a, b, c] {
+            list.append_task(task).unwrap();
+        }
+        assert!(matches!(
+            list.append_task(a),
+            Err(TaskDomainError::TaskAlreadyInList { .. })
+        ));
+        assert_eq!(list.data.tasks(), [a, b, c]);
+
+        list.move_task_before(c, a).unwrap();
+        assert_eq!(list.data.tasks(), [c, a, b]);
+        list.move_task_after(c, b).unwrap();
+        assert_eq!(list.data.tasks(), [a, b, c]);
+        list.take_events();
+        list.move_task_after(a, c).unwrap();
+        list.move_task_before(b, c).unwrap();
+        assert_eq!(list.data.tasks(), [b, c, a]);
+        assert_eq!(list.data.position_of(a), Some(2));
+        assert_eq!(list.take_events().len(), 1);
+
+        list.remove_task(c).unwrap();
+        assert_eq!(list.data.tasks(), [b, a]);
+        assert!(matches!(
+            list.move_task_before(c, a),
+            Err(TaskDomainError::TaskNotInList { .. })
+        ));
+        assert!(matches!(
+            list.move_task_after(a, c),
+            Err(TaskDomainError::TaskNotInList { .. })
+        ));
+        assert!(list.remove_task(c).is_err());
+    }
+
+    #[test]
+    fn test_sections() {
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let [a, b, c] = [Id::new(), Id::new(), Id::new()];
+        for task in [a, b, c] {
+            list.append_task(task).unwrap();
+        }
+        let today = list
+            .add_section(SectionName::new("Today").unwrap())
+            .unwrap();
+        let later = list
+            .add_section(SectionName::new("Later").unwrap())
+            .unwrap();
+        assert!(matches!(
+            list.add_section(SectionName::new("Today").unwrap()),
+            Err(TaskDomainError::SectionAlreadyDefined { .. })
+        ));
+        assert!(matches!(
+            list.rename_section(later, SectionName::new("Today").unwrap()),
+            Err(TaskDomainError::SectionAlreadyDefined { .. })
+        ));
+        list.rename_section(later, SectionName::new("Someday").unwrap())
+            .unwrap();
+        assert_eq!(list.data.section(later).unwrap().data.name(), "Someday");
+
+        list.move_section(later, 0).unwrap();
+        list.move_section(today, 7).unwrap();
+        let order: Vec<_> = list.data.sections().iter().map(|s| s.id).collect();
+        assert_eq!(order, [later, today]);
+
+        list.place_task(c, Some(today)).unwrap();
+        list.place_task(a, Some(today)).unwrap();
+        list.place_task(b, Some(later)).unwrap();
+        list.place_task(b, None).unwrap();
+        assert_eq!(list.data.tasks_in(Some(today)), [a, c]);
+        assert_eq!(list.data.tasks_in(None), [b]);
+        assert!(matches!(
+            list.place_task(Id::new(), Some(today)),
+            Err(TaskDomainError::TaskNotInList { .. })
+        ));
+        assert!(matches!(
+            list.place_task(a, Some(Id::new())),
+            Err(TaskDomainError::SectionNotFound { .. })
+        ));
+
+        list.remove_task(c).unwrap();
+        assert_eq!(list.data.section_of(c), None);
+        list.remove_section(today).unwrap();
+        assert_eq!(list.data.section_of(a), None);
+        assert!(list.remove_section(today).is_err());
+    }
+
+    #[test]
+    fn test_archiving() {
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("2023").unwrap());
+        let task = Id::new();
+        list.append_task(task).unwrap();
+        list.take_events();
+
+        list.archive();
+        list.archive();
+        assert!(list.data.is_archived());
+        assert!(matches!(
+            list.append_task(Id::new()),
+            Err(TaskDomainError::ListArchived { .. })
+        ));
+        assert_eq!(list.data.tasks(), [task]);
+
+        list.unarchive();
+        assert!(!list.data.is_archived());
+        list.append_task(Id::new()).unwrap();
+        assert_eq!(list.take_events().len(), 3);
+    }
+
+    #[test]
+    fn test_duplicate_list() {
+        use shared_kernel::SystemClock;
+
+        use crate::domain::{
+            custom_field::{FieldValue, FiniteNumber},
+            name::{StatusName, TaskName},
+            net::{NetAggregateRoot, NetQuery, RelationType},
+            task::TaskAggregateRoot,
+        };
+
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Sprint").unwrap());
+        let points = list
+            .define_field(FieldName::new("Points").unwrap(), FieldType::Number)
+            .unwrap();
+        let review = list
+            .add_section(SectionName::new("Review").unwrap())
+            .unwrap();
+        let mut plan: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Plan").unwrap(), list.id);
+        plan.set_field(
+            list.data.field(points).unwrap(),
+            FieldValue::Number(FiniteNumber::new(2.0).unwrap()),
+        )
+        .unwrap();
+        let demo: Entity<Task> = TaskAggregateRoot::new(TaskName::new("Demo").unwrap(), list.id);
+        let elsewhere: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Elsewhere").unwrap(), Id::new());
+        for task in [&demo, &plan] {
+            list.append_task(task.id).unwrap();
+        }
+        list.place_task(demo.id, Some(review)).unwrap();
+
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        for task in [&plan, &demo, &elsewhere] {
+            net.add_task(task.id).unwrap();
+        }
+        net.new_relation(plan.id, demo.id, RelationType::Require, &SystemClock)
+            .unwrap();
+        net.new_relation(elsewhere.id, demo.id, RelationType::Require, &SystemClock)
+            .unwrap();
+        net.change_task_status(plan.id, net.accepted_status(), &SystemClock)
+            .unwrap();
+
+        let duplicate = duplicate_list(
+            &list,
+            &[plan.clone(), demo.clone(), elsewhere.clone()],
+            Some(&mut net),
+            &SystemClock,
+        )
+        .unwrap();
+        let copy = &duplicate.list;
+        let plan_copy = duplicate.copies[&plan.id];
+        let demo_copy = duplicate.copies[&demo.id];
+        assert_ne!(copy.id, list.id);
+        assert_eq!(copy.data.title(), "Sprint");
+        assert_eq!(duplicate.tasks.len(), 2);
+        assert!(!duplicate.copies.contains_key(&elsewhere.id));
+        assert_eq!(copy.data.tasks(), [demo_copy, plan_copy]);
+
+        let section = copy.data.sections()[0].id;
+        assert_ne!(section, review);
+        assert_eq!(copy.data.section_of(demo_copy), Some(section));
+        let field = copy.data.fields()[0].id;
+        assert_eq!(copy.data.field(field).unwrap().data.list(), copy.id);
+        let plan_task = duplicate
+            .tasks
+            .iter()
+            .find(|task| task.id == plan_copy)
+            .unwrap();
+        assert_eq!(plan_task.data.list(), copy.id);
+        assert_eq!(
+            plan_task.data.field(field),
+            Some(&FieldValue::Number(FiniteNumber::new(2.0).unwrap()))
+        );
+
+        assert_eq!(net.status_of(plan_copy), Some(net.default_status()));
+        let relations: Vec<_> = net
+            .relations()
+            .filter(|(_, to, _)| *to == demo_copy)
+            .map(|(from, _, _)| from)
+            .collect();
+        assert_eq!(relations, [plan_copy]);
+    }
+
+    #[test]
+    fn test_merge_lists() {
+        use crate::domain::{name::TaskName, task::TaskAggregateRoot};
+
+        let mut source: Entity<List> = ListAggregateRoot::new(ListTitle::new("Old").unwrap());
+        let mut target: Entity<List> = ListAggregateRoot::new(ListTitle::new("New").unwrap());
+        let bug = LabelName::new("bug").unwrap();
+        let red = Color {
+            red: 255,
+            green: 0,
+            blue: 0,
+        };
+        source.define_label(Label::new(bug.clone(), red)).unwrap();
+        let kept = Id::new();
+        target.append_task(kept).unwrap();
+        let mut tasks: Vec<Entity<Task>> = ["First", "Second"]
+            .into_iter()
+            .map(|name| TaskAggregateRoot::new(TaskName::new(name).unwrap(), source.id))
+            .collect();
+        for task in tasks.iter().rev() {
+            source.append_task(task.id).unwrap();
+        }
+
+        assert!(matches!(
+            merge_lists(&mut target.clone(), &mut target, &mut tasks),
+            Err(TaskDomainError::ListMergedIntoItself { .. })
+        ));
+        assert!(matches!(
+            merge_lists(&mut source, &mut target, &mut tasks[..1]),
+            Err(TaskDomainError::ListTaskNotGiven { .. })
+        ));
+        assert_eq!(target.data.tasks(), [kept]);
+        assert!(target.data.label(&bug).is_none());
+        merge_lists(&mut source, &mut target, &mut tasks).unwrap();
+        assert!(tasks.iter().all(|task| task.data.list() == target.id));
+        assert_eq!(target.data.tasks(), [kept, tasks[1].id, tasks[0].id]);
+        assert!(target.data.label(&bug).is_some());
+        assert!(source.data.tasks().is_empty());
+        assert!(source.data.is_archived());
+        assert!(source
+            .take_events()
+            .contains(&TaskDomainEvent::ListsMerged {
+                source: source.id,
+                target: target.id,
+            }));
+
+        let mut fresh: Entity<List> = ListAggregateRoot::new(ListTitle::new("Fresh").unwrap());
+        assert!(matches!(
+            merge_lists(&mut fresh, &mut source, &mut tasks),
+            Err(TaskDomainError::ListArchived { .. })
+        ));
+    }
+
+    #[test]
+    fn test_list_tree() {
+        let mut work: Entity<List> = ListAggregateRoot::new(ListTitle::new("Work").unwrap());
+        let mut clients: Entity<List> = ListAggregateRoot::new(ListTitle::new("Clients").unwrap());
+        let mut acme: Entity<List> = ListAggregateRoot::new(ListTitle::new("Acme").unwrap());
+        let home: Entity<List> = ListAggregateRoot::new(ListTitle::new("Home").unwrap());
+
+        let tree = ListTree::new([&work, &clients, &acme, &home]);
+        clients.move_under(work.id, &tree).unwrap();
+        let tree = ListTree::new([&work, &clients, &acme, &home]);
+        acme.move_under(clients.id, &tree).unwrap();
+        let tree = ListTree::new([&work, &clients, &acme, &home]);
+
+        assert_eq!(tree.roots(), [work.id, home.id]);
+        assert_eq!(tree.children_of(work.id), [clients.id]);
+        assert_eq!(tree.ancestors_of(acme.id), [clients.id, work.id]);
+        assert_eq!(tree.descendants_of(work.id), [clients.id, acme.id]);
+        assert!(matches!(
+            work.move_under(acme.id, &tree),
+            Err(TaskDomainError::ListCycleNotAllowed { .. })
+        ));
+        assert!(matches!(
+            work.move_under(work.id, &tree),
+            Err(TaskDomainError::ListCycleNotAllowed { .. })
+        ));
+        assert!(matches!(
+            work.move_under(Id::new(), &tree),
+            Err(TaskDomainError::ListNotFound { .. })
+        ));
+
+        clients.make_root();
+        assert_eq!(clients.data.parent(), None);
+        let tree = ListTree::new([&work, &clients, &acme, &home]);
+        assert_eq!(tree.roots(), [work.id, clients.id, home.id]);
+        assert_eq!(tree.ancestors_of(acme.id), [clients.id]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rejects_invalid_title() {
+        let list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let mut json = serde_json::to_value(&list).unwrap();
+        assert_eq!(
+            json["data"],
+            serde_json::json!({"version": "1", "title": "Inbox"})
+        );
+
+        json["data"]["title"] = serde_json::json!("   ");
+        assert!(serde_json::from_value::<Entity<List>>(json).is_err());
+    }
+
+    #[test]
+    fn test_timestamped_list_mutation() {
+        let start = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let later = start + chrono::TimeDelta::seconds(1);
+        let clock = shared_kernel::ManualClock::new(start);
+        let mut list = Timestamped::new(
+            <Entity<List> as ListAggregateRoot>::new(ListTitle::new("Inbox").unwrap()),
+            clock.clone(),
+        );
+
+        clock.set(later);
+        list.archive();
+        assert_eq!(list.updated_at(), later);
+
+        clock.set(later + chrono::TimeDelta::seconds(1));
+        assert!(list.remove_task(Id::new()).is_err());
+        assert_eq!(list.updated_at(), later);
+        assert_eq!(list.created_at(), start);
+    }
+
+    #[test]
+    fn test_audited_list_mutation() {
+        let (creator, editor) = (Id::new(), Id::new());
+        let mut list: Audited<Entity<List>> =
+            AuditedListAggregateRoot::new_by(creator, ListTitle::new("Inbox").unwrap());
+
+        list.rename_by(editor, "Later").unwrap();
+        assert_eq!(list.last_modified_by(), editor);
+        assert!(list.rename_by(creator, " ").is_err());
+        assert_eq!(list.last_modified_by(), editor);
+        assert_eq!(list.created_by(), creator);
+    }
+}
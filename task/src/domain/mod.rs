@@ -1,4 +1,14 @@
-mod error;
-mod list;
-mod net;
-mod task;
+pub mod calendar;
+pub mod custom_field;
+pub mod error;
+pub mod event;
+pub mod link;
+pub mod list;
+pub mod name;
+pub mod net;
+pub mod recurrence;
+pub mod scheduler;
+pub mod smart_list;
+pub mod specification;
+pub mod task;
+pub mod task_template;
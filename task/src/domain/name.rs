@@ -0,0 +1,183 @@
+use std::fmt;
+
+use super::error::TaskDomainError;
+
+/// Identifies which kind of name or text failed validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameKind {
+    /// The name of a task.
+    TaskName,
+    /// The title of a list.
+    ListTitle,
+    /// The name of a status.
+    StatusName,
+    /// The description of a task.
+    TaskDescription,
+    /// The name of a label.
+    LabelName,
+    /// The name of a custom field.
+    FieldName,
+    /// The name of a section of a list.
+    SectionName,
+}
+
+impl fmt::Display for NameKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NameKind::TaskName => f.write_str("task name"),
+            NameKind::ListTitle => f.write_str("list title"),
+            NameKind::StatusName => f.write_str("status name"),
+            NameKind::TaskDescription => f.write_str("task description"),
+            NameKind::LabelName => f.write_str("label name"),
+            NameKind::FieldName => f.write_str("field name"),
+            NameKind::SectionName => f.write_str("section name"),
+        }
+    }
+}
+
+pub use shared_kernel::NameViolation;
+
+/// Defines a name of a task aggregate, reporting violations as [`TaskDomainError::InvalidName`].
+macro_rules! validated_name {
+    ($(#[$meta:meta])* $name:ident, $kind:expr, $max:expr) => {
+        shared_kernel::validated_text!(
+            $(#[$meta])*
+            $name,
+            $max,
+            TaskDomainError,
+            |reason| TaskDomainError::InvalidName { kind: $kind, reason }
+        );
+    };
+}
+
+validated_name!(
+    /// The validated name of a task.
+    TaskName,
+    NameKind::TaskName,
+    256
+);
+
+validated_name!(
+    /// The validated title of a list.
+    ListTitle,
+    NameKind::ListTitle,
+    128
+);
+
+validated_name!(
+    /// The validated name of a status.
+    StatusName,
+    NameKind::StatusName,
+    64
+);
+
+validated_name!(
+    /// The validated name of a label.
+    LabelName,
+    NameKind::LabelName,
+    64
+);
+
+validated_name!(
+    /// The validated name of a custom field.
+    FieldName,
+    NameKind::FieldName,
+    64
+);
+
+validated_name!(
+    /// The validated name of a section of a list.
+    SectionName,
+    NameKind::SectionName,
+    128
+);
+
+validated_name!(
+    /// The validated Markdown description of a task.
+    TaskDescription,
+    NameKind::TaskDescription,
+    16_384
+);
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+
+    use super::*;
+
+    fn violation<T>(result: Result<T, TaskDomainError>) -> (NameKind, NameViolation) {
+        match result {
+            Err(TaskDomainError::InvalidName { kind, reason }) => (kind, reason),
+            _ => panic!("expected an invalid name error"),
+        }
+    }
+
+    #[test]
+    fn test_valid_names() {
+        assert_eq!(TaskName::new("Write docs").unwrap(), "Write docs");
+        assert_eq!(ListTitle::new("Backlog").unwrap().as_str(), "Backlog");
+        assert_eq!(
+            StatusName::new("x".repeat(StatusName::MAX_LEN))
+                .unwrap()
+                .into_inner()
+                .len(),
+            StatusName::MAX_LEN
+        );
+    }
+
+    #[test]
+    fn test_trimmed_names() {
+        assert_eq!(
+            TaskName::new_trimmed("  Write docs\n").unwrap(),
+            "Write docs"
+        );
+        assert_eq!(ListTitle::new_trimmed("Backlog").unwrap(), "Backlog");
+        let padded = format!("  {}  ", "x".repeat(TaskName::MAX_LEN));
+        assert_eq!(
+            TaskName::new_trimmed(padded).unwrap().as_str().len(),
+            TaskName::MAX_LEN
+        );
+        assert!(matches!(
+            TaskName::new_trimmed("   "),
+            Err(TaskDomainError::InvalidName {
+                reason: NameViolation::WhitespaceOnly,
+                ..
+            })
+        ));
+        assert!(matches!(
+            TaskName::new_trimmed(""),
+            Err(TaskDomainError::InvalidName {
+                reason: NameViolation::Empty,
+                ..
+            })
+        ));
+        assert!(matches!(
+            TaskName::new_trimmed(format!(" {} ", "x".repeat(TaskName::MAX_LEN + 1))),
+            Err(TaskDomainError::InvalidName {
+                reason: NameViolation::TooLong { .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_names() {
+        assert_eq!(
+            violation(TaskName::new("")),
+            (NameKind::TaskName, NameViolation::Empty)
+        );
+        assert_eq!(
+            violation(ListTitle::new(" \t\n")),
+            (NameKind::ListTitle, NameViolation::WhitespaceOnly)
+        );
+        assert_eq!(
+            violation(StatusName::new("x".repeat(StatusName::MAX_LEN + 1))),
+            (
+                NameKind::StatusName,
+                NameViolation::TooLong {
+                    max: StatusName::MAX_LEN
+                }
+            )
+        );
+    }
+}
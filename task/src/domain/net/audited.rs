@@ -0,0 +1,761 @@
+use std::{collections::HashMap, time::Duration};
+
+use shared_kernel::{Audited, Clock, Entity, Id, User};
+
+use super::{
+    Color, Net, NetAggregateRoot, NetOp, PropagationPolicy, RelationStrength, RelationType,
+    SchemaParts, Status, StatusCategory, TaskDomainResult, WipLimit,
+};
+use crate::domain::{link::TaskRef, name::StatusName, task::Task};
+
+/// Attributed variants of the [`NetAggregateRoot`] operations, recording the user performing each
+/// one on an [`Audited`] net.
+pub trait AuditedNetAggregateRoot {
+    /// Attributed variant of [`NetAggregateRoot::new`], created by `actor`.
+    fn new_by(actor: Id<User>, default: StatusName, accepted: StatusName) -> Self;
+    /// Attributed variant of [`NetAggregateRoot::with_statuses`], created by `actor`.
+    fn with_statuses_by(
+        actor: Id<User>,
+        default: StatusName,
+        normal: Vec<StatusName>,
+        accepted: StatusName,
+    ) -> Self;
+    /// Attributed variant of [`NetAggregateRoot::lock`].
+    fn lock_by(&mut self, actor: Id<User>);
+    /// Attributed variant of [`NetAggregateRoot::unlock`].
+    fn unlock_by(&mut self, actor: Id<User>);
+    /// Attributed variant of [`NetAggregateRoot::new_status`].
+    fn new_status_by(&mut self, actor: Id<User>, status_name: StatusName) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::remove_status`].
+    fn remove_status_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::change_status_name`].
+    fn change_status_name_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        new_name: StatusName,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::change_status_color`].
+    fn change_status_color_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        color: Option<Color>,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::change_status_description`].
+    fn change_status_description_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        description: Option<String>,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::change_status_wip_limit`].
+    fn change_status_wip_limit_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        wip_limit: Option<WipLimit>,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::move_status`].
+    fn move_status_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        new_index: usize,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::change_default`].
+    fn change_default_by(
+        &mut self,
+        actor: Id<User>,
+        new_default: Id<Status>,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::change_accepted`].
+    fn change_accepted_by(
+        &mut self,
+        actor: Id<User>,
+        new_accepted: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::set_status_category`].
+    fn set_status_category_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        category: StatusCategory,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::mark_status_accepted`].
+    fn mark_status_accepted_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::unmark_status_accepted`].
+    fn unmark_status_accepted_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::allow_transition`].
+    fn allow_transition_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Status>,
+        to: Id<Status>,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::forbid_transition`].
+    fn forbid_transition_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Status>,
+        to: Id<Status>,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::replace_schema`].
+    fn replace_schema_by(
+        &mut self,
+        actor: Id<User>,
+        schema: SchemaParts,
+        status_mapping: HashMap<Id<Status>, Id<Status>>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::change_propagation_policy`].
+    fn change_propagation_policy_by(
+        &mut self,
+        actor: Id<User>,
+        policy: PropagationPolicy,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::require_single_parent`].
+    fn require_single_parent_by(&mut self, actor: Id<User>, enabled: bool) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::warn_on_orphaning`].
+    fn warn_on_orphaning_by(&mut self, actor: Id<User>, enabled: bool) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::estimate_task`].
+    fn estimate_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        estimate: Option<Duration>,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::add_task`].
+    fn add_task_by(&mut self, actor: Id<User>, task_id: Id<Task>) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::add_tasks`].
+    fn add_tasks_by(
+        &mut self,
+        actor: Id<User>,
+        task_ids: impl IntoIterator<Item = Id<Task>>,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::remove_task`].
+    fn remove_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::soft_remove_task`].
+    fn soft_remove_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::restore_task`].
+    fn restore_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::new_relation`].
+    fn new_relation_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Task>,
+        to: Id<Task>,
+        relation_type: RelationType,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::new_relations`].
+    fn new_relations_by(
+        &mut self,
+        actor: Id<User>,
+        relations: impl IntoIterator<Item = (Id<Task>, Id<Task>, RelationType)>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::link_requirement`].
+    fn link_requirement_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        required: TaskRef,
+        accepted: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::unlink_requirement`].
+    fn unlink_requirement_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        required: TaskRef,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::update_linked_requirement`].
+    fn update_linked_requirement_by(
+        &mut self,
+        actor: Id<User>,
+        required: TaskRef,
+        accepted: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::set_milestone`].
+    fn set_milestone_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        milestone: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::archive_task`].
+    fn archive_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::unarchive_task`].
+    fn unarchive_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::set_relation_strength`].
+    fn set_relation_strength_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Task>,
+        to: Id<Task>,
+        strength: RelationStrength,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::annotate_relation`].
+    fn annotate_relation_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Task>,
+        to: Id<Task>,
+        note: Option<String>,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::remove_relation`].
+    fn remove_relation_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Task>,
+        to: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::change_task_status`].
+    fn change_task_status_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::change_task_status_with_reason`].
+    fn change_task_status_with_reason_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        status_id: Id<Status>,
+        reason: Option<String>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::apply`].
+    fn apply_by(
+        &mut self,
+        actor: Id<User>,
+        ops: Vec<NetOp>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Attributed variant of [`NetAggregateRoot::clone_subtree`].
+    fn clone_subtree_by(
+        &mut self,
+        actor: Id<User>,
+        root: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<HashMap<Id<Task>, Id<Task>>>;
+    /// Attributed variant of [`NetAggregateRoot::copy_tasks`].
+    fn copy_tasks_by(
+        &mut self,
+        actor: Id<User>,
+        copies: &HashMap<Id<Task>, Id<Task>>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+}
+
+impl AuditedNetAggregateRoot for Audited<Entity<Net>> {
+    fn new_by(actor: Id<User>, default: StatusName, accepted: StatusName) -> Self {
+        Audited::new(
+            <Entity<Net> as NetAggregateRoot>::new(default, accepted),
+            actor,
+        )
+    }
+
+    fn with_statuses_by(
+        actor: Id<User>,
+        default: StatusName,
+        normal: Vec<StatusName>,
+        accepted: StatusName,
+    ) -> Self {
+        Audited::new(
+            <Entity<Net> as NetAggregateRoot>::with_statuses(default, normal, accepted),
+            actor,
+        )
+    }
+
+    fn lock_by(&mut self, actor: Id<User>) {
+        self.update_by(actor, |net| net.lock());
+    }
+
+    fn unlock_by(&mut self, actor: Id<User>) {
+        self.update_by(actor, |net| net.unlock());
+    }
+
+    fn new_status_by(&mut self, actor: Id<User>, status_name: StatusName) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.new_status(status_name))
+    }
+
+    fn remove_status_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.remove_status(status_id, clock))
+    }
+
+    fn change_status_name_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        new_name: StatusName,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.change_status_name(status_id, new_name))
+    }
+
+    fn change_status_color_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        color: Option<Color>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.change_status_color(status_id, color))
+    }
+
+    fn change_status_description_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        description: Option<String>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| {
+            net.change_status_description(status_id, description)
+        })
+    }
+
+    fn change_status_wip_limit_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        wip_limit: Option<WipLimit>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| {
+            net.change_status_wip_limit(status_id, wip_limit)
+        })
+    }
+
+    fn move_status_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        new_index: usize,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.move_status(status_id, new_index))
+    }
+
+    fn change_default_by(
+        &mut self,
+        actor: Id<User>,
+        new_default: Id<Status>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.change_default(new_default))
+    }
+
+    fn change_accepted_by(
+        &mut self,
+        actor: Id<User>,
+        new_accepted: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.change_accepted(new_accepted, clock))
+    }
+
+    fn set_status_category_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        category: StatusCategory,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| {
+            net.set_status_category(status_id, category, clock)
+        })
+    }
+
+    fn mark_status_accepted_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.mark_status_accepted(status_id, clock))
+    }
+
+    fn unmark_status_accepted_by(
+        &mut self,
+        actor: Id<User>,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.unmark_status_accepted(status_id, clock))
+    }
+
+    fn allow_transition_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Status>,
+        to: Id<Status>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.allow_transition(from, to))
+    }
+
+    fn forbid_transition_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Status>,
+        to: Id<Status>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.forbid_transition(from, to))
+    }
+
+    fn replace_schema_by(
+        &mut self,
+        actor: Id<User>,
+        schema: SchemaParts,
+        status_mapping: HashMap<Id<Status>, Id<Status>>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| {
+            net.replace_schema(schema, status_mapping, clock)
+        })
+    }
+
+    fn change_propagation_policy_by(
+        &mut self,
+        actor: Id<User>,
+        policy: PropagationPolicy,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.change_propagation_policy(policy, clock))
+    }
+
+    fn require_single_parent_by(&mut self, actor: Id<User>, enabled: bool) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.require_single_parent(enabled))
+    }
+
+    fn warn_on_orphaning_by(&mut self, actor: Id<User>, enabled: bool) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.warn_on_orphaning(enabled))
+    }
+
+    fn estimate_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        estimate: Option<Duration>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.estimate_task(task_id, estimate))
+    }
+
+    fn add_task_by(&mut self, actor: Id<User>, task_id: Id<Task>) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.add_task(task_id))
+    }
+
+    fn add_tasks_by(
+        &mut self,
+        actor: Id<User>,
+        task_ids: impl IntoIterator<Item = Id<Task>>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.add_tasks(task_ids))
+    }
+
+    fn remove_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.remove_task(task_id, clock))
+    }
+
+    fn soft_remove_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.soft_remove_task(task_id, clock))
+    }
+
+    fn restore_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.restore_task(task_id, clock))
+    }
+
+    fn new_relation_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Task>,
+        to: Id<Task>,
+        relation_type: RelationType,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| {
+            net.new_relation(from, to, relation_type, clock)
+        })
+    }
+
+    fn new_relations_by(
+        &mut self,
+        actor: Id<User>,
+        relations: impl IntoIterator<Item = (Id<Task>, Id<Task>, RelationType)>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.new_relations(relations, clock))
+    }
+
+    fn link_requirement_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        required: TaskRef,
+        accepted: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| {
+            net.link_requirement(task_id, required, accepted, clock)
+        })
+    }
+
+    fn unlink_requirement_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        required: TaskRef,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| {
+            net.unlink_requirement(task_id, required, clock)
+        })
+    }
+
+    fn update_linked_requirement_by(
+        &mut self,
+        actor: Id<User>,
+        required: TaskRef,
+        accepted: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| {
+            net.update_linked_requirement(required, accepted, clock)
+        })
+    }
+
+    fn set_milestone_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        milestone: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.set_milestone(task_id, milestone, clock))
+    }
+
+    fn archive_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.archive_task(task_id, clock))
+    }
+
+    fn unarchive_task_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.unarchive_task(task_id, clock))
+    }
+
+    fn set_relation_strength_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Task>,
+        to: Id<Task>,
+        strength: RelationStrength,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| {
+            net.set_relation_strength(from, to, strength, clock)
+        })
+    }
+
+    fn annotate_relation_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Task>,
+        to: Id<Task>,
+        note: Option<String>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.annotate_relation(from, to, note))
+    }
+
+    fn remove_relation_by(
+        &mut self,
+        actor: Id<User>,
+        from: Id<Task>,
+        to: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.remove_relation(from, to, clock))
+    }
+
+    fn change_task_status_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| {
+            net.change_task_status(task_id, status_id, clock)
+        })
+    }
+
+    fn change_task_status_with_reason_by(
+        &mut self,
+        actor: Id<User>,
+        task_id: Id<Task>,
+        status_id: Id<Status>,
+        reason: Option<String>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| {
+            net.change_task_status_with_reason(task_id, status_id, reason, clock)
+        })
+    }
+
+    fn apply_by(
+        &mut self,
+        actor: Id<User>,
+        ops: Vec<NetOp>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.apply(ops, clock))
+    }
+
+    fn clone_subtree_by(
+        &mut self,
+        actor: Id<User>,
+        root: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<HashMap<Id<Task>, Id<Task>>> {
+        self.try_update_by(actor, |net| net.clone_subtree(root, clock))
+    }
+
+    fn copy_tasks_by(
+        &mut self,
+        actor: Id<User>,
+        copies: &HashMap<Id<Task>, Id<Task>>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |net| net.copy_tasks(copies, clock))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::SystemClock;
+
+    use super::*;
+    use crate::domain::net::NetQuery;
+
+    fn net(creator: Id<User>) -> Audited<Entity<Net>> {
+        AuditedNetAggregateRoot::new_by(
+            creator,
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_operation_records_actor() {
+        let (creator, editor) = (Id::new(), Id::new());
+        let mut net = net(creator);
+        let task_id = Id::new();
+
+        net.add_task_by(editor, task_id).unwrap();
+        assert_eq!(net.last_modified_by(), editor);
+
+        net.change_task_status_by(creator, task_id, net.accepted_status(), &SystemClock)
+            .unwrap();
+        assert_eq!(net.last_modified_by(), creator);
+        assert_eq!(net.created_by(), creator);
+    }
+
+    #[test]
+    fn test_failed_operation_keeps_actor() {
+        let (creator, editor) = (Id::new(), Id::new());
+        let mut net = net(creator);
+        let task_id = Id::new();
+        net.add_task_by(creator, task_id).unwrap();
+
+        assert!(net.add_task_by(editor, task_id).is_err());
+        assert!(net
+            .new_relation_by(
+                editor,
+                task_id,
+                task_id,
+                RelationType::Compose,
+                &SystemClock
+            )
+            .is_err());
+        assert!(net.remove_task_by(editor, Id::new(), &SystemClock).is_err());
+
+        assert_eq!(net.last_modified_by(), creator);
+        assert_eq!(net.created_by(), creator);
+    }
+}
@@ -0,0 +1,270 @@
+use std::time::Duration;
+
+use shared_kernel::{Clock, Entity, Id};
+
+use super::{
+    Color, Net, NetAggregateRoot, PropagationPolicy, RelationStrength, RelationType, Status,
+    StatusCategory, TaskDomainResult, WipLimit,
+};
+use crate::domain::{link::TaskRef, name::StatusName, task::Task};
+
+/// Represents a single operation of a batch applied to a net with
+/// [`NetAggregateRoot::apply`].
+///
+/// Every variant mirrors the aggregate root operation of the same name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetOp {
+    NewStatus(StatusName),
+    RemoveStatus(Id<Status>),
+    ChangeStatusName {
+        status: Id<Status>,
+        name: StatusName,
+    },
+    ChangeStatusColor {
+        status: Id<Status>,
+        color: Option<Color>,
+    },
+    ChangeStatusDescription {
+        status: Id<Status>,
+        description: Option<String>,
+    },
+    ChangeStatusWipLimit {
+        status: Id<Status>,
+        wip_limit: Option<WipLimit>,
+    },
+    MoveStatus {
+        status: Id<Status>,
+        position: usize,
+    },
+    ChangeDefault(Id<Status>),
+    ChangeAccepted(Id<Status>),
+    SetStatusCategory {
+        status: Id<Status>,
+        category: StatusCategory,
+    },
+    MarkStatusAccepted(Id<Status>),
+    UnmarkStatusAccepted(Id<Status>),
+    AllowTransition {
+        from: Id<Status>,
+        to: Id<Status>,
+    },
+    ForbidTransition {
+        from: Id<Status>,
+        to: Id<Status>,
+    },
+    ChangePropagationPolicy(PropagationPolicy),
+    RequireSingleParent(bool),
+    WarnOnOrphaning(bool),
+    EstimateTask {
+        task: Id<Task>,
+        estimate: Option<Duration>,
+    },
+    AddTask(Id<Task>),
+    RemoveTask(Id<Task>),
+    SoftRemoveTask(Id<Task>),
+    RestoreTask(Id<Task>),
+    NewRelation {
+        from: Id<Task>,
+        to: Id<Task>,
+        relation_type: RelationType,
+    },
+    SetMilestone {
+        task: Id<Task>,
+        milestone: bool,
+    },
+    ArchiveTask(Id<Task>),
+    UnarchiveTask(Id<Task>),
+    LinkRequirement {
+        task: Id<Task>,
+        required: TaskRef,
+        accepted: bool,
+    },
+    UnlinkRequirement {
+        task: Id<Task>,
+        required: TaskRef,
+    },
+    UpdateLinkedRequirement {
+        required: TaskRef,
+        accepted: bool,
+    },
+    SetRelationStrength {
+        from: Id<Task>,
+        to: Id<Task>,
+        strength: RelationStrength,
+    },
+    AnnotateRelation {
+        from: Id<Task>,
+        to: Id<Task>,
+        note: Option<String>,
+    },
+    RemoveRelation {
+        from: Id<Task>,
+        to: Id<Task>,
+    },
+    ChangeTaskStatus {
+        task: Id<Task>,
+        status: Id<Status>,
+        reason: Option<String>,
+    },
+}
+
+/// Applies a single operation of a batch to a net.
+pub(super) fn apply_op(
+    net: &mut Entity<Net>,
+    op: NetOp,
+    clock: &impl Clock,
+) -> TaskDomainResult<()> {
+    match op {
+        NetOp::NewStatus(name) => net.new_status(name),
+        NetOp::RemoveStatus(status) => net.remove_status(status, clock),
+        NetOp::ChangeStatusName { status, name } => net.change_status_name(status, name),
+        NetOp::ChangeStatusColor { status, color } => net.change_status_color(status, color),
+        NetOp::ChangeStatusDescription {
+            status,
+            description,
+        } => net.change_status_description(status, description),
+        NetOp::ChangeStatusWipLimit { status, wip_limit } => {
+            net.change_status_wip_limit(status, wip_limit)
+        }
+        NetOp::MoveStatus { status, position } => net.move_status(status, position),
+        NetOp::ChangeDefault(status) => net.change_default(status),
+        NetOp::ChangeAccepted(status) => net.change_accepted(status, clock),
+        NetOp::SetStatusCategory { status, category } => {
+            net.set_status_category(status, category, clock)
+        }
+        NetOp::MarkStatusAccepted(status) => net.mark_status_accepted(status, clock),
+        NetOp::UnmarkStatusAccepted(status) => net.unmark_status_accepted(status, clock),
+        NetOp::AllowTransition { from, to } => net.allow_transition(from, to),
+        NetOp::ForbidTransition { from, to } => net.forbid_transition(from, to),
+        NetOp::ChangePropagationPolicy(policy) => net.change_propagation_policy(policy, clock),
+        NetOp::RequireSingleParent(enabled) => net.require_single_parent(enabled),
+        NetOp::WarnOnOrphaning(enabled) => net.warn_on_orphaning(enabled),
+        NetOp::EstimateTask { task, estimate } => net.estimate_task(task, estimate),
+        NetOp::AddTask(task) => net.add_task(task),
+        NetOp::RemoveTask(task) => net.remove_task(task, clock),
+        NetOp::SoftRemoveTask(task) => net.soft_remove_task(task, clock),
+        NetOp::RestoreTask(task) => net.restore_task(task, clock),
+        NetOp::NewRelation {
+            from,
+            to,
+            relation_type,
+        } => net.new_relation(from, to, relation_type, clock),
+        NetOp::SetMilestone { task, milestone } => net.set_milestone(task, milestone, clock),
+        NetOp::ArchiveTask(task) => net.archive_task(task, clock),
+        NetOp::UnarchiveTask(task) => net.unarchive_task(task, clock),
+        NetOp::LinkRequirement {
+            task,
+            required,
+            accepted,
+        } => net.link_requirement(task, required, accepted, clock),
+        NetOp::UnlinkRequirement { task, required } => {
+            net.unlink_requirement(task, required, clock)
+        }
+        NetOp::UpdateLinkedRequirement { required, accepted } => {
+            net.update_linked_requirement(required, accepted, clock)
+        }
+        NetOp::SetRelationStrength { from, to, strength } => {
+            net.set_relation_strength(from, to, strength, clock)
+        }
+        NetOp::AnnotateRelation { from, to, note } => net.annotate_relation(from, to, note),
+        NetOp::RemoveRelation { from, to } => net.remove_relation(from, to, clock),
+        NetOp::ChangeTaskStatus {
+            task,
+            status,
+            reason,
+        } => net.change_task_status_with_reason(task, status, reason, clock),
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{AggregateRoot, SystemClock};
+
+    use super::*;
+    use crate::domain::{error::TaskDomainError, event::TaskDomainEvent, net::NetQuery};
+
+    fn net() -> Entity<Net> {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.take_events();
+        net
+    }
+
+    #[test]
+    fn test_apply_propagates_once() {
+        let mut net = net();
+        let parent = Id::new();
+        let child = Id::new();
+        let accepted = net.accepted_status();
+
+        net.apply(
+            vec![
+                NetOp::AddTask(parent),
+                NetOp::AddTask(child),
+                NetOp::ChangeTaskStatus {
+                    task: child,
+                    status: accepted,
+                    reason: None,
+                },
+                NetOp::NewRelation {
+                    from: child,
+                    to: parent,
+                    relation_type: RelationType::Compose,
+                },
+            ],
+            &SystemClock,
+        )
+        .unwrap();
+
+        assert_eq!(net.status_of(parent), Some(accepted));
+        let propagated: Vec<_> = net
+            .take_events()
+            .into_iter()
+            .filter(|event| matches!(event, TaskDomainEvent::StatusPropagated { .. }))
+            .collect();
+        assert_eq!(propagated.len(), 1);
+    }
+
+    #[test]
+    fn test_apply_rolls_back_on_failure() {
+        let mut net = net();
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+
+        let result = net.apply(
+            vec![
+                NetOp::AddTask(task1_id),
+                NetOp::AddTask(task2_id),
+                NetOp::NewRelation {
+                    from: task1_id,
+                    to: task2_id,
+                    relation_type: RelationType::Require,
+                },
+                NetOp::NewRelation {
+                    from: task2_id,
+                    to: task1_id,
+                    relation_type: RelationType::Require,
+                },
+            ],
+            &SystemClock,
+        );
+
+        assert!(matches!(
+            result,
+            Err(TaskDomainError::CycleNotAllowedInNet { .. })
+        ));
+        assert_eq!(net.tasks().count(), 0);
+        assert!(net.take_events().is_empty());
+
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.change_task_status(task2_id, net.accepted_status(), &SystemClock)
+            .unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Require, &SystemClock)
+            .unwrap();
+        assert_eq!(net.status_of(task2_id), Some(net.default_status()));
+    }
+}
@@ -0,0 +1,223 @@
+use std::time::Duration;
+
+use shared_kernel::{Clock, Entity, Id};
+
+use super::{batch, Net, NetAggregateRoot, NetOp, NetQuery, PropagationPolicy, RelationType};
+use crate::domain::{error::TaskDomainError, name::StatusName, task::Task};
+
+/// Declares the statuses, tasks and relations of a new net and builds it in one step.
+///
+/// Statuses are referred to by name, since their ids are only assigned once the net is built.
+/// Building reports every problem it finds rather than stopping at the first one.
+#[derive(Debug, Clone)]
+pub struct NetBuilder {
+    default: StatusName,
+    normal: Vec<StatusName>,
+    accepted: StatusName,
+    propagation_policy: Option<PropagationPolicy>,
+    single_parent: bool,
+    tasks: Vec<(Id<Task>, Option<StatusName>)>,
+    estimates: Vec<(Id<Task>, Duration)>,
+    relations: Vec<(Id<Task>, Id<Task>, RelationType)>,
+}
+
+impl NetBuilder {
+    /// Starts declaring a net with the given default and accepted statuses.
+    pub fn new(default: StatusName, accepted: StatusName) -> Self {
+        NetBuilder {
+            default,
+            normal: Vec::new(),
+            accepted,
+            propagation_policy: None,
+            single_parent: false,
+            tasks: Vec::new(),
+            estimates: Vec::new(),
+            relations: Vec::new(),
+        }
+    }
+
+    /// Adds an in-progress status, placed after the previously added ones.
+    pub fn status(mut self, name: StatusName) -> Self {
+        self.normal.push(name);
+        self
+    }
+
+    /// Sets how status changes propagate through the net.
+    pub fn propagation_policy(mut self, policy: PropagationPolicy) -> Self {
+        self.propagation_policy = Some(policy);
+        self
+    }
+
+    /// Requires every task to have at most one composition parent.
+    pub fn single_parent(mut self) -> Self {
+        self.single_parent = true;
+        self
+    }
+
+    /// Adds a task on the default status.
+    pub fn task(mut self, task: Id<Task>) -> Self {
+        self.tasks.push((task, None));
+        self
+    }
+
+    /// Adds a task on the status with the given name.
+    pub fn task_in(mut self, task: Id<Task>, status: StatusName) -> Self {
+        self.tasks.push((task, Some(status)));
+        self
+    }
+
+    /// Estimates the work left on a task.
+    pub fn estimate(mut self, task: Id<Task>, estimate: Duration) -> Self {
+        self.estimates.push((task, estimate));
+        self
+    }
+
+    /// Relates two tasks of the net.
+    pub fn relation(mut self, from: Id<Task>, to: Id<Task>, relation_type: RelationType) -> Self {
+        self.relations.push((from, to, relation_type));
+        self
+    }
+
+    /// Builds the net, propagating statuses once every declaration is applied.
+    ///
+    /// Returns every error found if any declaration is invalid.
+    pub fn build(self, clock: &impl Clock) -> Result<Entity<Net>, Vec<TaskDomainError>> {
+        let mut net: Entity<Net> =
+            NetAggregateRoot::with_statuses(self.default, self.normal, self.accepted);
+        let mut errors = Vec::new();
+
+        let mut ops = Vec::new();
+        if let Some(policy) = self.propagation_policy {
+            ops.push(NetOp::ChangePropagationPolicy(policy));
+        }
+        if self.single_parent {
+            ops.push(NetOp::RequireSingleParent(true));
+        }
+        ops.extend(self.tasks.iter().map(|(task, _)| NetOp::AddTask(*task)));
+        for (task, name) in self.tasks {
+            let Some(name) = name else {
+                continue;
+            };
+            match net.statuses().find(|status| status.data.name() == &name) {
+                Some(status) => ops.push(NetOp::ChangeTaskStatus {
+                    task,
+                    status: status.id,
+                    reason: None,
+                }),
+                None => errors.push(TaskDomainError::StatusNameNotFoundInNet { net: net.id, name }),
+            }
+        }
+        ops.extend(
+            self.estimates
+                .into_iter()
+                .map(|(task, estimate)| NetOp::EstimateTask {
+                    task,
+                    estimate: Some(estimate),
+                }),
+        );
+        ops.extend(self.relations.into_iter().map(|(from, to, relation_type)| {
+            NetOp::NewRelation {
+                from,
+                to,
+                relation_type,
+            }
+        }));
+
+        net.data.propagation_deferred = true;
+        for op in ops {
+            if let NetOp::NewRelation { from, to, .. } = op {
+                let missing = [from, to]
+                    .into_iter()
+                    .find(|task| !net.data.tasks.contains_key(task));
+                if let Some(task) = missing {
+                    errors.push(TaskDomainError::TaskNotFoundInNet { net: net.id, task });
+                    continue;
+                }
+            }
+            if let Err(error) = batch::apply_op(&mut net, op, clock) {
+                errors.push(error);
+            }
+        }
+        net.data.propagation_deferred = false;
+
+        if errors.is_empty() {
+            if let Err(error) = super::propagate_all(&mut net, clock) {
+                errors.push(error);
+            }
+        }
+        if errors.is_empty() {
+            Ok(net)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::SystemClock;
+
+    use super::*;
+
+    fn name(name: &str) -> StatusName {
+        StatusName::new(name).unwrap()
+    }
+
+    #[test]
+    fn test_build() {
+        let parent = Id::new();
+        let child = Id::new();
+
+        let net = NetBuilder::new(name("Todo"), name("Done"))
+            .status(name("Doing"))
+            .single_parent()
+            .task(parent)
+            .task_in(child, name("Done"))
+            .estimate(parent, Duration::from_secs(60))
+            .relation(child, parent, RelationType::Compose)
+            .build(&SystemClock)
+            .unwrap();
+
+        assert_eq!(net.statuses().count(), 3);
+        assert!(net.requires_single_parent());
+        assert_eq!(net.status_of(child), Some(net.accepted_status()));
+        assert_eq!(net.status_of(parent), Some(net.accepted_status()));
+        assert_eq!(net.estimate_of(parent), Some(Duration::from_secs(60)));
+        assert_eq!(net.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_build_reports_every_error() {
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        let ghost = Id::new();
+
+        let errors = NetBuilder::new(name("Todo"), name("Done"))
+            .task(task1_id)
+            .task(task1_id)
+            .task_in(task2_id, name("Review"))
+            .relation(task1_id, ghost, RelationType::Require)
+            .relation(task2_id, task2_id, RelationType::Require)
+            .build(&SystemClock)
+            .unwrap_err();
+
+        assert_eq!(errors.len(), 4);
+        assert!(matches!(
+            errors[0],
+            TaskDomainError::StatusNameNotFoundInNet { .. }
+        ));
+        assert!(matches!(
+            errors[1],
+            TaskDomainError::TaskAlreadyInNet { .. }
+        ));
+        assert!(matches!(
+            errors[2],
+            TaskDomainError::TaskNotFoundInNet { task, .. } if task == ghost
+        ));
+        assert!(matches!(
+            errors[3],
+            TaskDomainError::CycleNotAllowedInNet { .. }
+        ));
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    time::Duration,
+};
+
+use shared_kernel::Id;
+
+use super::{
+    LinkParts, Net, NetParts, PropagationPolicy, RelationParts, RemovedTaskParts, SchemaParts,
+    Status, StatusChange, TaskDomainResult,
+};
+use crate::domain::{link::TaskRef, task::Task};
+
+/// Represents a value an edit replaced, along with the value it was replaced by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Change<T> {
+    pub before: T,
+    pub after: T,
+}
+
+impl<T> Change<T> {
+    /// Swaps the values, describing the change that reverts this one.
+    pub fn reversed(self) -> Self {
+        Change {
+            before: self.after,
+            after: self.before,
+        }
+    }
+}
+
+/// Represents an entry of the parts of a net, identified by a key unique within its list.
+trait Keyed {
+    type Key: Ord;
+
+    fn key(&self) -> Self::Key;
+}
+
+impl Keyed for (Id<Task>, Id<Status>) {
+    type Key = Id<Task>;
+
+    fn key(&self) -> Self::Key {
+        self.0
+    }
+}
+
+impl Keyed for (Id<Task>, Duration) {
+    type Key = Id<Task>;
+
+    fn key(&self) -> Self::Key {
+        self.0
+    }
+}
+
+impl Keyed for (Id<Task>, Vec<StatusChange>) {
+    type Key = Id<Task>;
+
+    fn key(&self) -> Self::Key {
+        self.0
+    }
+}
+
+impl Keyed for Id<Task> {
+    type Key = Id<Task>;
+
+    fn key(&self) -> Self::Key {
+        *self
+    }
+}
+
+impl Keyed for RelationParts {
+    type Key = (Id<Task>, Id<Task>);
+
+    fn key(&self) -> Self::Key {
+        (self.from, self.to)
+    }
+}
+
+impl Keyed for RemovedTaskParts {
+    type Key = Id<Task>;
+
+    fn key(&self) -> Self::Key {
+        self.task
+    }
+}
+
+impl Keyed for LinkParts {
+    type Key = (Id<Task>, TaskRef);
+
+    fn key(&self) -> Self::Key {
+        (self.task, self.required)
+    }
+}
+
+/// The changes of the entries of a list of parts, ordered by key. An entry that was added has
+/// no value before, and an entry that was removed has none after.
+type Entries<T> = Vec<Change<Option<T>>>;
+
+/// Represents how an edit changed a net, as the entries of its parts the edit replaced and
+/// the entries it replaced them by. Entries the edit left alone are not part of it, so the
+/// change stays as small as the edit whatever the size of the net.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetChange {
+    /// The status schema, if it changed.
+    pub schema: Option<Change<SchemaParts>>,
+    /// The changed tasks and their statuses.
+    pub tasks: Entries<(Id<Task>, Id<Status>)>,
+    /// The changed relations.
+    pub relations: Entries<RelationParts>,
+    /// The changed soft-removed tasks.
+    pub removed_tasks: Entries<RemovedTaskParts>,
+    /// The propagation policy, if it changed.
+    pub propagation_policy: Option<Change<PropagationPolicy>>,
+    /// Whether a single parent is required, if it changed.
+    pub single_parent: Option<Change<bool>>,
+    /// Whether orphaning warns, if it changed.
+    pub warn_on_orphaning: Option<Change<bool>>,
+    /// The changed estimates.
+    pub estimates: Entries<(Id<Task>, Duration)>,
+    /// The changed links to tasks of other nets.
+    pub links: Entries<LinkParts>,
+    /// The tasks that became or stopped being milestones.
+    pub milestones: Entries<Id<Task>>,
+    /// The tasks that were archived or unarchived.
+    pub archived: Entries<Id<Task>>,
+    /// Whether the net is locked, if it changed.
+    pub locked: Option<Change<bool>>,
+    /// The changed status histories.
+    pub status_history: Entries<(Id<Task>, Vec<StatusChange>)>,
+}
+
+impl NetChange {
+    /// Computes the change from the parts of a net before an edit to its parts after it.
+    pub fn between(before: &NetParts, after: &NetParts) -> Self {
+        NetChange {
+            schema: changed(&before.schema, &after.schema),
+            tasks: entries(&before.tasks, &after.tasks),
+            relations: entries(&before.relations, &after.relations),
+            removed_tasks: entries(&before.removed_tasks, &after.removed_tasks),
+            propagation_policy: changed(&before.propagation_policy, &after.propagation_policy),
+            single_parent: changed(&before.single_parent, &after.single_parent),
+            warn_on_orphaning: changed(&before.warn_on_orphaning, &after.warn_on_orphaning),
+            estimates: entries(&before.estimates, &after.estimates),
+            links: entries(&before.links, &after.links),
+            milestones: entries(&before.milestones, &after.milestones),
+            archived: entries(&before.archived, &after.archived),
+            locked: changed(&before.locked, &after.locked),
+            status_history: entries(&before.status_history, &after.status_history),
+        }
+    }
+
+    /// Checks whether the change leaves the net as it is.
+    pub fn is_empty(&self) -> bool {
+        *self == NetChange::default()
+    }
+
+    /// Describes the change that reverts this one.
+    pub fn reversed(self) -> Self {
+        fn reverse<T>(entries: Entries<T>) -> Entries<T> {
+            entries.into_iter().map(Change::reversed).collect()
+        }
+
+        NetChange {
+            schema: self.schema.map(Change::reversed),
+            tasks: reverse(self.tasks),
+            relations: reverse(self.relations),
+            removed_tasks: reverse(self.removed_tasks),
+            propagation_policy: self.propagation_policy.map(Change::reversed),
+            single_parent: self.single_parent.map(Change::reversed),
+            warn_on_orphaning: self.warn_on_orphaning.map(Change::reversed),
+            estimates: reverse(self.estimates),
+            links: reverse(self.links),
+            milestones: reverse(self.milestones),
+            archived: reverse(self.archived),
+            locked: self.locked.map(Change::reversed),
+            status_history: reverse(self.status_history),
+        }
+    }
+
+    /// Sets the values the change leads to in the parts of a net, keeping every list in
+    /// order. Whether the result is a valid net is left to rebuilding it from the parts.
+    pub fn apply_to(&self, parts: &mut NetParts) {
+        fn set<T: Clone>(value: &mut T, change: &Option<Change<T>>) {
+            if let Some(change) = change {
+                *value = change.after.clone();
+            }
+        }
+
+        set(&mut parts.schema, &self.schema);
+        apply_entries(&mut parts.tasks, &self.tasks);
+        apply_entries(&mut parts.relations, &self.relations);
+        apply_entries(&mut parts.removed_tasks, &self.removed_tasks);
+        set(&mut parts.propagation_policy, &self.propagation_policy);
+        set(&mut parts.single_parent, &self.single_parent);
+        set(&mut parts.warn_on_orphaning, &self.warn_on_orphaning);
+        apply_entries(&mut parts.estimates, &self.estimates);
+        apply_entries(&mut parts.links, &self.links);
+        apply_entries(&mut parts.milestones, &self.milestones);
+        apply_entries(&mut parts.archived, &self.archived);
+        set(&mut parts.locked, &self.locked);
+        apply_entries(&mut parts.status_history, &self.status_history);
+    }
+}
+
+impl Net {
+    /// Rebuilds the net with a change applied, carrying the pending events over.
+    pub(super) fn apply_change(&self, id: Id<Net>, change: &NetChange) -> TaskDomainResult<Net> {
+        let mut parts = self.to_parts();
+        change.apply_to(&mut parts);
+        let mut net = Net::from_parts(id, parts)?;
+        net.events = self.events.clone();
+        Ok(net)
+    }
+}
+
+/// Records a value if it differs between the two versions.
+fn changed<T: Clone + PartialEq>(before: &T, after: &T) -> Option<Change<T>> {
+    (before != after).then(|| Change {
+        before: before.clone(),
+        after: after.clone(),
+    })
+}
+
+/// Lists the entries that differ between two versions of a list, ordered by key.
+fn entries<T: Keyed + Clone + PartialEq>(before: &[T], after: &[T]) -> Entries<T> {
+    let before: BTreeMap<_, _> = before.iter().map(|entry| (entry.key(), entry)).collect();
+    let after: BTreeMap<_, _> = after.iter().map(|entry| (entry.key(), entry)).collect();
+    let keys: BTreeSet<_> = before.keys().chain(after.keys()).collect();
+
+    keys.into_iter()
+        .filter_map(|key| {
+            let (before, after) = (before.get(key).copied(), after.get(key).copied());
+            (before != after).then(|| Change {
+                before: before.cloned(),
+                after: after.cloned(),
+            })
+        })
+        .collect()
+}
+
+/// Replaces the changed entries of a list by their values after the change.
+fn apply_entries<T: Keyed + Clone>(list: &mut Vec<T>, entries: &Entries<T>) {
+    if entries.is_empty() {
+        return;
+    }
+
+    let keys: BTreeSet<_> = entries
+        .iter()
+        .filter_map(|entry| entry.before.as_ref().or(entry.after.as_ref()))
+        .map(Keyed::key)
+        .collect();
+    list.retain(|entry| !keys.contains(&entry.key()));
+    list.extend(entries.iter().filter_map(|entry| entry.after.clone()));
+    list.sort_by_key(Keyed::key);
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{Entity, SystemClock};
+
+    use super::*;
+    use crate::domain::{
+        name::StatusName,
+        net::{Net, NetAggregateRoot, NetQuery, RelationType},
+    };
+
+    #[test]
+    fn test_net_change() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let tasks: Vec<Id<Task>> = (0..10).map(|_| Id::new()).collect();
+        net.add_tasks(tasks.iter().copied()).unwrap();
+        let before = net.to_parts();
+
+        net.new_relation(tasks[1], tasks[0], RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.change_task_status(tasks[1], net.accepted_status(), &SystemClock)
+            .unwrap();
+        net.remove_task(tasks[2], &SystemClock).unwrap();
+        let after = net.to_parts();
+
+        let change = NetChange::between(&before, &after);
+        assert_eq!(change.relations.len(), 1);
+        // Both tasks of the composition were accepted, and the removed task is gone.
+        assert_eq!(change.tasks.len(), 3);
+        assert!(change.schema.is_none());
+        assert!(NetChange::between(&after, &after).is_empty());
+
+        let mut parts = before.clone();
+        change.apply_to(&mut parts);
+        assert_eq!(parts, after);
+        change.reversed().apply_to(&mut parts);
+        assert_eq!(parts, before);
+    }
+}
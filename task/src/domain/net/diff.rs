@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+
+use shared_kernel::{Entity, Id};
+
+use super::{Net, RelationType, Status};
+use crate::domain::task::Task;
+
+/// Lists what changed between two versions of a net, each list ordered by id.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetDiff {
+    /// The tasks only in the newer version.
+    pub added_tasks: Vec<Id<Task>>,
+    /// The tasks only in the older version.
+    pub removed_tasks: Vec<Id<Task>>,
+    /// The relations only in the newer version, including relations whose type changed.
+    pub added_relations: Vec<(Id<Task>, Id<Task>, RelationType)>,
+    /// The relations only in the older version, including relations whose type changed.
+    pub removed_relations: Vec<(Id<Task>, Id<Task>, RelationType)>,
+    /// The statuses only in the newer version.
+    pub added_statuses: Vec<Id<Status>>,
+    /// The statuses only in the older version.
+    pub removed_statuses: Vec<Id<Status>>,
+    /// The tasks in both versions whose status differs.
+    pub status_changes: Vec<StatusDiff>,
+}
+
+/// Represents a task whose status differs between two versions of a net.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusDiff {
+    pub task: Id<Task>,
+    pub from: Id<Status>,
+    pub to: Id<Status>,
+}
+
+impl NetDiff {
+    /// Checks whether the two versions have the same tasks, relations and statuses.
+    pub fn is_empty(&self) -> bool {
+        *self == NetDiff::default()
+    }
+}
+
+/// Computes what changed from the older version `a` of a net to the newer version `b`.
+///
+/// Soft-removed tasks count as removed.
+pub fn diff(a: &Entity<Net>, b: &Entity<Net>) -> NetDiff {
+    let (a, b) = (&a.data, &b.data);
+
+    let a_relations: HashSet<_> = a
+        .relations
+        .all_edges()
+        .map(|(from, to, relation)| (from, to, relation.relation_type))
+        .collect();
+    let b_relations: HashSet<_> = b
+        .relations
+        .all_edges()
+        .map(|(from, to, relation)| (from, to, relation.relation_type))
+        .collect();
+
+    let mut status_changes: Vec<_> = a
+        .tasks
+        .iter()
+        .filter_map(|(task, from)| {
+            let to = *b.tasks.get(task)?;
+            (*from != to).then_some(StatusDiff {
+                task: *task,
+                from: *from,
+                to,
+            })
+        })
+        .collect();
+    status_changes.sort_by_key(|change| change.task);
+
+    NetDiff {
+        added_tasks: sorted_difference(b.tasks.keys(), |task| a.tasks.contains_key(task)),
+        removed_tasks: sorted_difference(a.tasks.keys(), |task| b.tasks.contains_key(task)),
+        added_relations: sorted_difference(&b_relations, |relation| a_relations.contains(relation)),
+        removed_relations: sorted_difference(&a_relations, |relation| {
+            b_relations.contains(relation)
+        }),
+        added_statuses: sorted_difference(b.schema.statuses.keys(), |status| {
+            a.schema.contains(*status)
+        }),
+        removed_statuses: sorted_difference(a.schema.statuses.keys(), |status| {
+            b.schema.contains(*status)
+        }),
+        status_changes,
+    }
+}
+
+/// Sorts the items not found by `contained`.
+fn sorted_difference<'a, T: Ord + Copy + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+    contained: impl Fn(&T) -> bool,
+) -> Vec<T> {
+    let mut items: Vec<_> = items
+        .into_iter()
+        .filter(|item| !contained(item))
+        .copied()
+        .collect();
+    items.sort();
+    items
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::SystemClock;
+
+    use super::*;
+    use crate::domain::{
+        name::StatusName,
+        net::{NetAggregateRoot, NetQuery},
+    };
+
+    #[test]
+    fn test_diff() {
+        let mut before: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let (kept, removed, other) = (Id::new(), Id::new(), Id::new());
+        before.add_tasks([kept, removed, other]).unwrap();
+        before
+            .new_relation(removed, kept, RelationType::Require, &SystemClock)
+            .unwrap();
+        before
+            .new_relation(other, kept, RelationType::RelatesTo, &SystemClock)
+            .unwrap();
+        assert!(diff(&before, &before).is_empty());
+
+        let mut after = before.clone();
+        let added = Id::new();
+        after.add_task(added).unwrap();
+        after.remove_task(removed, &SystemClock).unwrap();
+        after.remove_relation(other, kept, &SystemClock).unwrap();
+        after
+            .new_relation(other, kept, RelationType::Blocks, &SystemClock)
+            .unwrap();
+        after
+            .change_task_status(added, after.accepted_status(), &SystemClock)
+            .unwrap();
+        after
+            .change_task_status(other, after.accepted_status(), &SystemClock)
+            .unwrap();
+        after.new_status(StatusName::new("Doing").unwrap()).unwrap();
+
+        let diff = diff(&before, &after);
+
+        assert_eq!(diff.added_tasks, [added]);
+        assert_eq!(diff.removed_tasks, [removed]);
+        assert_eq!(diff.added_relations, [(other, kept, RelationType::Blocks)]);
+        let mut removed_relations = vec![
+            (removed, kept, RelationType::Require),
+            (other, kept, RelationType::RelatesTo),
+        ];
+        removed_relations.sort();
+        assert_eq!(diff.removed_relations, removed_relations);
+        assert_eq!(diff.added_statuses.len(), 1);
+        assert!(diff.removed_statuses.is_empty());
+        assert_eq!(
+            diff.status_changes,
+            [StatusDiff {
+                task: other,
+                from: before.default_status(),
+                to: after.accepted_status(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt::Write;
+
+use shared_kernel::{Entity, Id};
+
+use super::{Net, NetQuery, RelationType, Status, StatusCategory};
+use crate::domain::task::Task;
+
+/// Renders a net as a Graphviz DOT digraph.
+///
+/// Tasks are labelled with their short id and status name, and relations are styled by
+/// their type. Tasks and relations are emitted in id order so the output is stable.
+pub fn to_dot(net: &Entity<Net>) -> String {
+    let mut dot = String::new();
+    writeln!(dot, "digraph \"{}\" {{", net.id.to_short_string()).unwrap();
+
+    for (task, status) in sorted_tasks(net) {
+        writeln!(
+            dot,
+            "    \"{}\" [label=\"{}\\n{}\"];",
+            task.to_short_string(),
+            task.to_short_string(),
+            escape(&status_name(net, status)),
+        )
+        .unwrap();
+    }
+
+    for (from, to, relation_type) in sorted_relations(net) {
+        writeln!(
+            dot,
+            "    \"{}\" -> \"{}\" [{}];",
+            from.to_short_string(),
+            to.to_short_string(),
+            dot_style(relation_type),
+        )
+        .unwrap();
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Renders a net as a Mermaid `graph TD` flowchart.
+///
+/// Every status gets a class, filled with the status color or a default for its category, so
+/// task nodes are colored by status when the chart is embedded in Markdown.
+pub fn to_mermaid(net: &Entity<Net>) -> String {
+    let statuses: Vec<_> = net.statuses().collect();
+    let class_of = |status: Id<Status>| {
+        statuses
+            .iter()
+            .position(|entity| entity.id == status)
+            .map(|index| format!("status{index}"))
+    };
+
+    let mut mermaid = String::from("graph TD\n");
+
+    for (task, status) in sorted_tasks(net) {
+        writeln!(
+            mermaid,
+            "    t{}[\"{}<br/>{}\"]",
+            task.to_short_string(),
+            task.to_short_string(),
+            escape_mermaid(&status_name(net, status)),
+        )
+        .unwrap();
+    }
+
+    for (from, to, relation_type) in sorted_relations(net) {
+        writeln!(
+            mermaid,
+            "    t{} {} t{}",
+            from.to_short_string(),
+            mermaid_arrow(relation_type),
+            to.to_short_string(),
+        )
+        .unwrap();
+    }
+
+    for (index, status) in statuses.iter().enumerate() {
+        let fill = status
+            .data
+            .color()
+            .map(|color| color.to_string())
+            .unwrap_or_else(|| category_fill(status.data.category()).to_string());
+        writeln!(mermaid, "    classDef status{index} fill:{fill}").unwrap();
+    }
+    for (task, status) in sorted_tasks(net) {
+        if let Some(class) = class_of(status) {
+            writeln!(mermaid, "    class t{} {class}", task.to_short_string()).unwrap();
+        }
+    }
+
+    mermaid
+}
+
+/// Lists the tasks of a net with their status, ordered by task id.
+pub(super) fn sorted_tasks(net: &Entity<Net>) -> Vec<(Id<Task>, Id<Status>)> {
+    let mut tasks: Vec<_> = net.tasks().collect();
+    tasks.sort();
+    tasks
+}
+
+/// Lists the relations of a net, ordered by their endpoints.
+pub(super) fn sorted_relations(net: &Entity<Net>) -> Vec<(Id<Task>, Id<Task>, RelationType)> {
+    let mut relations: Vec<_> = net
+        .relations()
+        .map(|(from, to, relation)| (from, to, relation.relation_type()))
+        .collect();
+    relations.sort_by_key(|(from, to, _)| (*from, *to));
+    relations
+}
+
+/// Returns the name of a status, or its id if the schema does not know it.
+pub(super) fn status_name(net: &Entity<Net>, status: Id<Status>) -> String {
+    net.statuses()
+        .find(|entity| entity.id == status)
+        .map(|entity| entity.data.name().to_string())
+        .unwrap_or_else(|| status.to_string())
+}
+
+/// Returns the DOT edge attributes for a relation type.
+fn dot_style(relation_type: RelationType) -> &'static str {
+    match relation_type {
+        RelationType::Compose => "label=\"compose\", arrowhead=diamond",
+        RelationType::Require => "label=\"require\", style=dashed",
+        RelationType::Blocks => "label=\"blocks\", style=bold, color=red",
+        RelationType::Duplicates => "label=\"duplicates\", style=dotted",
+        RelationType::RelatesTo => "label=\"relates to\", style=dotted, arrowhead=none",
+    }
+}
+
+/// Returns the Mermaid link for a relation type.
+fn mermaid_arrow(relation_type: RelationType) -> &'static str {
+    match relation_type {
+        RelationType::Compose => "-->|compose|",
+        RelationType::Require => "-.->|require|",
+        RelationType::Blocks => "==>|blocks|",
+        RelationType::Duplicates => "-.-|duplicates|",
+        RelationType::RelatesTo => "---|relates to|",
+    }
+}
+
+/// Returns the fill used for statuses of a category that have no color of their own.
+fn category_fill(category: StatusCategory) -> &'static str {
+    match category {
+        StatusCategory::Todo => "#d0d7de",
+        StatusCategory::InProgress => "#f2cc60",
+        StatusCategory::Done => "#4ac26b",
+    }
+}
+
+/// Escapes a string for use inside a double-quoted Mermaid label.
+fn escape_mermaid(value: &str) -> String {
+    value.replace('"', "#quot;")
+}
+
+/// Escapes a string for use inside a double-quoted DOT identifier.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::SystemClock;
+
+    use super::*;
+    use crate::domain::{
+        name::StatusName,
+        net::{Color, NetAggregateRoot},
+    };
+
+    #[test]
+    fn test_to_dot() {
+        let mut net = Entity::new(
+            StatusName::new("To \"do\"").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Blocks, &SystemClock)
+            .unwrap();
+
+        let dot = to_dot(&net);
+
+        assert!(dot.starts_with(&format!("digraph \"{}\" {{\n", net.id.to_short_string())));
+        assert!(dot.contains(&format!(
+            "    \"{0}\" [label=\"{0}\\nTo \\\"do\\\"\"];\n",
+            task1_id.to_short_string()
+        )));
+        assert!(dot.contains(&format!(
+            "    \"{}\" -> \"{}\" [label=\"blocks\", style=bold, color=red];\n",
+            task1_id.to_short_string(),
+            task2_id.to_short_string()
+        )));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let done = net.accepted_status();
+        net.change_status_color(done, Some(Color::rgb(0, 0x80, 0)))
+            .unwrap();
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.change_task_status(task1_id, done, &SystemClock)
+            .unwrap();
+
+        let mermaid = to_mermaid(&net);
+        let task1 = task1_id.to_short_string();
+        let task2 = task2_id.to_short_string();
+
+        assert!(mermaid.starts_with("graph TD\n"));
+        assert!(mermaid.contains(&format!("    t{task1}[\"{task1}<br/>Done\"]\n")));
+        assert!(mermaid.contains(&format!("    t{task1} -->|compose| t{task2}\n")));
+        assert!(mermaid.contains("    classDef status0 fill:#d0d7de\n"));
+        assert!(mermaid.contains("    classDef status1 fill:#008000\n"));
+        assert!(mermaid.contains(&format!("    class t{task1} status1\n")));
+        assert!(mermaid.contains(&format!("    class t{task2} status1\n")));
+    }
+}
@@ -0,0 +1,983 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    str::FromStr,
+    time::Duration,
+};
+
+use shared_kernel::{Entity, Id, Timestamp};
+use thiserror::Error;
+
+use super::{
+    ChangeCause, Color, LinkParts, Net, NetParts, PropagationPolicy, RelationParts,
+    RelationStrength, RelationType, RemovedTaskParts, SchemaParts, StatusCategory, StatusChange,
+    StatusParts, WipLimit, WipLimitMode,
+};
+use crate::domain::{error::TaskDomainError, link::TaskRef, name::StatusName, task::Task};
+
+const NAMESPACE: &str = "http://graphml.graphdrawing.org/xmlns";
+
+/// Represents errors that can occur when reading a net from GraphML.
+#[derive(Debug, Error)]
+pub enum GraphmlError {
+    /// Error indicating that the document is not well-formed XML.
+    #[error("malformed GraphML: {0}")]
+    Xml(#[from] roxmltree::Error),
+
+    /// Error indicating that the document does not describe a valid net.
+    #[error("invalid GraphML: {0}")]
+    Invalid(String),
+
+    /// Error indicating that the described net breaks a domain rule.
+    #[error(transparent)]
+    Domain(#[from] TaskDomainError),
+}
+
+/// Renders a net as a GraphML document.
+///
+/// Statuses and tasks are both emitted as nodes, told apart by their `kind` data; the default
+/// and accepted statuses and the settings of the net are graph data. Relations become edges
+/// between tasks carrying their type, note, creation time and, for soft requirements, their
+/// strength, and workflow transitions become edges between statuses. Soft-removed tasks keep
+/// the relations they had as edges marked with `removed_with`. Nothing of the net is lost:
+/// [`from_graphml`] rebuilds the same net.
+pub fn to_graphml(net: &Entity<Net>) -> String {
+    let parts = net.data.to_parts();
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(xml, "<graphml xmlns=\"{NAMESPACE}\">").unwrap();
+    for (key, domain) in [
+        ("default", "graph"),
+        ("accepted", "graph"),
+        ("policy", "graph"),
+        ("single_parent", "graph"),
+        ("warn_on_orphaning", "graph"),
+        ("locked", "graph"),
+        ("kind", "node"),
+        ("name", "node"),
+        ("category", "node"),
+        ("color", "node"),
+        ("description", "node"),
+        ("wip_limit", "node"),
+        ("wip_mode", "node"),
+        ("status", "node"),
+        ("removed_at", "node"),
+        ("estimate", "node"),
+        ("milestone", "node"),
+        ("archived", "node"),
+        ("link", "node"),
+        ("change", "node"),
+        ("transition", "edge"),
+        ("relation", "edge"),
+        ("strength", "edge"),
+        ("note", "edge"),
+        ("created_at", "edge"),
+        ("removed_with", "edge"),
+    ] {
+        writeln!(
+            xml,
+            "  <key id=\"{key}\" for=\"{domain}\" attr.name=\"{key}\" attr.type=\"string\"/>"
+        )
+        .unwrap();
+    }
+    writeln!(xml, "  <graph id=\"{}\" edgedefault=\"directed\">", net.id).unwrap();
+    writeln!(
+        xml,
+        "    <data key=\"default\">{}</data>",
+        parts.schema.default
+    )
+    .unwrap();
+    writeln!(
+        xml,
+        "    <data key=\"accepted\">{}</data>",
+        parts.schema.accepted
+    )
+    .unwrap();
+    writeln!(
+        xml,
+        "    <data key=\"policy\">{}</data>",
+        policy_name(parts.propagation_policy)
+    )
+    .unwrap();
+    for (key, enabled) in [
+        ("single_parent", parts.single_parent),
+        ("warn_on_orphaning", parts.warn_on_orphaning),
+        ("locked", parts.locked),
+    ] {
+        if enabled {
+            writeln!(xml, "    <data key=\"{key}\">true</data>").unwrap();
+        }
+    }
+
+    for status in &parts.schema.statuses {
+        writeln!(xml, "    <node id=\"{}\">", status.id).unwrap();
+        writeln!(xml, "      <data key=\"kind\">status</data>").unwrap();
+        writeln!(
+            xml,
+            "      <data key=\"name\">{}</data>",
+            escape(status.name.as_str())
+        )
+        .unwrap();
+        writeln!(
+            xml,
+            "      <data key=\"category\">{}</data>",
+            category_name(status.category)
+        )
+        .unwrap();
+        if let Some(color) = status.color {
+            writeln!(xml, "      <data key=\"color\">{color}</data>").unwrap();
+        }
+        if let Some(description) = &status.description {
+            writeln!(
+                xml,
+                "      <data key=\"description\">{}</data>",
+                escape(description)
+            )
+            .unwrap();
+        }
+        if let Some(wip_limit) = status.wip_limit {
+            writeln!(
+                xml,
+                "      <data key=\"wip_limit\">{}</data>",
+                wip_limit.limit
+            )
+            .unwrap();
+            writeln!(
+                xml,
+                "      <data key=\"wip_mode\">{}</data>",
+                wip_mode_name(wip_limit.mode)
+            )
+            .unwrap();
+        }
+        xml.push_str("    </node>\n");
+    }
+
+    let details = TaskDetails::of(&parts);
+    for (task, status) in &parts.tasks {
+        writeln!(xml, "    <node id=\"{task}\">").unwrap();
+        writeln!(xml, "      <data key=\"kind\">task</data>").unwrap();
+        writeln!(xml, "      <data key=\"status\">{status}</data>").unwrap();
+        details.write(&mut xml, *task);
+        xml.push_str("    </node>\n");
+    }
+    for removed in &parts.removed_tasks {
+        writeln!(xml, "    <node id=\"{}\">", removed.task).unwrap();
+        writeln!(xml, "      <data key=\"kind\">removed_task</data>").unwrap();
+        writeln!(xml, "      <data key=\"status\">{}</data>", removed.status).unwrap();
+        writeln!(
+            xml,
+            "      <data key=\"removed_at\">{}</data>",
+            removed.removed_at.to_rfc3339()
+        )
+        .unwrap();
+        details.write(&mut xml, removed.task);
+        xml.push_str("    </node>\n");
+    }
+
+    for (transition, pairs) in [
+        ("allowed", &parts.schema.allowed_transitions),
+        ("forbidden", &parts.schema.forbidden_transitions),
+    ] {
+        for (from, to) in pairs {
+            writeln!(xml, "    <edge source=\"{from}\" target=\"{to}\">").unwrap();
+            writeln!(xml, "      <data key=\"transition\">{transition}</data>").unwrap();
+            xml.push_str("    </edge>\n");
+        }
+    }
+
+    for relation in &parts.relations {
+        write_relation(&mut xml, relation, None);
+    }
+    for removed in &parts.removed_tasks {
+        for relation in &removed.relations {
+            write_relation(&mut xml, relation, Some(removed.task));
+        }
+    }
+
+    xml.push_str("  </graph>\n</graphml>\n");
+    xml
+}
+
+/// Collects what a net knows about its tasks besides their status, so it can be written next
+/// to both active and soft-removed tasks.
+struct TaskDetails<'a> {
+    estimates: HashMap<Id<Task>, Duration>,
+    milestones: HashSet<Id<Task>>,
+    archived: HashSet<Id<Task>>,
+    links: HashMap<Id<Task>, Vec<&'a LinkParts>>,
+    history: HashMap<Id<Task>, &'a [StatusChange]>,
+}
+
+impl<'a> TaskDetails<'a> {
+    fn of(parts: &'a NetParts) -> Self {
+        let mut links: HashMap<_, Vec<_>> = HashMap::new();
+        for link in &parts.links {
+            links.entry(link.task).or_default().push(link);
+        }
+        TaskDetails {
+            estimates: parts.estimates.iter().copied().collect(),
+            milestones: parts.milestones.iter().copied().collect(),
+            archived: parts.archived.iter().copied().collect(),
+            links,
+            history: parts
+                .status_history
+                .iter()
+                .map(|(task, changes)| (*task, changes.as_slice()))
+                .collect(),
+        }
+    }
+
+    fn write(&self, xml: &mut String, task: Id<Task>) {
+        if let Some(estimate) = self.estimates.get(&task) {
+            writeln!(
+                xml,
+                "      <data key=\"estimate\">{}</data>",
+                duration_text(*estimate)
+            )
+            .unwrap();
+        }
+        if self.milestones.contains(&task) {
+            writeln!(xml, "      <data key=\"milestone\">true</data>").unwrap();
+        }
+        if self.archived.contains(&task) {
+            writeln!(xml, "      <data key=\"archived\">true</data>").unwrap();
+        }
+        for link in self.links.get(&task).into_iter().flatten() {
+            writeln!(
+                xml,
+                "      <data key=\"link\">{} {} {}</data>",
+                link.required.net,
+                link.required.task,
+                if link.accepted { "accepted" } else { "pending" }
+            )
+            .unwrap();
+        }
+        for change in self.history.get(&task).copied().unwrap_or_default() {
+            writeln!(
+                xml,
+                "      <data key=\"change\">{}</data>",
+                escape(&change_text(change))
+            )
+            .unwrap();
+        }
+    }
+}
+
+fn write_relation(xml: &mut String, relation: &RelationParts, removed_with: Option<Id<Task>>) {
+    writeln!(
+        xml,
+        "    <edge source=\"{}\" target=\"{}\">",
+        relation.from, relation.to
+    )
+    .unwrap();
+    writeln!(
+        xml,
+        "      <data key=\"relation\">{}</data>",
+        relation_name(relation.relation_type)
+    )
+    .unwrap();
+    if relation.strength == RelationStrength::Soft {
+        writeln!(xml, "      <data key=\"strength\">soft</data>").unwrap();
+    }
+    if let Some(note) = &relation.note {
+        writeln!(xml, "      <data key=\"note\">{}</data>", escape(note)).unwrap();
+    }
+    writeln!(
+        xml,
+        "      <data key=\"created_at\">{}</data>",
+        relation.created_at.to_rfc3339()
+    )
+    .unwrap();
+    if let Some(task) = removed_with {
+        writeln!(xml, "      <data key=\"removed_with\">{task}</data>").unwrap();
+    }
+    xml.push_str("    </edge>\n");
+}
+
+/// Reads a net from a GraphML document produced by [`to_graphml`].
+///
+/// The net keeps the ids of the document, is validated like [`NetParts`] and starts without
+/// pending events. Edges repeating the source and target of another edge of the same kind are
+/// rejected rather than silently merged.
+pub fn from_graphml(document: &str) -> Result<Entity<Net>, GraphmlError> {
+    let document = roxmltree::Document::parse(document)?;
+    let graph = document
+        .root_element()
+        .children()
+        .find(|node| node.has_tag_name((NAMESPACE, "graph")))
+        .ok_or_else(|| invalid("missing graph element"))?;
+
+    let net_id = parse_id(graph.attribute("id"), "graph id")?;
+    let graph_data = data_of(graph);
+    let default = parse_id(graph_data.get("default").copied(), "default status")?;
+    let accepted = parse_id(graph_data.get("accepted").copied(), "accepted status")?;
+    let propagation_policy = match graph_data.get("policy").copied() {
+        None => PropagationPolicy::default(),
+        Some(policy) => parse_policy(policy)
+            .ok_or_else(|| invalid(format!("unknown propagation policy {policy:?}")))?,
+    };
+    let flag = |key: &str| parse_flag(graph_data.get(key).copied(), key);
+
+    let mut statuses = Vec::new();
+    let mut tasks = Vec::new();
+    let mut removed_tasks = Vec::new();
+    let mut estimates = Vec::new();
+    let mut links = Vec::new();
+    let mut milestones = Vec::new();
+    let mut archived = Vec::new();
+    let mut status_history = Vec::new();
+    for node in graph
+        .children()
+        .filter(|node| node.has_tag_name((NAMESPACE, "node")))
+    {
+        let data = data_of(node);
+        match data.get("kind").copied() {
+            Some("status") => {
+                let id = parse_id(node.attribute("id"), "status id")?;
+                let category = data
+                    .get("category")
+                    .copied()
+                    .and_then(parse_category)
+                    .ok_or_else(|| invalid(format!("status {id} has no valid category")))?;
+                let color = data
+                    .get("color")
+                    .map(|color| {
+                        parse_color(color)
+                            .ok_or_else(|| invalid(format!("invalid color {color:?}")))
+                    })
+                    .transpose()?;
+                let wip_limit = data
+                    .get("wip_limit")
+                    .map(|limit| {
+                        let mode = data
+                            .get("wip_mode")
+                            .copied()
+                            .and_then(parse_wip_mode)
+                            .ok_or_else(|| {
+                                invalid(format!("status {id} has no valid WIP limit mode"))
+                            })?;
+                        let limit = limit.parse().map_err(|_| {
+                            invalid(format!("status {id} has invalid WIP limit {limit:?}"))
+                        })?;
+                        Ok::<_, GraphmlError>(WipLimit { limit, mode })
+                    })
+                    .transpose()?;
+                statuses.push(StatusParts {
+                    id,
+                    name: StatusName::new(data.get("name").copied().unwrap_or_default())?,
+                    category,
+                    color,
+                    description: data.get("description").map(|text| text.to_string()),
+                    wip_limit,
+                });
+                continue;
+            }
+            Some("task") => {
+                let id = parse_id(node.attribute("id"), "task id")?;
+                let status = parse_id(data.get("status").copied(), "task status")?;
+                tasks.push((id, status));
+            }
+            Some("removed_task") => {
+                let id = parse_id(node.attribute("id"), "task id")?;
+                let status = parse_id(data.get("status").copied(), "task status")?;
+                let removed_at = data
+                    .get("removed_at")
+                    .and_then(|text| text.parse::<Timestamp>().ok())
+                    .ok_or_else(|| invalid(format!("removed task {id} has no valid time")))?;
+                removed_tasks.push(RemovedTaskParts {
+                    task: id,
+                    status,
+                    relations: Vec::new(),
+                    removed_at,
+                });
+            }
+            kind => return Err(invalid(format!("unknown node kind {kind:?}"))),
+        }
+
+        let task: Id<Task> = parse_id(node.attribute("id"), "task id")?;
+        if let Some(estimate) = data.get("estimate").copied() {
+            let estimate = parse_duration(estimate)
+                .ok_or_else(|| invalid(format!("task {task} has invalid estimate {estimate:?}")))?;
+            estimates.push((task, estimate));
+        }
+        if parse_flag(data.get("milestone").copied(), "milestone")? {
+            milestones.push(task);
+        }
+        if parse_flag(data.get("archived").copied(), "archived")? {
+            archived.push(task);
+        }
+        for link in values_of(node, "link") {
+            let required = parse_link(link)
+                .ok_or_else(|| invalid(format!("task {task} has invalid link {link:?}")))?;
+            links.push(LinkParts {
+                task,
+                required: required.0,
+                accepted: required.1,
+            });
+        }
+        let changes = values_of(node, "change")
+            .map(|change| {
+                parse_change(change)
+                    .ok_or_else(|| invalid(format!("task {task} has invalid change {change:?}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if !changes.is_empty() {
+            status_history.push((task, changes));
+        }
+    }
+
+    let mut allowed_transitions = Vec::new();
+    let mut forbidden_transitions = Vec::new();
+    let mut relations = Vec::new();
+    let mut transition_edges = HashSet::new();
+    let mut relation_edges = HashSet::new();
+    for edge in graph
+        .children()
+        .filter(|node| node.has_tag_name((NAMESPACE, "edge")))
+    {
+        let data = data_of(edge);
+        if let Some(transition) = data.get("transition").copied() {
+            let from = parse_id(edge.attribute("source"), "edge source")?;
+            let to = parse_id(edge.attribute("target"), "edge target")?;
+            if !transition_edges.insert((from, to)) {
+                return Err(invalid(format!("duplicate transition from {from} to {to}")));
+            }
+            match transition {
+                "allowed" => allowed_transitions.push((from, to)),
+                "forbidden" => forbidden_transitions.push((from, to)),
+                _ => {
+                    return Err(invalid(format!(
+                        "transition from {from} to {to} has unknown kind {transition:?}"
+                    )))
+                }
+            }
+            continue;
+        }
+
+        let from: Id<Task> = parse_id(edge.attribute("source"), "edge source")?;
+        let to: Id<Task> = parse_id(edge.attribute("target"), "edge target")?;
+        let removed_with: Option<Id<Task>> = data
+            .get("removed_with")
+            .map(|task| parse_id(Some(task), "removed_with"))
+            .transpose()?;
+        if !relation_edges.insert((removed_with, from, to)) {
+            return Err(invalid(format!("duplicate relation from {from} to {to}")));
+        }
+        let relation_type = data
+            .get("relation")
+            .copied()
+            .and_then(parse_relation_type)
+            .ok_or_else(|| invalid(format!("relation from {from} to {to} has no valid type")))?;
+        let created_at = data
+            .get("created_at")
+            .and_then(|text| text.parse::<Timestamp>().ok())
+            .ok_or_else(|| invalid(format!("relation from {from} to {to} has no valid time")))?;
+        let strength = match data.get("strength").copied() {
+            None | Some("hard") => RelationStrength::Hard,
+            Some("soft") => RelationStrength::Soft,
+            Some(strength) => {
+                return Err(invalid(format!(
+                    "relation from {from} to {to} has unknown strength {strength:?}"
+                )))
+            }
+        };
+        let relation = RelationParts {
+            from,
+            to,
+            relation_type,
+            strength,
+            note: data.get("note").map(|text| text.to_string()),
+            created_at,
+        };
+        match removed_with {
+            None => relations.push(relation),
+            Some(task) => removed_tasks
+                .iter_mut()
+                .find(|removed| removed.task == task)
+                .ok_or_else(|| invalid(format!("relation removed with unknown task {task}")))?
+                .relations
+                .push(relation),
+        }
+    }
+
+    let parts = NetParts {
+        schema: SchemaParts {
+            statuses,
+            default,
+            accepted,
+            allowed_transitions,
+            forbidden_transitions,
+        },
+        tasks,
+        relations,
+        removed_tasks,
+        propagation_policy,
+        single_parent: flag("single_parent")?,
+        warn_on_orphaning: flag("warn_on_orphaning")?,
+        estimates,
+        links,
+        milestones,
+        archived,
+        locked: flag("locked")?,
+        status_history,
+    };
+
+    Ok(Entity {
+        id: net_id,
+        data: Net::from_parts(net_id, parts)?,
+    })
+}
+
+/// Collects the `data` children of a GraphML element by key.
+fn data_of<'a>(node: roxmltree::Node<'a, '_>) -> HashMap<&'a str, &'a str> {
+    node.children()
+        .filter(|child| child.has_tag_name((NAMESPACE, "data")))
+        .filter_map(|child| Some((child.attribute("key")?, child.text().unwrap_or_default())))
+        .collect()
+}
+
+/// Lists every `data` child of a GraphML element with the given key, for keys that repeat.
+fn values_of<'a>(
+    node: roxmltree::Node<'a, '_>,
+    key: &'a str,
+) -> impl Iterator<Item = &'a str> + 'a {
+    node.children()
+        .filter(move |child| {
+            child.has_tag_name((NAMESPACE, "data")) && child.attribute("key") == Some(key)
+        })
+        .map(|child| child.text().unwrap_or_default())
+}
+
+fn parse_id<T>(value: Option<&str>, what: &str) -> Result<Id<T>, GraphmlError> {
+    let value = value.ok_or_else(|| invalid(format!("missing {what}")))?;
+    Id::from_str(value).map_err(|error| invalid(format!("{what}: {error}")))
+}
+
+fn parse_flag(value: Option<&str>, what: &str) -> Result<bool, GraphmlError> {
+    match value {
+        None | Some("false") => Ok(false),
+        Some("true") => Ok(true),
+        Some(value) => Err(invalid(format!(
+            "{what}: expected a boolean, got {value:?}"
+        ))),
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> GraphmlError {
+    GraphmlError::Invalid(reason.into())
+}
+
+fn policy_name(policy: PropagationPolicy) -> &'static str {
+    match policy {
+        PropagationPolicy::Strict => "strict",
+        PropagationPolicy::Advisory => "advisory",
+    }
+}
+
+fn parse_policy(value: &str) -> Option<PropagationPolicy> {
+    match value {
+        "strict" => Some(PropagationPolicy::Strict),
+        "advisory" => Some(PropagationPolicy::Advisory),
+        _ => None,
+    }
+}
+
+fn wip_mode_name(mode: WipLimitMode) -> &'static str {
+    match mode {
+        WipLimitMode::Hard => "hard",
+        WipLimitMode::Soft => "soft",
+    }
+}
+
+fn parse_wip_mode(value: &str) -> Option<WipLimitMode> {
+    match value {
+        "hard" => Some(WipLimitMode::Hard),
+        "soft" => Some(WipLimitMode::Soft),
+        _ => None,
+    }
+}
+
+/// Writes a duration as seconds with nanosecond precision, e.g. `5400.000000000`.
+fn duration_text(duration: Duration) -> String {
+    format!("{}.{:09}", duration.as_secs(), duration.subsec_nanos())
+}
+
+fn parse_duration(value: &str) -> Option<Duration> {
+    let (secs, nanos) = value.split_once('.')?;
+    if nanos.len() != 9 {
+        return None;
+    }
+    Some(Duration::new(secs.parse().ok()?, nanos.parse().ok()?))
+}
+
+/// Parses a link written as `<net> <task> accepted|pending`.
+fn parse_link(value: &str) -> Option<(TaskRef, bool)> {
+    let mut fields = value.split(' ');
+    let net = Id::from_str(fields.next()?).ok()?;
+    let task = Id::from_str(fields.next()?).ok()?;
+    let accepted = match fields.next()? {
+        "accepted" => true,
+        "pending" => false,
+        _ => return None,
+    };
+    fields
+        .next()
+        .is_none()
+        .then_some((TaskRef::new(net, task), accepted))
+}
+
+/// Writes a status change as `<at> <from> <to> manual [<reason>]`,
+/// `<at> <from> <to> propagated [<origin>]` or `<at> <from> <to> remapped`.
+fn change_text(change: &StatusChange) -> String {
+    let cause = match &change.cause {
+        ChangeCause::Manual { reason: None } => "manual".to_string(),
+        ChangeCause::Manual {
+            reason: Some(reason),
+        } => format!("manual {reason}"),
+        ChangeCause::Propagated { origin: None } => "propagated".to_string(),
+        ChangeCause::Propagated {
+            origin: Some(origin),
+        } => format!("propagated {origin}"),
+        ChangeCause::Remapped => "remapped".to_string(),
+    };
+    format!(
+        "{} {} {} {cause}",
+        change.at.to_rfc3339(),
+        change.from,
+        change.to
+    )
+}
+
+fn parse_change(value: &str) -> Option<StatusChange> {
+    let mut fields = value.splitn(5, ' ');
+    let at = fields.next()?.parse().ok()?;
+    let from = Id::from_str(fields.next()?).ok()?;
+    let to = Id::from_str(fields.next()?).ok()?;
+    let cause = match (fields.next()?, fields.next()) {
+        ("manual", reason) => ChangeCause::Manual {
+            reason: reason.map(str::to_string),
+        },
+        ("propagated", origin) => ChangeCause::Propagated {
+            origin: origin.map(Id::from_str).transpose().ok()?,
+        },
+        ("remapped", None) => ChangeCause::Remapped,
+        _ => return None,
+    };
+    Some(StatusChange {
+        at,
+        from,
+        to,
+        cause,
+    })
+}
+
+fn category_name(category: StatusCategory) -> &'static str {
+    match category {
+        StatusCategory::Todo => "todo",
+        StatusCategory::InProgress => "in_progress",
+        StatusCategory::Done => "done",
+    }
+}
+
+fn parse_category(value: &str) -> Option<StatusCategory> {
+    match value {
+        "todo" => Some(StatusCategory::Todo),
+        "in_progress" => Some(StatusCategory::InProgress),
+        "done" => Some(StatusCategory::Done),
+        _ => None,
+    }
+}
+
+fn relation_name(relation_type: RelationType) -> &'static str {
+    match relation_type {
+        RelationType::Compose => "compose",
+        RelationType::Require => "require",
+        RelationType::Blocks => "blocks",
+        RelationType::Duplicates => "duplicates",
+        RelationType::RelatesTo => "relates_to",
+    }
+}
+
+fn parse_relation_type(value: &str) -> Option<RelationType> {
+    match value {
+        "compose" => Some(RelationType::Compose),
+        "require" => Some(RelationType::Require),
+        "blocks" => Some(RelationType::Blocks),
+        "duplicates" => Some(RelationType::Duplicates),
+        "relates_to" => Some(RelationType::RelatesTo),
+        _ => None,
+    }
+}
+
+/// Parses a color written as `#rrggbb`.
+fn parse_color(value: &str) -> Option<Color> {
+    let hex = value.strip_prefix('#').filter(|hex| hex.len() == 6)?;
+    let channel = |index: usize| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok();
+    Some(Color::rgb(channel(0)?, channel(2)?, channel(4)?))
+}
+
+/// Escapes a string for use as XML character data.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::SystemClock;
+
+    use super::*;
+    use crate::domain::net::{export::sorted_tasks, NetAggregateRoot, NetQuery, Status};
+
+    #[test]
+    fn test_graphml_round_trip() {
+        let mut net = Entity::new(
+            StatusName::new("To <do>").unwrap(),
+            StatusName::new("Done & dusted").unwrap(),
+        );
+        net.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        let doing = net.statuses().nth(2).unwrap().id;
+        net.change_status_color(doing, Some(Color::rgb(0xf2, 0xcc, 0x60)))
+            .unwrap();
+        net.change_status_description(doing, Some("Someone is on it".to_string()))
+            .unwrap();
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Require, &SystemClock)
+            .unwrap();
+        net.annotate_relation(task1_id, task2_id, Some("\"needs\" <API>".to_string()))
+            .unwrap();
+        net.set_relation_strength(task1_id, task2_id, RelationStrength::Soft, &SystemClock)
+            .unwrap();
+        net.change_task_status(task1_id, doing, &SystemClock)
+            .unwrap();
+
+        let imported = from_graphml(&to_graphml(&net)).unwrap();
+
+        assert_eq!(imported.id, net.id);
+        assert_eq!(imported.default_status(), net.default_status());
+        assert_eq!(imported.accepted_status(), net.accepted_status());
+        let describe = |net: &Entity<Net>| {
+            net.statuses()
+                .map(|status| {
+                    (
+                        status.id,
+                        status.data.name().as_str().to_string(),
+                        status.data.category(),
+                        status.data.color(),
+                        status.data.description().map(str::to_string),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(describe(&imported), describe(&net));
+        assert_eq!(sorted_tasks(&imported), sorted_tasks(&net));
+        assert_eq!(
+            imported.relations().collect::<Vec<_>>(),
+            net.relations().collect::<Vec<_>>()
+        );
+        assert!(imported.data.events.is_empty());
+    }
+
+    #[test]
+    fn test_graphml_round_trip_keeps_every_detail() {
+        let todo = Id::new();
+        let doing = Id::new();
+        let done = Id::new();
+        let status = |id, name, category, wip_limit| StatusParts {
+            id,
+            name: StatusName::new(name).unwrap(),
+            category,
+            color: None,
+            description: None,
+            wip_limit,
+        };
+        let [task1, task2, task3, removed] = [Id::new(), Id::new(), Id::new(), Id::new()];
+        let at =
+            |seconds: i64| Timestamp::from_timestamp(1_700_000_000 + seconds, 123_456_789).unwrap();
+        let relation = |from, to, relation_type, strength, note: Option<&str>| RelationParts {
+            from,
+            to,
+            relation_type,
+            strength,
+            note: note.map(str::to_string),
+            created_at: at(0),
+        };
+        let mut relations = vec![
+            relation(
+                task1,
+                task2,
+                RelationType::Compose,
+                RelationStrength::Hard,
+                None,
+            ),
+            relation(
+                task1,
+                task3,
+                RelationType::Require,
+                RelationStrength::Soft,
+                Some("a & b"),
+            ),
+        ];
+        relations.sort_by_key(|relation| (relation.from, relation.to));
+        let mut tasks = vec![(task1, doing), (task2, todo), (task3, done)];
+        tasks.sort();
+        let mut milestones = vec![task3, removed];
+        milestones.sort();
+        let parts = NetParts {
+            schema: SchemaParts {
+                statuses: vec![
+                    status(todo, "Todo", StatusCategory::Todo, None),
+                    status(
+                        doing,
+                        "Doing",
+                        StatusCategory::InProgress,
+                        Some(WipLimit {
+                            limit: 3,
+                            mode: WipLimitMode::Soft,
+                        }),
+                    ),
+                    status(
+                        done,
+                        "Done",
+                        StatusCategory::Done,
+                        Some(WipLimit {
+                            limit: 10,
+                            mode: WipLimitMode::Hard,
+                        }),
+                    ),
+                ],
+                default: todo,
+                accepted: done,
+                allowed_transitions: vec![(todo, doing)],
+                forbidden_transitions: vec![(done, todo)],
+            },
+            tasks,
+            relations,
+            removed_tasks: vec![RemovedTaskParts {
+                task: removed,
+                status: doing,
+                relations: vec![relation(
+                    task2,
+                    removed,
+                    RelationType::Blocks,
+                    RelationStrength::Hard,
+                    None,
+                )],
+                removed_at: at(60),
+            }],
+            propagation_policy: PropagationPolicy::Advisory,
+            single_parent: true,
+            warn_on_orphaning: true,
+            estimates: vec![(task1, Duration::new(5400, 250))],
+            links: vec![LinkParts {
+                task: task2,
+                required: TaskRef::new(Id::new(), Id::new()),
+                accepted: true,
+            }],
+            milestones,
+            archived: vec![task3],
+            locked: true,
+            status_history: vec![(
+                task1,
+                vec![
+                    StatusChange {
+                        at: at(10),
+                        from: todo,
+                        to: doing,
+                        cause: ChangeCause::Manual {
+                            reason: Some("picked <up> today".to_string()),
+                        },
+                    },
+                    StatusChange {
+                        at: at(20),
+                        from: doing,
+                        to: todo,
+                        cause: ChangeCause::Propagated {
+                            origin: Some(task3),
+                        },
+                    },
+                    StatusChange {
+                        at: at(30),
+                        from: todo,
+                        to: doing,
+                        cause: ChangeCause::Manual { reason: None },
+                    },
+                ],
+            )],
+        };
+        let net = Entity::<Net>::from_parts(Id::new(), parts.clone()).unwrap();
+
+        let imported = from_graphml(&to_graphml(&net)).unwrap();
+
+        assert_eq!(imported.id, net.id);
+        assert_eq!(imported.to_parts(), parts);
+    }
+
+    #[test]
+    fn test_from_graphml_rejects_duplicate_edges() {
+        let mut net: Entity<Net> = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::RelatesTo, &SystemClock)
+            .unwrap();
+        let document = to_graphml(&net);
+        let edge_start = document.find("    <edge").unwrap();
+        let edge_end = document.find("</edge>\n").unwrap() + "</edge>\n".len();
+        let edge = &document[edge_start..edge_end];
+        let duplicated = document.replace(edge, &edge.repeat(2));
+
+        assert!(matches!(
+            from_graphml(&duplicated),
+            Err(GraphmlError::Invalid(reason)) if reason.contains("duplicate relation")
+        ));
+    }
+
+    #[test]
+    fn test_from_graphml_rejects_invalid_nets() {
+        let net: Entity<Net> = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let document = to_graphml(&net);
+
+        assert!(matches!(
+            from_graphml("<graphml"),
+            Err(GraphmlError::Xml(_))
+        ));
+        assert!(matches!(
+            from_graphml(&document.replace(
+                "<data key=\"category\">done</data>",
+                "<data key=\"category\">todo</data>"
+            )),
+            Err(GraphmlError::Domain(
+                TaskDomainError::StatusNotUnmarkable { .. }
+            ))
+        ));
+
+        let task_id = Id::<Task>::new();
+        let unknown_status = Id::<Status>::new();
+        let with_task = document.replace(
+            "  </graph>",
+            &format!(
+                "    <node id=\"{task_id}\"><data key=\"kind\">task</data><data key=\"status\">{unknown_status}</data></node>\n  </graph>"
+            ),
+        );
+        assert!(matches!(
+            from_graphml(&with_task),
+            Err(GraphmlError::Domain(TaskDomainError::StatusNotFoundInNet { status, .. }))
+                if status == unknown_status
+        ));
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::VecDeque;
+
+use shared_kernel::{AggregateRoot, Entity, Restorable};
+
+use super::{Net, NetChange, TaskDomainResult};
+use crate::domain::event::TaskDomainEvent;
+
+/// The number of edits a [`NetHistory`] keeps to undo; older edits are dropped first.
+pub const UNDO_LIMIT: usize = 100;
+
+/// Wraps a net and records every edit made through it, so edits can be undone and redone.
+///
+/// Each edit is recorded as the [`NetChange`] it made rather than as a list of inverse
+/// operations, since an edit may move many tasks through propagation. Undoing or redoing keeps
+/// the events still pending and records a `NetEditUndone` or `NetEditRedone` event carrying the
+/// change it applied, so the event stream still describes the net.
+#[derive(Debug, Clone)]
+pub struct NetHistory {
+    net: Entity<Net>,
+    undo: VecDeque<NetChange>,
+    redo: Vec<NetChange>,
+}
+
+impl NetHistory {
+    /// Starts recording the edits of a net.
+    pub fn new(net: Entity<Net>) -> Self {
+        NetHistory {
+            net,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// Returns the current state of the net.
+    pub fn net(&self) -> &Entity<Net> {
+        &self.net
+    }
+
+    /// Stops recording and returns the net.
+    pub fn into_inner(self) -> Entity<Net> {
+        self.net
+    }
+
+    /// Applies an edit made of any number of operations, recording it as one undoable step.
+    /// Once [`UNDO_LIMIT`] edits are recorded, the oldest can no longer be undone.
+    ///
+    /// A failed edit is rolled back and not recorded.
+    pub fn edit<R>(
+        &mut self,
+        edit: impl FnOnce(&mut Entity<Net>) -> TaskDomainResult<R>,
+    ) -> TaskDomainResult<R> {
+        let before = self.net.data.to_parts();
+        let result = self.net.transaction(edit)?;
+        let change = NetChange::between(&before, &self.net.data.to_parts());
+        if self.undo.len() == UNDO_LIMIT {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(change);
+        self.redo.clear();
+        Ok(result)
+    }
+
+    /// Checks whether there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Checks whether there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Reverts the last recorded edit, returning whether one was reverted. Nothing is
+    /// reverted while the net is locked.
+    pub fn undo(&mut self) -> bool {
+        if self.net.data.locked {
+            return false;
+        }
+        let Some(change) = self.undo.pop_back() else {
+            return false;
+        };
+        let reverted = change.clone().reversed();
+        self.apply(&reverted);
+        self.redo.push(change);
+        self.net.data.events.push(TaskDomainEvent::NetEditUndone {
+            net: self.net.id,
+            change: Box::new(reverted),
+        });
+        true
+    }
+
+    /// Applies the last undone edit again, returning whether one was applied. Nothing is
+    /// applied while the net is locked.
+    pub fn redo(&mut self) -> bool {
+        if self.net.data.locked {
+            return false;
+        }
+        let Some(change) = self.redo.pop() else {
+            return false;
+        };
+        self.apply(&change);
+        self.undo.push_back(change.clone());
+        self.net.data.events.push(TaskDomainEvent::NetEditRedone {
+            net: self.net.id,
+            change: Box::new(change),
+        });
+        true
+    }
+
+    /// Applies a recorded change to the net, carrying the pending events over.
+    fn apply(&mut self, change: &NetChange) {
+        self.net.data = self
+            .net
+            .data
+            .apply_change(self.net.id, change)
+            .expect("a recorded change applies to the net it was recorded on");
+    }
+}
+
+impl AggregateRoot for NetHistory {
+    type Event = TaskDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        self.net.data.take_events()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{Id, SystemClock};
+
+    use super::*;
+    use crate::domain::{
+        error::TaskDomainError,
+        name::StatusName,
+        net::{NetAggregateRoot, NetQuery, RelationType},
+    };
+
+    fn history() -> NetHistory {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.take_events();
+        NetHistory::new(net)
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut history = history();
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        history
+            .edit(|net| {
+                net.add_task(task1_id)?;
+                net.add_task(task2_id)
+            })
+            .unwrap();
+        history
+            .edit(|net| net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock))
+            .unwrap();
+        history.take_events();
+
+        assert!(history.undo());
+        assert_eq!(history.net().relations().count(), 0);
+        assert_eq!(history.net().tasks().count(), 2);
+
+        assert!(history.undo());
+        assert_eq!(history.net().tasks().count(), 0);
+        assert!(!history.undo());
+        assert!(history.can_redo());
+
+        assert!(history.redo());
+        assert!(history.redo());
+        assert!(!history.redo());
+        assert_eq!(history.net().relations().count(), 1);
+
+        let net_id = history.net().id;
+        let events = history.take_events();
+        assert_eq!(events.len(), 4);
+        assert!(matches!(
+            &events[0],
+            TaskDomainEvent::NetEditUndone { net, change }
+                if *net == net_id
+                    && change.tasks.is_empty()
+                    && change.relations.len() == 1
+                    && change.relations[0].after.is_none()
+        ));
+        assert!(matches!(
+            events[3],
+            TaskDomainEvent::NetEditRedone { net, .. } if net == net_id
+        ));
+    }
+
+    #[test]
+    fn test_failed_edit_is_not_recorded() {
+        let mut history = history();
+        let task_id = Id::new();
+        history.edit(|net| net.add_task(task_id)).unwrap();
+        history.undo();
+        assert!(history.can_redo());
+
+        let result = history.edit(|net| {
+            net.add_task(task_id)?;
+            net.add_task(task_id)
+        });
+
+        assert!(matches!(
+            result,
+            Err(TaskDomainError::TaskAlreadyInNet { .. })
+        ));
+        assert!(!history.can_undo());
+        assert!(history.can_redo());
+        assert_eq!(history.net().tasks().count(), 0);
+    }
+
+    #[test]
+    fn test_undo_limit() {
+        let mut history = history();
+        for _ in 0..=UNDO_LIMIT {
+            history.edit(|net| net.add_task(Id::new())).unwrap();
+        }
+
+        let mut undone = 0;
+        while history.undo() {
+            undone += 1;
+        }
+
+        assert_eq!(undone, UNDO_LIMIT);
+        assert_eq!(history.net().tasks().count(), 1);
+    }
+
+    #[test]
+    fn test_new_edit_clears_redo() {
+        let mut history = history();
+        history.edit(|net| net.add_task(Id::new())).unwrap();
+        history.undo();
+
+        history.edit(|net| net.add_task(Id::new())).unwrap();
+
+        assert!(!history.can_redo());
+        assert!(history.can_undo());
+    }
+}
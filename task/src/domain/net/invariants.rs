@@ -0,0 +1,207 @@
+use shared_kernel::{Entity, Id};
+
+use super::{is_controlled_task_accepted, Net, Status};
+use crate::domain::task::Task;
+
+/// Represents a rule of a net found broken by `check_invariants`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// The default or primary accepted status is not part of the schema.
+    MissingSchemaStatus { status: Id<Status> },
+    /// The primary accepted status does not count as accepted.
+    PrimaryAcceptedNotDone { status: Id<Status> },
+    /// A task, possibly soft-removed, is on a status that is not part of the schema.
+    UnknownTaskStatus { task: Id<Task>, status: Id<Status> },
+    /// A task has no node in the relation graph.
+    TaskWithoutNode { task: Id<Task> },
+    /// The relation graph has a node for a task that is not in the net.
+    NodeWithoutTask { task: Id<Task> },
+    /// A task is both in the net and soft-removed from it.
+    TaskAlsoRemoved { task: Id<Task> },
+    /// A task has an estimate but is neither in the net nor soft-removed.
+    EstimateWithoutTask { task: Id<Task> },
+    /// A task requires a task of another net but is neither in the net nor soft-removed.
+    LinkWithoutTask { task: Id<Task> },
+    /// A milestone is neither in the net nor soft-removed.
+    MilestoneWithoutTask { task: Id<Task> },
+    /// An archived task is neither in the net nor soft-removed.
+    ArchivedWithoutTask { task: Id<Task> },
+    /// A task has a status history but is neither in the net nor soft-removed.
+    HistoryWithoutTask { task: Id<Task> },
+    /// The constraining relations form a cycle through the listed tasks.
+    Cycle { cycle: Vec<Id<Task>> },
+    /// A controlled task is not on the status propagation would put it on.
+    UnpropagatedStatus { task: Id<Task>, status: Id<Status> },
+}
+
+/// Collects every broken invariant of a net.
+pub(super) fn violations(net: &Entity<Net>) -> Vec<InvariantViolation> {
+    let data = &net.data;
+    let schema = &data.schema;
+    let mut violations = Vec::new();
+
+    for status in [schema.default, schema.primary_accepted] {
+        if !schema.contains(status) {
+            violations.push(InvariantViolation::MissingSchemaStatus { status });
+        }
+    }
+    if schema.contains(schema.primary_accepted) && !schema.is_accepted(schema.primary_accepted) {
+        violations.push(InvariantViolation::PrimaryAcceptedNotDone {
+            status: schema.primary_accepted,
+        });
+    }
+
+    let removed = data
+        .removed_tasks
+        .iter()
+        .map(|(task, removed)| (task, &removed.status));
+    for (task, status) in data.tasks.iter().chain(removed) {
+        if !schema.contains(*status) {
+            violations.push(InvariantViolation::UnknownTaskStatus {
+                task: *task,
+                status: *status,
+            });
+        }
+    }
+    for task in data.tasks.keys() {
+        if !data.relations.contains_node(*task) {
+            violations.push(InvariantViolation::TaskWithoutNode { task: *task });
+        }
+        if data.removed_tasks.contains_key(task) {
+            violations.push(InvariantViolation::TaskAlsoRemoved { task: *task });
+        }
+    }
+    for task in data.relations.nodes() {
+        if !data.tasks.contains_key(&task) {
+            violations.push(InvariantViolation::NodeWithoutTask { task });
+        }
+    }
+    for task in data.estimates.keys() {
+        if !data.tasks.contains_key(task) && !data.removed_tasks.contains_key(task) {
+            violations.push(InvariantViolation::EstimateWithoutTask { task: *task });
+        }
+    }
+    for task in data.links.keys() {
+        if !data.tasks.contains_key(task) && !data.removed_tasks.contains_key(task) {
+            violations.push(InvariantViolation::LinkWithoutTask { task: *task });
+        }
+    }
+    for task in &data.milestones {
+        if !data.tasks.contains_key(task) && !data.removed_tasks.contains_key(task) {
+            violations.push(InvariantViolation::MilestoneWithoutTask { task: *task });
+        }
+    }
+    for task in &data.archived {
+        if !data.tasks.contains_key(task) && !data.removed_tasks.contains_key(task) {
+            violations.push(InvariantViolation::ArchivedWithoutTask { task: *task });
+        }
+    }
+    for task in data.status_history.keys() {
+        if !data.tasks.contains_key(task) && !data.removed_tasks.contains_key(task) {
+            violations.push(InvariantViolation::HistoryWithoutTask { task: *task });
+        }
+    }
+
+    match data.topo_order() {
+        Ok(order) => {
+            for task in order {
+                let (Some(status), Ok(Some(accepted))) = (
+                    data.status_of(*task),
+                    is_controlled_task_accepted(net, task),
+                ) else {
+                    continue;
+                };
+                let consistent = if accepted {
+                    schema.is_accepted(status)
+                } else {
+                    status == schema.default
+                };
+                if !consistent {
+                    violations.push(InvariantViolation::UnpropagatedStatus {
+                        task: *task,
+                        status,
+                    });
+                }
+            }
+        }
+        Err(task) => violations.push(InvariantViolation::Cycle {
+            cycle: data.cycle_through(task),
+        }),
+    }
+
+    violations
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::SystemClock;
+
+    use super::*;
+    use crate::domain::{
+        name::StatusName,
+        net::{NetAggregateRoot, NetQuery, RelationType},
+    };
+
+    fn net() -> (Entity<Net>, Id<Task>, Id<Task>) {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let child = Id::new();
+        let parent = Id::new();
+        net.add_tasks([child, parent]).unwrap();
+        net.new_relation(child, parent, RelationType::Compose, &SystemClock)
+            .unwrap();
+        (net, child, parent)
+    }
+
+    #[test]
+    fn test_valid_net_has_no_violations() {
+        let (mut net, child, _) = net();
+        net.change_task_status(child, net.accepted_status(), &SystemClock)
+            .unwrap();
+
+        assert_eq!(net.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_check_invariants_reports_every_violation() {
+        let (mut net, child, parent) = net();
+        let unknown = Id::new();
+        let ghost = Id::new();
+        net.data.tasks.insert(child, unknown);
+        net.data
+            .tasks
+            .insert(parent, net.data.schema.primary_accepted);
+        net.data.relations_mut().add_node(ghost);
+
+        let violations = net.check_invariants().unwrap_err();
+
+        assert!(violations.contains(&InvariantViolation::UnknownTaskStatus {
+            task: child,
+            status: unknown,
+        }));
+        assert!(violations.contains(&InvariantViolation::NodeWithoutTask { task: ghost }));
+        assert!(
+            violations.contains(&InvariantViolation::UnpropagatedStatus {
+                task: parent,
+                status: net.data.schema.primary_accepted,
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_invariants_reports_cycles() {
+        let (mut net, child, parent) = net();
+        let relation = net.data.relations[(child, parent)].clone();
+        net.data.relations_mut().add_edge(parent, child, relation);
+
+        let violations = net.check_invariants().unwrap_err();
+
+        assert!(matches!(
+            violations.as_slice(),
+            [InvariantViolation::Cycle { cycle }] if cycle.len() == 2
+        ));
+    }
+}
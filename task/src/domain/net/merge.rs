@@ -0,0 +1,418 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use shared_kernel::{Clock, Entity, Id};
+
+use super::{propagate_all, Net, NetQuery, RelationParts, Status, StatusParts, TaskDomainResult};
+use crate::domain::task::Task;
+
+/// Represents a change both sides of a merge made differently, `None` meaning a side removed
+/// the item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeConflict {
+    /// The presence or status of a task.
+    Task {
+        task: Id<Task>,
+        ours: Option<Id<Status>>,
+        theirs: Option<Id<Status>>,
+    },
+    /// The presence, type or note of a relation.
+    Relation {
+        from: Id<Task>,
+        to: Id<Task>,
+        ours: Option<RelationParts>,
+        theirs: Option<RelationParts>,
+    },
+    /// The presence or settings of a status.
+    Status {
+        status: Id<Status>,
+        ours: Option<StatusParts>,
+        theirs: Option<StatusParts>,
+    },
+    /// The status new tasks are put on.
+    DefaultStatus {
+        ours: Id<Status>,
+        theirs: Id<Status>,
+    },
+    /// The primary status counting a task as done.
+    AcceptedStatus {
+        ours: Id<Status>,
+        theirs: Id<Status>,
+    },
+}
+
+/// Represents which side of a merge a conflict is resolved to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    Ours,
+    Theirs,
+}
+
+/// Decides how conflicts of a merge are resolved.
+///
+/// Any `Fn(&MergeConflict) -> Resolution` closure is a strategy.
+pub trait MergeStrategy {
+    /// Picks the side a conflict is resolved to.
+    fn resolve(&self, conflict: &MergeConflict) -> Resolution;
+}
+
+impl<F> MergeStrategy for F
+where
+    F: Fn(&MergeConflict) -> Resolution,
+{
+    fn resolve(&self, conflict: &MergeConflict) -> Resolution {
+        self(conflict)
+    }
+}
+
+/// Resolves every conflict to our side.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PreferOurs;
+
+impl MergeStrategy for PreferOurs {
+    fn resolve(&self, _: &MergeConflict) -> Resolution {
+        Resolution::Ours
+    }
+}
+
+/// Resolves every conflict to their side.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PreferTheirs;
+
+impl MergeStrategy for PreferTheirs {
+    fn resolve(&self, _: &MergeConflict) -> Resolution {
+        Resolution::Theirs
+    }
+}
+
+/// Represents the result of a merge together with the conflicts met along the way.
+#[derive(Debug, Clone)]
+pub struct MergeOutcome {
+    /// The merged net, keeping the id of our side.
+    pub net: Entity<Net>,
+    /// The conflicts met, with how each was resolved.
+    pub conflicts: Vec<(MergeConflict, Resolution)>,
+}
+
+/// Merges the changes two sides made to a common base version of a net.
+///
+/// Tasks, relations, statuses and the default and accepted statuses are merged three ways,
+/// a change made by only one side winning and conflicting changes being resolved by
+/// `strategy`. Every other setting is taken from our side. Tasks left on a status the merge
+/// removed are put on the default status, relations to removed tasks are dropped, and the
+/// statuses of the merged net are propagated.
+///
+/// Fails if the merged net breaks an invariant, e.g. when the sides added relations forming
+/// a cycle together.
+pub fn merge(
+    base: &Entity<Net>,
+    ours: &Entity<Net>,
+    theirs: &Entity<Net>,
+    strategy: &impl MergeStrategy,
+    clock: &impl Clock,
+) -> TaskDomainResult<MergeOutcome> {
+    let (base, ours_parts, theirs_parts) = (base.to_parts(), ours.to_parts(), theirs.to_parts());
+    let mut merger = Merger {
+        strategy,
+        conflicts: Vec::new(),
+    };
+
+    let statuses: HashMap<_, _> = merger
+        .merge_maps(
+            keyed(&base.schema.statuses, |status| status.id),
+            keyed(&ours_parts.schema.statuses, |status| status.id),
+            keyed(&theirs_parts.schema.statuses, |status| status.id),
+            |status, ours, theirs| MergeConflict::Status {
+                status,
+                ours,
+                theirs,
+            },
+        )
+        .into_iter()
+        .collect();
+    let default = merger.merge_value(
+        base.schema.default,
+        ours_parts.schema.default,
+        theirs_parts.schema.default,
+        |ours, theirs| MergeConflict::DefaultStatus { ours, theirs },
+    );
+    let accepted = merger.merge_value(
+        base.schema.accepted,
+        ours_parts.schema.accepted,
+        theirs_parts.schema.accepted,
+        |ours, theirs| MergeConflict::AcceptedStatus { ours, theirs },
+    );
+    let tasks = merger.merge_maps(
+        base.tasks.iter().copied().collect(),
+        ours_parts.tasks.iter().copied().collect(),
+        theirs_parts.tasks.iter().copied().collect(),
+        |task, ours, theirs| MergeConflict::Task { task, ours, theirs },
+    );
+    let relations = merger.merge_maps(
+        keyed(&base.relations, |relation| (relation.from, relation.to)),
+        keyed(&ours_parts.relations, |relation| {
+            (relation.from, relation.to)
+        }),
+        keyed(&theirs_parts.relations, |relation| {
+            (relation.from, relation.to)
+        }),
+        |(from, to), ours, theirs| MergeConflict::Relation {
+            from,
+            to,
+            ours,
+            theirs,
+        },
+    );
+
+    let mut parts = ours_parts;
+    let ours_order = parts.schema.statuses.iter().map(|status| status.id);
+    let theirs_order = theirs_parts.schema.statuses.iter().map(|status| status.id);
+    let mut ordered = HashSet::new();
+    parts.schema.statuses = ours_order
+        .chain(theirs_order)
+        .filter(|status| ordered.insert(*status))
+        .filter_map(|status| statuses.get(&status).cloned())
+        .collect();
+    parts.schema.default = default;
+    parts.schema.accepted = accepted;
+    for transitions in [
+        &mut parts.schema.allowed_transitions,
+        &mut parts.schema.forbidden_transitions,
+    ] {
+        transitions.retain(|(from, to)| statuses.contains_key(from) && statuses.contains_key(to));
+    }
+
+    let on_schema = |status: Id<Status>| {
+        if statuses.contains_key(&status) {
+            status
+        } else {
+            default
+        }
+    };
+    parts.tasks = tasks
+        .into_iter()
+        .map(|(task, status)| (task, on_schema(status)))
+        .collect();
+    let present: HashSet<_> = parts.tasks.iter().map(|(task, _)| *task).collect();
+    parts.relations = relations
+        .into_iter()
+        .map(|(_, relation)| relation)
+        .filter(|relation| present.contains(&relation.from) && present.contains(&relation.to))
+        .collect();
+    parts
+        .removed_tasks
+        .retain(|removed| !present.contains(&removed.task));
+    for removed in &mut parts.removed_tasks {
+        removed.status = on_schema(removed.status);
+    }
+    let known: HashSet<_> = present
+        .iter()
+        .copied()
+        .chain(parts.removed_tasks.iter().map(|removed| removed.task))
+        .collect();
+    parts.estimates.retain(|(task, _)| known.contains(task));
+    parts.links.retain(|link| known.contains(&link.task));
+    parts.milestones.retain(|task| known.contains(task));
+    parts.archived.retain(|task| known.contains(task));
+    parts
+        .status_history
+        .retain(|(task, _)| known.contains(task));
+
+    let mut net = Entity {
+        id: ours.id,
+        data: Net::from_parts(ours.id, parts)?,
+    };
+    propagate_all(&mut net, clock)?;
+
+    Ok(MergeOutcome {
+        net,
+        conflicts: merger.conflicts,
+    })
+}
+
+/// Keys plain parts by one of their fields.
+fn keyed<K: Eq + Hash, V: Clone>(items: &[V], key: impl Fn(&V) -> K) -> HashMap<K, V> {
+    items.iter().map(|item| (key(item), item.clone())).collect()
+}
+
+/// Carries the strategy and the conflicts met through a merge.
+struct Merger<'a, S> {
+    strategy: &'a S,
+    conflicts: Vec<(MergeConflict, Resolution)>,
+}
+
+impl<S: MergeStrategy> Merger<'_, S> {
+    /// Merges a single value three ways.
+    fn merge_value<V: Clone + PartialEq>(
+        &mut self,
+        base: V,
+        ours: V,
+        theirs: V,
+        conflict: impl FnOnce(V, V) -> MergeConflict,
+    ) -> V {
+        if ours == theirs || theirs == base {
+            ours
+        } else if ours == base {
+            theirs
+        } else {
+            match self.resolve(conflict(ours.clone(), theirs.clone())) {
+                Resolution::Ours => ours,
+                Resolution::Theirs => theirs,
+            }
+        }
+    }
+
+    /// Merges keyed items three ways, listing the merged items ordered by key.
+    fn merge_maps<K: Copy + Ord + Hash, V: Clone + PartialEq>(
+        &mut self,
+        base: HashMap<K, V>,
+        ours: HashMap<K, V>,
+        theirs: HashMap<K, V>,
+        conflict: impl Fn(K, Option<V>, Option<V>) -> MergeConflict,
+    ) -> Vec<(K, V)> {
+        let mut keys: Vec<_> = ours.keys().chain(theirs.keys()).copied().collect();
+        keys.sort();
+        keys.dedup();
+        keys.into_iter()
+            .filter_map(|key| {
+                let merged = self.merge_value(
+                    base.get(&key).cloned(),
+                    ours.get(&key).cloned(),
+                    theirs.get(&key).cloned(),
+                    |ours, theirs| conflict(key, ours, theirs),
+                );
+                merged.map(|value| (key, value))
+            })
+            .collect()
+    }
+
+    fn resolve(&mut self, conflict: MergeConflict) -> Resolution {
+        let resolution = self.strategy.resolve(&conflict);
+        self.conflicts.push((conflict, resolution));
+        resolution
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::SystemClock;
+
+    use super::*;
+    use crate::domain::{
+        error::TaskDomainError,
+        name::StatusName,
+        net::{NetAggregateRoot, RelationType},
+    };
+
+    fn base() -> (Entity<Net>, Id<Task>, Id<Task>) {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_tasks([task1_id, task2_id]).unwrap();
+        (net, task1_id, task2_id)
+    }
+
+    #[test]
+    fn test_merge_combines_independent_changes() {
+        let (base, task1_id, task2_id) = base();
+        let mut ours = base.clone();
+        let mut theirs = base.clone();
+        let our_task = Id::new();
+        ours.add_task(our_task).unwrap();
+        ours.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        theirs.remove_task(task2_id, &SystemClock).unwrap();
+        theirs
+            .change_task_status(task1_id, theirs.accepted_status(), &SystemClock)
+            .unwrap();
+
+        let outcome = merge(&base, &ours, &theirs, &PreferOurs, &SystemClock).unwrap();
+
+        let net = outcome.net;
+        assert!(outcome.conflicts.is_empty());
+        assert_eq!(net.id, ours.id);
+        assert_eq!(net.status_of(task1_id), Some(net.accepted_status()));
+        assert_eq!(net.status_of(task2_id), None);
+        assert_eq!(net.status_of(our_task), Some(net.default_status()));
+        assert_eq!(net.statuses().count(), 3);
+    }
+
+    #[test]
+    fn test_merge_resolves_conflicts_with_strategy() {
+        let (base, task1_id, task2_id) = base();
+        let mut ours = base.clone();
+        let mut theirs = base.clone();
+        ours.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        let doing = ours.statuses().last().unwrap().id;
+        ours.change_task_status(task1_id, doing, &SystemClock)
+            .unwrap();
+        theirs
+            .change_task_status(task1_id, theirs.accepted_status(), &SystemClock)
+            .unwrap();
+        ours.remove_task(task2_id, &SystemClock).unwrap();
+        theirs
+            .change_task_status(task2_id, theirs.accepted_status(), &SystemClock)
+            .unwrap();
+
+        let theirs_for_tasks = |conflict: &MergeConflict| match conflict {
+            MergeConflict::Task { .. } => Resolution::Theirs,
+            _ => Resolution::Ours,
+        };
+        let outcome = merge(&base, &ours, &theirs, &theirs_for_tasks, &SystemClock).unwrap();
+
+        assert_eq!(outcome.conflicts.len(), 2);
+        assert!(outcome
+            .conflicts
+            .iter()
+            .all(|(_, resolution)| *resolution == Resolution::Theirs));
+        let net = outcome.net;
+        assert_eq!(net.status_of(task1_id), Some(net.accepted_status()));
+        assert_eq!(net.status_of(task2_id), Some(net.accepted_status()));
+
+        let outcome = merge(&base, &ours, &theirs, &PreferOurs, &SystemClock).unwrap();
+        let net = outcome.net;
+        assert_eq!(net.status_of(task1_id), Some(doing));
+        assert_eq!(net.status_of(task2_id), None);
+    }
+
+    #[test]
+    fn test_merge_rejects_cycles_across_sides() {
+        let (base, task1_id, task2_id) = base();
+        let mut ours = base.clone();
+        let mut theirs = base.clone();
+        ours.new_relation(task1_id, task2_id, RelationType::Require, &SystemClock)
+            .unwrap();
+        theirs
+            .new_relation(task2_id, task1_id, RelationType::Require, &SystemClock)
+            .unwrap();
+
+        assert!(matches!(
+            merge(&base, &ours, &theirs, &PreferTheirs, &SystemClock),
+            Err(TaskDomainError::CycleNotAllowedInNet { .. })
+        ));
+    }
+
+    #[test]
+    fn test_merge_moves_tasks_off_removed_statuses() {
+        let (mut base, task1_id, _) = base();
+        base.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        let doing = base.statuses().last().unwrap().id;
+        let mut ours = base.clone();
+        let mut theirs = base.clone();
+        ours.change_task_status(task1_id, doing, &SystemClock)
+            .unwrap();
+        theirs.remove_status(doing, &SystemClock).unwrap();
+
+        let outcome = merge(&base, &ours, &theirs, &PreferOurs, &SystemClock).unwrap();
+
+        let net = outcome.net;
+        assert!(outcome.conflicts.is_empty());
+        assert_eq!(net.statuses().count(), 2);
+        assert_eq!(net.status_of(task1_id), Some(net.default_status()));
+    }
+}
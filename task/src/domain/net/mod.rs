@@ -0,0 +1,6126 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use petgraph::{
+    algo::{astar, toposort},
+    prelude::DiGraphMap,
+    visit::{DfsPostOrder, EdgeFiltered},
+    Direction::{self, Incoming, Outgoing},
+};
+use shared_kernel::{
+    AggregateRoot, Clock, Entity, EntityKind, Id, Restorable, Snapshot, Timestamp,
+};
+
+use super::{
+    error::TaskDomainError,
+    event::TaskDomainEvent,
+    link::TaskRef,
+    name::StatusName,
+    task::{by_priority, Task},
+};
+
+/// Renders nets in formats understood by external graph tooling.
+pub mod export;
+
+/// Reads and writes nets as GraphML documents for exchange between installations.
+#[cfg(feature = "graphml")]
+pub mod graphml;
+
+/// Applies batches of operations to a net all at once.
+pub mod batch;
+
+/// Declares whole nets fluently and builds them in one validated step.
+pub mod builder;
+
+/// Describes how an edit changed a net, entry by entry.
+pub mod change;
+
+/// Compares two versions of a net.
+pub mod diff;
+
+/// Records edits of a net so they can be undone and redone.
+pub mod history;
+
+/// Reconciles concurrent edits of a net with a three-way merge.
+pub mod merge;
+
+/// Checks the consistency rules every net is expected to follow.
+pub mod invariants;
+
+/// Converts nets to and from their plain, persistable parts.
+pub mod parts;
+
+/// Rebuilds nets from the events they recorded.
+pub mod replay;
+
+/// Provides predefined workflows for new nets.
+pub mod templates;
+
+/// Bumps the modification time of timestamped nets on every operation.
+mod timestamped;
+
+/// Increments the version of versioned nets on every operation.
+mod versioned;
+
+/// Attributes every operation on an audited net to the user performing it.
+mod audited;
+
+pub use audited::AuditedNetAggregateRoot;
+pub use batch::NetOp;
+pub use builder::NetBuilder;
+pub use change::{Change, NetChange};
+pub use diff::{diff, NetDiff, StatusDiff};
+pub use history::NetHistory;
+pub use invariants::InvariantViolation;
+pub use merge::{
+    merge, MergeConflict, MergeOutcome, MergeStrategy, PreferOurs, PreferTheirs, Resolution,
+};
+pub use parts::{LinkParts, NetParts, RelationParts, RemovedTaskParts, SchemaParts, StatusParts};
+pub use templates::SchemaTemplate;
+
+/// Represents a network of tasks and their relations.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "parts::NetRecord", try_from = "parts::NetRecord")
+)]
+pub struct Net {
+    relations: DiGraphMap<Id<Task>, Relation>,
+    schema: Schema,
+    tasks: HashMap<Id<Task>, Id<Status>>,
+    removed_tasks: HashMap<Id<Task>, RemovedTask>,
+    propagation_policy: PropagationPolicy,
+    single_parent: bool,
+    warn_on_orphaning: bool,
+    estimates: HashMap<Id<Task>, Duration>,
+    links: HashMap<Id<Task>, HashMap<TaskRef, bool>>,
+    milestones: HashSet<Id<Task>>,
+    archived: HashSet<Id<Task>>,
+    locked: bool,
+    status_history: HashMap<Id<Task>, VecDeque<StatusChange>>,
+    propagation_deferred: bool,
+    topo_order: OnceLock<Vec<Id<Task>>>,
+    events: Vec<TaskDomainEvent>,
+}
+
+/// Represents the longest chain of constraining relations in a net, weighted by task estimates.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CriticalPath {
+    /// The tasks along the chain, in the order they have to be done.
+    pub tasks: Vec<Id<Task>>,
+    /// The sum of the estimates of the tasks along the chain.
+    pub duration: Duration,
+}
+
+/// Compares the effort estimated for a task in a net with the time logged on it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Effort {
+    /// The estimate of the task in the net, if one is set.
+    pub estimated: Option<Duration>,
+    /// The total time logged on the task.
+    pub logged: Duration,
+}
+
+impl Effort {
+    /// Returns how much longer the task took than estimated, or `None` without an estimate or
+    /// when it is still within the estimate.
+    pub fn overrun(&self) -> Option<Duration> {
+        self.estimated
+            .and_then(|estimated| self.logged.checked_sub(estimated))
+            .filter(|overrun| !overrun.is_zero())
+    }
+}
+
+/// The number of status changes kept per task; older changes are dropped first.
+pub const STATUS_HISTORY_LIMIT: usize = 100;
+
+/// Represents what moved a task to another status.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeCause {
+    /// The status was changed by hand, for the given reason if any.
+    Manual { reason: Option<String> },
+    /// The status was changed by propagation through the relations of the task, started by a
+    /// change of `origin`, or by a change of the whole net if there is none.
+    Propagated { origin: Option<Id<Task>> },
+    /// The status was mapped onto a status of a new schema when the schema of the net was
+    /// replaced.
+    Remapped,
+}
+
+/// Represents a change of the status of a task, as kept in its history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusChange {
+    pub at: Timestamp,
+    pub from: Id<Status>,
+    pub to: Id<Status>,
+    pub cause: ChangeCause,
+}
+
+/// Represents something in a net worth a look that does not block any task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NetWarning {
+    /// A task left its todo stage while a task it softly requires is not accepted yet.
+    SoftRequirementUnmet { required: Id<Task>, task: Id<Task> },
+}
+
+/// Represents summary figures of a net, e.g. for dashboards.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetStats {
+    /// The number of tasks on each status of the schema, including empty ones.
+    pub tasks_per_status: HashMap<Id<Status>, usize>,
+    /// The number of relations of each type present in the net.
+    pub relations_per_type: HashMap<RelationType, usize>,
+    /// The number of tasks waiting on a related task that is not accepted yet.
+    pub blocked: usize,
+    /// The number of tasks that are not accepted yet and wait on nothing.
+    pub ready: usize,
+    /// The number of tasks on the longest chain of constraining relations.
+    pub depth: usize,
+}
+
+/// Represents how requirement relations (`Require` and `Blocks`) control their targets.
+///
+/// Composition relations always propagate, whatever the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PropagationPolicy {
+    /// Targets are reset to the default status while a requirement is unaccepted, and cannot
+    /// be moved by hand until every requirement is accepted.
+    #[default]
+    Strict,
+    /// Targets move freely, but cannot be accepted by hand while a requirement is unaccepted.
+    Advisory,
+}
+
+/// Represents a soft-removed task, kept so that it can be restored with its status and relations.
+#[derive(Debug, Clone)]
+struct RemovedTask {
+    status: Id<Status>,
+    relations: Vec<(Id<Task>, Id<Task>, Relation)>,
+    removed_at: Timestamp,
+}
+
+/// Represents the type of relation between tasks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RelationType {
+    /// A composition relation.
+    Compose,
+    /// A requirement relation.
+    Require,
+    /// A blocking relation: the source blocks the target until it is accepted, which is the
+    /// requirement relation read from the other end.
+    Blocks,
+    /// The source duplicates the target; does not constrain acceptance.
+    Duplicates,
+    /// The tasks are related; does not constrain acceptance.
+    RelatesTo,
+}
+
+/// Represents whether a requirement relation holds its target back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RelationStrength {
+    /// The requirement controls its target through propagation.
+    #[default]
+    Hard,
+    /// The requirement is informational: it never blocks its target and is only reported by
+    /// [`NetQuery::warnings`].
+    Soft,
+}
+
+/// Represents a relation between two tasks together with its metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Relation {
+    relation_type: RelationType,
+    strength: RelationStrength,
+    note: Option<String>,
+    created_at: Timestamp,
+}
+
+/// The relations of a net restricted to those constraining acceptance.
+type Constraints<'a> =
+    EdgeFiltered<&'a DiGraphMap<Id<Task>, Relation>, fn((Id<Task>, Id<Task>, &Relation)) -> bool>;
+
+impl Relation {
+    fn new(relation_type: RelationType, created_at: Timestamp) -> Self {
+        Relation {
+            relation_type,
+            strength: RelationStrength::Hard,
+            note: None,
+            created_at,
+        }
+    }
+
+    /// Checks whether the relation holds its target back until its source is accepted.
+    ///
+    /// Soft requirements still count for cycle detection, so they can be hardened at any time.
+    fn is_enforced(&self) -> bool {
+        self.relation_type.is_constraining() && self.strength == RelationStrength::Hard
+    }
+
+    /// Returns the type of the relation.
+    pub fn relation_type(&self) -> RelationType {
+        self.relation_type
+    }
+
+    /// Returns whether the relation holds its target back.
+    pub fn strength(&self) -> RelationStrength {
+        self.strength
+    }
+
+    /// Returns the note recording why the relation exists, if one is set.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    /// Returns the time the relation was created.
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+}
+
+impl RelationType {
+    /// Checks whether the relation takes part in acceptance control and cycle detection.
+    pub fn is_constraining(self) -> bool {
+        match self {
+            RelationType::Compose | RelationType::Require | RelationType::Blocks => true,
+            RelationType::Duplicates | RelationType::RelatesTo => false,
+        }
+    }
+}
+
+/// Represents the status schema of a network, including statuses and default/accepted statuses.
+///
+/// Every status in the [`StatusCategory::Done`] category counts as accepted; the primary
+/// accepted status is where propagation moves controlled tasks once they are accepted.
+///
+/// The workflow restricts manual status changes: a status with allowed transitions may
+/// only move to those targets, and forbidden transitions are never permitted.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "parts::SchemaRecord", try_from = "parts::SchemaRecord")
+)]
+pub struct Schema {
+    statuses: HashMap<Id<Status>, Entity<Status>>,
+    order: Vec<Id<Status>>,
+    default: Id<Status>,
+    primary_accepted: Id<Status>,
+    allowed_transitions: HashSet<(Id<Status>, Id<Status>)>,
+    forbidden_transitions: HashSet<(Id<Status>, Id<Status>)>,
+}
+
+/// Represents the status of a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Status {
+    name: StatusName,
+    category: StatusCategory,
+    color: Option<Color>,
+    description: Option<String>,
+    wip_limit: Option<WipLimit>,
+}
+
+/// Represents the maximum number of tasks a status may hold at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WipLimit {
+    pub limit: usize,
+    pub mode: WipLimitMode,
+}
+
+/// Represents how a work-in-progress limit is enforced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WipLimitMode {
+    /// Status changes past the limit are rejected.
+    Hard,
+    /// Status changes past the limit are accepted but recorded as a `WipLimitExceeded` event.
+    Soft,
+}
+
+/// Represents the color a status or label is rendered with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+/// Represents the stage of work a status stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatusCategory {
+    /// Work that has not started.
+    Todo,
+    /// Work that is under way.
+    InProgress,
+    /// Work that is finished; tasks on these statuses count as accepted.
+    Done,
+}
+
+type TaskDomainResult<T> = Result<T, TaskDomainError>;
+
+impl Schema {
+    fn new(default: StatusName, accepted: StatusName, normal: Vec<StatusName>) -> Schema {
+        let default_id = Id::new();
+        let default = Entity {
+            id: default_id,
+            data: Status::new(default, StatusCategory::Todo),
+        };
+
+        let accepted_id = Id::new();
+        let accepted = Entity {
+            id: accepted_id,
+            data: Status::new(accepted, StatusCategory::Done),
+        };
+
+        let mut schema = Schema {
+            statuses: HashMap::new(),
+            order: Vec::new(),
+            default: default_id,
+            primary_accepted: accepted_id,
+            allowed_transitions: HashSet::new(),
+            forbidden_transitions: HashSet::new(),
+        };
+        schema.push(default);
+        for normal in normal {
+            schema.push(Entity {
+                id: Id::new(),
+                data: Status::new(normal, StatusCategory::InProgress),
+            });
+        }
+        schema.push(accepted);
+        schema
+    }
+
+    /// Checks whether a status belongs to the schema.
+    fn contains(&self, status: Id<Status>) -> bool {
+        self.statuses.contains_key(&status)
+    }
+
+    /// Looks a status up by id.
+    fn get(&self, status: Id<Status>) -> Option<&Entity<Status>> {
+        self.statuses.get(&status)
+    }
+
+    /// Looks a status up by id for changing it.
+    fn get_mut(&mut self, status: Id<Status>) -> Option<&mut Entity<Status>> {
+        self.statuses.get_mut(&status)
+    }
+
+    /// Lists the statuses in board order.
+    fn iter(&self) -> impl Iterator<Item = &Entity<Status>> + '_ {
+        self.order.iter().map(|status| &self.statuses[status])
+    }
+
+    /// Appends a status at the end of the board order.
+    fn push(&mut self, status: Entity<Status>) {
+        self.order.push(status.id);
+        self.statuses.insert(status.id, status);
+    }
+
+    /// Removes a status, returning whether it was in the schema.
+    fn remove(&mut self, status: Id<Status>) -> bool {
+        self.order.retain(|id| *id != status);
+        self.statuses.remove(&status).is_some()
+    }
+
+    /// Checks whether the workflow permits moving a task from one status to another.
+    fn is_transition_allowed(&self, from: Id<Status>, to: Id<Status>) -> bool {
+        if from == to {
+            return true;
+        }
+        if self.forbidden_transitions.contains(&(from, to)) {
+            return false;
+        }
+        let restricted = self
+            .allowed_transitions
+            .iter()
+            .any(|(source, _)| *source == from);
+        !restricted || self.allowed_transitions.contains(&(from, to))
+    }
+
+    /// Checks whether a status counts as accepted.
+    fn is_accepted(&self, status: Id<Status>) -> bool {
+        self.get(status)
+            .is_some_and(|entity| entity.data.category == StatusCategory::Done)
+    }
+}
+
+impl EntityKind for Net {
+    const KIND: &'static str = "net";
+}
+
+impl Net {
+    /// Fails while the net is locked.
+    fn ensure_unlocked(&self, net: Id<Net>) -> TaskDomainResult<()> {
+        if self.locked {
+            Err(TaskDomainError::NetLocked { net })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Creates a net without tasks on a schema.
+    fn empty(schema: Schema) -> Net {
+        Net {
+            relations: DiGraphMap::new(),
+            schema,
+            tasks: HashMap::new(),
+            removed_tasks: HashMap::new(),
+            propagation_policy: PropagationPolicy::default(),
+            single_parent: false,
+            warn_on_orphaning: false,
+            estimates: HashMap::new(),
+            links: HashMap::new(),
+            milestones: HashSet::new(),
+            archived: HashSet::new(),
+            locked: false,
+            status_history: HashMap::new(),
+            propagation_deferred: false,
+            topo_order: OnceLock::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns the status of a task, if the task is in the net.
+    pub(crate) fn status_of(&self, task: Id<Task>) -> Option<Id<Status>> {
+        self.tasks.get(&task).copied()
+    }
+
+    /// Checks whether a task of the net has an accepted status.
+    pub(crate) fn is_task_accepted(&self, task: Id<Task>) -> bool {
+        self.status_of(task)
+            .is_some_and(|status| self.schema.is_accepted(status))
+    }
+
+    /// Lists a task and every task it controls at any depth, in topological order.
+    ///
+    /// Only the reachable part of the net is visited; the reverse post-order of a depth-first
+    /// search is a topological order since the constraining relations never form a cycle.
+    fn downstream_of(&self, task: Id<Task>) -> Vec<Id<Task>> {
+        if !self.relations.contains_node(task) {
+            return Vec::new();
+        }
+        let constraints = self.constraints();
+        let mut dfs = DfsPostOrder::new(&constraints, task);
+        let mut tasks = Vec::new();
+        while let Some(task) = dfs.next(&constraints) {
+            tasks.push(task);
+        }
+        tasks.reverse();
+        tasks
+    }
+
+    /// Returns the relations for changing them, dropping the cached topological order.
+    fn relations_mut(&mut self) -> &mut DiGraphMap<Id<Task>, Relation> {
+        self.topo_order.take();
+        &mut self.relations
+    }
+
+    /// Returns the tasks in topological order of the constraining relations, or a task on a
+    /// cycle. The order is cached until the relations change.
+    fn topo_order(&self) -> Result<&[Id<Task>], Id<Task>> {
+        if let Some(order) = self.topo_order.get() {
+            return Ok(order);
+        }
+        let order = toposort(&self.constraints(), None).map_err(|cycle| cycle.node_id())?;
+        Ok(self.topo_order.get_or_init(|| order))
+    }
+
+    /// Returns the relations that constrain acceptance.
+    fn constraints(&self) -> Constraints<'_> {
+        EdgeFiltered(&self.relations, |(_, _, relation)| {
+            relation.relation_type.is_constraining()
+        })
+    }
+
+    /// Finds a shortest path of constraining relations from one task to another.
+    fn constraint_path(&self, from: Id<Task>, to: Id<Task>) -> Option<Vec<Id<Task>>> {
+        if !self.relations.contains_node(from) {
+            return None;
+        }
+        astar(&self.constraints(), from, |task| task == to, |_| 1, |_| 0).map(|(_, path)| path)
+    }
+
+    /// Finds a cycle of constraining relations going through a task.
+    fn cycle_through(&self, task: Id<Task>) -> Vec<Id<Task>> {
+        self.relations
+            .edges_directed(task, Outgoing)
+            .filter(|(_, _, relation)| relation.relation_type.is_constraining())
+            .find_map(|(_, successor, _)| self.constraint_path(successor, task))
+            .map(|mut path| {
+                path.pop();
+                path.insert(0, task);
+                path
+            })
+            .unwrap_or_else(|| vec![task])
+    }
+
+    /// Lists the composition parents of a task.
+    fn parents_of(&self, task: Id<Task>) -> impl Iterator<Item = Id<Task>> + '_ {
+        self.relations
+            .edges_directed(task, Outgoing)
+            .filter(|(_, _, relation)| relation.relation_type == RelationType::Compose)
+            .map(|(_, parent, _)| parent)
+    }
+
+    /// Lists the direct composition children of a task.
+    fn children_of(&self, task: Id<Task>) -> impl Iterator<Item = Id<Task>> + '_ {
+        self.relations
+            .edges_directed(task, Incoming)
+            .filter(|(_, _, relation)| relation.relation_type == RelationType::Compose)
+            .map(|(child, _, _)| child)
+    }
+
+    /// Collects every task reachable from a task through composition relations in a direction,
+    /// in depth-first order, excluding the task itself.
+    fn compose_closure(&self, task: Id<Task>, direction: Direction) -> Vec<Id<Task>> {
+        let mut visited = HashSet::from([task]);
+        let mut stack = vec![task];
+        let mut closure = Vec::new();
+        while let Some(current) = stack.pop() {
+            let next: Vec<_> = match direction {
+                Incoming => self.children_of(current).collect(),
+                Outgoing => self.parents_of(current).collect(),
+            };
+            for related in next {
+                if visited.insert(related) {
+                    closure.push(related);
+                    stack.push(related);
+                }
+            }
+        }
+        closure
+    }
+
+    /// Collects the tasks connected to any of the roots through relations in either direction,
+    /// including the roots themselves.
+    fn connected_to(&self, roots: impl IntoIterator<Item = Id<Task>>) -> HashSet<Id<Task>> {
+        let mut stack: Vec<_> = roots
+            .into_iter()
+            .filter(|task| self.tasks.contains_key(task))
+            .collect();
+        let mut visited: HashSet<_> = stack.iter().copied().collect();
+        while let Some(current) = stack.pop() {
+            for related in self
+                .relations
+                .neighbors_directed(current, Incoming)
+                .chain(self.relations.neighbors_directed(current, Outgoing))
+            {
+                if visited.insert(related) {
+                    stack.push(related);
+                }
+            }
+        }
+        visited
+    }
+
+    /// Checks whether composing `child` into `parent` would give the child a second parent
+    /// while the net requires a single one.
+    fn violates_single_parent(&self, child: Id<Task>, parent: Id<Task>) -> bool {
+        self.single_parent && self.parents_of(child).any(|existing| existing != parent)
+    }
+
+    /// Checks that a relation may be added to the net of id `net`: both tasks are in it, they
+    /// are not related yet, the relation closes no cycle of constraining relations and keeps
+    /// a single parent where the net requires one.
+    fn check_new_relation(
+        &self,
+        net: Id<Net>,
+        from: Id<Task>,
+        to: Id<Task>,
+        relation_type: RelationType,
+    ) -> TaskDomainResult<()> {
+        if let Some(task) = [from, to]
+            .into_iter()
+            .find(|task| !self.tasks.contains_key(task))
+        {
+            return Err(TaskDomainError::TaskNotFoundInNet { net, task });
+        }
+        let cycle = if from == to {
+            Some(vec![from])
+        } else if relation_type.is_constraining() {
+            self.constraint_path(to, from).map(|mut path| {
+                path.pop();
+                path.insert(0, from);
+                path
+            })
+        } else {
+            None
+        };
+        if let Some(cycle) = cycle {
+            return Err(TaskDomainError::CycleNotAllowedInNet { net, cycle });
+        }
+        if self.relations.contains_edge(from, to) {
+            return Err(TaskDomainError::RelationAlreadyInNet { net, from, to });
+        }
+        if relation_type == RelationType::Compose && self.violates_single_parent(from, to) {
+            return Err(TaskDomainError::MultipleParentsNotAllowed { net, task: from });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that propagation through the whole net of id `net` cannot fail: every related
+    /// task is in the net and the constraining relations form no cycle. Nothing is checked
+    /// while propagation is deferred, since a deferred batch is rolled back as a whole.
+    fn check_propagation(&self, net: Id<Net>) -> TaskDomainResult<()> {
+        if self.propagation_deferred {
+            return Ok(());
+        }
+        if let Some(task) = self
+            .relations
+            .nodes()
+            .find(|task| !self.tasks.contains_key(task))
+        {
+            return Err(TaskDomainError::TaskNotFoundInNet { net, task });
+        }
+        self.topo_order()
+            .map(|_| ())
+            .map_err(|task| TaskDomainError::CycleNotAllowedInNet {
+                net,
+                cycle: self.cycle_through(task),
+            })
+    }
+
+    /// Checks that a task soft-removed from the net of id `net` may be restored: its relations
+    /// to tasks in the net close no cycle of constraining relations and keep a single parent
+    /// where the net requires one, and its status has room under a hard WIP limit.
+    fn check_restore(&self, net: Id<Net>, task_id: Id<Task>) -> TaskDomainResult<()> {
+        let removed = self
+            .removed_tasks
+            .get(&task_id)
+            .ok_or(TaskDomainError::TaskNotRemovedFromNet { net, task: task_id })?;
+
+        let restorable: Vec<_> = removed
+            .relations
+            .iter()
+            .filter(|(from, to, _)| {
+                let other = if *from == task_id { to } else { from };
+                self.tasks.contains_key(other)
+            })
+            .collect();
+
+        let constraining =
+            |(_, _, relation): &&&(_, _, Relation)| relation.relation_type.is_constraining();
+        let cycle = restorable
+            .iter()
+            .filter(constraining)
+            .filter(|(from, _, _)| *from == task_id)
+            .find_map(|(_, successor, _)| {
+                restorable
+                    .iter()
+                    .filter(constraining)
+                    .filter(|(_, to, _)| *to == task_id)
+                    .find_map(|(predecessor, _, _)| self.constraint_path(*successor, *predecessor))
+            });
+        if let Some(mut cycle) = cycle {
+            cycle.insert(0, task_id);
+            return Err(TaskDomainError::CycleNotAllowedInNet { net, cycle });
+        }
+        let orphaned_child = restorable
+            .iter()
+            .filter(|(from, to, relation)| {
+                relation.relation_type == RelationType::Compose
+                    && *to == task_id
+                    && self.violates_single_parent(*from, *to)
+            })
+            .map(|(from, _, _)| *from)
+            .next();
+        if let Some(child) = orphaned_child {
+            return Err(TaskDomainError::MultipleParentsNotAllowed { net, task: child });
+        }
+
+        // Propagation only moves tasks to the default and primary accepted statuses, which
+        // take no hard limit, so only the restored status can go past its limit.
+        let status = removed.status;
+        let wip_limit = self
+            .schema
+            .get(status)
+            .and_then(|status| status.data.wip_limit);
+        if let Some(WipLimit {
+            limit,
+            mode: WipLimitMode::Hard,
+        }) = wip_limit
+        {
+            if ![self.schema.default, self.schema.primary_accepted].contains(&status)
+                && self.tasks.values().filter(|task| **task == status).count() >= limit
+            {
+                return Err(TaskDomainError::WipLimitExceeded { net, status, limit });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether a task of the net is past its due date without being accepted.
+    ///
+    /// Archived tasks are never overdue.
+    pub(crate) fn is_overdue(&self, task: &Entity<Task>, at: Timestamp) -> bool {
+        self.tasks.get(&task.id).is_some_and(|status| {
+            !self.schema.is_accepted(*status)
+                && !self.archived.contains(&task.id)
+                && task
+                    .data
+                    .schedule()
+                    .is_some_and(|schedule| schedule.is_past_due(at))
+        })
+    }
+
+    /// Checks whether a task waits on a related task that is not accepted yet.
+    ///
+    /// Archived tasks are never blocked, and never block other tasks.
+    pub(crate) fn is_blocked(&self, task: Id<Task>) -> bool {
+        self.tasks.contains_key(&task)
+            && !self.archived.contains(&task)
+            && (self
+                .relations
+                .edges_directed(task, Incoming)
+                .filter(|(predecessor, _, relation)| {
+                    relation.is_enforced() && !self.archived.contains(predecessor)
+                })
+                .any(|(predecessor, _, _)| {
+                    !self
+                        .status_of(predecessor)
+                        .is_some_and(|status| self.schema.is_accepted(status))
+                })
+                || self.has_unaccepted_link(task))
+    }
+
+    /// Lists the unaccepted tasks a task waits on through constraining relations, directly or
+    /// transitively.
+    fn unaccepted_upstream_of(&self, task: Id<Task>) -> HashSet<Id<Task>> {
+        let mut upstream = HashSet::new();
+        let mut stack = vec![task];
+        while let Some(task) = stack.pop() {
+            let predecessors = self
+                .relations
+                .edges_directed(task, Incoming)
+                .filter(|(predecessor, _, relation)| {
+                    relation.is_enforced() && !self.archived.contains(predecessor)
+                })
+                .map(|(predecessor, _, _)| predecessor);
+            for predecessor in predecessors {
+                if upstream.insert(predecessor) {
+                    stack.push(predecessor);
+                }
+            }
+        }
+        upstream.retain(|task| {
+            !self
+                .status_of(*task)
+                .is_some_and(|status| self.schema.is_accepted(status))
+        });
+        upstream
+    }
+
+    /// Appends a status change to the history of a task, dropping the oldest change once the
+    /// history is full.
+    fn record_status_change(&mut self, task: Id<Task>, change: StatusChange) {
+        let history = self.status_history.entry(task).or_default();
+        if history.len() == STATUS_HISTORY_LIMIT {
+            history.pop_front();
+        }
+        history.push_back(change);
+    }
+
+    /// Records that a status moved from one category to another, and whether it started or
+    /// stopped counting as accepted with it.
+    fn record_category_change(
+        &mut self,
+        net: Id<Net>,
+        status: Id<Status>,
+        from: StatusCategory,
+        to: StatusCategory,
+    ) {
+        self.events.push(TaskDomainEvent::StatusCategoryChanged {
+            net,
+            status,
+            category: to,
+        });
+        match (from, to) {
+            (StatusCategory::Done, StatusCategory::Done) => {}
+            (_, StatusCategory::Done) => {
+                self.events
+                    .push(TaskDomainEvent::StatusMarkedAccepted { net, status });
+            }
+            (StatusCategory::Done, _) => {
+                self.events
+                    .push(TaskDomainEvent::StatusUnmarkedAccepted { net, status });
+            }
+            _ => {}
+        }
+    }
+
+    /// Finds a status holding more tasks than its hard work-in-progress limit allows, with the
+    /// limit. The default and primary accepted statuses are left out, since propagation moves
+    /// tasks onto them regardless of their limits.
+    fn exceeded_hard_wip_limit(&self) -> Option<(Id<Status>, usize)> {
+        self.schema
+            .iter()
+            .filter(|status| {
+                ![self.schema.default, self.schema.primary_accepted].contains(&status.id)
+            })
+            .find_map(|status| match status.data.wip_limit {
+                Some(WipLimit {
+                    limit,
+                    mode: WipLimitMode::Hard,
+                }) if self
+                    .tasks
+                    .values()
+                    .filter(|task| **task == status.id)
+                    .count()
+                    > limit =>
+                {
+                    Some((status.id, limit))
+                }
+                _ => None,
+            })
+    }
+
+    /// Checks whether a task requires a task of another net that was last seen unaccepted.
+    fn has_unaccepted_link(&self, task: Id<Task>) -> bool {
+        self.links
+            .get(&task)
+            .is_some_and(|links| links.values().any(|accepted| !accepted))
+    }
+
+    /// Removes a task with its relations and everything recorded about it, returning whether
+    /// it was in the net.
+    fn detach_task(&mut self, task: Id<Task>) -> bool {
+        if self.tasks.remove(&task).is_none() {
+            return false;
+        }
+        self.relations_mut().remove_node(task);
+        self.estimates.remove(&task);
+        self.links.remove(&task);
+        self.milestones.remove(&task);
+        self.archived.remove(&task);
+        self.status_history.remove(&task);
+        true
+    }
+
+    /// Sets a task aside with its status and relations so it can be restored, returning
+    /// whether it was in the net.
+    fn shelve_task(&mut self, task: Id<Task>, removed_at: Timestamp) -> bool {
+        let Some(status) = self.tasks.remove(&task) else {
+            return false;
+        };
+        let relations = self
+            .relations
+            .edges_directed(task, Incoming)
+            .chain(self.relations.edges_directed(task, Outgoing))
+            .map(|(from, to, relation)| (from, to, relation.clone()))
+            .collect();
+        self.relations_mut().remove_node(task);
+        self.removed_tasks.insert(
+            task,
+            RemovedTask {
+                status,
+                relations,
+                removed_at,
+            },
+        );
+        true
+    }
+
+    /// Puts a task set aside back with its status, restoring its relations to tasks in the net
+    /// and handing those to other removed tasks over to them.
+    fn unshelve_task(&mut self, task: Id<Task>, removed: RemovedTask) {
+        self.tasks.insert(task, removed.status);
+        self.relations_mut().add_node(task);
+        for (from, to, relation) in removed.relations {
+            let other = if from == task { to } else { from };
+            if self.tasks.contains_key(&other) {
+                self.relations_mut().add_edge(from, to, relation);
+            } else if let Some(other) = self.removed_tasks.get_mut(&other) {
+                other.relations.push((from, to, relation));
+            }
+        }
+    }
+
+    /// Removes a status from the schema, moving its tasks to the default status.
+    fn detach_status(&mut self, removed_status: Id<Status>) {
+        let default = self.schema.default;
+        for status in self.tasks.values_mut() {
+            if *status == removed_status {
+                *status = default;
+            }
+        }
+        for removed in self.removed_tasks.values_mut() {
+            if removed.status == removed_status {
+                removed.status = default;
+            }
+        }
+
+        let schema = &mut self.schema;
+        schema.remove(removed_status);
+        schema
+            .allowed_transitions
+            .retain(|(from, to)| *from != removed_status && *to != removed_status);
+        schema
+            .forbidden_transitions
+            .retain(|(from, to)| *from != removed_status && *to != removed_status);
+    }
+
+    /// Makes a status the primary accepted one, moving the tasks of the previous one onto it.
+    fn switch_accepted(&mut self, new_accepted: Id<Status>) -> Vec<Id<Task>> {
+        let old_accepted = self.schema.primary_accepted;
+        let mut moved = Vec::new();
+        for (task, status) in &mut self.tasks {
+            if *status == old_accepted && old_accepted != new_accepted {
+                *status = new_accepted;
+                moved.push(*task);
+            }
+        }
+        moved.sort();
+        for removed in self.removed_tasks.values_mut() {
+            if removed.status == old_accepted {
+                removed.status = new_accepted;
+            }
+        }
+
+        if old_accepted != new_accepted {
+            if let Some(status) = self.schema.get_mut(old_accepted) {
+                status.data.category = StatusCategory::InProgress;
+            }
+        }
+        if let Some(status) = self.schema.get_mut(new_accepted) {
+            status.data.category = StatusCategory::Done;
+        }
+        self.schema.primary_accepted = new_accepted;
+        moved
+    }
+
+    /// Replaces the schema, moving every task and history entry to the status `status_mapping`
+    /// maps its status to. Statuses left out of the mapping are kept.
+    fn switch_schema(
+        &mut self,
+        schema: Schema,
+        status_mapping: &HashMap<Id<Status>, Id<Status>>,
+        at: Timestamp,
+    ) {
+        self.schema = schema;
+        let removed = self
+            .removed_tasks
+            .iter_mut()
+            .map(|(task, removed)| (task, &mut removed.status));
+        let mut remapped = Vec::new();
+        for (task, status) in self.tasks.iter_mut().chain(removed) {
+            if let Some(mapped) = status_mapping
+                .get(status)
+                .filter(|mapped| *mapped != status)
+            {
+                remapped.push((*task, *status, *mapped));
+                *status = *mapped;
+            }
+        }
+        // Past changes keep the statuses they were made between; the move onto the new
+        // schema is recorded as a change of its own.
+        for (task, from, to) in remapped {
+            self.record_status_change(
+                task,
+                StatusChange {
+                    at,
+                    from,
+                    to,
+                    cause: ChangeCause::Remapped,
+                },
+            );
+        }
+    }
+}
+
+impl Color {
+    /// Creates a color from its red, green and blue components.
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Color { red, green, blue }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+impl Status {
+    fn new(name: StatusName, category: StatusCategory) -> Self {
+        Status {
+            name,
+            category,
+            color: None,
+            description: None,
+            wip_limit: None,
+        }
+    }
+
+    /// Returns the name of the status.
+    pub fn name(&self) -> &StatusName {
+        &self.name
+    }
+
+    /// Returns the category of the status.
+    pub fn category(&self) -> StatusCategory {
+        self.category
+    }
+
+    /// Returns the color of the status, if one is set.
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+
+    /// Returns the description of the status, if one is set.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the work-in-progress limit of the status, if one is set.
+    pub fn wip_limit(&self) -> Option<WipLimit> {
+        self.wip_limit
+    }
+}
+
+/// Trait for read-only queries on a `Net`.
+pub trait NetQuery {
+    /// Returns the status of a task, if the task is in the net.
+    fn status_of(&self, task_id: Id<Task>) -> Option<Id<Status>>;
+    /// Lists the statuses of the net's schema in board order.
+    fn statuses(&self) -> impl Iterator<Item = &Entity<Status>> + '_;
+    /// Returns the status schema of the network.
+    fn schema(&self) -> &Schema;
+    /// Returns the status new tasks are put on.
+    fn default_status(&self) -> Id<Status>;
+    /// Returns the primary status counting a task as done.
+    fn accepted_status(&self) -> Id<Status>;
+    /// Lists every status counting a task as done.
+    fn accepted_statuses(&self) -> impl Iterator<Item = Id<Status>> + '_;
+    /// Lists the tasks of the net together with their status.
+    fn tasks(&self) -> impl Iterator<Item = (Id<Task>, Id<Status>)> + '_;
+    /// Lists the relations of the net as `(from, to, relation)`.
+    fn relations(&self) -> impl Iterator<Item = (Id<Task>, Id<Task>, &Relation)> + '_;
+    /// Lists the soft-removed tasks of the network with the time they were removed.
+    fn removed_tasks(&self) -> impl Iterator<Item = (Id<Task>, Timestamp)> + '_;
+    /// Checks whether the workflow permits moving a task from one status to another.
+    fn is_transition_allowed(&self, from: Id<Status>, to: Id<Status>) -> bool;
+    /// Returns how requirement relations control their targets.
+    fn propagation_policy(&self) -> PropagationPolicy;
+    /// Checks whether every task may have at most one composition parent.
+    fn requires_single_parent(&self) -> bool;
+    /// Returns the composition parent of a task, or the first one found if it has several.
+    fn parent_of(&self, task_id: Id<Task>) -> Option<Id<Task>>;
+    /// Lists the direct subtasks of a task, following composition relations.
+    fn direct_subtasks_of(&self, task_id: Id<Task>) -> Vec<Id<Task>>;
+    /// Lists every subtask of a task at any depth, following composition relations.
+    fn descendants_of(&self, task_id: Id<Task>) -> Vec<Id<Task>>;
+    /// Lists every task a task is a subtask of at any depth, following composition relations.
+    fn ancestors_of(&self, task_id: Id<Task>) -> Vec<Id<Task>>;
+    /// Returns the fraction of accepted subtasks at any depth, or whether the task itself is
+    /// accepted when it has no subtasks, as a value between `0.0` and `1.0`.
+    fn progress_of(&self, task_id: Id<Task>) -> f64;
+    /// Returns the fraction of accepted tasks in the net, as a value between `0.0` and `1.0`.
+    fn progress(&self) -> f64;
+    /// Returns the duration estimate of a task, if one is set.
+    fn estimate_of(&self, task_id: Id<Task>) -> Option<Duration>;
+    /// Compares the estimate of a task with the time logged on it.
+    fn effort_of(&self, task: &Entity<Task>) -> Effort;
+    /// Computes the longest chain of constraining relations, counting tasks without an
+    /// estimate as taking no time.
+    fn critical_path(&self) -> CriticalPath;
+    /// Lists the tasks waiting on a related task that is not accepted yet.
+    fn blocked_tasks(&self) -> impl Iterator<Item = Id<Task>> + '_;
+    /// Lists the tasks that are not accepted yet and wait on nothing, i.e. what can be worked on next.
+    fn ready_tasks(&self) -> impl Iterator<Item = Id<Task>> + '_;
+    /// Lists the given tasks that are ready to be worked on, the most urgent first.
+    fn ready_tasks_by_priority<'a>(
+        &self,
+        tasks: impl IntoIterator<Item = &'a Entity<Task>>,
+    ) -> Vec<Id<Task>>;
+    /// Lists what deserves attention without blocking anything, such as unmet soft
+    /// requirements. Archived tasks are left out.
+    fn warnings(&self) -> impl Iterator<Item = NetWarning> + '_;
+    /// Lists the tasks requiring a task of another net, with whether that task was last seen
+    /// accepted.
+    fn linked_requirements(&self) -> impl Iterator<Item = (Id<Task>, TaskRef, bool)> + '_;
+    /// Lists the latest status changes of a task, oldest first, up to
+    /// [`STATUS_HISTORY_LIMIT`] of them.
+    fn history_of(&self, task_id: Id<Task>) -> impl Iterator<Item = &StatusChange> + '_;
+    /// Explains the current status of a task with the change that put it there, if it is
+    /// still in the task's history.
+    fn why_status(&self, task_id: Id<Task>) -> Option<&StatusChange>;
+    /// Checks whether a task is a milestone.
+    fn is_milestone(&self, task_id: Id<Task>) -> bool;
+    /// Lists the milestones of the net.
+    fn milestones(&self) -> impl Iterator<Item = Id<Task>> + '_;
+    /// Finds the unaccepted milestone with the fewest unaccepted tasks left before it.
+    fn next_milestone(&self) -> Option<Id<Task>>;
+    /// Checks whether the net is locked against changes.
+    fn is_locked(&self) -> bool;
+    /// Checks whether a task is archived.
+    fn is_archived(&self, task_id: Id<Task>) -> bool;
+    /// Lists the archived tasks of the net.
+    fn archived_tasks(&self) -> impl Iterator<Item = Id<Task>> + '_;
+    /// Lists the tasks of the network that have no relations at all.
+    fn orphans(&self) -> impl Iterator<Item = Id<Task>> + '_;
+    /// Lists the tasks of the network, ordered by id, that no relation chain connects to any of
+    /// the roots, whichever way the relations point.
+    ///
+    /// Roots that are not in the network are ignored.
+    fn unreachable_from(&self, roots: impl IntoIterator<Item = Id<Task>>) -> Vec<Id<Task>>;
+    /// Checks whether removing a relation warns when it splits a subtree off the network.
+    fn warns_on_orphaning(&self) -> bool;
+    /// Lists the given tasks that are in the network, not accepted, and past their due date at
+    /// the given time, earliest due date first.
+    fn overdue_tasks<'a>(
+        &self,
+        tasks: impl IntoIterator<Item = &'a Entity<Task>>,
+        at: Timestamp,
+    ) -> Vec<Id<Task>>;
+    /// Splits the net into its plain parts, e.g. to persist it.
+    fn to_parts(&self) -> NetParts;
+    /// Computes summary figures of the net.
+    fn stats(&self) -> NetStats;
+    /// Verifies the rules every operation upholds, e.g. after deserialization or a migration,
+    /// listing each one that is broken.
+    fn check_invariants(&self) -> Result<(), Vec<InvariantViolation>>;
+}
+
+impl NetQuery for Entity<Net> {
+    fn status_of(&self, task_id: Id<Task>) -> Option<Id<Status>> {
+        self.data.status_of(task_id)
+    }
+
+    fn statuses(&self) -> impl Iterator<Item = &Entity<Status>> + '_ {
+        self.data.schema.iter()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.data.schema
+    }
+
+    fn default_status(&self) -> Id<Status> {
+        self.data.schema.default
+    }
+
+    fn accepted_status(&self) -> Id<Status> {
+        self.data.schema.primary_accepted
+    }
+
+    fn accepted_statuses(&self) -> impl Iterator<Item = Id<Status>> + '_ {
+        self.data
+            .schema
+            .iter()
+            .filter(|status| status.data.category == StatusCategory::Done)
+            .map(|status| status.id)
+    }
+
+    fn tasks(&self) -> impl Iterator<Item = (Id<Task>, Id<Status>)> + '_ {
+        self.data
+            .tasks
+            .iter()
+            .map(|(task, status)| (*task, *status))
+    }
+
+    fn relations(&self) -> impl Iterator<Item = (Id<Task>, Id<Task>, &Relation)> + '_ {
+        self.data.relations.all_edges()
+    }
+
+    fn removed_tasks(&self) -> impl Iterator<Item = (Id<Task>, Timestamp)> + '_ {
+        self.data
+            .removed_tasks
+            .iter()
+            .map(|(task, removed)| (*task, removed.removed_at))
+    }
+
+    fn is_transition_allowed(&self, from: Id<Status>, to: Id<Status>) -> bool {
+        self.data.schema.is_transition_allowed(from, to)
+    }
+
+    fn propagation_policy(&self) -> PropagationPolicy {
+        self.data.propagation_policy
+    }
+
+    fn requires_single_parent(&self) -> bool {
+        self.data.single_parent
+    }
+
+    fn parent_of(&self, task_id: Id<Task>) -> Option<Id<Task>> {
+        if !self.data.relations.contains_node(task_id) {
+            return None;
+        }
+        self.data.parents_of(task_id).next()
+    }
+
+    fn direct_subtasks_of(&self, task_id: Id<Task>) -> Vec<Id<Task>> {
+        if !self.data.relations.contains_node(task_id) {
+            return Vec::new();
+        }
+        self.data.children_of(task_id).collect()
+    }
+
+    fn descendants_of(&self, task_id: Id<Task>) -> Vec<Id<Task>> {
+        if !self.data.relations.contains_node(task_id) {
+            return Vec::new();
+        }
+        self.data.compose_closure(task_id, Incoming)
+    }
+
+    fn ancestors_of(&self, task_id: Id<Task>) -> Vec<Id<Task>> {
+        if !self.data.relations.contains_node(task_id) {
+            return Vec::new();
+        }
+        self.data.compose_closure(task_id, Outgoing)
+    }
+
+    fn progress_of(&self, task_id: Id<Task>) -> f64 {
+        let descendants = self.descendants_of(task_id);
+        let tasks = if descendants.is_empty() {
+            vec![task_id]
+        } else {
+            descendants
+        };
+        accepted_fraction(&self.data, tasks)
+    }
+
+    fn progress(&self) -> f64 {
+        accepted_fraction(&self.data, self.data.tasks.keys().copied())
+    }
+
+    fn estimate_of(&self, task_id: Id<Task>) -> Option<Duration> {
+        self.data.estimates.get(&task_id).copied()
+    }
+
+    fn effort_of(&self, task: &Entity<Task>) -> Effort {
+        Effort {
+            estimated: self.estimate_of(task.id),
+            logged: task.data.logged_time(),
+        }
+    }
+
+    fn critical_path(&self) -> CriticalPath {
+        // Relations are kept acyclic by every mutation, so sorting cannot fail.
+        let Ok(order) = self.data.topo_order() else {
+            return CriticalPath::default();
+        };
+
+        let mut longest: HashMap<Id<Task>, (Duration, Option<Id<Task>>)> = HashMap::new();
+        for &task in order {
+            let estimate = self.estimate_of(task).unwrap_or_default();
+            let (before, predecessor) = self
+                .data
+                .relations
+                .edges_directed(task, Incoming)
+                .filter(|(_, _, relation)| relation.relation_type.is_constraining())
+                .map(|(predecessor, _, _)| (longest[&predecessor].0, Some(predecessor)))
+                .max_by_key(|(duration, _)| *duration)
+                .unwrap_or_default();
+            longest.insert(task, (before + estimate, predecessor));
+        }
+
+        // Among chains of equal length, prefer the one ending last in dependency order.
+        let Some((mut task, duration)) = order
+            .iter()
+            .map(|task| (*task, longest[task].0))
+            .reduce(|best, next| if next.1 >= best.1 { next } else { best })
+        else {
+            return CriticalPath::default();
+        };
+        let mut tasks = vec![task];
+        while let Some(predecessor) = longest[&task].1 {
+            tasks.push(predecessor);
+            task = predecessor;
+        }
+        tasks.reverse();
+
+        CriticalPath { tasks, duration }
+    }
+
+    fn blocked_tasks(&self) -> impl Iterator<Item = Id<Task>> + '_ {
+        self.data
+            .tasks
+            .keys()
+            .copied()
+            .filter(|task| self.data.is_blocked(*task))
+    }
+
+    fn ready_tasks(&self) -> impl Iterator<Item = Id<Task>> + '_ {
+        self.data
+            .tasks
+            .iter()
+            .filter(|(task, status)| {
+                !self.data.archived.contains(task)
+                    && !self.data.schema.is_accepted(**status)
+                    && !self.data.is_blocked(**task)
+            })
+            .map(|(task, _)| *task)
+    }
+
+    fn ready_tasks_by_priority<'a>(
+        &self,
+        tasks: impl IntoIterator<Item = &'a Entity<Task>>,
+    ) -> Vec<Id<Task>> {
+        let ready: HashSet<_> = self.ready_tasks().collect();
+        let mut tasks: Vec<_> = tasks
+            .into_iter()
+            .filter(|task| ready.contains(&task.id))
+            .collect();
+        tasks.sort_by(|a, b| by_priority(a, b));
+        tasks.dedup_by_key(|task| task.id);
+        tasks.into_iter().map(|task| task.id).collect()
+    }
+
+    fn warnings(&self) -> impl Iterator<Item = NetWarning> + '_ {
+        let is_accepted = |task| {
+            self.data
+                .status_of(task)
+                .is_some_and(|status| self.data.schema.is_accepted(status))
+        };
+        let is_started = |task| {
+            self.data.status_of(task).is_some_and(|status| {
+                self.data
+                    .schema
+                    .get(status)
+                    .is_some_and(|status| status.data.category != StatusCategory::Todo)
+            })
+        };
+        self.relations()
+            .filter(|(from, to, relation)| {
+                relation.relation_type.is_constraining()
+                    && relation.relation_type != RelationType::Compose
+                    && relation.strength == RelationStrength::Soft
+                    && !self.data.archived.contains(from)
+                    && !self.data.archived.contains(to)
+            })
+            .filter(move |(from, to, _)| !is_accepted(*from) && is_started(*to))
+            .map(|(required, task, _)| NetWarning::SoftRequirementUnmet { required, task })
+    }
+
+    fn linked_requirements(&self) -> impl Iterator<Item = (Id<Task>, TaskRef, bool)> + '_ {
+        self.data.links.iter().flat_map(|(task, links)| {
+            links
+                .iter()
+                .map(|(required, accepted)| (*task, *required, *accepted))
+        })
+    }
+
+    fn history_of(&self, task_id: Id<Task>) -> impl Iterator<Item = &StatusChange> + '_ {
+        self.data.status_history.get(&task_id).into_iter().flatten()
+    }
+
+    fn why_status(&self, task_id: Id<Task>) -> Option<&StatusChange> {
+        let status = self.data.status_of(task_id)?;
+        self.history_of(task_id)
+            .last()
+            .filter(|change| change.to == status)
+    }
+
+    fn is_milestone(&self, task_id: Id<Task>) -> bool {
+        self.data.milestones.contains(&task_id)
+    }
+
+    fn milestones(&self) -> impl Iterator<Item = Id<Task>> + '_ {
+        self.data
+            .milestones
+            .iter()
+            .copied()
+            .filter(|task| self.data.tasks.contains_key(task))
+    }
+
+    fn next_milestone(&self) -> Option<Id<Task>> {
+        self.milestones()
+            .filter(|task| {
+                !self.data.archived.contains(task)
+                    && !self
+                        .data
+                        .status_of(*task)
+                        .is_some_and(|status| self.data.schema.is_accepted(status))
+            })
+            .min_by_key(|task| (self.data.unaccepted_upstream_of(*task).len(), *task))
+    }
+
+    fn is_locked(&self) -> bool {
+        self.data.locked
+    }
+
+    fn is_archived(&self, task_id: Id<Task>) -> bool {
+        self.data.archived.contains(&task_id)
+    }
+
+    fn archived_tasks(&self) -> impl Iterator<Item = Id<Task>> + '_ {
+        self.data
+            .archived
+            .iter()
+            .copied()
+            .filter(|task| self.data.tasks.contains_key(task))
+    }
+
+    fn orphans(&self) -> impl Iterator<Item = Id<Task>> + '_ {
+        self.data.tasks.keys().copied().filter(|task| {
+            !self.data.relations.contains_node(*task)
+                || [Incoming, Outgoing].into_iter().all(|direction| {
+                    self.data
+                        .relations
+                        .neighbors_directed(*task, direction)
+                        .next()
+                        .is_none()
+                })
+        })
+    }
+
+    fn unreachable_from(&self, roots: impl IntoIterator<Item = Id<Task>>) -> Vec<Id<Task>> {
+        let reachable = self.data.connected_to(roots);
+        let mut unreachable: Vec<_> = self
+            .data
+            .tasks
+            .keys()
+            .copied()
+            .filter(|task| !reachable.contains(task))
+            .collect();
+        unreachable.sort();
+        unreachable
+    }
+
+    fn warns_on_orphaning(&self) -> bool {
+        self.data.warn_on_orphaning
+    }
+
+    fn overdue_tasks<'a>(
+        &self,
+        tasks: impl IntoIterator<Item = &'a Entity<Task>>,
+        at: Timestamp,
+    ) -> Vec<Id<Task>> {
+        let mut overdue: Vec<_> = tasks
+            .into_iter()
+            .filter(|task| self.data.is_overdue(task, at))
+            .map(|task| (task.data.schedule().and_then(|s| s.due_date()), task.id))
+            .collect();
+        overdue.sort();
+        overdue.dedup();
+        overdue.into_iter().map(|(_, task)| task).collect()
+    }
+
+    fn to_parts(&self) -> NetParts {
+        self.data.to_parts()
+    }
+
+    fn stats(&self) -> NetStats {
+        let mut tasks_per_status: HashMap<_, _> =
+            self.statuses().map(|status| (status.id, 0)).collect();
+        for (task, status) in &self.data.tasks {
+            if !self.data.archived.contains(task) {
+                *tasks_per_status.entry(*status).or_default() += 1;
+            }
+        }
+
+        let mut relations_per_type = HashMap::new();
+        let relations = self.relations().filter(|(from, to, _)| {
+            !self.data.archived.contains(from) && !self.data.archived.contains(to)
+        });
+        for (_, _, relation) in relations {
+            *relations_per_type
+                .entry(relation.relation_type)
+                .or_default() += 1;
+        }
+
+        let mut depth = HashMap::new();
+        for &task in self.data.topo_order().unwrap_or_default() {
+            if self.data.archived.contains(&task) {
+                continue;
+            }
+            let before = self
+                .data
+                .relations
+                .edges_directed(task, Incoming)
+                .filter(|(_, _, relation)| relation.relation_type.is_constraining())
+                .filter_map(|(from, _, _)| depth.get(&from).copied())
+                .max()
+                .unwrap_or(0);
+            depth.insert(task, before + 1);
+        }
+
+        NetStats {
+            tasks_per_status,
+            relations_per_type,
+            blocked: self.blocked_tasks().count(),
+            ready: self.ready_tasks().count(),
+            depth: depth.into_values().max().unwrap_or(0),
+        }
+    }
+
+    fn check_invariants(&self) -> Result<(), Vec<InvariantViolation>> {
+        let violations = invariants::violations(self);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// Computes the fraction of the given tasks that are on an accepted status.
+fn accepted_fraction(net: &Net, tasks: impl IntoIterator<Item = Id<Task>>) -> f64 {
+    let (accepted, total) = tasks
+        .into_iter()
+        .fold((0usize, 0usize), |(accepted, total), task| {
+            let is_accepted = net
+                .status_of(task)
+                .is_some_and(|status| net.schema.is_accepted(status));
+            (accepted + usize::from(is_accepted), total + 1)
+        });
+    if total == 0 {
+        0.0
+    } else {
+        accepted as f64 / total as f64
+    }
+}
+
+/// Trait for aggregate root operations on a `Net`.
+pub trait NetAggregateRoot {
+    /// Create an empty net by providing the default and accepted status name.
+    fn new(default: StatusName, accepted: StatusName) -> Self;
+    /// Create an empty net with its full initial board: the default status, the in-progress
+    /// statuses in order, then the accepted status.
+    fn with_statuses(default: StatusName, normal: Vec<StatusName>, accepted: StatusName) -> Self;
+    /// Create an empty net starting with the workflow of a template.
+    fn from_template(template: SchemaTemplate) -> Self
+    where
+        Self: Sized,
+    {
+        let (default, normal, accepted) = template.statuses();
+        Self::with_statuses(default, normal, accepted)
+    }
+    /// Rebuilds a net from its plain parts, checking every invariant the other operations
+    /// uphold. The net starts without pending events.
+    fn from_parts(id: Id<Net>, parts: NetParts) -> TaskDomainResult<Self>
+    where
+        Self: Sized;
+    /// Rebuilds a net from the events it recorded, starting with its creation. The net starts
+    /// without pending events.
+    ///
+    /// Status histories are not part of the events and start empty. Undone and redone edits
+    /// are replayed too, from the changes their events carry.
+    fn replay(events: impl IntoIterator<Item = TaskDomainEvent>) -> TaskDomainResult<Self>
+    where
+        Self: Sized;
+    /// Applies an event the net recorded to its state, e.g. to bring a replayed net up to
+    /// date. Nothing is validated beyond what the event refers to, nothing propagates and no
+    /// event is recorded; a failed event leaves the net unchanged.
+    fn apply_event(&mut self, event: &TaskDomainEvent) -> TaskDomainResult<()>;
+    /// Freezes the network, e.g. during a release cut or an import: every operation changing
+    /// it fails with `NetLocked` until it is unlocked.
+    fn lock(&mut self);
+    /// Lets the network be changed again.
+    fn unlock(&mut self);
+    /// Adds a new status to the network.
+    fn new_status(&mut self, status_name: StatusName) -> TaskDomainResult<()>;
+    /// Removes a status from the network.
+    fn remove_status(&mut self, status_id: Id<Status>, clock: &impl Clock) -> TaskDomainResult<()>;
+    /// Changes the name of a status in the network.
+    fn change_status_name(
+        &mut self,
+        status_id: Id<Status>,
+        new_name: StatusName,
+    ) -> TaskDomainResult<()>;
+    /// Changes the color of a status in the network.
+    fn change_status_color(
+        &mut self,
+        status_id: Id<Status>,
+        color: Option<Color>,
+    ) -> TaskDomainResult<()>;
+    /// Changes the description of a status in the network.
+    fn change_status_description(
+        &mut self,
+        status_id: Id<Status>,
+        description: Option<String>,
+    ) -> TaskDomainResult<()>;
+    /// Changes the work-in-progress limit of a status in the network.
+    fn change_status_wip_limit(
+        &mut self,
+        status_id: Id<Status>,
+        wip_limit: Option<WipLimit>,
+    ) -> TaskDomainResult<()>;
+    /// Moves a status to a new position in the schema's board order.
+    fn move_status(&mut self, status_id: Id<Status>, new_index: usize) -> TaskDomainResult<()>;
+    /// Changes the default status of the network.
+    fn change_default(&mut self, new_default: Id<Status>) -> TaskDomainResult<()>;
+    /// Changes the primary accepted status of the network, moving its tasks onto the new one.
+    ///
+    /// The default status cannot become the accepted one. Every moved task records a
+    /// `StatusPropagated` event, as if the change of the net had propagated to it.
+    fn change_accepted(
+        &mut self,
+        new_accepted: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Changes the category of a status in the network.
+    fn set_status_category(
+        &mut self,
+        status_id: Id<Status>,
+        category: StatusCategory,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Makes a status count as accepted by moving it to the `Done` category.
+    fn mark_status_accepted(
+        &mut self,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Makes a `Done` status stop counting as accepted by moving it to `InProgress`.
+    fn unmark_status_accepted(
+        &mut self,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Allows moving tasks from one status to another, restricting the source status to its
+    /// allowed targets.
+    fn allow_transition(&mut self, from: Id<Status>, to: Id<Status>) -> TaskDomainResult<()>;
+    /// Forbids moving tasks from one status to another.
+    fn forbid_transition(&mut self, from: Id<Status>, to: Id<Status>) -> TaskDomainResult<()>;
+    /// Replaces the status schema of the network with an exported one, moving every task,
+    /// including soft-removed ones, to the status `status_mapping` maps its status to.
+    ///
+    /// The schema is validated as part of this network. Statuses left out of the mapping must
+    /// also be part of the new schema, and no status may end up holding more tasks than its
+    /// hard work-in-progress limit allows.
+    fn replace_schema(
+        &mut self,
+        schema: SchemaParts,
+        status_mapping: HashMap<Id<Status>, Id<Status>>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Changes how requirement relations control their targets.
+    fn change_propagation_policy(
+        &mut self,
+        policy: PropagationPolicy,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Makes the network require, or stop requiring, a single composition parent per task.
+    fn require_single_parent(&mut self, enabled: bool) -> TaskDomainResult<()>;
+    /// Makes removing a relation warn, or stop warning, when it splits a subtree off the network.
+    ///
+    /// The removal still goes through; the split is recorded as a `SubtreeOrphaned` event.
+    fn warn_on_orphaning(&mut self, enabled: bool) -> TaskDomainResult<()>;
+    /// Sets or clears the duration estimate of a task in the network.
+    fn estimate_task(
+        &mut self,
+        task_id: Id<Task>,
+        estimate: Option<Duration>,
+    ) -> TaskDomainResult<()>;
+    /// Adds a new task to the network.
+    fn add_task(&mut self, task_id: Id<Task>) -> TaskDomainResult<()>;
+    /// Adds several new tasks to the network, adding none of them if any is already in it
+    /// or given twice.
+    fn add_tasks(&mut self, task_ids: impl IntoIterator<Item = Id<Task>>) -> TaskDomainResult<()>;
+    /// Removes a task from the network.
+    fn remove_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()>;
+    /// Removes a task from the network, keeping its status and relations so it can be restored.
+    fn soft_remove_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()>;
+    /// Restores a soft-removed task together with its status and relations.
+    ///
+    /// A task is not restored to a status already at its hard WIP limit.
+    fn restore_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()>;
+    /// Adds a new relation between tasks in the network.
+    fn new_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        relation_type: RelationType,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Adds several new relations between tasks in the network, propagating once for all of
+    /// them. If any relation is invalid, relates tasks that are already related in the same
+    /// direction, is given twice or they form a cycle, none of them is added.
+    fn new_relations(
+        &mut self,
+        relations: impl IntoIterator<Item = (Id<Task>, Id<Task>, RelationType)>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Makes a task require a task of another net, which was last seen accepted or not.
+    ///
+    /// Links are kept in step by [`LinkCoordinator`](crate::domain::link::LinkCoordinator).
+    fn link_requirement(
+        &mut self,
+        task_id: Id<Task>,
+        required: TaskRef,
+        accepted: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Stops a task from requiring a task of another net.
+    fn unlink_requirement(
+        &mut self,
+        task_id: Id<Task>,
+        required: TaskRef,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Records whether a task of another net linked from this one is accepted, propagating
+    /// to the tasks requiring it.
+    fn update_linked_requirement(
+        &mut self,
+        required: TaskRef,
+        accepted: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Makes a task a milestone, or a plain task again.
+    ///
+    /// A milestone is accepted as soon as every task constraining it is accepted, and its
+    /// status cannot be changed by hand. A milestone without any task constraining it stays on
+    /// the default status.
+    fn set_milestone(
+        &mut self,
+        task_id: Id<Task>,
+        milestone: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Archives a task, keeping it and its relations while leaving it out of propagation,
+    /// readiness queries and stats. An archived task no longer constrains the tasks it relates
+    /// to.
+    fn archive_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()>;
+    /// Brings an archived task back into propagation, readiness queries and stats.
+    fn unarchive_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()>;
+    /// Makes a requirement relation hard, holding its target back, or soft, only reporting
+    /// it through [`NetQuery::warnings`].
+    fn set_relation_strength(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        strength: RelationStrength,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Sets or clears the note recording why a relation exists.
+    fn annotate_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        note: Option<String>,
+    ) -> TaskDomainResult<()>;
+    /// Removes a relation between tasks in the network.
+    fn remove_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Changes the status of a task in the network.
+    fn change_task_status(
+        &mut self,
+        task_id: Id<Task>,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.change_task_status_with_reason(task_id, status_id, None, clock)
+    }
+    /// Changes the status of a task in the network, recording why in its status history.
+    fn change_task_status_with_reason(
+        &mut self,
+        task_id: Id<Task>,
+        status_id: Id<Status>,
+        reason: Option<String>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+    /// Applies a batch of operations in order, propagating once at the end.
+    ///
+    /// Each operation is validated against the net as left by the previous ones, before
+    /// propagation. If any operation fails, the whole batch is rolled back.
+    fn apply(&mut self, ops: Vec<NetOp>, clock: &impl Clock) -> TaskDomainResult<()>;
+    /// Duplicates a task and its Compose descendants under new ids, returning the id of each
+    /// copy by the id of its original.
+    ///
+    /// The copies start in the default status and keep the estimates, milestones and
+    /// relations among themselves; relations to tasks outside the subtree are not copied.
+    fn clone_subtree(
+        &mut self,
+        root: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<HashMap<Id<Task>, Id<Task>>>;
+    /// Adds a copy of each task of the network under the id it is mapped to.
+    ///
+    /// The copies start in the default status and keep the estimates, milestones and
+    /// relations among themselves; relations to tasks that are not copied are left out. If
+    /// any task is missing or any copy is already in the network, nothing is added.
+    fn copy_tasks(
+        &mut self,
+        copies: &HashMap<Id<Task>, Id<Task>>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()>;
+}
+
+/// Propagates changes through all tasks in the network.
+fn propagate_all(net: &mut Entity<Net>, clock: &impl Clock) -> TaskDomainResult<()> {
+    let id = net.id;
+    propagate(net, clock, None, |net| {
+        net.topo_order()
+            .map(<[_]>::to_vec)
+            .map_err(|task| TaskDomainError::CycleNotAllowedInNet {
+                net: id,
+                cycle: net.cycle_through(task),
+            })
+    })
+}
+
+/// Propagates changes from a specific task in the network to the tasks it controls.
+fn propagate_from(
+    net: &mut Entity<Net>,
+    clock: &impl Clock,
+    task: &Id<Task>,
+) -> TaskDomainResult<()> {
+    propagate(net, clock, Some(*task), |net| {
+        Ok(net.downstream_of(*task).into_iter().skip(1).collect())
+    })
+}
+
+/// Propagates changes at a specific task in the network and the tasks it controls, caused by
+/// a change of `origin` if any.
+fn propagate_at(
+    net: &mut Entity<Net>,
+    clock: &impl Clock,
+    task: &Id<Task>,
+    origin: Option<Id<Task>>,
+) -> TaskDomainResult<()> {
+    propagate(net, clock, origin, |net| Ok(net.downstream_of(*task)))
+}
+
+/// Propagates changes through the tasks listed by `order`, which must be in topological order.
+fn propagate<F>(
+    net: &mut Entity<Net>,
+    clock: &impl Clock,
+    origin: Option<Id<Task>>,
+    order: F,
+) -> TaskDomainResult<()>
+where
+    F: FnOnce(&Net) -> TaskDomainResult<Vec<Id<Task>>>,
+{
+    if net.data.propagation_deferred {
+        return Ok(());
+    }
+
+    let tasks = order(&net.data)?;
+    // Statuses are moved first and recorded once all of them are, so a failure part way
+    // through only has the statuses moved so far to put back.
+    let mut moved = Vec::new();
+    let planned = tasks.into_iter().try_for_each(|task| {
+        if let Some(accepted) = is_controlled_task_accepted(net, &task)? {
+            let stored_task_status = net
+                .data
+                .tasks
+                .get_mut(&task)
+                .ok_or(TaskDomainError::TaskNotFoundInNet { net: net.id, task })?;
+
+            let new_status = match accepted {
+                true if net.data.schema.is_accepted(*stored_task_status) => *stored_task_status,
+                true => net.data.schema.primary_accepted,
+                false => net.data.schema.default,
+            };
+
+            if *stored_task_status != new_status {
+                let old_status = std::mem::replace(stored_task_status, new_status);
+                moved.push((task, old_status, new_status));
+            }
+        }
+        Ok(())
+    });
+    if let Err(error) = planned {
+        for (task, old_status, _) in moved.into_iter().rev() {
+            net.data.tasks.insert(task, old_status);
+        }
+        return Err(error);
+    }
+
+    for (task, old_status, new_status) in moved {
+        net.data.record_status_change(
+            task,
+            StatusChange {
+                at: clock.now(),
+                from: old_status,
+                to: new_status,
+                cause: ChangeCause::Propagated { origin },
+            },
+        );
+        net.data.events.push(TaskDomainEvent::StatusPropagated {
+            net: net.id,
+            task,
+            from: old_status,
+            to: new_status,
+        });
+    }
+
+    Ok(())
+}
+
+/// Propagates the change an operation just made. If propagation fails, `undo` reverts the
+/// change and the events recorded after the first `events` are dropped, so the operation
+/// leaves the net as it found it.
+///
+/// Operations propagating through the whole net, or from several tasks at once, check up front
+/// that it can be propagated instead, see [`Net::check_propagation`].
+fn propagate_or_undo(
+    net: &mut Entity<Net>,
+    events: usize,
+    propagation: impl FnOnce(&mut Entity<Net>) -> TaskDomainResult<()>,
+    undo: impl FnOnce(&mut Net),
+) -> TaskDomainResult<()> {
+    let propagated = propagation(net);
+    if propagated.is_err() {
+        undo(&mut net.data);
+        net.data.events.truncate(events);
+    }
+    propagated
+}
+
+/// Checks if a controlled task is accepted in the network.
+fn is_controlled_task_accepted(
+    net: &Entity<Net>,
+    task: &Id<Task>,
+) -> TaskDomainResult<Option<bool>> {
+    if net.data.archived.contains(task) {
+        return Ok(None);
+    }
+    if net.data.milestones.contains(task) {
+        // A milestone nothing leads up to has nothing to mark, so it is not accepted.
+        let constrained =
+            net.data
+                .relations
+                .edges_directed(*task, Incoming)
+                .any(|(predecessor, _, relation)| {
+                    relation.is_enforced() && !net.data.archived.contains(&predecessor)
+                })
+                || net
+                    .data
+                    .links
+                    .get(task)
+                    .is_some_and(|links| !links.is_empty());
+        return Ok(Some(constrained && !net.data.is_blocked(*task)));
+    }
+    if net.data.propagation_policy == PropagationPolicy::Strict
+        && net.data.has_unaccepted_link(*task)
+    {
+        return Ok(Some(false));
+    }
+
+    let incoming_edges = net.data.relations.edges_directed(*task, Incoming);
+
+    let mut have_subtasks = false;
+    for incoming_edge in incoming_edges {
+        let relation_type = incoming_edge.2.relation_type;
+        let task_id = incoming_edge.0;
+
+        if !incoming_edge.2.is_enforced()
+            || net.data.archived.contains(&task_id)
+            || (relation_type != RelationType::Compose
+                && net.data.propagation_policy == PropagationPolicy::Advisory)
+        {
+            continue;
+        }
+
+        let status = *net
+            .data
+            .tasks
+            .get(&task_id)
+            .ok_or(TaskDomainError::TaskNotFoundInNet {
+                net: net.id,
+                task: task_id,
+            })?;
+        if !net.data.schema.is_accepted(status) {
+            return Ok(Some(false));
+        }
+
+        if relation_type == RelationType::Compose {
+            have_subtasks = true;
+        }
+    }
+    if have_subtasks {
+        return Ok(Some(true));
+    }
+    Ok(None)
+}
+
+impl NetAggregateRoot for Entity<Net> {
+    fn from_parts(id: Id<Net>, parts: NetParts) -> TaskDomainResult<Self> {
+        Ok(Entity {
+            id,
+            data: Net::from_parts(id, parts)?,
+        })
+    }
+
+    fn replay(events: impl IntoIterator<Item = TaskDomainEvent>) -> TaskDomainResult<Self> {
+        replay::replay(events)
+    }
+
+    fn apply_event(&mut self, event: &TaskDomainEvent) -> TaskDomainResult<()> {
+        let pending = std::mem::take(&mut self.data.events);
+        let applied = self.transaction(|net| replay::apply_event(net, event));
+        self.data.events = pending;
+        applied
+    }
+
+    fn lock(&mut self) {
+        if !self.data.locked {
+            self.data.locked = true;
+            self.data
+                .events
+                .push(TaskDomainEvent::NetLocked { net: self.id });
+        }
+    }
+
+    fn unlock(&mut self) {
+        if self.data.locked {
+            self.data.locked = false;
+            self.data
+                .events
+                .push(TaskDomainEvent::NetUnlocked { net: self.id });
+        }
+    }
+
+    fn apply(&mut self, ops: Vec<NetOp>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        self.transaction(|net| {
+            net.data.propagation_deferred = true;
+            let applied = ops
+                .into_iter()
+                .try_for_each(|op| batch::apply_op(net, op, clock));
+            net.data.propagation_deferred = false;
+            applied?;
+            propagate_all(net, clock)
+        })
+    }
+
+    fn clone_subtree(
+        &mut self,
+        root: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<HashMap<Id<Task>, Id<Task>>> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if !self.data.tasks.contains_key(&root) {
+            return Err(TaskDomainError::TaskNotFoundInNet {
+                net: self.id,
+                task: root,
+            });
+        }
+
+        let mut originals = vec![root];
+        originals.extend(self.descendants_of(root));
+        let copies: HashMap<_, _> = originals.iter().map(|task| (*task, Id::new())).collect();
+        self.copy_tasks(&copies, clock)?;
+
+        Ok(copies)
+    }
+
+    fn copy_tasks(
+        &mut self,
+        copies: &HashMap<Id<Task>, Id<Task>>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let mut originals: Vec<_> = copies.keys().copied().collect();
+        originals.sort();
+        if let Some(task) = originals
+            .iter()
+            .find(|task| !self.data.tasks.contains_key(task))
+        {
+            return Err(TaskDomainError::TaskNotFoundInNet {
+                net: self.id,
+                task: *task,
+            });
+        }
+        let relations: Vec<_> = self
+            .relations()
+            .filter_map(|(from, to, relation)| {
+                Some((
+                    *copies.get(&from)?,
+                    *copies.get(&to)?,
+                    relation.relation_type,
+                    relation.strength,
+                    relation.note.clone(),
+                ))
+            })
+            .collect();
+
+        self.transaction(|net| {
+            net.data.propagation_deferred = true;
+            let cloned = (|| {
+                for original in &originals {
+                    let copy = copies[original];
+                    net.add_task(copy)?;
+                    if let Some(estimate) = net.estimate_of(*original) {
+                        net.estimate_task(copy, Some(estimate))?;
+                    }
+                    if net.is_milestone(*original) {
+                        net.set_milestone(copy, true, clock)?;
+                    }
+                }
+                for (from, to, relation_type, strength, note) in relations {
+                    net.new_relation(from, to, relation_type, clock)?;
+                    if strength != RelationStrength::Hard {
+                        net.set_relation_strength(from, to, strength, clock)?;
+                    }
+                    if note.is_some() {
+                        net.annotate_relation(from, to, note)?;
+                    }
+                }
+                Ok(())
+            })();
+            net.data.propagation_deferred = false;
+            cloned?;
+            propagate_all(net, clock)
+        })
+    }
+
+    fn change_status_name(
+        &mut self,
+        status_id: Id<Status>,
+        new_name: StatusName,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        self.data
+            .schema
+            .get_mut(status_id)
+            .map(|status| {
+                status.data.name = new_name.clone();
+            })
+            .ok_or(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: status_id,
+            })?;
+
+        self.data.events.push(TaskDomainEvent::StatusRenamed {
+            net: self.id,
+            status: status_id,
+            name: new_name,
+        });
+
+        Ok(())
+    }
+
+    fn change_status_color(
+        &mut self,
+        status_id: Id<Status>,
+        color: Option<Color>,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        self.data
+            .schema
+            .get_mut(status_id)
+            .map(|status| {
+                status.data.color = color;
+            })
+            .ok_or(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: status_id,
+            })?;
+
+        self.data.events.push(TaskDomainEvent::StatusColorChanged {
+            net: self.id,
+            status: status_id,
+            color,
+        });
+
+        Ok(())
+    }
+
+    fn change_status_description(
+        &mut self,
+        status_id: Id<Status>,
+        description: Option<String>,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        self.data
+            .schema
+            .get_mut(status_id)
+            .map(|status| {
+                status.data.description = description.clone();
+            })
+            .ok_or(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: status_id,
+            })?;
+
+        self.data
+            .events
+            .push(TaskDomainEvent::StatusDescriptionChanged {
+                net: self.id,
+                status: status_id,
+                description,
+            });
+
+        Ok(())
+    }
+
+    fn change_default(&mut self, new_default: Id<Status>) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if !self.data.schema.contains(new_default) {
+            return Err(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: new_default,
+            });
+        }
+
+        for (_, status) in self.data.tasks.iter_mut() {
+            if *status == self.data.schema.default {
+                *status = new_default;
+            }
+        }
+        for (_, removed) in self.data.removed_tasks.iter_mut() {
+            if removed.status == self.data.schema.default {
+                removed.status = new_default;
+            }
+        }
+
+        self.data.schema.default = new_default;
+        self.data
+            .events
+            .push(TaskDomainEvent::DefaultStatusChanged {
+                net: self.id,
+                status: new_default,
+            });
+
+        Ok(())
+    }
+
+    fn change_accepted(
+        &mut self,
+        new_accepted: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if !self.data.schema.contains(new_accepted) {
+            return Err(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: new_accepted,
+            });
+        }
+        if new_accepted == self.data.schema.default {
+            return Err(TaskDomainError::DefaultStatusNotAcceptable {
+                net: self.id,
+                status: new_accepted,
+            });
+        }
+        self.data.check_propagation(self.id)?;
+
+        let old_accepted = self.data.schema.primary_accepted;
+        let previous_category = self
+            .data
+            .schema
+            .get(new_accepted)
+            .map_or(StatusCategory::InProgress, |status| status.data.category);
+        let moved = self.data.switch_accepted(new_accepted);
+        self.data
+            .events
+            .push(TaskDomainEvent::AcceptedStatusChanged {
+                net: self.id,
+                status: new_accepted,
+            });
+        if previous_category != StatusCategory::Done {
+            self.data.record_category_change(
+                self.id,
+                new_accepted,
+                previous_category,
+                StatusCategory::Done,
+            );
+        }
+        if old_accepted != new_accepted {
+            self.data.record_category_change(
+                self.id,
+                old_accepted,
+                StatusCategory::Done,
+                StatusCategory::InProgress,
+            );
+        }
+        let at = clock.now();
+        for task in moved {
+            self.data.record_status_change(
+                task,
+                StatusChange {
+                    at,
+                    from: old_accepted,
+                    to: new_accepted,
+                    cause: ChangeCause::Propagated { origin: None },
+                },
+            );
+            self.data.events.push(TaskDomainEvent::StatusPropagated {
+                net: self.id,
+                task,
+                from: old_accepted,
+                to: new_accepted,
+            });
+        }
+
+        propagate_all(self, clock)
+    }
+
+    fn add_task(&mut self, task_id: Id<Task>) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if self.data.tasks.contains_key(&task_id) || self.data.removed_tasks.contains_key(&task_id)
+        {
+            return Err(TaskDomainError::TaskAlreadyInNet {
+                task: task_id,
+                net: self.id,
+            });
+        }
+
+        self.data.tasks.insert(task_id, self.data.schema.default);
+        self.data.relations_mut().add_node(task_id);
+        self.data.events.push(TaskDomainEvent::TaskAddedToNet {
+            net: self.id,
+            task: task_id,
+        });
+
+        Ok(())
+    }
+
+    fn change_task_status_with_reason(
+        &mut self,
+        task_id: Id<Task>,
+        status_id: Id<Status>,
+        reason: Option<String>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let wip_limit = self
+            .data
+            .schema
+            .get(status_id)
+            .ok_or(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: status_id,
+            })?
+            .data
+            .wip_limit;
+        if self.data.milestones.contains(&task_id) {
+            return Err(TaskDomainError::MilestoneNotChangeable {
+                net: self.id,
+                task: task_id,
+            });
+        }
+
+        if is_controlled_task_accepted(self, &task_id)?.is_none() {
+            let current_status =
+                self.data
+                    .status_of(task_id)
+                    .ok_or(TaskDomainError::TaskNotFoundInNet {
+                        net: self.id,
+                        task: task_id,
+                    })?;
+            if self.data.propagation_policy == PropagationPolicy::Advisory
+                && self.data.schema.is_accepted(status_id)
+                && self.data.is_blocked(task_id)
+            {
+                return Err(TaskDomainError::RelationConstraintNotSatisfied {
+                    net: self.id,
+                    task: task_id,
+                });
+            }
+            if !self
+                .data
+                .schema
+                .is_transition_allowed(current_status, status_id)
+            {
+                return Err(TaskDomainError::TransitionNotAllowed {
+                    net: self.id,
+                    from: current_status,
+                    to: status_id,
+                });
+            }
+
+            let wip_exceeded = wip_limit.filter(|wip_limit| {
+                current_status != status_id
+                    && self
+                        .data
+                        .tasks
+                        .values()
+                        .filter(|status| **status == status_id)
+                        .count()
+                        >= wip_limit.limit
+            });
+            if let Some(WipLimit {
+                limit,
+                mode: WipLimitMode::Hard,
+            }) = wip_exceeded
+            {
+                return Err(TaskDomainError::WipLimitExceeded {
+                    net: self.id,
+                    status: status_id,
+                    limit,
+                });
+            }
+
+            let events = self.data.events.len();
+            let at = clock.now();
+            self.data.tasks.insert(task_id, status_id);
+            if let Some(WipLimit { limit, .. }) = wip_exceeded {
+                self.data.events.push(TaskDomainEvent::WipLimitExceeded {
+                    net: self.id,
+                    status: status_id,
+                    limit,
+                });
+            }
+            self.data.events.push(TaskDomainEvent::TaskStatusChanged {
+                net: self.id,
+                task: task_id,
+                from: current_status,
+                to: status_id,
+                reason: reason.clone(),
+            });
+            propagate_or_undo(
+                self,
+                events,
+                |net| propagate_from(net, clock, &task_id),
+                |net| {
+                    net.tasks.insert(task_id, current_status);
+                },
+            )?;
+
+            if current_status != status_id {
+                self.data.record_status_change(
+                    task_id,
+                    StatusChange {
+                        at,
+                        from: current_status,
+                        to: status_id,
+                        cause: ChangeCause::Manual { reason },
+                    },
+                );
+            }
+
+            Ok(())
+        } else {
+            Err(TaskDomainError::RelationConstraintNotSatisfied {
+                net: self.id,
+                task: task_id,
+            })
+        }
+    }
+
+    fn new_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        relation_type: RelationType,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        self.data
+            .check_new_relation(self.id, from, to, relation_type)?;
+
+        let events = self.data.events.len();
+        let created_at = clock.now();
+        self.data
+            .relations_mut()
+            .add_edge(from, to, Relation::new(relation_type, created_at));
+        self.data.events.push(TaskDomainEvent::RelationAdded {
+            net: self.id,
+            from,
+            to,
+            relation_type,
+            created_at,
+        });
+
+        propagate_or_undo(
+            self,
+            events,
+            |net| propagate_at(net, clock, &to, Some(from)),
+            |net| {
+                net.relations_mut().remove_edge(from, to);
+            },
+        )
+    }
+
+    fn add_tasks(&mut self, task_ids: impl IntoIterator<Item = Id<Task>>) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let task_ids: Vec<_> = task_ids.into_iter().collect();
+        let mut seen = HashSet::with_capacity(task_ids.len());
+        if let Some(task) = task_ids.iter().find(|task| {
+            !seen.insert(**task)
+                || self.data.tasks.contains_key(task)
+                || self.data.removed_tasks.contains_key(task)
+        }) {
+            return Err(TaskDomainError::TaskAlreadyInNet {
+                task: *task,
+                net: self.id,
+            });
+        }
+
+        for task_id in task_ids {
+            self.add_task(task_id)?;
+        }
+
+        Ok(())
+    }
+
+    fn new_relations(
+        &mut self,
+        relations: impl IntoIterator<Item = (Id<Task>, Id<Task>, RelationType)>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let now = clock.now();
+        self.transaction(|net| {
+            for (from, to, relation_type) in relations {
+                // Relations added earlier in the batch are already in the graph, so this also
+                // catches a pair given twice or a cycle closed by the batch.
+                net.data
+                    .check_new_relation(net.id, from, to, relation_type)?;
+
+                net.data
+                    .relations_mut()
+                    .add_edge(from, to, Relation::new(relation_type, now));
+                net.data.events.push(TaskDomainEvent::RelationAdded {
+                    net: net.id,
+                    from,
+                    to,
+                    relation_type,
+                    created_at: now,
+                });
+            }
+
+            propagate_all(net, clock)
+        })
+    }
+
+    fn remove_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if !self.data.tasks.contains_key(&task_id) {
+            return Err(TaskDomainError::TaskNotFoundInNet {
+                net: self.id,
+                task: task_id,
+            });
+        }
+        self.data.check_propagation(self.id)?;
+
+        self.data.detach_task(task_id);
+        self.data.events.push(TaskDomainEvent::TaskRemovedFromNet {
+            net: self.id,
+            task: task_id,
+        });
+
+        propagate_all(self, clock)
+    }
+
+    fn soft_remove_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if !self.data.tasks.contains_key(&task_id) {
+            return Err(TaskDomainError::TaskNotFoundInNet {
+                net: self.id,
+                task: task_id,
+            });
+        }
+        self.data.check_propagation(self.id)?;
+
+        let removed_at = clock.now();
+        self.data.shelve_task(task_id, removed_at);
+        self.data
+            .events
+            .push(TaskDomainEvent::TaskSoftRemovedFromNet {
+                net: self.id,
+                task: task_id,
+                removed_at,
+            });
+
+        propagate_all(self, clock)
+    }
+
+    fn restore_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        self.data.check_restore(self.id, task_id)?;
+        self.data.check_propagation(self.id)?;
+
+        let removed = self.data.removed_tasks.remove(&task_id).ok_or(
+            TaskDomainError::TaskNotRemovedFromNet {
+                net: self.id,
+                task: task_id,
+            },
+        )?;
+        self.data.unshelve_task(task_id, removed);
+        self.data.events.push(TaskDomainEvent::TaskRestoredToNet {
+            net: self.id,
+            task: task_id,
+        });
+
+        propagate_all(self, clock)
+    }
+
+    fn set_milestone(
+        &mut self,
+        task_id: Id<Task>,
+        milestone: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if !self.data.tasks.contains_key(&task_id) {
+            return Err(TaskDomainError::TaskNotFoundInNet {
+                net: self.id,
+                task: task_id,
+            });
+        }
+
+        let events = self.data.events.len();
+        let was_milestone = !self.data.milestones.insert(task_id);
+        if !milestone {
+            self.data.milestones.remove(&task_id);
+        }
+        self.data.events.push(TaskDomainEvent::MilestoneSet {
+            net: self.id,
+            task: task_id,
+            milestone,
+        });
+
+        propagate_or_undo(
+            self,
+            events,
+            |net| propagate_at(net, clock, &task_id, None),
+            |net| {
+                if !was_milestone {
+                    net.milestones.remove(&task_id);
+                } else {
+                    net.milestones.insert(task_id);
+                }
+            },
+        )
+    }
+
+    fn link_requirement(
+        &mut self,
+        task_id: Id<Task>,
+        required: TaskRef,
+        accepted: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if !self.data.tasks.contains_key(&task_id) {
+            return Err(TaskDomainError::TaskNotFoundInNet {
+                net: self.id,
+                task: task_id,
+            });
+        }
+
+        let events = self.data.events.len();
+        let previous = self
+            .data
+            .links
+            .entry(task_id)
+            .or_default()
+            .insert(required, accepted);
+        self.data.events.push(TaskDomainEvent::RequirementLinked {
+            net: self.id,
+            task: task_id,
+            required,
+            accepted,
+        });
+
+        propagate_or_undo(
+            self,
+            events,
+            |net| propagate_at(net, clock, &task_id, None),
+            |net| {
+                let links = net.links.entry(task_id).or_default();
+                match previous {
+                    Some(accepted) => links.insert(required, accepted),
+                    None => links.remove(&required),
+                };
+                if links.is_empty() {
+                    net.links.remove(&task_id);
+                }
+            },
+        )
+    }
+
+    fn unlink_requirement(
+        &mut self,
+        task_id: Id<Task>,
+        required: TaskRef,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let links = self.data.links.get_mut(&task_id);
+        let Some(accepted) = links.and_then(|links| links.remove(&required)) else {
+            return Err(TaskDomainError::LinkNotFoundInNet {
+                net: self.id,
+                task: task_id,
+                required,
+            });
+        };
+        let events = self.data.events.len();
+        if self.data.links[&task_id].is_empty() {
+            self.data.links.remove(&task_id);
+        }
+        self.data.events.push(TaskDomainEvent::RequirementUnlinked {
+            net: self.id,
+            task: task_id,
+            required,
+        });
+
+        propagate_or_undo(
+            self,
+            events,
+            |net| propagate_at(net, clock, &task_id, None),
+            |net| {
+                net.links
+                    .entry(task_id)
+                    .or_default()
+                    .insert(required, accepted);
+            },
+        )
+    }
+
+    fn update_linked_requirement(
+        &mut self,
+        required: TaskRef,
+        accepted: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+        self.data.check_propagation(self.id)?;
+
+        let mut linked = Vec::new();
+        for (task, links) in &mut self.data.links {
+            if let Some(known) = links.get_mut(&required) {
+                *known = accepted;
+                linked.push(*task);
+            }
+        }
+        if linked.is_empty() {
+            return Ok(());
+        }
+        self.data
+            .events
+            .push(TaskDomainEvent::LinkedRequirementUpdated {
+                net: self.id,
+                required,
+                accepted,
+            });
+
+        for task in linked {
+            if self.data.tasks.contains_key(&task) {
+                propagate_at(self, clock, &task, None)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn archive_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if !self.data.tasks.contains_key(&task_id) {
+            return Err(TaskDomainError::TaskNotFoundInNet {
+                net: self.id,
+                task: task_id,
+            });
+        }
+        if !self.data.archived.insert(task_id) {
+            return Ok(());
+        }
+        let events = self.data.events.len();
+        self.data.events.push(TaskDomainEvent::TaskArchived {
+            net: self.id,
+            task: task_id,
+        });
+
+        propagate_or_undo(
+            self,
+            events,
+            |net| propagate_from(net, clock, &task_id),
+            |net| {
+                net.archived.remove(&task_id);
+            },
+        )
+    }
+
+    fn unarchive_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if !self.data.tasks.contains_key(&task_id) {
+            return Err(TaskDomainError::TaskNotFoundInNet {
+                net: self.id,
+                task: task_id,
+            });
+        }
+        if !self.data.archived.remove(&task_id) {
+            return Ok(());
+        }
+        let events = self.data.events.len();
+        self.data.events.push(TaskDomainEvent::TaskUnarchived {
+            net: self.id,
+            task: task_id,
+        });
+
+        propagate_or_undo(
+            self,
+            events,
+            |net| propagate_at(net, clock, &task_id, None),
+            |net| {
+                net.archived.insert(task_id);
+            },
+        )
+    }
+
+    fn set_relation_strength(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        strength: RelationStrength,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let relation = self.data.relations.edge_weight_mut(from, to).ok_or(
+            TaskDomainError::RelationNotFoundInNet {
+                net: self.id,
+                from,
+                to,
+            },
+        )?;
+        if !matches!(
+            relation.relation_type,
+            RelationType::Require | RelationType::Blocks
+        ) {
+            return Err(TaskDomainError::RelationNotRequirement {
+                net: self.id,
+                from,
+                to,
+            });
+        }
+        if relation.strength == strength {
+            return Ok(());
+        }
+
+        let previous = std::mem::replace(&mut relation.strength, strength);
+        let events = self.data.events.len();
+        self.data
+            .events
+            .push(TaskDomainEvent::RelationStrengthChanged {
+                net: self.id,
+                from,
+                to,
+                strength,
+            });
+
+        propagate_or_undo(
+            self,
+            events,
+            |net| propagate_at(net, clock, &to, Some(from)),
+            |net| {
+                if let Some(relation) = net.relations.edge_weight_mut(from, to) {
+                    relation.strength = previous;
+                }
+            },
+        )
+    }
+
+    fn annotate_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        note: Option<String>,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let relation = self.data.relations.edge_weight_mut(from, to).ok_or(
+            TaskDomainError::RelationNotFoundInNet {
+                net: self.id,
+                from,
+                to,
+            },
+        )?;
+        relation.note = note.clone();
+        self.data.events.push(TaskDomainEvent::RelationAnnotated {
+            net: self.id,
+            from,
+            to,
+            note,
+        });
+
+        Ok(())
+    }
+
+    fn remove_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let events = self.data.events.len();
+        let Some(relation) = self.data.relations_mut().remove_edge(from, to) else {
+            return Err(TaskDomainError::RelationNotFoundInNet {
+                net: self.id,
+                from,
+                to,
+            });
+        };
+        self.data.events.push(TaskDomainEvent::RelationRemoved {
+            net: self.id,
+            from,
+            to,
+        });
+
+        if self.data.warn_on_orphaning {
+            let component = self.data.connected_to([from]);
+            if !component.contains(&to) {
+                let mut tasks: Vec<_> = component.into_iter().collect();
+                tasks.sort();
+                self.data.events.push(TaskDomainEvent::SubtreeOrphaned {
+                    net: self.id,
+                    tasks,
+                });
+            }
+        }
+
+        propagate_or_undo(
+            self,
+            events,
+            |net| propagate_at(net, clock, &to, Some(from)),
+            |net| {
+                net.relations_mut().add_edge(from, to, relation);
+            },
+        )
+    }
+
+    fn new_status(&mut self, status_name: StatusName) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let status_id = Id::new();
+        self.data.schema.push(Entity {
+            id: status_id,
+            data: Status::new(status_name.clone(), StatusCategory::InProgress),
+        });
+        self.data.events.push(TaskDomainEvent::StatusAdded {
+            net: self.id,
+            status: status_id,
+            name: status_name,
+        });
+
+        Ok(())
+    }
+
+    fn remove_status(
+        &mut self,
+        removed_status: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if !self.data.schema.contains(removed_status) {
+            return Err(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: removed_status,
+            });
+        }
+
+        if removed_status == self.data.schema.default
+            || removed_status == self.data.schema.primary_accepted
+        {
+            return Err(TaskDomainError::StatusNotRemovable {
+                net: self.id,
+                status: removed_status,
+            });
+        }
+        self.data.check_propagation(self.id)?;
+
+        self.data.detach_status(removed_status);
+        self.data.events.push(TaskDomainEvent::StatusRemoved {
+            net: self.id,
+            status: removed_status,
+        });
+
+        propagate_all(self, clock)
+    }
+
+    fn set_status_category(
+        &mut self,
+        status_id: Id<Status>,
+        category: StatusCategory,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if status_id == self.data.schema.primary_accepted && category != StatusCategory::Done {
+            return Err(TaskDomainError::StatusNotUnmarkable {
+                net: self.id,
+                status: status_id,
+            });
+        }
+
+        let previous = self
+            .data
+            .schema
+            .get(status_id)
+            .ok_or(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: status_id,
+            })?
+            .data
+            .category;
+
+        if previous != category {
+            self.data.check_propagation(self.id)?;
+            if let Some(status) = self.data.schema.get_mut(status_id) {
+                status.data.category = category;
+            }
+            self.data
+                .record_category_change(self.id, status_id, previous, category);
+            propagate_all(self, clock)?;
+        }
+
+        Ok(())
+    }
+
+    fn change_status_wip_limit(
+        &mut self,
+        status_id: Id<Status>,
+        wip_limit: Option<WipLimit>,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let previous = self
+            .data
+            .schema
+            .get_mut(status_id)
+            .map(|status| std::mem::replace(&mut status.data.wip_limit, wip_limit))
+            .ok_or(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: status_id,
+            })?;
+        if let Some((status, limit)) = self.data.exceeded_hard_wip_limit() {
+            if let Some(changed) = self.data.schema.get_mut(status_id) {
+                changed.data.wip_limit = previous;
+            }
+            return Err(TaskDomainError::WipLimitExceeded {
+                net: self.id,
+                status,
+                limit,
+            });
+        }
+
+        self.data
+            .events
+            .push(TaskDomainEvent::StatusWipLimitChanged {
+                net: self.id,
+                status: status_id,
+                wip_limit,
+            });
+
+        Ok(())
+    }
+
+    fn move_status(&mut self, status_id: Id<Status>, new_index: usize) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let order = &mut self.data.schema.order;
+        let old_index = order.iter().position(|status| *status == status_id).ok_or(
+            TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: status_id,
+            },
+        )?;
+        if new_index >= order.len() {
+            return Err(TaskDomainError::StatusPositionOutOfRange {
+                net: self.id,
+                position: new_index,
+                len: order.len(),
+            });
+        }
+
+        let status = order.remove(old_index);
+        order.insert(new_index, status);
+        self.data.events.push(TaskDomainEvent::StatusMoved {
+            net: self.id,
+            status: status_id,
+            position: new_index,
+        });
+
+        Ok(())
+    }
+
+    fn allow_transition(&mut self, from: Id<Status>, to: Id<Status>) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        for status in [from, to] {
+            if !self.data.schema.contains(status) {
+                return Err(TaskDomainError::StatusNotFoundInNet {
+                    net: self.id,
+                    status,
+                });
+            }
+        }
+
+        let schema = &mut self.data.schema;
+        schema.forbidden_transitions.remove(&(from, to));
+        schema.allowed_transitions.insert((from, to));
+        self.data.events.push(TaskDomainEvent::TransitionAllowed {
+            net: self.id,
+            from,
+            to,
+        });
+
+        Ok(())
+    }
+
+    fn forbid_transition(&mut self, from: Id<Status>, to: Id<Status>) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        for status in [from, to] {
+            if !self.data.schema.contains(status) {
+                return Err(TaskDomainError::StatusNotFoundInNet {
+                    net: self.id,
+                    status,
+                });
+            }
+        }
+
+        let schema = &mut self.data.schema;
+        schema.allowed_transitions.remove(&(from, to));
+        schema.forbidden_transitions.insert((from, to));
+        self.data.events.push(TaskDomainEvent::TransitionForbidden {
+            net: self.id,
+            from,
+            to,
+        });
+
+        Ok(())
+    }
+
+    fn replace_schema(
+        &mut self,
+        schema: SchemaParts,
+        status_mapping: HashMap<Id<Status>, Id<Status>>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        let schema = Schema::from_parts(self.id, schema)?;
+        if let Some(status) = status_mapping
+            .values()
+            .find(|status| !schema.contains(**status))
+        {
+            return Err(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: *status,
+            });
+        }
+        let unmapped = self
+            .data
+            .tasks
+            .values()
+            .chain(
+                self.data
+                    .removed_tasks
+                    .values()
+                    .map(|removed| &removed.status),
+            )
+            .find(|status| !status_mapping.contains_key(status) && !schema.contains(**status));
+        if let Some(status) = unmapped {
+            return Err(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: *status,
+            });
+        }
+
+        self.transaction(|net| {
+            let replaced_at = clock.now();
+            let replaced = TaskDomainEvent::SchemaReplaced {
+                net: net.id,
+                schema: schema.to_parts(),
+                status_mapping: status_mapping.clone(),
+                replaced_at,
+            };
+            net.data.switch_schema(schema, &status_mapping, replaced_at);
+            net.data.events.push(replaced);
+
+            propagate_all(net, clock)?;
+
+            match net.data.exceeded_hard_wip_limit() {
+                Some((status, limit)) => Err(TaskDomainError::WipLimitExceeded {
+                    net: net.id,
+                    status,
+                    limit,
+                }),
+                None => Ok(()),
+            }
+        })
+    }
+
+    fn change_propagation_policy(
+        &mut self,
+        policy: PropagationPolicy,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if self.data.propagation_policy == policy {
+            return Ok(());
+        }
+        self.data.check_propagation(self.id)?;
+
+        self.data.propagation_policy = policy;
+        self.data
+            .events
+            .push(TaskDomainEvent::PropagationPolicyChanged {
+                net: self.id,
+                policy,
+            });
+
+        propagate_all(self, clock)
+    }
+
+    fn require_single_parent(&mut self, enabled: bool) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if self.data.single_parent == enabled {
+            return Ok(());
+        }
+
+        if enabled {
+            if let Some(task) = self
+                .data
+                .tasks
+                .keys()
+                .find(|task| self.data.parents_of(**task).nth(1).is_some())
+            {
+                return Err(TaskDomainError::MultipleParentsNotAllowed {
+                    net: self.id,
+                    task: *task,
+                });
+            }
+        }
+
+        self.data.single_parent = enabled;
+        self.data
+            .events
+            .push(TaskDomainEvent::SingleParentRequirementChanged {
+                net: self.id,
+                enabled,
+            });
+
+        Ok(())
+    }
+
+    fn warn_on_orphaning(&mut self, enabled: bool) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if self.data.warn_on_orphaning == enabled {
+            return Ok(());
+        }
+
+        self.data.warn_on_orphaning = enabled;
+        self.data
+            .events
+            .push(TaskDomainEvent::OrphanWarningsChanged {
+                net: self.id,
+                enabled,
+            });
+
+        Ok(())
+    }
+
+    fn estimate_task(
+        &mut self,
+        task_id: Id<Task>,
+        estimate: Option<Duration>,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if !self.data.tasks.contains_key(&task_id) {
+            return Err(TaskDomainError::TaskNotFoundInNet {
+                net: self.id,
+                task: task_id,
+            });
+        }
+
+        match estimate {
+            Some(estimate) => self.data.estimates.insert(task_id, estimate),
+            None => self.data.estimates.remove(&task_id),
+        };
+        self.data.events.push(TaskDomainEvent::TaskEstimated {
+            net: self.id,
+            task: task_id,
+            estimate,
+        });
+
+        Ok(())
+    }
+
+    fn mark_status_accepted(
+        &mut self,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.set_status_category(status_id, StatusCategory::Done, clock)
+    }
+
+    fn unmark_status_accepted(
+        &mut self,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.data.ensure_unlocked(self.id)?;
+
+        if self.data.schema.is_accepted(status_id) {
+            self.set_status_category(status_id, StatusCategory::InProgress, clock)
+        } else if self.data.schema.contains(status_id) {
+            Ok(())
+        } else {
+            Err(TaskDomainError::StatusNotFoundInNet {
+                net: self.id,
+                status: status_id,
+            })
+        }
+    }
+
+    fn new(default: StatusName, accepted: StatusName) -> Self {
+        Self::with_statuses(default, Vec::new(), accepted)
+    }
+
+    fn with_statuses(default: StatusName, normal: Vec<StatusName>, accepted: StatusName) -> Self {
+        let id = Id::new();
+        let schema = Schema::new(default, accepted, normal);
+        let created = TaskDomainEvent::NetCreated {
+            net: id,
+            statuses: schema
+                .iter()
+                .map(|status| (status.id, status.data.name.clone()))
+                .collect(),
+            default: schema.default,
+            accepted: schema.primary_accepted,
+        };
+
+        let mut data = Net::empty(schema);
+        data.events.push(created);
+        Self { id, data }
+    }
+}
+
+impl AggregateRoot for Net {
+    type Event = TaskDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+/// A checkpoint of a net's tasks, relations, schema and pending events.
+///
+/// Restoring it also discards events recorded after the checkpoint, so rolled
+/// back edits are never published.
+#[derive(Debug, Clone)]
+pub struct NetSnapshot(Net);
+
+impl Snapshot for Net {
+    type Snapshot = NetSnapshot;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        NetSnapshot(self.clone())
+    }
+}
+
+impl Restorable for Net {
+    fn restore(&mut self, snapshot: Self::Snapshot) {
+        *self = snapshot.0;
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+
+    use shared_kernel::{Clock, SystemClock};
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let default_name = StatusName::new("Test Default").unwrap();
+        let accepted_name = StatusName::new("Test Accepted").unwrap();
+        let net = Entity::new(default_name.clone(), accepted_name.clone());
+
+        assert_eq!(
+            net.data
+                .schema
+                .iter()
+                .find(|status| status.id == net.data.schema.default)
+                .unwrap()
+                .data
+                .name,
+            default_name,
+        );
+
+        assert_eq!(
+            net.data
+                .schema
+                .iter()
+                .find(|status| status.id == net.data.schema.primary_accepted)
+                .unwrap()
+                .data
+                .name,
+            accepted_name
+        );
+    }
+
+    #[test]
+    fn test_with_statuses() {
+        let net: Entity<Net> = NetAggregateRoot::with_statuses(
+            StatusName::new("Backlog").unwrap(),
+            vec![
+                StatusName::new("Doing").unwrap(),
+                StatusName::new("Review").unwrap(),
+            ],
+            StatusName::new("Done").unwrap(),
+        );
+
+        let board: Vec<_> = net
+            .statuses()
+            .map(|status| (status.data.name().as_str(), status.data.category()))
+            .collect();
+        assert_eq!(
+            board,
+            [
+                ("Backlog", StatusCategory::Todo),
+                ("Doing", StatusCategory::InProgress),
+                ("Review", StatusCategory::InProgress),
+                ("Done", StatusCategory::Done),
+            ]
+        );
+        assert_eq!(net.statuses().next().unwrap().id, net.default_status());
+        assert!(matches!(
+            &net.data.events[..],
+            [TaskDomainEvent::NetCreated { statuses, .. }] if statuses.len() == 4
+        ));
+    }
+
+    #[test]
+    fn test_replace_schema() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let open = Id::new();
+        let done = Id::new();
+        let removed = Id::new();
+        net.add_tasks([open, done, removed]).unwrap();
+        net.change_task_status(done, net.accepted_status(), &SystemClock)
+            .unwrap();
+        net.soft_remove_task(removed, &SystemClock).unwrap();
+        net.take_events();
+
+        let template: Entity<Net> = NetAggregateRoot::from_template(SchemaTemplate::Kanban);
+        let schema = template.schema().export();
+        let unmapped = net.replace_schema(schema.clone(), HashMap::new(), &SystemClock);
+        assert!(matches!(
+            unmapped,
+            Err(TaskDomainError::StatusNotFoundInNet { status, .. })
+                if status == net.default_status() || status == net.accepted_status()
+        ));
+        assert!(net.take_events().is_empty());
+
+        let old_accepted = net.accepted_status();
+        let mapping = HashMap::from([
+            (net.default_status(), template.default_status()),
+            (net.accepted_status(), template.accepted_status()),
+        ]);
+        net.replace_schema(schema, mapping, &SystemClock).unwrap();
+
+        assert_eq!(net.default_status(), template.default_status());
+        assert_eq!(net.statuses().count(), 4);
+        assert_eq!(net.status_of(open), Some(template.default_status()));
+        assert_eq!(net.status_of(done), Some(template.accepted_status()));
+        let history: Vec<_> = net.history_of(done).collect();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].to, old_accepted);
+        assert_eq!(
+            (history[1].from, history[1].to, &history[1].cause),
+            (
+                old_accepted,
+                template.accepted_status(),
+                &ChangeCause::Remapped
+            )
+        );
+        net.restore_task(removed, &SystemClock).unwrap();
+        assert_eq!(net.status_of(removed), Some(template.default_status()));
+        assert!(matches!(
+            net.take_events()[0],
+            TaskDomainEvent::SchemaReplaced { net: id, .. } if id == net.id
+        ));
+    }
+
+    #[test]
+    fn test_replace_schema_validates_against_net() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let task = Id::new();
+        net.add_task(task).unwrap();
+        net.take_events();
+        let template: Entity<Net> = NetAggregateRoot::from_template(SchemaTemplate::Kanban);
+        let middle = template
+            .statuses()
+            .map(|status| status.id)
+            .find(|status| {
+                ![template.default_status(), template.accepted_status()].contains(status)
+            })
+            .unwrap();
+        let mapping = HashMap::from([
+            (net.default_status(), middle),
+            (net.accepted_status(), template.accepted_status()),
+        ]);
+
+        let mut broken = template.schema().export();
+        broken.default = Id::new();
+        assert!(matches!(
+            net.replace_schema(broken, mapping.clone(), &SystemClock),
+            Err(TaskDomainError::StatusNotFoundInNet { net: id, .. }) if id == net.id
+        ));
+
+        let mut limited = template.schema().export();
+        limited
+            .statuses
+            .iter_mut()
+            .find(|status| status.id == middle)
+            .unwrap()
+            .wip_limit = Some(WipLimit {
+            limit: 0,
+            mode: WipLimitMode::Hard,
+        });
+        assert!(matches!(
+            net.replace_schema(limited, mapping, &SystemClock),
+            Err(TaskDomainError::WipLimitExceeded { net: id, status, limit: 0 })
+                if id == net.id && status == middle
+        ));
+        assert_eq!(net.status_of(task), Some(net.default_status()));
+        assert_eq!(net.statuses().count(), 2);
+        assert!(net.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_milestone() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let (design, build, beta, release) = (Id::new(), Id::new(), Id::new(), Id::new());
+        net.add_tasks([design, build, beta, release]).unwrap();
+        net.change_propagation_policy(PropagationPolicy::Advisory, &SystemClock)
+            .unwrap();
+        net.new_relations(
+            [
+                (design, beta, RelationType::Require),
+                (beta, build, RelationType::Require),
+                (build, release, RelationType::Require),
+            ],
+            &SystemClock,
+        )
+        .unwrap();
+        net.set_milestone(beta, true, &SystemClock).unwrap();
+        net.set_milestone(release, true, &SystemClock).unwrap();
+
+        assert!(net.is_milestone(beta));
+        assert_eq!(net.milestones().count(), 2);
+        assert_eq!(net.next_milestone(), Some(beta));
+        assert!(matches!(
+            net.change_task_status(beta, net.accepted_status(), &SystemClock),
+            Err(TaskDomainError::MilestoneNotChangeable { task, .. }) if task == beta
+        ));
+
+        net.change_task_status(design, net.accepted_status(), &SystemClock)
+            .unwrap();
+        assert_eq!(net.status_of(beta), Some(net.accepted_status()));
+        assert_eq!(net.status_of(release), Some(net.default_status()));
+        assert_eq!(net.next_milestone(), Some(release));
+
+        net.change_task_status(build, net.accepted_status(), &SystemClock)
+            .unwrap();
+        assert_eq!(net.status_of(release), Some(net.accepted_status()));
+        assert_eq!(net.next_milestone(), None);
+
+        net.set_milestone(release, false, &SystemClock).unwrap();
+        net.change_task_status(release, net.default_status(), &SystemClock)
+            .unwrap();
+        assert!(!net.is_milestone(release));
+
+        let launch = Id::new();
+        net.add_task(launch).unwrap();
+        net.set_milestone(launch, true, &SystemClock).unwrap();
+        assert_eq!(net.status_of(launch), Some(net.default_status()));
+        assert_eq!(net.next_milestone(), Some(launch));
+        net.new_relation(design, launch, RelationType::Require, &SystemClock)
+            .unwrap();
+        assert_eq!(net.status_of(launch), Some(net.accepted_status()));
+    }
+
+    #[test]
+    fn test_archive_task() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let (epic, next) = (Id::new(), Id::new());
+        net.add_tasks([epic, next]).unwrap();
+        net.new_relation(epic, next, RelationType::Require, &SystemClock)
+            .unwrap();
+        assert_eq!(net.blocked_tasks().collect::<Vec<_>>(), [next]);
+
+        net.archive_task(epic, &SystemClock).unwrap();
+        assert!(net.is_archived(epic));
+        assert_eq!(net.archived_tasks().collect::<Vec<_>>(), [epic]);
+        assert_eq!(net.blocked_tasks().count(), 0);
+        assert_eq!(net.ready_tasks().collect::<Vec<_>>(), [next]);
+        let stats = net.stats();
+        assert_eq!(stats.tasks_per_status[&net.default_status()], 1);
+        assert!(stats.relations_per_type.is_empty());
+        assert_eq!(stats.depth, 1);
+        assert_eq!(net.relations().count(), 1);
+
+        net.change_task_status(next, net.accepted_status(), &SystemClock)
+            .unwrap();
+        assert_eq!(net.check_invariants(), Ok(()));
+
+        net.take_events();
+        net.unarchive_task(epic, &SystemClock).unwrap();
+        assert!(!net.is_archived(epic));
+        assert_eq!(net.status_of(next), Some(net.default_status()));
+        assert_eq!(
+            net.take_events(),
+            [
+                TaskDomainEvent::TaskUnarchived {
+                    net: net.id,
+                    task: epic,
+                },
+                TaskDomainEvent::StatusPropagated {
+                    net: net.id,
+                    task: next,
+                    from: net.accepted_status(),
+                    to: net.default_status(),
+                },
+            ]
+        );
+        assert!(matches!(
+            net.archive_task(Id::new(), &SystemClock),
+            Err(TaskDomainError::TaskNotFoundInNet { .. })
+        ));
+    }
+
+    #[test]
+    fn test_soft_requirement() {
+        let mut net: Entity<Net> = NetAggregateRoot::with_statuses(
+            StatusName::new("Todo").unwrap(),
+            vec![StatusName::new("Doing").unwrap()],
+            StatusName::new("Done").unwrap(),
+        );
+        let doing = net.statuses().nth(1).unwrap().id;
+        let (docs, release) = (Id::new(), Id::new());
+        net.add_tasks([docs, release]).unwrap();
+        net.new_relation(docs, release, RelationType::Require, &SystemClock)
+            .unwrap();
+        assert_eq!(net.blocked_tasks().collect::<Vec<_>>(), [release]);
+
+        net.set_relation_strength(docs, release, RelationStrength::Soft, &SystemClock)
+            .unwrap();
+        assert_eq!(
+            net.relations().next().unwrap().2.strength(),
+            RelationStrength::Soft
+        );
+        assert_eq!(net.blocked_tasks().count(), 0);
+        assert_eq!(net.warnings().count(), 0);
+
+        net.change_task_status(release, doing, &SystemClock)
+            .unwrap();
+        assert_eq!(
+            net.warnings().collect::<Vec<_>>(),
+            [NetWarning::SoftRequirementUnmet {
+                required: docs,
+                task: release,
+            }]
+        );
+        net.change_task_status(release, net.accepted_status(), &SystemClock)
+            .unwrap();
+        net.change_task_status(docs, net.accepted_status(), &SystemClock)
+            .unwrap();
+        assert_eq!(net.warnings().count(), 0);
+
+        net.change_task_status(docs, net.default_status(), &SystemClock)
+            .unwrap();
+        net.set_relation_strength(docs, release, RelationStrength::Hard, &SystemClock)
+            .unwrap();
+        assert_eq!(net.status_of(release), Some(net.default_status()));
+
+        net.new_relation(release, docs, RelationType::RelatesTo, &SystemClock)
+            .unwrap();
+        assert!(matches!(
+            net.set_relation_strength(release, docs, RelationStrength::Soft, &SystemClock),
+            Err(TaskDomainError::RelationNotRequirement { .. })
+        ));
+    }
+
+    #[test]
+    fn test_lock() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let task = Id::new();
+        net.add_task(task).unwrap();
+        net.take_events();
+
+        net.lock();
+        net.lock();
+        assert!(net.is_locked());
+        assert_eq!(
+            net.take_events(),
+            [TaskDomainEvent::NetLocked { net: net.id }]
+        );
+        let accepted = net.accepted_status();
+        assert!(matches!(
+            net.change_task_status(task, accepted, &SystemClock),
+            Err(TaskDomainError::NetLocked { net: id }) if id == net.id
+        ));
+        assert!(matches!(
+            net.new_status(StatusName::new("Doing").unwrap()),
+            Err(TaskDomainError::NetLocked { .. })
+        ));
+        assert!(matches!(
+            net.apply(vec![NetOp::AddTask(Id::new())], &SystemClock),
+            Err(TaskDomainError::NetLocked { .. })
+        ));
+        assert_eq!(net.status_of(task), Some(net.default_status()));
+        assert_eq!(net.tasks().count(), 1);
+        assert!(net.take_events().is_empty());
+
+        net.unlock();
+        assert!(!net.is_locked());
+        net.change_task_status(task, accepted, &SystemClock)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_new_status() {
+        let default_name = StatusName::new("Test Default").unwrap();
+        let accepted_name = StatusName::new("Test Accepted").unwrap();
+        let mut net = Entity::new(default_name.clone(), accepted_name.clone());
+        let new_status_name1 = StatusName::new("Test Status 1").unwrap();
+        let new_status_name2 = StatusName::new("Test Status 2").unwrap();
+        net.new_status(new_status_name1.clone()).unwrap();
+        net.new_status(new_status_name2.clone()).unwrap();
+
+        assert!(net
+            .data
+            .schema
+            .iter()
+            .any(|status| status.data.name == new_status_name1));
+
+        assert!(net
+            .data
+            .schema
+            .iter()
+            .any(|status| status.data.name == new_status_name2));
+    }
+
+    #[test]
+    fn test_remove_normal_status() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+        net.new_status(StatusName::new("Test").unwrap()).unwrap();
+
+        let id = net
+            .data
+            .schema
+            .iter()
+            .find(|status| status.data.name != default && status.data.name != accepted)
+            .unwrap()
+            .id;
+
+        net.remove_status(id, &SystemClock).unwrap();
+
+        assert!(!net
+            .data
+            .schema
+            .iter()
+            .any(|status| status.data.name != default && status.data.name != accepted));
+    }
+
+    #[test]
+    fn test_remove_default_status_error() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+
+        let default_id = net
+            .data
+            .schema
+            .iter()
+            .find(|status| status.data.name == default)
+            .unwrap()
+            .id;
+
+        assert!(net.remove_status(default_id, &SystemClock).is_err());
+
+        assert!(net.data.schema.iter().any(|status| status.id == default_id));
+    }
+
+    #[test]
+    fn test_remove_accepted_status_error() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+
+        let accepted_id = net
+            .data
+            .schema
+            .iter()
+            .find(|status| status.data.name == accepted)
+            .unwrap()
+            .id;
+
+        assert!(net.remove_status(accepted_id, &SystemClock).is_err());
+
+        assert!(net
+            .data
+            .schema
+            .iter()
+            .any(|status| status.id == accepted_id));
+    }
+
+    #[test]
+    fn test_add_new_task() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+
+        let task_id = Id::<Task>::new();
+        net.add_task(task_id).unwrap();
+
+        assert_eq!(
+            *net.data.tasks.get(&task_id).unwrap(),
+            net.data.schema.default
+        );
+    }
+
+    #[test]
+    fn test_add_existing_task_error() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+
+        let task_id = Id::<Task>::new();
+        net.add_task(task_id).unwrap();
+
+        assert!(net.add_task(task_id).is_err());
+    }
+
+    #[test]
+    fn test_remove_task() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+
+        let task_id = Id::<Task>::new();
+        net.add_task(task_id).unwrap();
+
+        net.remove_task(task_id, &SystemClock).unwrap();
+
+        assert!(!net.data.tasks.contains_key(&task_id));
+    }
+
+    #[test]
+    fn test_remove_nonexistent_task() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+
+        let task_id = Id::<Task>::new();
+        net.take_events();
+
+        assert!(matches!(
+            net.remove_task(task_id, &SystemClock),
+            Err(TaskDomainError::TaskNotFoundInNet { net: net_id, task }) if net_id == net.id && task == task_id
+        ));
+        assert!(net.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_remove_task_change_status() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        let task3_id = Id::new();
+
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.add_task(task3_id).unwrap();
+        net.new_relation(task1_id, task3_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(task2_id, task3_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.change_task_status(task1_id, net.data.schema.primary_accepted, &SystemClock)
+            .unwrap();
+
+        assert_eq!(
+            *net.data.tasks.get(&task3_id).unwrap(),
+            net.data.schema.default
+        );
+
+        net.remove_task(task2_id, &SystemClock).unwrap();
+
+        assert_eq!(
+            *net.data.tasks.get(&task3_id).unwrap(),
+            net.data.schema.primary_accepted
+        );
+    }
+
+    #[test]
+    fn test_soft_remove_and_restore_task() {
+        let now = shared_kernel::SystemClock.now();
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        let task3_id = Id::new();
+
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.add_task(task3_id).unwrap();
+        net.new_relation(task1_id, task3_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(task2_id, task3_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.change_task_status(task1_id, net.data.schema.primary_accepted, &SystemClock)
+            .unwrap();
+
+        net.soft_remove_task(task2_id, &|| now).unwrap();
+
+        assert!(!net.data.tasks.contains_key(&task2_id));
+        assert_eq!(
+            net.removed_tasks().collect::<Vec<_>>(),
+            vec![(task2_id, now)]
+        );
+        assert_eq!(
+            *net.data.tasks.get(&task3_id).unwrap(),
+            net.data.schema.primary_accepted
+        );
+        assert!(net.add_task(task2_id).is_err());
+
+        net.restore_task(task2_id, &SystemClock).unwrap();
+
+        assert!(net.data.relations.contains_edge(task2_id, task3_id));
+        assert_eq!(
+            *net.data.tasks.get(&task3_id).unwrap(),
+            net.data.schema.default
+        );
+        assert!(net.restore_task(task2_id, &SystemClock).is_err());
+    }
+
+    #[test]
+    fn test_restore_keeps_relations_to_removed_tasks() {
+        let now = shared_kernel::SystemClock.now();
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Require, &SystemClock)
+            .unwrap();
+
+        net.soft_remove_task(task1_id, &|| now).unwrap();
+        net.soft_remove_task(task2_id, &|| now).unwrap();
+        net.restore_task(task1_id, &SystemClock).unwrap();
+        net.restore_task(task2_id, &SystemClock).unwrap();
+
+        assert!(net.data.relations.contains_edge(task1_id, task2_id));
+    }
+
+    #[test]
+    fn test_restore_task_cycle_error() {
+        let now = shared_kernel::SystemClock.now();
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        let task3_id = Id::new();
+
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.add_task(task3_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Require, &SystemClock)
+            .unwrap();
+        net.new_relation(task2_id, task3_id, RelationType::Require, &SystemClock)
+            .unwrap();
+
+        net.soft_remove_task(task2_id, &|| now).unwrap();
+        net.new_relation(task3_id, task1_id, RelationType::Require, &SystemClock)
+            .unwrap();
+
+        assert!(net.restore_task(task2_id, &SystemClock).is_err());
+        assert!(!net.data.tasks.contains_key(&task2_id));
+        assert_eq!(net.removed_tasks().count(), 1);
+    }
+
+    #[test]
+    fn test_restore_task_wip_limit_error() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        let doing = net
+            .statuses()
+            .find(|status| status.data.name() == "Doing")
+            .unwrap()
+            .id;
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.change_task_status(task1_id, doing, &SystemClock)
+            .unwrap();
+        net.soft_remove_task(task1_id, &SystemClock).unwrap();
+        net.change_task_status(task2_id, doing, &SystemClock)
+            .unwrap();
+        let hard = WipLimit {
+            limit: 1,
+            mode: WipLimitMode::Hard,
+        };
+        net.change_status_wip_limit(doing, Some(hard)).unwrap();
+        net.take_events();
+
+        assert!(matches!(
+            net.restore_task(task1_id, &SystemClock),
+            Err(TaskDomainError::WipLimitExceeded { status, limit: 1, .. }) if status == doing
+        ));
+        assert!(!net.data.tasks.contains_key(&task1_id));
+        assert_eq!(net.removed_tasks().count(), 1);
+        assert!(net.take_events().is_empty());
+
+        net.change_task_status(task2_id, net.default_status(), &SystemClock)
+            .unwrap();
+        net.restore_task(task1_id, &SystemClock).unwrap();
+        assert_eq!(net.status_of(task1_id), Some(doing));
+    }
+
+    #[test]
+    fn test_new_relation() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+
+        net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+
+        assert!(net.data.relations.contains_edge(task1_id, task2_id));
+        assert!(!net.data.relations.contains_edge(task2_id, task1_id));
+    }
+
+    #[test]
+    fn test_new_relation_with_missing_task() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let (task, missing) = (Id::new(), Id::new());
+        net.add_task(task).unwrap();
+        net.take_events();
+
+        for relation_type in [
+            RelationType::Compose,
+            RelationType::Require,
+            RelationType::Blocks,
+            RelationType::Duplicates,
+            RelationType::RelatesTo,
+        ] {
+            for (from, to) in [(task, missing), (missing, task)] {
+                assert!(matches!(
+                    net.new_relation(from, to, relation_type, &SystemClock),
+                    Err(TaskDomainError::TaskNotFoundInNet { task, .. }) if task == missing
+                ));
+            }
+        }
+        assert!(!net.data.relations.contains_node(missing));
+        assert!(net.check_invariants().is_ok());
+        assert!(net.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_new_relation_already_in_net() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let (child, parent) = (Id::new(), Id::new());
+        net.add_tasks([child, parent]).unwrap();
+        net.new_relation(child, parent, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.annotate_relation(child, parent, Some("part of it".to_string()))
+            .unwrap();
+        net.take_events();
+
+        for relation_type in [RelationType::Compose, RelationType::Require] {
+            assert!(matches!(
+                net.new_relation(child, parent, relation_type, &SystemClock),
+                Err(TaskDomainError::RelationAlreadyInNet { from, to, .. })
+                    if (from, to) == (child, parent)
+            ));
+            assert!(matches!(
+                net.new_relations([(child, parent, relation_type)], &SystemClock),
+                Err(TaskDomainError::RelationAlreadyInNet { .. })
+            ));
+        }
+        let relation = net.data.relations.edge_weight(child, parent).unwrap();
+        assert_eq!(relation.relation_type(), RelationType::Compose);
+        assert_eq!(relation.note(), Some("part of it"));
+        assert!(net.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_new_relation_cycle_error() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        let task3_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.add_task(task3_id).unwrap();
+
+        net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(task2_id, task3_id, RelationType::Require, &SystemClock)
+            .unwrap();
+
+        assert!(net
+            .new_relation(task3_id, task1_id, RelationType::Compose, &SystemClock)
+            .is_err());
+    }
+
+    #[test]
+    fn test_remove_relation() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+
+        net.remove_relation(task1_id, task2_id, &SystemClock)
+            .unwrap();
+
+        assert!(!net.data.relations.contains_edge(task1_id, task2_id));
+    }
+
+    #[test]
+    fn test_remove_nonexistent_relation_error() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+
+        assert!(net
+            .remove_relation(task2_id, task1_id, &SystemClock)
+            .is_err());
+
+        net.remove_relation(task1_id, task2_id, &SystemClock)
+            .unwrap();
+        net.take_events();
+
+        assert!(matches!(
+            net.remove_relation(task1_id, task2_id, &SystemClock),
+            Err(TaskDomainError::RelationNotFoundInNet { from, to, .. })
+                if from == task1_id && to == task2_id
+        ));
+        assert!(matches!(
+            net.remove_relation(Id::new(), Id::new(), &SystemClock),
+            Err(TaskDomainError::RelationNotFoundInNet { .. })
+        ));
+        assert!(net.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_change_task_status() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+
+        net.change_task_status(task1_id, net.data.schema.default, &SystemClock)
+            .unwrap();
+
+        assert_eq!(
+            *net.data.tasks.get(&task1_id).unwrap(),
+            net.data.schema.default
+        );
+        assert_eq!(
+            *net.data.tasks.get(&task2_id).unwrap(),
+            net.data.schema.default
+        );
+
+        assert!(net
+            .change_task_status(task2_id, net.data.schema.default, &SystemClock)
+            .is_err());
+
+        net.change_task_status(task1_id, net.data.schema.default, &SystemClock)
+            .unwrap();
+        assert_eq!(
+            *net.data.tasks.get(&task2_id).unwrap(),
+            net.data.schema.default
+        );
+    }
+
+    #[test]
+    fn test_remove_relation_change_status() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        let task3_id = Id::new();
+
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.add_task(task3_id).unwrap();
+        net.new_relation(task1_id, task3_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(task2_id, task3_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.change_task_status(task1_id, net.data.schema.primary_accepted, &SystemClock)
+            .unwrap();
+        assert_eq!(
+            *net.data.tasks.get(&task3_id).unwrap(),
+            net.data.schema.default
+        );
+
+        net.remove_relation(task2_id, task3_id, &SystemClock)
+            .unwrap();
+
+        assert_eq!(
+            *net.data.tasks.get(&task3_id).unwrap(),
+            net.data.schema.primary_accepted
+        );
+    }
+
+    #[test]
+    fn test_requirement_compose_relation() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        let task3_id = Id::new();
+
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.add_task(task3_id).unwrap();
+
+        net.new_relation(task1_id, task3_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(task2_id, task3_id, RelationType::Require, &SystemClock)
+            .unwrap();
+
+        net.change_task_status(task1_id, net.data.schema.primary_accepted, &SystemClock)
+            .unwrap();
+        assert_eq!(
+            *net.data.tasks.get(&task3_id).unwrap(),
+            net.data.schema.default
+        );
+
+        net.change_task_status(task2_id, net.data.schema.primary_accepted, &SystemClock)
+            .unwrap();
+        assert_eq!(
+            *net.data.tasks.get(&task3_id).unwrap(),
+            net.data.schema.primary_accepted
+        );
+
+        net.remove_task(task1_id, &SystemClock).unwrap();
+        net.change_task_status(task3_id, net.data.schema.default, &SystemClock)
+            .unwrap();
+        assert_eq!(
+            *net.data.tasks.get(&task3_id).unwrap(),
+            net.data.schema.default
+        );
+    }
+
+    #[test]
+    fn test_status_history() {
+        let start = SystemClock.now();
+        let later = start + chrono::TimeDelta::seconds(1);
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let (todo, done) = (net.default_status(), net.accepted_status());
+        let child = Id::new();
+        let parent = Id::new();
+        net.add_tasks([child, parent]).unwrap();
+        net.new_relation(child, parent, RelationType::Compose, &SystemClock)
+            .unwrap();
+
+        net.change_task_status(child, done, &|| start).unwrap();
+        net.change_task_status(child, todo, &|| later).unwrap();
+
+        let child_history: Vec<_> = net.history_of(child).cloned().collect();
+        assert_eq!(
+            child_history,
+            [
+                StatusChange {
+                    at: start,
+                    from: todo,
+                    to: done,
+                    cause: ChangeCause::Manual { reason: None },
+                },
+                StatusChange {
+                    at: later,
+                    from: done,
+                    to: todo,
+                    cause: ChangeCause::Manual { reason: None },
+                },
+            ]
+        );
+        assert!(net.history_of(parent).all(|change| change.cause
+            == ChangeCause::Propagated {
+                origin: Some(child)
+            }));
+        assert_eq!(net.history_of(parent).count(), 2);
+
+        for _ in 0..STATUS_HISTORY_LIMIT {
+            net.change_task_status(child, done, &|| later).unwrap();
+            net.change_task_status(child, todo, &|| later).unwrap();
+        }
+        assert_eq!(net.history_of(child).count(), STATUS_HISTORY_LIMIT);
+        assert!(net.history_of(child).all(|change| change.at == later));
+
+        net.remove_task(child, &SystemClock).unwrap();
+        assert_eq!(net.history_of(child).count(), 0);
+    }
+
+    #[test]
+    fn test_why_status() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let (todo, done) = (net.default_status(), net.accepted_status());
+        let child = Id::new();
+        let parent = Id::new();
+        net.add_tasks([child, parent]).unwrap();
+        assert_eq!(net.why_status(child), None);
+
+        net.new_relation(child, parent, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.change_task_status(child, done, &SystemClock).unwrap();
+        net.change_task_status_with_reason(
+            child,
+            todo,
+            Some("failed review".to_string()),
+            &SystemClock,
+        )
+        .unwrap();
+
+        assert_eq!(
+            net.why_status(child).unwrap().cause,
+            ChangeCause::Manual {
+                reason: Some("failed review".to_string())
+            }
+        );
+        assert_eq!(
+            net.why_status(parent).unwrap().cause,
+            ChangeCause::Propagated {
+                origin: Some(child)
+            }
+        );
+        assert!(matches!(
+            net.take_events().last(),
+            Some(TaskDomainEvent::StatusPropagated { task, .. }) if *task == parent
+        ));
+    }
+
+    #[test]
+    fn test_failed_propagation_keeps_no_events() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let (parent, child) = (Id::new(), Id::new());
+        net.add_task(parent).unwrap();
+        net.add_task(child).unwrap();
+        // A relation from a task the net does not hold makes every propagation through its
+        // target fail.
+        net.data.relations_mut().add_edge(
+            Id::new(),
+            parent,
+            Relation::new(RelationType::Compose, SystemClock.now()),
+        );
+        net.take_events();
+
+        assert!(net
+            .change_propagation_policy(PropagationPolicy::Advisory, &SystemClock)
+            .is_err());
+        assert!(net
+            .new_relation(child, parent, RelationType::Compose, &SystemClock)
+            .is_err());
+        let required = TaskRef::new(Id::new(), Id::new());
+        assert!(net
+            .link_requirement(parent, required, true, &SystemClock)
+            .is_err());
+        assert!(net.remove_task(child, &SystemClock).is_err());
+        assert!(net.take_events().is_empty());
+        assert_eq!(net.propagation_policy(), PropagationPolicy::default());
+        assert!(!net.data.relations.contains_edge(child, parent));
+        assert!(net.data.links.is_empty());
+        assert!(net.data.tasks.contains_key(&child));
+    }
+
+    #[test]
+    fn test_task_specifications() {
+        use shared_kernel::Specification;
+
+        use crate::domain::{
+            name::TaskName,
+            specification::{TaskBlocked, TaskCandidate, TaskInList, TaskWithStatus},
+            task::TaskAggregateRoot,
+        };
+
+        let list_id = Id::new();
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let task1: Entity<Task> = TaskAggregateRoot::new(TaskName::new("Task 1").unwrap(), list_id);
+        let task2: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Task 2").unwrap(), Id::new());
+        net.add_task(task1.id).unwrap();
+        net.add_task(task2.id).unwrap();
+        net.new_relation(task1.id, task2.id, RelationType::Require, &SystemClock)
+            .unwrap();
+
+        let candidate1 = TaskCandidate {
+            task: &task1,
+            net: &net,
+        };
+        let candidate2 = TaskCandidate {
+            task: &task2,
+            net: &net,
+        };
+
+        let spec = TaskInList(list_id).and(TaskWithStatus(net.data.schema.default));
+        assert!(spec.is_satisfied_by(&candidate1));
+        assert!(!spec.is_satisfied_by(&candidate2));
+
+        let spec = TaskBlocked.or(TaskInList(list_id));
+        assert!(spec.is_satisfied_by(&candidate1));
+        assert!(spec.is_satisfied_by(&candidate2));
+        assert!(!TaskBlocked.is_satisfied_by(&candidate1));
+
+        net.change_task_status(task1.id, net.data.schema.primary_accepted, &SystemClock)
+            .unwrap();
+        let candidate2 = TaskCandidate {
+            task: &task2,
+            net: &net,
+        };
+        assert!(TaskBlocked.not().is_satisfied_by(&candidate2));
+    }
+
+    #[test]
+    fn test_overdue_tasks() {
+        use shared_kernel::Specification;
+
+        use crate::domain::{
+            name::TaskName,
+            specification::{TaskCandidate, TaskInList, TaskOverdue},
+            task::{Schedule, TaskAggregateRoot},
+        };
+
+        let list_id = Id::new();
+        let now = SystemClock.now();
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let mut tasks: Vec<Entity<Task>> = (1..=4)
+            .map(|i| TaskAggregateRoot::new(TaskName::new(format!("Task {i}")).unwrap(), list_id))
+            .collect();
+        for (days, task) in [2, 1, 3].into_iter().zip(&mut tasks) {
+            task.schedule(Schedule::due(now - chrono::Duration::days(days)))
+                .unwrap();
+        }
+        tasks[3]
+            .schedule(Schedule::due(now + chrono::Duration::days(1)))
+            .unwrap();
+        for task in &tasks {
+            net.add_task(task.id).unwrap();
+        }
+        net.change_task_status(tasks[2].id, net.accepted_status(), &SystemClock)
+            .unwrap();
+
+        assert_eq!(
+            net.overdue_tasks(&tasks, now),
+            vec![tasks[0].id, tasks[1].id]
+        );
+        net.archive_task(tasks[1].id, &SystemClock).unwrap();
+        assert_eq!(net.overdue_tasks(&tasks, now), vec![tasks[0].id]);
+
+        let spec = TaskOverdue(now).and(TaskInList(list_id));
+        let candidate = |task| TaskCandidate { task, net: &net };
+        assert!(spec.is_satisfied_by(&candidate(&tasks[0])));
+        assert!(!spec.is_satisfied_by(&candidate(&tasks[3])));
+        assert!(TaskOverdue(now + chrono::Duration::days(2)).is_satisfied_by(&candidate(&tasks[3])));
+    }
+
+    #[test]
+    fn test_ready_tasks_by_priority() {
+        use crate::domain::{
+            name::TaskName,
+            task::{Priority, TaskAggregateRoot},
+        };
+
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let mut tasks: Vec<Entity<Task>> = [Priority::Low, Priority::Urgent, Priority::High]
+            .into_iter()
+            .map(|priority| {
+                let mut task: Entity<Task> =
+                    TaskAggregateRoot::new(TaskName::new("Task").unwrap(), Id::new());
+                task.set_priority(priority);
+                net.add_task(task.id).unwrap();
+                task
+            })
+            .collect();
+        net.new_relation(
+            tasks[0].id,
+            tasks[2].id,
+            RelationType::Require,
+            &SystemClock,
+        )
+        .unwrap();
+        assert_eq!(
+            net.ready_tasks_by_priority(&tasks),
+            vec![tasks[1].id, tasks[0].id]
+        );
+
+        tasks[0].set_priority(Priority::Urgent);
+        net.change_task_status(tasks[1].id, net.accepted_status(), &SystemClock)
+            .unwrap();
+        assert_eq!(net.ready_tasks_by_priority(&tasks), vec![tasks[0].id]);
+    }
+
+    #[test]
+    fn test_events_recorded() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.take_events();
+
+        net.change_task_status(task1_id, net.data.schema.primary_accepted, &SystemClock)
+            .unwrap();
+
+        assert_eq!(
+            net.take_events(),
+            vec![
+                TaskDomainEvent::TaskStatusChanged {
+                    net: net.id,
+                    task: task1_id,
+                    from: net.data.schema.default,
+                    to: net.data.schema.primary_accepted,
+                    reason: None,
+                },
+                TaskDomainEvent::StatusPropagated {
+                    net: net.id,
+                    task: task2_id,
+                    from: net.data.schema.default,
+                    to: net.data.schema.primary_accepted,
+                },
+            ]
+        );
+        assert!(net.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_new_records_created_event() {
+        let mut net: Entity<Net> = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+
+        assert_eq!(
+            net.take_events(),
+            vec![TaskDomainEvent::NetCreated {
+                net: net.id,
+                statuses: vec![
+                    (net.data.schema.default, StatusName::new("Default").unwrap()),
+                    (
+                        net.data.schema.primary_accepted,
+                        StatusName::new("Accepted").unwrap()
+                    ),
+                ],
+                default: net.data.schema.default,
+                accepted: net.data.schema.primary_accepted,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_failed_mutation_records_no_event() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let task_id = Id::new();
+        net.add_task(task_id).unwrap();
+        net.take_events();
+
+        assert!(net.add_task(task_id).is_err());
+        assert!(net
+            .remove_relation(task_id, Id::new(), &SystemClock)
+            .is_err());
+
+        assert!(net.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_requirement_relation() {
+        let default = "Default";
+        let accepted = "Accepted";
+
+        let mut net = Entity::new(
+            StatusName::new(default).unwrap(),
+            StatusName::new(accepted).unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        let task3_id = Id::new();
+
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.add_task(task3_id).unwrap();
+
+        net.new_relation(task1_id, task3_id, RelationType::Require, &SystemClock)
+            .unwrap();
+        net.new_relation(task2_id, task3_id, RelationType::Require, &SystemClock)
+            .unwrap();
+
+        assert!(net
+            .change_task_status(task3_id, net.data.schema.primary_accepted, &SystemClock)
+            .is_err());
+
+        net.change_task_status(task1_id, net.data.schema.primary_accepted, &SystemClock)
+            .unwrap();
+        net.change_task_status(task2_id, net.data.schema.primary_accepted, &SystemClock)
+            .unwrap();
+        net.change_task_status(task3_id, net.data.schema.primary_accepted, &SystemClock)
+            .unwrap();
+
+        assert_eq!(
+            *net.data.tasks.get(&task3_id).unwrap(),
+            net.data.schema.primary_accepted
+        );
+
+        net.change_task_status(task1_id, net.data.schema.default, &SystemClock)
+            .unwrap();
+        assert_eq!(
+            *net.data.tasks.get(&task3_id).unwrap(),
+            net.data.schema.default
+        );
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.take_events();
+
+        let snapshot = net.snapshot();
+
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        net.change_task_status(task1_id, net.data.schema.primary_accepted, &SystemClock)
+            .unwrap();
+
+        net.restore(snapshot);
+
+        assert_eq!(net.data.tasks.len(), 1);
+        assert_eq!(net.data.status_of(task1_id), Some(net.data.schema.default));
+        assert_eq!(net.data.relations.edge_count(), 0);
+        assert_eq!(net.data.schema.iter().count(), 2);
+        assert!(net.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_downstream_of_follows_constraining_relations_only() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let [a, b, c, d, e] = [Id::new(), Id::new(), Id::new(), Id::new(), Id::new()];
+        for task in [a, b, c, d, e] {
+            net.add_task(task).unwrap();
+        }
+        net.new_relation(a, b, RelationType::Require, &SystemClock)
+            .unwrap();
+        net.new_relation(b, c, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(a, c, RelationType::Blocks, &SystemClock)
+            .unwrap();
+        net.new_relation(c, d, RelationType::RelatesTo, &SystemClock)
+            .unwrap();
+
+        assert_eq!(net.data.downstream_of(a), vec![a, b, c]);
+        assert_eq!(net.data.downstream_of(d), vec![d]);
+        assert!(net.data.downstream_of(Id::new()).is_empty());
+    }
+
+    #[test]
+    fn test_propagation_only_touches_controlled_tasks() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let accepted = net.data.schema.primary_accepted;
+        let [child, parent, grandparent, unrelated] = [Id::new(), Id::new(), Id::new(), Id::new()];
+        for task in [child, parent, grandparent, unrelated] {
+            net.add_task(task).unwrap();
+        }
+        net.new_relation(child, parent, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(parent, grandparent, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.take_events();
+
+        net.change_task_status(child, accepted, &SystemClock)
+            .unwrap();
+
+        assert_eq!(net.data.status_of(grandparent), Some(accepted));
+        assert_eq!(net.data.status_of(unrelated), Some(net.data.schema.default));
+        let propagated: Vec<_> = net
+            .take_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                TaskDomainEvent::StatusPropagated { task, .. } => Some(task),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(propagated, vec![parent, grandparent]);
+    }
+
+    #[test]
+    fn test_topo_order_cache_follows_relation_changes() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task2_id, task1_id, RelationType::Require, &SystemClock)
+            .unwrap();
+        assert_eq!(net.data.topo_order().unwrap(), [task2_id, task1_id]);
+
+        net.remove_relation(task2_id, task1_id, &SystemClock)
+            .unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Require, &SystemClock)
+            .unwrap();
+        assert_eq!(net.data.topo_order().unwrap(), [task1_id, task2_id]);
+
+        let task3_id = Id::new();
+        net.add_task(task3_id).unwrap();
+        assert_eq!(net.data.topo_order().unwrap().len(), 3);
+        net.remove_task(task3_id, &SystemClock).unwrap();
+        assert_eq!(net.data.topo_order().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_add_tasks() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_tasks([task1_id, task2_id]).unwrap();
+        assert_eq!(net.data.tasks.len(), 2);
+        net.take_events();
+
+        let task3_id = Id::new();
+        assert!(matches!(
+            net.add_tasks([task3_id, task3_id]),
+            Err(TaskDomainError::TaskAlreadyInNet { task, .. }) if task == task3_id
+        ));
+        assert!(matches!(
+            net.add_tasks([task3_id, task1_id]),
+            Err(TaskDomainError::TaskAlreadyInNet { task, .. }) if task == task1_id
+        ));
+        assert_eq!(net.data.tasks.len(), 2);
+        assert!(net.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_new_relations() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let accepted = net.data.schema.primary_accepted;
+        let [a, b, c] = [Id::new(), Id::new(), Id::new()];
+        net.add_tasks([a, b, c]).unwrap();
+        net.change_task_status(a, accepted, &SystemClock).unwrap();
+        net.take_events();
+
+        net.new_relations(
+            [(a, b, RelationType::Compose), (b, c, RelationType::Compose)],
+            &SystemClock,
+        )
+        .unwrap();
+        assert_eq!(net.data.status_of(c), Some(accepted));
+        let events = net.take_events();
+        assert_eq!(events.len(), 4);
+
+        assert!(matches!(
+            net.new_relations(
+                [
+                    (a, c, RelationType::RelatesTo),
+                    (c, a, RelationType::Require),
+                ],
+                &SystemClock,
+            ),
+            Err(TaskDomainError::CycleNotAllowedInNet { .. })
+        ));
+        assert_eq!(net.data.relations.edge_count(), 2);
+        assert!(net.take_events().is_empty());
+
+        assert!(matches!(
+            net.new_relations(
+                [
+                    (a, c, RelationType::RelatesTo),
+                    (a, c, RelationType::Blocks),
+                ],
+                &SystemClock,
+            ),
+            Err(TaskDomainError::RelationAlreadyInNet { from, to, .. }) if (from, to) == (a, c)
+        ));
+        assert!(matches!(
+            net.new_relations([(a, b, RelationType::Require)], &SystemClock),
+            Err(TaskDomainError::RelationAlreadyInNet { from, to, .. }) if (from, to) == (a, b)
+        ));
+        assert_eq!(net.data.relations.edge_count(), 2);
+        assert!(net.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        net.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        let default = net.data.schema.default;
+        let accepted = net.data.schema.primary_accepted;
+        let [a, b, c, d] = [Id::new(), Id::new(), Id::new(), Id::new()];
+        net.add_tasks([a, b, c, d]).unwrap();
+        net.new_relations(
+            [
+                (a, b, RelationType::Require),
+                (b, c, RelationType::Compose),
+                (c, d, RelationType::RelatesTo),
+            ],
+            &SystemClock,
+        )
+        .unwrap();
+        net.change_task_status(d, accepted, &SystemClock).unwrap();
+
+        let stats = net.stats();
+
+        assert_eq!(stats.tasks_per_status.len(), 3);
+        assert_eq!(stats.tasks_per_status[&default], 3);
+        assert_eq!(stats.tasks_per_status[&accepted], 1);
+        assert_eq!(stats.relations_per_type[&RelationType::Require], 1);
+        assert_eq!(stats.relations_per_type[&RelationType::RelatesTo], 1);
+        assert!(!stats.relations_per_type.contains_key(&RelationType::Blocks));
+        assert_eq!(stats.blocked, 2);
+        assert_eq!(stats.ready, 1);
+        assert_eq!(stats.depth, 3);
+    }
+
+    #[test]
+    fn test_transaction_reverts_half_applied_edit() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.take_events();
+
+        let result = net.transaction(|net| {
+            net.new_relation(task1_id, task2_id, RelationType::Require, &SystemClock)?;
+            net.new_relation(task2_id, task1_id, RelationType::Require, &SystemClock)
+        });
+
+        assert!(matches!(
+            result,
+            Err(TaskDomainError::CycleNotAllowedInNet { .. })
+        ));
+        assert_eq!(net.data.relations.edge_count(), 0);
+        assert!(net.take_events().is_empty());
+
+        net.transaction(|net| {
+            net.new_relation(task1_id, task2_id, RelationType::Require, &SystemClock)
+        })
+        .unwrap();
+        assert_eq!(net.data.relations.edge_count(), 1);
+    }
+
+    #[test]
+    fn test_read_accessors() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Require, &SystemClock)
+            .unwrap();
+
+        assert_eq!(net.default_status(), net.data.schema.default);
+        assert_eq!(net.accepted_status(), net.data.schema.primary_accepted);
+        assert_eq!(net.status_of(task1_id), Some(net.default_status()));
+        assert_eq!(net.status_of(Id::new()), None);
+
+        let names: Vec<_> = net
+            .statuses()
+            .map(|status| status.data.name().as_str())
+            .collect();
+        assert_eq!(names, ["Default", "Accepted"]);
+
+        let mut tasks: Vec<_> = net.tasks().collect();
+        tasks.sort();
+        let mut expected = vec![
+            (task1_id, net.default_status()),
+            (task2_id, net.default_status()),
+        ];
+        expected.sort();
+        assert_eq!(tasks, expected);
+
+        let relations: Vec<_> = net
+            .relations()
+            .map(|(from, to, relation)| (from, to, relation.relation_type()))
+            .collect();
+        assert_eq!(relations, [(task1_id, task2_id, RelationType::Require)]);
+    }
+
+    #[test]
+    fn test_change_accepted() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        net.new_status(StatusName::new("Released").unwrap())
+            .unwrap();
+        let released = net
+            .statuses()
+            .find(|status| status.data.name() == "Released")
+            .unwrap()
+            .id;
+        let old_accepted = net.accepted_status();
+
+        let done_id = Id::new();
+        let child_id = Id::new();
+        let parent_id = Id::new();
+        net.add_task(done_id).unwrap();
+        net.add_task(child_id).unwrap();
+        net.add_task(parent_id).unwrap();
+        net.new_relation(child_id, parent_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.change_task_status(done_id, old_accepted, &SystemClock)
+            .unwrap();
+        net.take_events();
+
+        assert!(net.change_accepted(Id::new(), &SystemClock).is_err());
+        assert!(matches!(
+            net.change_accepted(net.default_status(), &SystemClock),
+            Err(TaskDomainError::DefaultStatusNotAcceptable { status, .. })
+                if status == net.default_status()
+        ));
+        assert_eq!(net.accepted_status(), old_accepted);
+        assert!(net.take_events().is_empty());
+
+        net.change_task_status(child_id, released, &SystemClock)
+            .unwrap();
+        assert_eq!(net.status_of(parent_id), Some(net.default_status()));
+        net.take_events();
+
+        net.change_accepted(released, &SystemClock).unwrap();
+
+        assert_eq!(net.accepted_status(), released);
+        assert_eq!(net.status_of(done_id), Some(released));
+        assert_eq!(net.status_of(parent_id), Some(released));
+        assert_eq!(
+            net.take_events(),
+            vec![
+                TaskDomainEvent::AcceptedStatusChanged {
+                    net: net.id,
+                    status: released,
+                },
+                TaskDomainEvent::StatusCategoryChanged {
+                    net: net.id,
+                    status: released,
+                    category: StatusCategory::Done,
+                },
+                TaskDomainEvent::StatusMarkedAccepted {
+                    net: net.id,
+                    status: released,
+                },
+                TaskDomainEvent::StatusCategoryChanged {
+                    net: net.id,
+                    status: old_accepted,
+                    category: StatusCategory::InProgress,
+                },
+                TaskDomainEvent::StatusUnmarkedAccepted {
+                    net: net.id,
+                    status: old_accepted,
+                },
+                TaskDomainEvent::StatusPropagated {
+                    net: net.id,
+                    task: done_id,
+                    from: old_accepted,
+                    to: released,
+                },
+                TaskDomainEvent::StatusPropagated {
+                    net: net.id,
+                    task: parent_id,
+                    from: net.default_status(),
+                    to: released,
+                },
+            ]
+        );
+        assert_eq!(
+            net.history_of(done_id)
+                .last()
+                .map(|change| change.cause.clone()),
+            Some(ChangeCause::Propagated { origin: None })
+        );
+
+        // The old accepted status no longer counts as accepted.
+        assert_eq!(net.accepted_statuses().collect::<Vec<_>>(), vec![released]);
+    }
+
+    #[test]
+    fn test_multiple_accepted_statuses() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        net.new_status(StatusName::new("Won't Fix").unwrap())
+            .unwrap();
+        let wont_fix = net
+            .statuses()
+            .find(|status| status.data.name() == "Won't Fix")
+            .unwrap()
+            .id;
+
+        let child_id = Id::new();
+        let parent_id = Id::new();
+        let next_id = Id::new();
+        net.add_task(child_id).unwrap();
+        net.add_task(parent_id).unwrap();
+        net.add_task(next_id).unwrap();
+        net.new_relation(child_id, parent_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(child_id, next_id, RelationType::Require, &SystemClock)
+            .unwrap();
+        net.change_task_status(child_id, wont_fix, &SystemClock)
+            .unwrap();
+        assert!(net.data.is_blocked(next_id));
+
+        assert!(net.mark_status_accepted(Id::new(), &SystemClock).is_err());
+        net.mark_status_accepted(wont_fix, &SystemClock).unwrap();
+
+        assert!(!net.data.is_blocked(next_id));
+        assert_eq!(net.status_of(parent_id), Some(net.accepted_status()));
+        let mut accepted: Vec<_> = net.accepted_statuses().collect();
+        accepted.sort();
+        let mut expected = vec![net.accepted_status(), wont_fix];
+        expected.sort();
+        assert_eq!(accepted, expected);
+
+        assert!(matches!(
+            net.unmark_status_accepted(net.accepted_status(), &SystemClock),
+            Err(TaskDomainError::StatusNotUnmarkable { .. })
+        ));
+        net.unmark_status_accepted(wont_fix, &SystemClock).unwrap();
+
+        assert!(net.data.is_blocked(next_id));
+        assert_eq!(net.status_of(parent_id), Some(net.default_status()));
+
+        net.mark_status_accepted(wont_fix, &SystemClock).unwrap();
+        net.remove_status(wont_fix, &SystemClock).unwrap();
+
+        assert_eq!(
+            net.accepted_statuses().collect::<Vec<_>>(),
+            [net.accepted_status()]
+        );
+        assert_eq!(net.status_of(parent_id), Some(net.default_status()));
+    }
+
+    #[test]
+    fn test_status_categories() {
+        let mut net = Entity::new(
+            StatusName::new("Default").unwrap(),
+            StatusName::new("Accepted").unwrap(),
+        );
+        net.new_status(StatusName::new("Shipped").unwrap()).unwrap();
+        let category_of = |net: &Entity<Net>, name: &str| {
+            net.statuses()
+                .find(|status| status.data.name() == name)
+                .unwrap()
+                .data
+                .category()
+        };
+        assert_eq!(category_of(&net, "Default"), StatusCategory::Todo);
+        assert_eq!(category_of(&net, "Accepted"), StatusCategory::Done);
+        assert_eq!(category_of(&net, "Shipped"), StatusCategory::InProgress);
+
+        let shipped = net
+            .statuses()
+            .find(|status| status.data.name() == "Shipped")
+            .unwrap()
+            .id;
+        let child_id = Id::new();
+        let parent_id = Id::new();
+        net.add_task(child_id).unwrap();
+        net.add_task(parent_id).unwrap();
+        net.new_relation(child_id, parent_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.change_task_status(child_id, shipped, &SystemClock)
+            .unwrap();
+        net.take_events();
+
+        net.set_status_category(shipped, StatusCategory::Done, &SystemClock)
+            .unwrap();
+        net.set_status_category(shipped, StatusCategory::Done, &SystemClock)
+            .unwrap();
+
+        assert_eq!(net.status_of(parent_id), Some(net.accepted_status()));
+        assert_eq!(
+            net.take_events(),
+            vec![
+                TaskDomainEvent::StatusCategoryChanged {
+                    net: net.id,
+                    status: shipped,
+                    category: StatusCategory::Done,
+                },
+                TaskDomainEvent::StatusMarkedAccepted {
+                    net: net.id,
+                    status: shipped,
+                },
+                TaskDomainEvent::StatusPropagated {
+                    net: net.id,
+                    task: parent_id,
+                    from: net.default_status(),
+                    to: net.accepted_status(),
+                },
+            ]
+        );
+
+        // Moving between categories that do not count as accepted changes nothing else.
+        net.unmark_status_accepted(shipped, &SystemClock).unwrap();
+        net.set_status_category(shipped, StatusCategory::Todo, &SystemClock)
+            .unwrap();
+        assert_eq!(
+            net.take_events()
+                .into_iter()
+                .filter(|event| !matches!(event, TaskDomainEvent::StatusPropagated { .. }))
+                .collect::<Vec<_>>(),
+            vec![
+                TaskDomainEvent::StatusCategoryChanged {
+                    net: net.id,
+                    status: shipped,
+                    category: StatusCategory::InProgress,
+                },
+                TaskDomainEvent::StatusUnmarkedAccepted {
+                    net: net.id,
+                    status: shipped,
+                },
+                TaskDomainEvent::StatusCategoryChanged {
+                    net: net.id,
+                    status: shipped,
+                    category: StatusCategory::Todo,
+                },
+            ]
+        );
+
+        assert!(matches!(
+            net.set_status_category(net.accepted_status(), StatusCategory::Todo, &SystemClock),
+            Err(TaskDomainError::StatusNotUnmarkable { .. })
+        ));
+        assert!(net
+            .set_status_category(Id::new(), StatusCategory::Todo, &SystemClock)
+            .is_err());
+    }
+
+    #[test]
+    fn test_workflow_transitions() {
+        let mut net = Entity::new(
+            StatusName::new("Backlog").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.new_status(StatusName::new("In Progress").unwrap())
+            .unwrap();
+        let backlog = net.default_status();
+        let done = net.accepted_status();
+        let in_progress = net
+            .statuses()
+            .find(|status| status.data.name() == "In Progress")
+            .unwrap()
+            .id;
+        let task_id = Id::new();
+        net.add_task(task_id).unwrap();
+
+        assert!(net.is_transition_allowed(backlog, done));
+        net.allow_transition(backlog, in_progress).unwrap();
+        net.forbid_transition(in_progress, backlog).unwrap();
+        assert!(net.allow_transition(backlog, Id::new()).is_err());
+        net.take_events();
+
+        assert!(matches!(
+            net.change_task_status(task_id, done, &SystemClock),
+            Err(TaskDomainError::TransitionNotAllowed { .. })
+        ));
+        assert!(matches!(
+            net.change_task_status(task_id, Id::new(), &SystemClock),
+            Err(TaskDomainError::StatusNotFoundInNet { .. })
+        ));
+        assert!(net.take_events().is_empty());
+
+        net.change_task_status(task_id, in_progress, &SystemClock)
+            .unwrap();
+        assert!(net
+            .change_task_status(task_id, backlog, &SystemClock)
+            .is_err());
+        net.change_task_status(task_id, done, &SystemClock).unwrap();
+
+        net.allow_transition(in_progress, backlog).unwrap();
+        assert!(net.is_transition_allowed(in_progress, backlog));
+
+        net.remove_status(in_progress, &SystemClock).unwrap();
+        assert!(net.is_transition_allowed(backlog, done));
+    }
+
+    #[test]
+    fn test_move_status() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        let names = |net: &Entity<Net>| -> Vec<String> {
+            net.statuses()
+                .map(|status| status.data.name().to_string())
+                .collect()
+        };
+        assert_eq!(names(&net), ["Todo", "Done", "Doing"]);
+
+        let doing = net.statuses().nth(2).unwrap().id;
+        net.move_status(doing, 1).unwrap();
+        assert_eq!(names(&net), ["Todo", "Doing", "Done"]);
+
+        assert!(matches!(
+            net.move_status(doing, 3),
+            Err(TaskDomainError::StatusPositionOutOfRange { len: 3, .. })
+        ));
+        assert!(net.move_status(Id::new(), 0).is_err());
+        assert_eq!(names(&net), ["Todo", "Doing", "Done"]);
+    }
+
+    #[test]
+    fn test_status_metadata() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let done = net.accepted_status();
+        let status = |net: &Entity<Net>| net.statuses().find(|s| s.id == done).unwrap().clone();
+        assert_eq!(status(&net).data.color(), None);
+        assert_eq!(status(&net).data.description(), None);
+
+        let green = Color::rgb(0x2e, 0xa0, 0x43);
+        net.change_status_color(done, Some(green)).unwrap();
+        net.change_status_description(done, Some("Shipped to users".to_string()))
+            .unwrap();
+
+        assert_eq!(status(&net).data.color(), Some(green));
+        assert_eq!(green.to_string(), "#2ea043");
+        assert_eq!(status(&net).data.description(), Some("Shipped to users"));
+
+        net.change_status_color(done, None).unwrap();
+        assert_eq!(status(&net).data.color(), None);
+        assert!(net.change_status_color(Id::new(), Some(green)).is_err());
+        assert!(net.change_status_description(Id::new(), None).is_err());
+    }
+
+    #[test]
+    fn test_wip_limits() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        let doing = net
+            .statuses()
+            .find(|status| status.data.name() == "Doing")
+            .unwrap()
+            .id;
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+
+        let hard = WipLimit {
+            limit: 1,
+            mode: WipLimitMode::Hard,
+        };
+        net.change_status_wip_limit(doing, Some(hard)).unwrap();
+        net.change_task_status(task1_id, doing, &SystemClock)
+            .unwrap();
+        net.change_task_status(task1_id, doing, &SystemClock)
+            .unwrap();
+        net.take_events();
+
+        assert!(matches!(
+            net.change_task_status(task2_id, doing, &SystemClock),
+            Err(TaskDomainError::WipLimitExceeded { limit: 1, .. })
+        ));
+        assert!(net.take_events().is_empty());
+        assert_eq!(net.status_of(task2_id), Some(net.default_status()));
+
+        let soft = WipLimit {
+            limit: 1,
+            mode: WipLimitMode::Soft,
+        };
+        net.change_status_wip_limit(doing, Some(soft)).unwrap();
+        net.take_events();
+        net.change_task_status(task2_id, doing, &SystemClock)
+            .unwrap();
+
+        assert_eq!(net.status_of(task2_id), Some(doing));
+        assert!(net
+            .take_events()
+            .contains(&TaskDomainEvent::WipLimitExceeded {
+                net: net.id,
+                status: doing,
+                limit: 1,
+            }));
+
+        assert!(matches!(
+            net.change_status_wip_limit(doing, Some(hard)),
+            Err(TaskDomainError::WipLimitExceeded { limit: 1, .. })
+        ));
+        assert_eq!(
+            net.statuses()
+                .find(|status| status.id == doing)
+                .unwrap()
+                .data
+                .wip_limit(),
+            Some(soft)
+        );
+    }
+
+    #[test]
+    fn test_blocking_and_informational_relations() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let blocker_id = Id::new();
+        let blocked_id = Id::new();
+        let other_id = Id::new();
+        net.add_task(blocker_id).unwrap();
+        net.add_task(blocked_id).unwrap();
+        net.add_task(other_id).unwrap();
+
+        net.new_relation(blocker_id, blocked_id, RelationType::Blocks, &SystemClock)
+            .unwrap();
+        assert!(net.data.is_blocked(blocked_id));
+        assert!(net
+            .change_task_status(blocked_id, net.accepted_status(), &SystemClock)
+            .is_err());
+        assert!(matches!(
+            net.new_relation(blocked_id, blocker_id, RelationType::Blocks, &SystemClock),
+            Err(TaskDomainError::CycleNotAllowedInNet { .. })
+        ));
+
+        net.new_relation(other_id, blocked_id, RelationType::RelatesTo, &SystemClock)
+            .unwrap();
+        net.new_relation(blocked_id, other_id, RelationType::RelatesTo, &SystemClock)
+            .unwrap();
+        net.new_relation(other_id, blocker_id, RelationType::Duplicates, &SystemClock)
+            .unwrap();
+        assert!(net
+            .new_relation(other_id, other_id, RelationType::RelatesTo, &SystemClock)
+            .is_err());
+
+        assert!(!net.data.is_blocked(other_id));
+        assert!(!net.data.is_blocked(blocker_id));
+        net.change_task_status(blocker_id, net.accepted_status(), &SystemClock)
+            .unwrap();
+        net.change_task_status(blocked_id, net.accepted_status(), &SystemClock)
+            .unwrap();
+        assert_eq!(net.status_of(other_id), Some(net.default_status()));
+    }
+
+    #[test]
+    fn test_advisory_propagation_policy() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        let doing = net
+            .statuses()
+            .find(|status| status.data.name() == "Doing")
+            .unwrap()
+            .id;
+        let required_id = Id::new();
+        let target_id = Id::new();
+        net.add_task(required_id).unwrap();
+        net.add_task(target_id).unwrap();
+        net.new_relation(required_id, target_id, RelationType::Require, &SystemClock)
+            .unwrap();
+        assert_eq!(net.propagation_policy(), PropagationPolicy::Strict);
+        assert!(net
+            .change_task_status(target_id, doing, &SystemClock)
+            .is_err());
+
+        net.change_propagation_policy(PropagationPolicy::Advisory, &SystemClock)
+            .unwrap();
+        net.change_task_status(target_id, doing, &SystemClock)
+            .unwrap();
+        assert!(matches!(
+            net.change_task_status(target_id, net.accepted_status(), &SystemClock),
+            Err(TaskDomainError::RelationConstraintNotSatisfied { .. })
+        ));
+
+        net.change_task_status(required_id, net.accepted_status(), &SystemClock)
+            .unwrap();
+        net.change_task_status(target_id, net.accepted_status(), &SystemClock)
+            .unwrap();
+        net.change_task_status(required_id, doing, &SystemClock)
+            .unwrap();
+        assert_eq!(net.status_of(target_id), Some(net.accepted_status()));
+
+        net.take_events();
+        net.change_propagation_policy(PropagationPolicy::Strict, &SystemClock)
+            .unwrap();
+        assert_eq!(net.status_of(target_id), Some(net.default_status()));
+        assert_eq!(
+            net.take_events(),
+            vec![
+                TaskDomainEvent::PropagationPolicyChanged {
+                    net: net.id,
+                    policy: PropagationPolicy::Strict,
+                },
+                TaskDomainEvent::StatusPropagated {
+                    net: net.id,
+                    task: target_id,
+                    from: net.accepted_status(),
+                    to: net.default_status(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_single_parent() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let child_id = Id::new();
+        let parent1_id = Id::new();
+        let parent2_id = Id::new();
+        net.add_task(child_id).unwrap();
+        net.add_task(parent1_id).unwrap();
+        net.add_task(parent2_id).unwrap();
+        assert_eq!(net.parent_of(child_id), None);
+
+        net.new_relation(child_id, parent1_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(child_id, parent2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        assert!(matches!(
+            net.require_single_parent(true),
+            Err(TaskDomainError::MultipleParentsNotAllowed { task, .. }) if task == child_id
+        ));
+        assert!(!net.requires_single_parent());
+
+        net.remove_relation(child_id, parent2_id, &SystemClock)
+            .unwrap();
+        net.require_single_parent(true).unwrap();
+        assert!(net.requires_single_parent());
+        assert_eq!(net.parent_of(child_id), Some(parent1_id));
+
+        assert!(matches!(
+            net.new_relation(child_id, parent2_id, RelationType::Compose, &SystemClock),
+            Err(TaskDomainError::MultipleParentsNotAllowed { .. })
+        ));
+        net.new_relation(child_id, parent2_id, RelationType::RelatesTo, &SystemClock)
+            .unwrap();
+
+        net.soft_remove_task(parent1_id, &SystemClock).unwrap();
+        net.remove_relation(child_id, parent2_id, &SystemClock)
+            .unwrap();
+        net.new_relation(child_id, parent2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        assert!(matches!(
+            net.restore_task(parent1_id, &SystemClock),
+            Err(TaskDomainError::MultipleParentsNotAllowed { .. })
+        ));
+        assert_eq!(net.parent_of(child_id), Some(parent2_id));
+    }
+
+    #[test]
+    fn test_orphans() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let mut tasks: Vec<Id<Task>> = (0..4).map(|_| Id::new()).collect();
+        tasks.sort();
+        let [root, child, grandchild, loose] = tasks[..] else {
+            unreachable!()
+        };
+        for task in &tasks {
+            net.add_task(*task).unwrap();
+        }
+        net.new_relation(child, root, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(child, grandchild, RelationType::Require, &SystemClock)
+            .unwrap();
+        assert_eq!(net.orphans().collect::<Vec<_>>(), vec![loose]);
+        assert_eq!(net.unreachable_from([root]), vec![loose]);
+        assert_eq!(net.unreachable_from([grandchild, Id::new()]), vec![loose]);
+        assert_eq!(net.unreachable_from([]), tasks);
+
+        net.warn_on_orphaning(true).unwrap();
+        assert!(net.warns_on_orphaning());
+        net.take_events();
+        net.new_relation(grandchild, root, RelationType::RelatesTo, &SystemClock)
+            .unwrap();
+        net.remove_relation(child, root, &SystemClock).unwrap();
+        assert!(!net
+            .take_events()
+            .iter()
+            .any(|event| matches!(event, TaskDomainEvent::SubtreeOrphaned { .. })));
+
+        net.remove_relation(grandchild, root, &SystemClock).unwrap();
+        assert!(net
+            .take_events()
+            .contains(&TaskDomainEvent::SubtreeOrphaned {
+                net: net.id,
+                tasks: {
+                    let mut split = vec![child, grandchild];
+                    split.sort();
+                    split
+                },
+            }));
+        assert_eq!(net.unreachable_from([root]), {
+            let mut split = vec![child, grandchild, loose];
+            split.sort();
+            split
+        });
+        let mut orphans: Vec<_> = net.orphans().collect();
+        orphans.sort();
+        assert_eq!(orphans, vec![root, loose]);
+    }
+
+    #[test]
+    fn test_relation_metadata() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        net.add_task(task1_id).unwrap();
+        net.add_task(task2_id).unwrap();
+        let created_at = SystemClock.now();
+        net.new_relation(task1_id, task2_id, RelationType::Require, &|| created_at)
+            .unwrap();
+        net.take_events();
+
+        let relation = |net: &Entity<Net>| net.relations().next().unwrap().2.clone();
+        assert_eq!(relation(&net).created_at(), created_at);
+        assert_eq!(relation(&net).note(), None);
+
+        net.annotate_relation(task1_id, task2_id, Some("API must land first".to_string()))
+            .unwrap();
+        assert_eq!(relation(&net).note(), Some("API must land first"));
+        assert_eq!(relation(&net).relation_type(), RelationType::Require);
+        assert_eq!(
+            net.take_events(),
+            vec![TaskDomainEvent::RelationAnnotated {
+                net: net.id,
+                from: task1_id,
+                to: task2_id,
+                note: Some("API must land first".to_string()),
+            }]
+        );
+
+        net.soft_remove_task(task2_id, &SystemClock).unwrap();
+        net.restore_task(task2_id, &SystemClock).unwrap();
+        assert_eq!(relation(&net).note(), Some("API must land first"));
+
+        assert!(matches!(
+            net.annotate_relation(task2_id, task1_id, None),
+            Err(TaskDomainError::RelationNotFoundInNet { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cycle_path() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let a = Id::new();
+        let b = Id::new();
+        let c = Id::new();
+        for task in [a, b, c] {
+            net.add_task(task).unwrap();
+        }
+        net.new_relation(a, b, RelationType::Require, &SystemClock)
+            .unwrap();
+        net.new_relation(b, c, RelationType::Compose, &SystemClock)
+            .unwrap();
+
+        assert!(matches!(
+            net.new_relation(c, a, RelationType::Blocks, &SystemClock),
+            Err(TaskDomainError::CycleNotAllowedInNet { cycle, .. }) if cycle == [c, a, b]
+        ));
+        assert!(matches!(
+            net.new_relation(a, a, RelationType::RelatesTo, &SystemClock),
+            Err(TaskDomainError::CycleNotAllowedInNet { cycle, .. }) if cycle == [a]
+        ));
+
+        net.soft_remove_task(b, &SystemClock).unwrap();
+        net.new_relation(c, a, RelationType::Require, &SystemClock)
+            .unwrap();
+        assert!(matches!(
+            net.restore_task(b, &SystemClock),
+            Err(TaskDomainError::CycleNotAllowedInNet { cycle, .. }) if cycle == [b, c, a]
+        ));
+    }
+
+    #[test]
+    fn test_blocked_and_ready_tasks() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let design = Id::new();
+        let build = Id::new();
+        let child = Id::new();
+        let parent = Id::new();
+        let notes = Id::new();
+        for task in [design, build, child, parent, notes] {
+            net.add_task(task).unwrap();
+        }
+        net.new_relation(design, build, RelationType::Require, &SystemClock)
+            .unwrap();
+        net.new_relation(child, parent, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(notes, design, RelationType::RelatesTo, &SystemClock)
+            .unwrap();
+
+        let sorted = |tasks: Vec<Id<Task>>| {
+            let mut tasks = tasks;
+            tasks.sort();
+            tasks
+        };
+        assert_eq!(
+            sorted(net.blocked_tasks().collect()),
+            sorted(vec![build, parent])
+        );
+        assert_eq!(
+            sorted(net.ready_tasks().collect()),
+            sorted(vec![design, child, notes])
+        );
+
+        net.change_task_status(design, net.accepted_status(), &SystemClock)
+            .unwrap();
+        assert_eq!(net.blocked_tasks().collect::<Vec<_>>(), [parent]);
+        assert_eq!(
+            sorted(net.ready_tasks().collect()),
+            sorted(vec![build, child, notes])
+        );
+    }
+
+    #[test]
+    fn test_subtree_traversal() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let epic = Id::new();
+        let story = Id::new();
+        let subtask1 = Id::new();
+        let subtask2 = Id::new();
+        let unrelated = Id::new();
+        for task in [epic, story, subtask1, subtask2, unrelated] {
+            net.add_task(task).unwrap();
+        }
+        net.new_relation(story, epic, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(subtask1, story, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(subtask2, story, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(unrelated, story, RelationType::Require, &SystemClock)
+            .unwrap();
+
+        let sorted = |tasks: Vec<Id<Task>>| {
+            let mut tasks = tasks;
+            tasks.sort();
+            tasks
+        };
+        assert_eq!(net.direct_subtasks_of(epic), [story]);
+        assert_eq!(
+            sorted(net.direct_subtasks_of(story)),
+            sorted(vec![subtask1, subtask2])
+        );
+        assert_eq!(
+            sorted(net.descendants_of(epic)),
+            sorted(vec![story, subtask1, subtask2])
+        );
+        assert_eq!(net.ancestors_of(subtask1), [story, epic]);
+        assert!(net.ancestors_of(epic).is_empty());
+        assert!(net.descendants_of(unrelated).is_empty());
+        assert!(net.descendants_of(Id::new()).is_empty());
+    }
+
+    #[test]
+    fn test_progress() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        assert_eq!(net.progress(), 0.0);
+
+        let epic = Id::new();
+        let story = Id::new();
+        let subtask1 = Id::new();
+        let subtask2 = Id::new();
+        for task in [epic, story, subtask1, subtask2] {
+            net.add_task(task).unwrap();
+        }
+        net.new_relation(story, epic, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(subtask1, story, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(subtask2, story, RelationType::Compose, &SystemClock)
+            .unwrap();
+
+        net.change_task_status(subtask1, net.accepted_status(), &SystemClock)
+            .unwrap();
+        assert_eq!(net.progress_of(story), 0.5);
+        assert_eq!(net.progress_of(epic), 1.0 / 3.0);
+        assert_eq!(net.progress_of(subtask1), 1.0);
+        assert_eq!(net.progress_of(subtask2), 0.0);
+        assert_eq!(net.progress(), 0.25);
+
+        net.change_task_status(subtask2, net.accepted_status(), &SystemClock)
+            .unwrap();
+        assert_eq!(net.progress_of(epic), 1.0);
+        assert_eq!(net.progress(), 1.0);
+        assert_eq!(net.progress_of(Id::new()), 0.0);
+    }
+
+    #[test]
+    fn test_critical_path() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        assert_eq!(net.critical_path(), CriticalPath::default());
+
+        let design = Id::new();
+        let backend = Id::new();
+        let frontend = Id::new();
+        let release = Id::new();
+        for task in [design, backend, frontend, release] {
+            net.add_task(task).unwrap();
+        }
+        let hours = |hours: u64| Duration::from_secs(hours * 3600);
+        net.estimate_task(design, Some(hours(4))).unwrap();
+        net.estimate_task(backend, Some(hours(16))).unwrap();
+        net.estimate_task(frontend, Some(hours(8))).unwrap();
+        assert!(net.estimate_task(Id::new(), Some(hours(1))).is_err());
+        assert_eq!(net.estimate_of(backend), Some(hours(16)));
+        assert_eq!(net.estimate_of(release), None);
+
+        for (from, to) in [
+            (design, backend),
+            (design, frontend),
+            (backend, release),
+            (frontend, release),
+        ] {
+            net.new_relation(from, to, RelationType::Require, &SystemClock)
+                .unwrap();
+        }
+
+        assert_eq!(
+            net.critical_path(),
+            CriticalPath {
+                tasks: vec![design, backend, release],
+                duration: hours(20),
+            }
+        );
+
+        net.estimate_task(backend, None).unwrap();
+        assert_eq!(
+            net.critical_path(),
+            CriticalPath {
+                tasks: vec![design, frontend, release],
+                duration: hours(12),
+            }
+        );
+    }
+
+    #[test]
+    fn test_effort() {
+        use crate::domain::{
+            name::TaskName,
+            task::{TaskAggregateRoot, WorkEntry},
+        };
+
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Task").unwrap(), Id::new());
+        net.add_task(task.id).unwrap();
+        let hours = |hours: u64| Duration::from_secs(hours * 3600);
+        assert_eq!(net.effort_of(&task), Effort::default());
+
+        net.estimate_task(task.id, Some(hours(3))).unwrap();
+        for logged in [2, 2] {
+            task.log_work(WorkEntry {
+                duration: hours(logged),
+                date: SystemClock.now(),
+                actor: Id::new(),
+            })
+            .unwrap();
+        }
+        let effort = net.effort_of(&task);
+        assert_eq!(
+            effort,
+            Effort {
+                estimated: Some(hours(3)),
+                logged: hours(4),
+            }
+        );
+        assert_eq!(effort.overrun(), Some(hours(1)));
+
+        net.estimate_task(task.id, Some(hours(5))).unwrap();
+        assert_eq!(net.effort_of(&task).overrun(), None);
+    }
+
+    #[test]
+    fn test_clone_subtree() {
+        let mut net = Entity::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let phase = Id::new();
+        let design = Id::new();
+        let review = Id::new();
+        let outside = Id::new();
+        for task in [phase, design, review, outside] {
+            net.add_task(task).unwrap();
+        }
+        for (from, to, relation_type) in [
+            (design, phase, RelationType::Compose),
+            (review, phase, RelationType::Compose),
+            (design, review, RelationType::Require),
+            (outside, design, RelationType::Require),
+        ] {
+            net.new_relation(from, to, relation_type, &SystemClock)
+                .unwrap();
+        }
+        net.set_relation_strength(design, review, RelationStrength::Soft, &SystemClock)
+            .unwrap();
+        net.annotate_relation(design, review, Some("Sign-off".to_owned()))
+            .unwrap();
+        net.estimate_task(design, Some(Duration::from_secs(3600)))
+            .unwrap();
+        net.set_milestone(review, true, &SystemClock).unwrap();
+        net.change_task_status(outside, net.accepted_status(), &SystemClock)
+            .unwrap();
+        net.change_task_status(design, net.accepted_status(), &SystemClock)
+            .unwrap();
+
+        let copies = net.clone_subtree(phase, &SystemClock).unwrap();
+        assert_eq!(copies.len(), 3);
+        assert!(!copies.contains_key(&outside));
+        let copy = |task| copies[&task];
+        assert_eq!(net.status_of(copy(design)), Some(net.default_status()));
+        assert_eq!(
+            net.estimate_of(copy(design)),
+            Some(Duration::from_secs(3600))
+        );
+        assert!(net.is_milestone(copy(review)));
+        let mut children = net.direct_subtasks_of(copy(phase));
+        children.sort();
+        let mut expected = vec![copy(design), copy(review)];
+        expected.sort();
+        assert_eq!(children, expected);
+        let relation = net
+            .relations()
+            .find(|(from, to, _)| (*from, *to) == (copy(design), copy(review)))
+            .map(|(_, _, relation)| relation.clone())
+            .unwrap();
+        assert_eq!(relation.strength(), RelationStrength::Soft);
+        assert_eq!(relation.note(), Some("Sign-off"));
+        assert!(!net
+            .relations()
+            .any(|(from, to, _)| from == outside && to == copy(design)));
+
+        assert!(matches!(
+            net.clone_subtree(Id::new(), &SystemClock),
+            Err(TaskDomainError::TaskNotFoundInNet { .. })
+        ));
+    }
+}
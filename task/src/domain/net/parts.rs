@@ -0,0 +1,698 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::OnceLock,
+    time::Duration,
+};
+
+use petgraph::{algo::toposort, prelude::DiGraphMap};
+use shared_kernel::{Entity, Id, Timestamp};
+
+use super::{
+    Color, Net, PropagationPolicy, Relation, RelationStrength, RelationType, RemovedTask, Schema,
+    Status, StatusCategory, StatusChange, TaskDomainResult, WipLimit, STATUS_HISTORY_LIMIT,
+};
+use crate::domain::{error::TaskDomainError, link::TaskRef, name::StatusName, task::Task};
+
+/// The plain, stable representation of a net, listing everything in a deterministic order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetParts {
+    /// The status schema of the net.
+    pub schema: SchemaParts,
+    /// The tasks of the net with their status, ordered by task id.
+    pub tasks: Vec<(Id<Task>, Id<Status>)>,
+    /// The relations between tasks, ordered by source and target.
+    pub relations: Vec<RelationParts>,
+    /// The soft-removed tasks, ordered by task id.
+    pub removed_tasks: Vec<RemovedTaskParts>,
+    /// How requirement relations control their targets.
+    pub propagation_policy: PropagationPolicy,
+    /// Whether every task may have at most one composition parent.
+    pub single_parent: bool,
+    /// Whether removing a relation warns when it splits a subtree off the net.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub warn_on_orphaning: bool,
+    /// The duration estimates of tasks, ordered by task id.
+    pub estimates: Vec<(Id<Task>, Duration)>,
+    /// The tasks requiring tasks of other nets, ordered by task and required task.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub links: Vec<LinkParts>,
+    /// The milestone tasks, ordered by task id.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub milestones: Vec<Id<Task>>,
+    /// The archived tasks, ordered by task id.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub archived: Vec<Id<Task>>,
+    /// Whether the net is locked against changes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub locked: bool,
+    /// The status histories of tasks, oldest change first, ordered by task id.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub status_history: Vec<(Id<Task>, Vec<StatusChange>)>,
+}
+
+/// The plain representation of a status schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaParts {
+    /// The statuses in board order.
+    pub statuses: Vec<StatusParts>,
+    /// The status new tasks are put on.
+    pub default: Id<Status>,
+    /// The primary status counting a task as done.
+    pub accepted: Id<Status>,
+    /// The allowed workflow transitions, ordered by source and target.
+    pub allowed_transitions: Vec<(Id<Status>, Id<Status>)>,
+    /// The forbidden workflow transitions, ordered by source and target.
+    pub forbidden_transitions: Vec<(Id<Status>, Id<Status>)>,
+}
+
+/// The plain representation of a status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusParts {
+    pub id: Id<Status>,
+    pub name: StatusName,
+    pub category: StatusCategory,
+    pub color: Option<Color>,
+    pub description: Option<String>,
+    pub wip_limit: Option<WipLimit>,
+}
+
+/// The plain representation of a relation between two tasks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelationParts {
+    pub from: Id<Task>,
+    pub to: Id<Task>,
+    pub relation_type: RelationType,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strength: RelationStrength,
+    pub note: Option<String>,
+    pub created_at: Timestamp,
+}
+
+/// The plain representation of a soft-removed task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemovedTaskParts {
+    pub task: Id<Task>,
+    pub status: Id<Status>,
+    /// The relations the task had when it was removed, ordered by source and target.
+    pub relations: Vec<RelationParts>,
+    pub removed_at: Timestamp,
+}
+
+/// The plain representation of a task requiring a task of another net.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinkParts {
+    pub task: Id<Task>,
+    pub required: TaskRef,
+    /// Whether the required task was last seen accepted.
+    pub accepted: bool,
+}
+
+/// The versioned on-disk form of a net.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+pub(super) enum NetRecord {
+    #[serde(rename = "1")]
+    V1(NetParts),
+}
+
+/// The versioned on-disk form of a status schema.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+pub(super) enum SchemaRecord {
+    #[serde(rename = "1")]
+    V1(SchemaParts),
+}
+
+/// The id validation errors refer to when a net or schema is rebuilt on its own, since the id
+/// of a net is stored beside its data.
+fn unknown_net() -> Id<Net> {
+    Id::from_uuid(shared_kernel::Uuid::nil())
+}
+
+#[cfg(feature = "serde")]
+impl From<Net> for NetRecord {
+    fn from(net: Net) -> Self {
+        NetRecord::V1(net.to_parts())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<NetRecord> for Net {
+    type Error = TaskDomainError;
+
+    fn try_from(record: NetRecord) -> Result<Self, Self::Error> {
+        match record {
+            NetRecord::V1(parts) => Net::from_parts(unknown_net(), parts),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Schema> for SchemaRecord {
+    fn from(schema: Schema) -> Self {
+        SchemaRecord::V1(schema.to_parts())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SchemaRecord> for Schema {
+    type Error = TaskDomainError;
+
+    fn try_from(record: SchemaRecord) -> Result<Self, Self::Error> {
+        match record {
+            SchemaRecord::V1(parts) => Schema::from_parts(unknown_net(), parts),
+        }
+    }
+}
+
+impl RelationParts {
+    fn new(from: Id<Task>, to: Id<Task>, relation: &Relation) -> Self {
+        RelationParts {
+            from,
+            to,
+            relation_type: relation.relation_type,
+            strength: relation.strength,
+            note: relation.note.clone(),
+            created_at: relation.created_at,
+        }
+    }
+
+    fn into_relation(self) -> (Id<Task>, Id<Task>, Relation) {
+        let relation = Relation {
+            relation_type: self.relation_type,
+            strength: self.strength,
+            note: self.note,
+            created_at: self.created_at,
+        };
+        (self.from, self.to, relation)
+    }
+}
+
+/// Sorts pairs of ids, which come out of hash-based collections in no particular order.
+fn sorted<A: Ord, B: Ord>(pairs: impl IntoIterator<Item = (A, B)>) -> Vec<(A, B)> {
+    let mut pairs: Vec<_> = pairs.into_iter().collect();
+    pairs.sort();
+    pairs
+}
+
+fn sorted_relations(relations: impl IntoIterator<Item = RelationParts>) -> Vec<RelationParts> {
+    let mut relations: Vec<_> = relations.into_iter().collect();
+    relations.sort_by_key(|relation| (relation.from, relation.to));
+    relations
+}
+
+impl Schema {
+    /// Exports the schema so it can be stored or applied to other nets.
+    pub fn export(&self) -> SchemaParts {
+        self.to_parts()
+    }
+
+    /// Imports an exported schema, checking that every referenced status exists and that the
+    /// primary accepted status counts as accepted.
+    pub fn import(parts: SchemaParts) -> TaskDomainResult<Schema> {
+        Schema::from_parts(unknown_net(), parts)
+    }
+
+    pub(super) fn to_parts(&self) -> SchemaParts {
+        SchemaParts {
+            statuses: self
+                .iter()
+                .map(|status| StatusParts {
+                    id: status.id,
+                    name: status.data.name.clone(),
+                    category: status.data.category,
+                    color: status.data.color,
+                    description: status.data.description.clone(),
+                    wip_limit: status.data.wip_limit,
+                })
+                .collect(),
+            default: self.default,
+            accepted: self.primary_accepted,
+            allowed_transitions: sorted(self.allowed_transitions.iter().copied()),
+            forbidden_transitions: sorted(self.forbidden_transitions.iter().copied()),
+        }
+    }
+
+    /// Rebuilds a schema from its parts, checking that every referenced status exists and
+    /// that the primary accepted status counts as accepted.
+    pub(super) fn from_parts(net: Id<Net>, parts: SchemaParts) -> TaskDomainResult<Schema> {
+        let mut schema = Schema {
+            statuses: HashMap::with_capacity(parts.statuses.len()),
+            order: Vec::with_capacity(parts.statuses.len()),
+            default: parts.default,
+            primary_accepted: parts.accepted,
+            allowed_transitions: parts.allowed_transitions.into_iter().collect(),
+            forbidden_transitions: parts.forbidden_transitions.into_iter().collect(),
+        };
+        for parts in parts.statuses {
+            if schema.contains(parts.id) {
+                return Err(TaskDomainError::StatusAlreadyInNet {
+                    net,
+                    status: parts.id,
+                });
+            }
+            schema.push(Entity {
+                id: parts.id,
+                data: Status {
+                    name: parts.name,
+                    category: parts.category,
+                    color: parts.color,
+                    description: parts.description,
+                    wip_limit: parts.wip_limit,
+                },
+            });
+        }
+
+        let referenced = [schema.default, schema.primary_accepted].into_iter().chain(
+            schema
+                .allowed_transitions
+                .iter()
+                .chain(&schema.forbidden_transitions)
+                .flat_map(|(from, to)| [*from, *to]),
+        );
+        for status in referenced {
+            if !schema.contains(status) {
+                return Err(TaskDomainError::StatusNotFoundInNet { net, status });
+            }
+        }
+        if !schema.is_accepted(schema.primary_accepted) {
+            return Err(TaskDomainError::StatusNotUnmarkable {
+                net,
+                status: schema.primary_accepted,
+            });
+        }
+
+        Ok(schema)
+    }
+}
+
+impl Net {
+    pub(super) fn to_parts(&self) -> NetParts {
+        let mut removed_tasks: Vec<_> = self
+            .removed_tasks
+            .iter()
+            .map(|(task, removed)| RemovedTaskParts {
+                task: *task,
+                status: removed.status,
+                relations: sorted_relations(
+                    removed
+                        .relations
+                        .iter()
+                        .map(|(from, to, relation)| RelationParts::new(*from, *to, relation)),
+                ),
+                removed_at: removed.removed_at,
+            })
+            .collect();
+        removed_tasks.sort_by_key(|removed| removed.task);
+
+        NetParts {
+            schema: self.schema.to_parts(),
+            tasks: sorted(self.tasks.iter().map(|(task, status)| (*task, *status))),
+            relations: sorted_relations(
+                self.relations
+                    .all_edges()
+                    .map(|(from, to, relation)| RelationParts::new(from, to, relation)),
+            ),
+            removed_tasks,
+            propagation_policy: self.propagation_policy,
+            single_parent: self.single_parent,
+            warn_on_orphaning: self.warn_on_orphaning,
+            estimates: sorted(
+                self.estimates
+                    .iter()
+                    .map(|(task, estimate)| (*task, *estimate)),
+            ),
+            links: {
+                let mut links: Vec<_> = self
+                    .links
+                    .iter()
+                    .flat_map(|(task, links)| {
+                        links.iter().map(|(required, accepted)| LinkParts {
+                            task: *task,
+                            required: *required,
+                            accepted: *accepted,
+                        })
+                    })
+                    .collect();
+                links.sort_by_key(|link| (link.task, link.required));
+                links
+            },
+            milestones: {
+                let mut milestones: Vec<_> = self.milestones.iter().copied().collect();
+                milestones.sort();
+                milestones
+            },
+            archived: {
+                let mut archived: Vec<_> = self.archived.iter().copied().collect();
+                archived.sort();
+                archived
+            },
+            locked: self.locked,
+            status_history: {
+                let mut history: Vec<_> = self
+                    .status_history
+                    .iter()
+                    .map(|(task, changes)| (*task, changes.iter().cloned().collect()))
+                    .collect();
+                history.sort_by_key(|(task, _)| *task);
+                history
+            },
+        }
+    }
+
+    /// Rebuilds a net from its parts, checking the invariants the mutations of the net
+    /// uphold, down to relations listed twice and hard work-in-progress limits. The net starts
+    /// without pending events.
+    pub(super) fn from_parts(net: Id<Net>, parts: NetParts) -> TaskDomainResult<Net> {
+        let schema = Schema::from_parts(net, parts.schema)?;
+
+        let mut tasks = HashMap::new();
+        let mut relations = DiGraphMap::new();
+        for (task, status) in parts.tasks {
+            if !schema.contains(status) {
+                return Err(TaskDomainError::StatusNotFoundInNet { net, status });
+            }
+            if tasks.insert(task, status).is_some() {
+                return Err(TaskDomainError::TaskAlreadyInNet { task, net });
+            }
+            relations.add_node(task);
+        }
+
+        for relation in parts.relations {
+            let (from, to, relation) = relation.into_relation();
+            if let Some(task) = [from, to]
+                .into_iter()
+                .find(|task| !tasks.contains_key(task))
+            {
+                return Err(TaskDomainError::TaskNotFoundInNet { net, task });
+            }
+            if from == to {
+                return Err(TaskDomainError::CycleNotAllowedInNet {
+                    net,
+                    cycle: vec![from],
+                });
+            }
+            if relations.add_edge(from, to, relation).is_some() {
+                return Err(TaskDomainError::RelationAlreadyInNet { net, from, to });
+            }
+        }
+
+        let mut removed_tasks = HashMap::new();
+        for removed in parts.removed_tasks {
+            if !schema.contains(removed.status) {
+                return Err(TaskDomainError::StatusNotFoundInNet {
+                    net,
+                    status: removed.status,
+                });
+            }
+            if tasks.contains_key(&removed.task) || removed_tasks.contains_key(&removed.task) {
+                return Err(TaskDomainError::TaskAlreadyInNet {
+                    task: removed.task,
+                    net,
+                });
+            }
+            removed_tasks.insert(
+                removed.task,
+                RemovedTask {
+                    status: removed.status,
+                    relations: removed
+                        .relations
+                        .into_iter()
+                        .map(RelationParts::into_relation)
+                        .collect(),
+                    removed_at: removed.removed_at,
+                },
+            );
+        }
+
+        let mut estimates = HashMap::new();
+        for (task, estimate) in parts.estimates {
+            if !tasks.contains_key(&task) && !removed_tasks.contains_key(&task) {
+                return Err(TaskDomainError::TaskNotFoundInNet { net, task });
+            }
+            estimates.insert(task, estimate);
+        }
+
+        let mut links: HashMap<_, HashMap<_, _>> = HashMap::new();
+        for link in parts.links {
+            if !tasks.contains_key(&link.task) && !removed_tasks.contains_key(&link.task) {
+                return Err(TaskDomainError::TaskNotFoundInNet {
+                    net,
+                    task: link.task,
+                });
+            }
+            links
+                .entry(link.task)
+                .or_default()
+                .insert(link.required, link.accepted);
+        }
+
+        let mut milestones = HashSet::new();
+        for task in parts.milestones {
+            if !tasks.contains_key(&task) && !removed_tasks.contains_key(&task) {
+                return Err(TaskDomainError::TaskNotFoundInNet { net, task });
+            }
+            milestones.insert(task);
+        }
+
+        let mut archived = HashSet::new();
+        for task in parts.archived {
+            if !tasks.contains_key(&task) && !removed_tasks.contains_key(&task) {
+                return Err(TaskDomainError::TaskNotFoundInNet { net, task });
+            }
+            archived.insert(task);
+        }
+
+        let mut status_history = HashMap::new();
+        for (task, changes) in parts.status_history {
+            if !tasks.contains_key(&task) && !removed_tasks.contains_key(&task) {
+                return Err(TaskDomainError::TaskNotFoundInNet { net, task });
+            }
+            let skipped = changes.len().saturating_sub(STATUS_HISTORY_LIMIT);
+            status_history.insert(task, changes.into_iter().skip(skipped).collect());
+        }
+
+        let result = Net {
+            relations,
+            schema,
+            tasks,
+            removed_tasks,
+            propagation_policy: parts.propagation_policy,
+            single_parent: parts.single_parent,
+            warn_on_orphaning: parts.warn_on_orphaning,
+            estimates,
+            links,
+            milestones,
+            archived,
+            locked: parts.locked,
+            status_history,
+            propagation_deferred: false,
+            topo_order: OnceLock::new(),
+            events: Vec::new(),
+        };
+
+        if let Some((status, limit)) = result.exceeded_hard_wip_limit() {
+            return Err(TaskDomainError::WipLimitExceeded { net, status, limit });
+        }
+        if let Err(cycle) = toposort(&result.constraints(), None) {
+            return Err(TaskDomainError::CycleNotAllowedInNet {
+                net,
+                cycle: result.cycle_through(cycle.node_id()),
+            });
+        }
+        if result.single_parent {
+            if let Some(task) = result
+                .tasks
+                .keys()
+                .find(|task| result.parents_of(**task).nth(1).is_some())
+            {
+                return Err(TaskDomainError::MultipleParentsNotAllowed { net, task: *task });
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::SystemClock;
+
+    use super::*;
+    use crate::domain::net::{NetAggregateRoot, NetQuery, WipLimitMode};
+
+    fn sample_net() -> Entity<Net> {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        net.new_status(StatusName::new("Doing").unwrap()).unwrap();
+        let doing = net
+            .statuses()
+            .find(|status| status.data.name() == "Doing")
+            .unwrap()
+            .id;
+        net.change_status_wip_limit(
+            doing,
+            Some(WipLimit {
+                limit: 2,
+                mode: WipLimitMode::Soft,
+            }),
+        )
+        .unwrap();
+        net.allow_transition(net.default_status(), doing).unwrap();
+        net.require_single_parent(true).unwrap();
+
+        let parent = Id::new();
+        let child = Id::new();
+        let removed = Id::new();
+        for task in [parent, child, removed] {
+            net.add_task(task).unwrap();
+        }
+        net.new_relation(child, parent, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.new_relation(removed, child, RelationType::RelatesTo, &SystemClock)
+            .unwrap();
+        net.estimate_task(child, Some(Duration::from_secs(3600)))
+            .unwrap();
+        net.set_milestone(parent, true, &SystemClock).unwrap();
+        net.link_requirement(
+            parent,
+            TaskRef::new(Id::new(), Id::new()),
+            true,
+            &SystemClock,
+        )
+        .unwrap();
+        net.soft_remove_task(removed, &SystemClock).unwrap();
+        net.change_task_status(child, doing, &SystemClock).unwrap();
+        net
+    }
+
+    #[test]
+    fn test_parts_round_trip() {
+        let net = sample_net();
+        let parts = net.to_parts();
+
+        let restored: Entity<Net> = NetAggregateRoot::from_parts(net.id, parts.clone()).unwrap();
+
+        assert_eq!(restored.id, net.id);
+        assert_eq!(restored.to_parts(), parts);
+        assert!(restored.data.events.is_empty());
+    }
+
+    #[test]
+    fn test_from_parts_rejects_broken_invariants() {
+        let net = sample_net();
+        let parts = net.to_parts();
+
+        let mut unknown_status = parts.clone();
+        unknown_status.tasks[0].1 = Id::new();
+        assert!(matches!(
+            Net::from_parts(net.id, unknown_status),
+            Err(TaskDomainError::StatusNotFoundInNet { .. })
+        ));
+
+        let mut unaccepted = parts.clone();
+        let accepted = unaccepted.schema.accepted;
+        unaccepted
+            .schema
+            .statuses
+            .iter_mut()
+            .find(|status| status.id == accepted)
+            .unwrap()
+            .category = StatusCategory::InProgress;
+        assert!(matches!(
+            Net::from_parts(net.id, unaccepted),
+            Err(TaskDomainError::StatusNotUnmarkable { status, .. }) if status == accepted
+        ));
+
+        let mut cyclic = parts.clone();
+        let mut back = cyclic.relations[0].clone();
+        std::mem::swap(&mut back.from, &mut back.to);
+        cyclic.relations.push(back);
+        assert!(matches!(
+            Net::from_parts(net.id, cyclic),
+            Err(TaskDomainError::CycleNotAllowedInNet { .. })
+        ));
+
+        let mut duplicated = parts.clone();
+        duplicated.relations.push(duplicated.relations[0].clone());
+        assert!(matches!(
+            Net::from_parts(net.id, duplicated),
+            Err(TaskDomainError::RelationAlreadyInNet { from, to, .. })
+                if (from, to) == (parts.relations[0].from, parts.relations[0].to)
+        ));
+
+        let mut over_limit = parts.clone();
+        let doing = over_limit
+            .schema
+            .statuses
+            .iter_mut()
+            .find(|status| status.name == "Doing")
+            .unwrap();
+        doing.wip_limit = Some(WipLimit {
+            limit: 0,
+            mode: WipLimitMode::Hard,
+        });
+        let doing = doing.id;
+        assert!(matches!(
+            Net::from_parts(net.id, over_limit),
+            Err(TaskDomainError::WipLimitExceeded { status, limit: 0, .. }) if status == doing
+        ));
+
+        let mut dangling = parts;
+        dangling.relations[0].to = Id::new();
+        assert!(matches!(
+            Net::from_parts(net.id, dangling),
+            Err(TaskDomainError::TaskNotFoundInNet { .. })
+        ));
+    }
+
+    #[test]
+    fn test_schema_export_import() {
+        let net = sample_net();
+        let exported = net.schema().export();
+
+        let imported = Schema::import(exported.clone()).unwrap();
+        assert_eq!(imported.export(), exported);
+
+        let mut broken = exported;
+        broken.default = Id::new();
+        assert!(matches!(
+            Schema::import(broken),
+            Err(TaskDomainError::StatusNotFoundInNet { .. })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_net_serde_round_trip() {
+        let net = sample_net();
+        let json = serde_json::to_value(&net).unwrap();
+
+        assert_eq!(json["data"]["version"], "1");
+
+        let restored: Entity<Net> = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.id, net.id);
+        assert_eq!(restored.to_parts(), net.to_parts());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_net_deserialize_validates() {
+        let net = sample_net();
+        let mut json = serde_json::to_value(&net).unwrap();
+        json["data"]["schema"]["default"] = serde_json::json!(Id::<Status>::new());
+
+        assert!(serde_json::from_value::<Entity<Net>>(json).is_err());
+    }
+}
@@ -0,0 +1,667 @@
+use std::collections::{HashMap, HashSet};
+
+use shared_kernel::{Entity, Id};
+
+use super::{
+    Net, NetAggregateRoot, NetChange, Relation, Schema, Status, StatusCategory, TaskDomainResult,
+};
+use crate::domain::{error::TaskDomainError, event::TaskDomainEvent, name::StatusName};
+
+/// Rebuilds a net from its event stream, which has to start with the creation of the net.
+pub(super) fn replay(
+    events: impl IntoIterator<Item = TaskDomainEvent>,
+) -> TaskDomainResult<Entity<Net>> {
+    let mut events = events.into_iter();
+    let Some(TaskDomainEvent::NetCreated {
+        net: id,
+        statuses,
+        default,
+        accepted,
+    }) = events.next()
+    else {
+        return Err(TaskDomainError::NetCreationMissing);
+    };
+
+    let mut net = Entity {
+        id,
+        data: Net::empty(created_schema(id, statuses, default, accepted)?),
+    };
+    for event in events {
+        apply_event(&mut net, &event)?;
+    }
+    net.data.events.clear();
+
+    Ok(net)
+}
+
+/// Rebuilds the schema a net was created with, its statuses categorized the way
+/// [`NetAggregateRoot::with_statuses`] categorizes them.
+fn created_schema(
+    net: Id<Net>,
+    statuses: Vec<(Id<Status>, StatusName)>,
+    default: Id<Status>,
+    accepted: Id<Status>,
+) -> TaskDomainResult<Schema> {
+    let mut schema = Schema {
+        statuses: HashMap::with_capacity(statuses.len()),
+        order: Vec::with_capacity(statuses.len()),
+        default,
+        primary_accepted: accepted,
+        allowed_transitions: HashSet::new(),
+        forbidden_transitions: HashSet::new(),
+    };
+    for (id, name) in statuses {
+        let category = if id == default {
+            StatusCategory::Todo
+        } else if id == accepted {
+            StatusCategory::Done
+        } else {
+            StatusCategory::InProgress
+        };
+        schema.push(Entity {
+            id,
+            data: Status::new(name, category),
+        });
+    }
+    for status in [default, accepted] {
+        if !schema.contains(status) {
+            return Err(TaskDomainError::StatusNotFoundInNet { net, status });
+        }
+    }
+
+    Ok(schema)
+}
+
+/// Applies an event recorded by a net to its state.
+///
+/// A lock only keeps a net from making new changes, so the net is unlocked while the event
+/// is applied.
+pub(super) fn apply_event(net: &mut Entity<Net>, event: &TaskDomainEvent) -> TaskDomainResult<()> {
+    let id = net.id;
+    if net_of(event) != Some(id) {
+        return Err(not_applicable(id, event));
+    }
+
+    let locked = std::mem::take(&mut net.data.locked);
+    let applied = apply_recorded(net, event);
+    net.data.locked = match event {
+        TaskDomainEvent::NetLocked { .. } => true,
+        TaskDomainEvent::NetUnlocked { .. } => false,
+        _ => locked,
+    };
+    applied
+}
+
+/// Applies an event recorded by a net to its unlocked state.
+///
+/// Status changes caused by propagation are events of their own, so nothing propagates here.
+/// Events that do not propagate are applied through the operation that recorded them, whose
+/// events the caller discards.
+fn apply_recorded(net: &mut Entity<Net>, event: &TaskDomainEvent) -> TaskDomainResult<()> {
+    let id = net.id;
+    let task_not_found = |task| TaskDomainError::TaskNotFoundInNet { net: id, task };
+    let status_not_found = |status| TaskDomainError::StatusNotFoundInNet { net: id, status };
+
+    match event {
+        TaskDomainEvent::SchemaReplaced {
+            schema,
+            status_mapping,
+            replaced_at,
+            ..
+        } => {
+            let schema = Schema::from_parts(id, schema.clone())?;
+            net.data.switch_schema(schema, status_mapping, *replaced_at);
+        }
+        TaskDomainEvent::TaskAddedToNet { task, .. } => net.add_task(*task)?,
+        TaskDomainEvent::TaskRemovedFromNet { task, .. } => {
+            if !net.data.detach_task(*task) {
+                return Err(task_not_found(*task));
+            }
+        }
+        TaskDomainEvent::TaskSoftRemovedFromNet {
+            task, removed_at, ..
+        } => {
+            if !net.data.shelve_task(*task, *removed_at) {
+                return Err(task_not_found(*task));
+            }
+        }
+        TaskDomainEvent::TaskRestoredToNet { task, .. } => {
+            let removed = net.data.removed_tasks.remove(task).ok_or(
+                TaskDomainError::TaskNotRemovedFromNet {
+                    net: id,
+                    task: *task,
+                },
+            )?;
+            net.data.unshelve_task(*task, removed);
+        }
+        TaskDomainEvent::TaskStatusChanged { task, to, .. }
+        | TaskDomainEvent::StatusPropagated { task, to, .. } => {
+            if !net.data.schema.contains(*to) {
+                return Err(status_not_found(*to));
+            }
+            let status = net.data.tasks.get_mut(task).ok_or(task_not_found(*task))?;
+            *status = *to;
+        }
+        TaskDomainEvent::TaskArchived { task, .. } => {
+            if !net.data.tasks.contains_key(task) {
+                return Err(task_not_found(*task));
+            }
+            net.data.archived.insert(*task);
+        }
+        TaskDomainEvent::TaskUnarchived { task, .. } => {
+            if !net.data.tasks.contains_key(task) {
+                return Err(task_not_found(*task));
+            }
+            net.data.archived.remove(task);
+        }
+        TaskDomainEvent::MilestoneSet {
+            task, milestone, ..
+        } => {
+            if !net.data.tasks.contains_key(task) {
+                return Err(task_not_found(*task));
+            }
+            if *milestone {
+                net.data.milestones.insert(*task);
+            } else {
+                net.data.milestones.remove(task);
+            }
+        }
+        TaskDomainEvent::RequirementLinked {
+            task,
+            required,
+            accepted,
+            ..
+        } => {
+            if !net.data.tasks.contains_key(task) {
+                return Err(task_not_found(*task));
+            }
+            net.data
+                .links
+                .entry(*task)
+                .or_default()
+                .insert(*required, *accepted);
+        }
+        TaskDomainEvent::RequirementUnlinked { task, required, .. } => {
+            let links = net.data.links.get_mut(task);
+            if links.and_then(|links| links.remove(required)).is_none() {
+                return Err(TaskDomainError::LinkNotFoundInNet {
+                    net: id,
+                    task: *task,
+                    required: *required,
+                });
+            }
+            if net.data.links[task].is_empty() {
+                net.data.links.remove(task);
+            }
+        }
+        TaskDomainEvent::LinkedRequirementUpdated {
+            required, accepted, ..
+        } => {
+            for known in net
+                .data
+                .links
+                .values_mut()
+                .filter_map(|links| links.get_mut(required))
+            {
+                *known = *accepted;
+            }
+        }
+        TaskDomainEvent::RelationAdded {
+            from,
+            to,
+            relation_type,
+            created_at,
+            ..
+        } => {
+            if let Some(task) = [from, to]
+                .into_iter()
+                .find(|task| !net.data.tasks.contains_key(task))
+            {
+                return Err(task_not_found(*task));
+            }
+            net.data.relations_mut().add_edge(
+                *from,
+                *to,
+                Relation::new(*relation_type, *created_at),
+            );
+        }
+        TaskDomainEvent::RelationStrengthChanged {
+            from, to, strength, ..
+        } => {
+            let relation = net.data.relations.edge_weight_mut(*from, *to).ok_or(
+                TaskDomainError::RelationNotFoundInNet {
+                    net: id,
+                    from: *from,
+                    to: *to,
+                },
+            )?;
+            relation.strength = *strength;
+        }
+        TaskDomainEvent::RelationRemoved { from, to, .. } => {
+            if net.data.relations_mut().remove_edge(*from, *to).is_none() {
+                return Err(TaskDomainError::RelationNotFoundInNet {
+                    net: id,
+                    from: *from,
+                    to: *to,
+                });
+            }
+        }
+        TaskDomainEvent::StatusAdded { status, name, .. } => {
+            if net.data.schema.contains(*status) {
+                return Err(TaskDomainError::StatusAlreadyInNet {
+                    net: id,
+                    status: *status,
+                });
+            }
+            net.data.schema.push(Entity {
+                id: *status,
+                data: Status::new(name.clone(), StatusCategory::InProgress),
+            });
+        }
+        TaskDomainEvent::StatusRemoved { status, .. } => {
+            if !net.data.schema.contains(*status) {
+                return Err(status_not_found(*status));
+            }
+            net.data.detach_status(*status);
+        }
+        TaskDomainEvent::StatusRenamed { status, name, .. } => {
+            net.change_status_name(*status, name.clone())?
+        }
+        TaskDomainEvent::DefaultStatusChanged { status, .. } => net.change_default(*status)?,
+        TaskDomainEvent::AcceptedStatusChanged { status, .. } => {
+            if !net.data.schema.contains(*status) {
+                return Err(status_not_found(*status));
+            }
+            net.data.switch_accepted(*status);
+        }
+        TaskDomainEvent::StatusColorChanged { status, color, .. } => {
+            net.change_status_color(*status, *color)?
+        }
+        TaskDomainEvent::StatusDescriptionChanged {
+            status,
+            description,
+            ..
+        } => net.change_status_description(*status, description.clone())?,
+        TaskDomainEvent::StatusWipLimitChanged {
+            status, wip_limit, ..
+        } => net.change_status_wip_limit(*status, *wip_limit)?,
+        TaskDomainEvent::WipLimitExceeded { .. }
+        | TaskDomainEvent::SubtreeOrphaned { .. }
+        | TaskDomainEvent::NetLocked { .. }
+        | TaskDomainEvent::NetUnlocked { .. } => {}
+        TaskDomainEvent::PropagationPolicyChanged { policy, .. } => {
+            net.data.propagation_policy = *policy;
+        }
+        TaskDomainEvent::SingleParentRequirementChanged { enabled, .. } => {
+            net.require_single_parent(*enabled)?
+        }
+        TaskDomainEvent::OrphanWarningsChanged { enabled, .. } => {
+            net.warn_on_orphaning(*enabled)?
+        }
+        TaskDomainEvent::RelationAnnotated { from, to, note, .. } => {
+            net.annotate_relation(*from, *to, note.clone())?
+        }
+        TaskDomainEvent::TaskEstimated { task, estimate, .. } => {
+            net.estimate_task(*task, *estimate)?
+        }
+        TaskDomainEvent::StatusMoved {
+            status, position, ..
+        } => net.move_status(*status, *position)?,
+        TaskDomainEvent::TransitionAllowed { from, to, .. } => net.allow_transition(*from, *to)?,
+        TaskDomainEvent::TransitionForbidden { from, to, .. } => {
+            net.forbid_transition(*from, *to)?
+        }
+        TaskDomainEvent::StatusCategoryChanged {
+            status, category, ..
+        } => {
+            let status = net
+                .data
+                .schema
+                .get_mut(*status)
+                .ok_or(status_not_found(*status))?;
+            status.data.category = *category;
+        }
+        TaskDomainEvent::StatusMarkedAccepted { status, .. } => {
+            let status = net
+                .data
+                .schema
+                .get_mut(*status)
+                .ok_or(status_not_found(*status))?;
+            status.data.category = StatusCategory::Done;
+        }
+        TaskDomainEvent::StatusUnmarkedAccepted { status, .. } => {
+            let status = net
+                .data
+                .schema
+                .get_mut(*status)
+                .ok_or(status_not_found(*status))?;
+            if status.data.category == StatusCategory::Done {
+                status.data.category = StatusCategory::InProgress;
+            }
+        }
+        TaskDomainEvent::NetEditUndone { change, .. }
+        | TaskDomainEvent::NetEditRedone { change, .. } => {
+            // Replayed nets keep no status history, so the changes to it are left out.
+            let change = NetChange {
+                status_history: Vec::new(),
+                ..change.as_ref().clone()
+            };
+            net.data = net.data.apply_change(id, &change)?;
+        }
+        // A net is created once.
+        TaskDomainEvent::NetCreated { .. }
+        | TaskDomainEvent::ListCreated { .. }
+        | TaskDomainEvent::ListRenamed { .. }
+        | TaskDomainEvent::TaskCreated { .. }
+        | TaskDomainEvent::TaskRenamed { .. }
+        | TaskDomainEvent::TaskDescriptionChanged { .. }
+        | TaskDomainEvent::TaskScheduled { .. }
+        | TaskDomainEvent::TaskPrioritized { .. }
+        | TaskDomainEvent::TaskAssigned { .. }
+        | TaskDomainEvent::TaskUnassigned { .. }
+        | TaskDomainEvent::TaskLabeled { .. }
+        | TaskDomainEvent::TaskUnlabeled { .. }
+        | TaskDomainEvent::WorkLogged { .. }
+        | TaskDomainEvent::WorkEntryRemoved { .. }
+        | TaskDomainEvent::TaskRecurrenceChanged { .. }
+        | TaskDomainEvent::FieldDefined { .. }
+        | TaskDomainEvent::FieldRemoved { .. }
+        | TaskDomainEvent::TaskPositioned { .. }
+        | TaskDomainEvent::TaskRemovedFromList { .. }
+        | TaskDomainEvent::SectionAdded { .. }
+        | TaskDomainEvent::SectionRenamed { .. }
+        | TaskDomainEvent::SectionRemoved { .. }
+        | TaskDomainEvent::SectionMoved { .. }
+        | TaskDomainEvent::TaskSectioned { .. }
+        | TaskDomainEvent::ListMoved { .. }
+        | TaskDomainEvent::ListArchived { .. }
+        | TaskDomainEvent::ListUnarchived { .. }
+        | TaskDomainEvent::ListsMerged { .. }
+        | TaskDomainEvent::TaskFieldSet { .. }
+        | TaskDomainEvent::TaskFieldCleared { .. }
+        | TaskDomainEvent::TaskTemplateCreated { .. }
+        | TaskDomainEvent::TaskTemplateNamePatternChanged { .. }
+        | TaskDomainEvent::TaskTemplateDefaultsChanged { .. }
+        | TaskDomainEvent::TaskTemplateInstantiated { .. }
+        | TaskDomainEvent::SmartListCreated { .. }
+        | TaskDomainEvent::SmartListRenamed { .. }
+        | TaskDomainEvent::SmartListFilterChanged { .. }
+        | TaskDomainEvent::ReminderScheduled { .. }
+        | TaskDomainEvent::ReminderRescheduled { .. }
+        | TaskDomainEvent::ReminderFired { .. }
+        | TaskDomainEvent::LabelDefined { .. }
+        | TaskDomainEvent::LabelRecolored { .. }
+        | TaskDomainEvent::LabelUndefined { .. }
+        | TaskDomainEvent::TaskRecategorized { .. } => return Err(not_applicable(id, event)),
+    }
+
+    Ok(())
+}
+
+/// Returns the net an event was recorded by, if it was recorded by one.
+fn net_of(event: &TaskDomainEvent) -> Option<Id<Net>> {
+    match event {
+        TaskDomainEvent::NetCreated { net, .. }
+        | TaskDomainEvent::SchemaReplaced { net, .. }
+        | TaskDomainEvent::TaskAddedToNet { net, .. }
+        | TaskDomainEvent::TaskRemovedFromNet { net, .. }
+        | TaskDomainEvent::TaskSoftRemovedFromNet { net, .. }
+        | TaskDomainEvent::TaskRestoredToNet { net, .. }
+        | TaskDomainEvent::TaskStatusChanged { net, .. }
+        | TaskDomainEvent::StatusPropagated { net, .. }
+        | TaskDomainEvent::TaskArchived { net, .. }
+        | TaskDomainEvent::TaskUnarchived { net, .. }
+        | TaskDomainEvent::MilestoneSet { net, .. }
+        | TaskDomainEvent::RequirementLinked { net, .. }
+        | TaskDomainEvent::RequirementUnlinked { net, .. }
+        | TaskDomainEvent::LinkedRequirementUpdated { net, .. }
+        | TaskDomainEvent::RelationAdded { net, .. }
+        | TaskDomainEvent::RelationStrengthChanged { net, .. }
+        | TaskDomainEvent::RelationRemoved { net, .. }
+        | TaskDomainEvent::StatusAdded { net, .. }
+        | TaskDomainEvent::StatusRemoved { net, .. }
+        | TaskDomainEvent::StatusRenamed { net, .. }
+        | TaskDomainEvent::DefaultStatusChanged { net, .. }
+        | TaskDomainEvent::AcceptedStatusChanged { net, .. }
+        | TaskDomainEvent::StatusColorChanged { net, .. }
+        | TaskDomainEvent::StatusDescriptionChanged { net, .. }
+        | TaskDomainEvent::StatusWipLimitChanged { net, .. }
+        | TaskDomainEvent::WipLimitExceeded { net, .. }
+        | TaskDomainEvent::PropagationPolicyChanged { net, .. }
+        | TaskDomainEvent::SingleParentRequirementChanged { net, .. }
+        | TaskDomainEvent::OrphanWarningsChanged { net, .. }
+        | TaskDomainEvent::SubtreeOrphaned { net, .. }
+        | TaskDomainEvent::RelationAnnotated { net, .. }
+        | TaskDomainEvent::TaskEstimated { net, .. }
+        | TaskDomainEvent::StatusMoved { net, .. }
+        | TaskDomainEvent::TransitionAllowed { net, .. }
+        | TaskDomainEvent::TransitionForbidden { net, .. }
+        | TaskDomainEvent::StatusCategoryChanged { net, .. }
+        | TaskDomainEvent::StatusMarkedAccepted { net, .. }
+        | TaskDomainEvent::StatusUnmarkedAccepted { net, .. }
+        | TaskDomainEvent::NetLocked { net }
+        | TaskDomainEvent::NetUnlocked { net }
+        | TaskDomainEvent::NetEditUndone { net, .. }
+        | TaskDomainEvent::NetEditRedone { net, .. } => Some(*net),
+        TaskDomainEvent::ListCreated { .. }
+        | TaskDomainEvent::ListRenamed { .. }
+        | TaskDomainEvent::TaskCreated { .. }
+        | TaskDomainEvent::TaskRenamed { .. }
+        | TaskDomainEvent::TaskDescriptionChanged { .. }
+        | TaskDomainEvent::TaskScheduled { .. }
+        | TaskDomainEvent::TaskPrioritized { .. }
+        | TaskDomainEvent::TaskAssigned { .. }
+        | TaskDomainEvent::TaskUnassigned { .. }
+        | TaskDomainEvent::TaskLabeled { .. }
+        | TaskDomainEvent::TaskUnlabeled { .. }
+        | TaskDomainEvent::WorkLogged { .. }
+        | TaskDomainEvent::WorkEntryRemoved { .. }
+        | TaskDomainEvent::TaskRecurrenceChanged { .. }
+        | TaskDomainEvent::FieldDefined { .. }
+        | TaskDomainEvent::FieldRemoved { .. }
+        | TaskDomainEvent::TaskPositioned { .. }
+        | TaskDomainEvent::TaskRemovedFromList { .. }
+        | TaskDomainEvent::SectionAdded { .. }
+        | TaskDomainEvent::SectionRenamed { .. }
+        | TaskDomainEvent::SectionRemoved { .. }
+        | TaskDomainEvent::SectionMoved { .. }
+        | TaskDomainEvent::TaskSectioned { .. }
+        | TaskDomainEvent::ListMoved { .. }
+        | TaskDomainEvent::ListArchived { .. }
+        | TaskDomainEvent::ListUnarchived { .. }
+        | TaskDomainEvent::ListsMerged { .. }
+        | TaskDomainEvent::TaskFieldSet { .. }
+        | TaskDomainEvent::TaskFieldCleared { .. }
+        | TaskDomainEvent::TaskTemplateCreated { .. }
+        | TaskDomainEvent::TaskTemplateNamePatternChanged { .. }
+        | TaskDomainEvent::TaskTemplateDefaultsChanged { .. }
+        | TaskDomainEvent::TaskTemplateInstantiated { .. }
+        | TaskDomainEvent::SmartListCreated { .. }
+        | TaskDomainEvent::SmartListRenamed { .. }
+        | TaskDomainEvent::SmartListFilterChanged { .. }
+        | TaskDomainEvent::ReminderScheduled { .. }
+        | TaskDomainEvent::ReminderRescheduled { .. }
+        | TaskDomainEvent::ReminderFired { .. }
+        | TaskDomainEvent::LabelDefined { .. }
+        | TaskDomainEvent::LabelRecolored { .. }
+        | TaskDomainEvent::LabelUndefined { .. }
+        | TaskDomainEvent::TaskRecategorized { .. } => None,
+    }
+}
+
+fn not_applicable(net: Id<Net>, event: &TaskDomainEvent) -> TaskDomainError {
+    TaskDomainError::EventNotApplicable {
+        net,
+        event: Box::new(event.clone()),
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use std::time::Duration;
+
+    use shared_kernel::{AggregateRoot, SystemClock};
+
+    use super::*;
+    use crate::domain::{
+        link::TaskRef,
+        net::{NetHistory, NetQuery, PropagationPolicy, RelationType},
+    };
+
+    fn name(name: &str) -> StatusName {
+        StatusName::new(name).unwrap()
+    }
+
+    /// Compares everything but the status histories, which are not part of the stream.
+    fn assert_same_state(replayed: &Entity<Net>, net: &Entity<Net>) {
+        let (mut replayed_parts, mut parts) = (replayed.to_parts(), net.to_parts());
+        replayed_parts.status_history.clear();
+        parts.status_history.clear();
+        assert_eq!(replayed.id, net.id);
+        assert_eq!(replayed_parts, parts);
+    }
+
+    #[test]
+    fn test_replay() {
+        let clock = SystemClock;
+        let mut net: Entity<Net> =
+            NetAggregateRoot::with_statuses(name("Todo"), vec![name("Doing")], name("Done"));
+        let doing = net.statuses().nth(1).unwrap().id;
+        let (parent, child, removed, deleted) = (Id::new(), Id::new(), Id::new(), Id::new());
+        net.add_tasks([parent, child, removed, deleted]).unwrap();
+        net.new_relation(child, parent, RelationType::Compose, &clock)
+            .unwrap();
+        net.new_relations(
+            [
+                (removed, child, RelationType::RelatesTo),
+                (deleted, parent, RelationType::Require),
+            ],
+            &clock,
+        )
+        .unwrap();
+        net.annotate_relation(child, parent, Some("part of it".to_string()))
+            .unwrap();
+        net.estimate_task(child, Some(Duration::from_secs(60)))
+            .unwrap();
+        net.new_status(name("Review")).unwrap();
+        let review = net.statuses().last().unwrap().id;
+        net.move_status(review, 2).unwrap();
+        net.change_status_name(doing, name("In Progress")).unwrap();
+        net.allow_transition(doing, review).unwrap();
+        net.mark_status_accepted(review, &clock).unwrap();
+        let required = TaskRef::new(Id::new(), Id::new());
+        net.link_requirement(child, required, false, &clock)
+            .unwrap();
+        net.set_milestone(parent, true, &clock).unwrap();
+        net.soft_remove_task(removed, &clock).unwrap();
+        net.remove_task(deleted, &clock).unwrap();
+        net.change_propagation_policy(PropagationPolicy::Advisory, &clock)
+            .unwrap();
+        net.update_linked_requirement(required, true, &clock)
+            .unwrap();
+        net.change_task_status(child, review, &clock).unwrap();
+        net.remove_status(doing, &clock).unwrap();
+        net.restore_task(removed, &clock).unwrap();
+        net.change_accepted(review, &clock).unwrap();
+        net.lock();
+
+        let replayed: Entity<Net> = NetAggregateRoot::replay(net.take_events()).unwrap();
+
+        assert_same_state(&replayed, &net);
+        assert_eq!(replayed.status_of(child), Some(review));
+        assert_eq!(replayed.status_of(parent), Some(replayed.accepted_status()));
+        assert!(replayed.is_locked());
+        assert!(replayed.data.events.is_empty());
+    }
+
+    #[test]
+    fn test_replay_schema_replacement() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(name("Todo"), name("Done"));
+        let task = Id::new();
+        net.add_task(task).unwrap();
+        let other: Entity<Net> = NetAggregateRoot::new(name("Backlog"), name("Shipped"));
+        let mapping = HashMap::from([
+            (net.default_status(), other.default_status()),
+            (net.accepted_status(), other.accepted_status()),
+        ]);
+        net.replace_schema(other.schema().export(), mapping, &SystemClock)
+            .unwrap();
+
+        let replayed: Entity<Net> = NetAggregateRoot::replay(net.take_events()).unwrap();
+
+        assert_same_state(&replayed, &net);
+        assert_eq!(replayed.status_of(task), Some(other.default_status()));
+    }
+
+    #[test]
+    fn test_apply_event() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(name("Todo"), name("Done"));
+        let mut replica: Entity<Net> = NetAggregateRoot::replay(net.take_events()).unwrap();
+        let (first, second) = (Id::new(), Id::new());
+        net.add_tasks([first, second]).unwrap();
+        net.new_relation(first, second, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.change_task_status(first, net.accepted_status(), &SystemClock)
+            .unwrap();
+
+        let events = net.take_events();
+        for event in &events {
+            replica.apply_event(event).unwrap();
+        }
+
+        assert_same_state(&replica, &net);
+        assert!(replica.data.events.is_empty());
+        assert!(matches!(
+            replica.apply_event(&events[0]),
+            Err(TaskDomainError::TaskAlreadyInNet { .. })
+        ));
+    }
+
+    #[test]
+    fn test_apply_event_rejects_foreign_events() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(name("Todo"), name("Done"));
+        let mut other: Entity<Net> = NetAggregateRoot::new(name("Todo"), name("Done"));
+        other.add_task(Id::new()).unwrap();
+        let foreign = other.take_events().pop().unwrap();
+        let created = net.take_events().pop().unwrap();
+
+        assert!(matches!(
+            net.apply_event(&foreign),
+            Err(TaskDomainError::EventNotApplicable { .. })
+        ));
+        assert!(matches!(
+            net.apply_event(&created),
+            Err(TaskDomainError::EventNotApplicable { .. })
+        ));
+        assert!(matches!(
+            <Entity<Net> as NetAggregateRoot>::replay([foreign]),
+            Err(TaskDomainError::NetCreationMissing)
+        ));
+    }
+
+    #[test]
+    fn test_replay_undone_edits() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(name("Todo"), name("Done"));
+        let mut events = net.take_events();
+        let mut history = NetHistory::new(net);
+        let task1_id = Id::new();
+        let task2_id = Id::new();
+        history
+            .edit(|net| net.add_tasks([task1_id, task2_id]))
+            .unwrap();
+        history
+            .edit(|net| net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock))
+            .unwrap();
+        history.undo();
+        history.undo();
+        history.redo();
+        events.extend(history.take_events());
+
+        let replayed: Entity<Net> = NetAggregateRoot::replay(events).unwrap();
+
+        assert_same_state(&replayed, history.net());
+        assert_eq!(replayed.tasks().count(), 2);
+        assert_eq!(replayed.relations().count(), 0);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::domain::name::StatusName;
+
+/// Represents a predefined workflow a new net can start with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaTemplate {
+    /// Todo, In Progress and Done.
+    Simple,
+    /// Backlog, In Progress, Review and Done.
+    Kanban,
+    /// Backlog, Sprint, In Progress, Review and Done.
+    Scrum,
+}
+
+impl SchemaTemplate {
+    /// Returns the default status, the in-progress statuses in board order and the accepted
+    /// status of the template.
+    pub fn statuses(self) -> (StatusName, Vec<StatusName>, StatusName) {
+        let names: &[&str] = match self {
+            SchemaTemplate::Simple => &["Todo", "In Progress", "Done"],
+            SchemaTemplate::Kanban => &["Backlog", "In Progress", "Review", "Done"],
+            SchemaTemplate::Scrum => &["Backlog", "Sprint", "In Progress", "Review", "Done"],
+        };
+        let mut names: Vec<_> = names
+            .iter()
+            .map(|name| StatusName::new(*name).expect("template status names are valid"))
+            .collect();
+        let accepted = names.pop().expect("templates have an accepted status");
+        let default = names.remove(0);
+        (default, names, accepted)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::Entity;
+
+    use super::*;
+    use crate::domain::net::{Net, NetAggregateRoot, NetQuery, StatusCategory};
+
+    #[test]
+    fn test_from_template() {
+        let net: Entity<Net> = NetAggregateRoot::from_template(SchemaTemplate::Kanban);
+
+        let board: Vec<_> = net
+            .statuses()
+            .map(|status| (status.data.name().as_str(), status.data.category()))
+            .collect();
+        assert_eq!(
+            board,
+            [
+                ("Backlog", StatusCategory::Todo),
+                ("In Progress", StatusCategory::InProgress),
+                ("Review", StatusCategory::InProgress),
+                ("Done", StatusCategory::Done),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_template_statuses() {
+        for template in [
+            SchemaTemplate::Simple,
+            SchemaTemplate::Kanban,
+            SchemaTemplate::Scrum,
+        ] {
+            let (default, normal, accepted) = template.statuses();
+            assert_ne!(default, accepted);
+            assert!(!normal.contains(&default) && !normal.contains(&accepted));
+        }
+    }
+}
@@ -0,0 +1,431 @@
+use std::{collections::HashMap, time::Duration};
+
+use shared_kernel::{Clock, Entity, Id, Timestamped};
+
+use super::{
+    Color, Net, NetAggregateRoot, NetOp, NetParts, PropagationPolicy, RelationStrength,
+    RelationType, SchemaParts, Status, StatusCategory, TaskDomainResult, WipLimit,
+};
+use crate::domain::{event::TaskDomainEvent, link::TaskRef, name::StatusName, task::Task};
+
+/// A timestamped net dates everything its operations record by its own clock, the one that
+/// stamps its modification time, so the clock passed to an operation is not used.
+impl<C: Clock + Clone + Default> NetAggregateRoot for Timestamped<Entity<Net>, C> {
+    fn new(default: StatusName, accepted: StatusName) -> Self {
+        Timestamped::new(
+            <Entity<Net> as NetAggregateRoot>::new(default, accepted),
+            C::default(),
+        )
+    }
+
+    fn with_statuses(default: StatusName, normal: Vec<StatusName>, accepted: StatusName) -> Self {
+        Timestamped::new(
+            <Entity<Net> as NetAggregateRoot>::with_statuses(default, normal, accepted),
+            C::default(),
+        )
+    }
+
+    fn from_parts(id: Id<Net>, parts: NetParts) -> TaskDomainResult<Self> {
+        Ok(Timestamped::new(
+            <Entity<Net> as NetAggregateRoot>::from_parts(id, parts)?,
+            C::default(),
+        ))
+    }
+
+    fn replay(events: impl IntoIterator<Item = TaskDomainEvent>) -> TaskDomainResult<Self> {
+        Ok(Timestamped::new(
+            <Entity<Net> as NetAggregateRoot>::replay(events)?,
+            C::default(),
+        ))
+    }
+
+    fn apply_event(&mut self, event: &TaskDomainEvent) -> TaskDomainResult<()> {
+        self.try_update(|net| net.apply_event(event))
+    }
+
+    fn lock(&mut self) {
+        self.update(|net| net.lock());
+    }
+
+    fn unlock(&mut self) {
+        self.update(|net| net.unlock());
+    }
+
+    fn new_status(&mut self, status_name: StatusName) -> TaskDomainResult<()> {
+        self.try_update(|net| net.new_status(status_name))
+    }
+
+    fn remove_status(&mut self, status_id: Id<Status>, _: &impl Clock) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| net.remove_status(status_id, clock))
+    }
+
+    fn change_status_name(
+        &mut self,
+        status_id: Id<Status>,
+        new_name: StatusName,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_status_name(status_id, new_name))
+    }
+
+    fn change_status_color(
+        &mut self,
+        status_id: Id<Status>,
+        color: Option<Color>,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_status_color(status_id, color))
+    }
+
+    fn change_status_description(
+        &mut self,
+        status_id: Id<Status>,
+        description: Option<String>,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_status_description(status_id, description))
+    }
+
+    fn change_status_wip_limit(
+        &mut self,
+        status_id: Id<Status>,
+        wip_limit: Option<WipLimit>,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_status_wip_limit(status_id, wip_limit))
+    }
+
+    fn move_status(&mut self, status_id: Id<Status>, new_index: usize) -> TaskDomainResult<()> {
+        self.try_update(|net| net.move_status(status_id, new_index))
+    }
+
+    fn change_default(&mut self, new_default: Id<Status>) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_default(new_default))
+    }
+
+    fn change_accepted(
+        &mut self,
+        new_accepted: Id<Status>,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| net.change_accepted(new_accepted, clock))
+    }
+
+    fn set_status_category(
+        &mut self,
+        status_id: Id<Status>,
+        category: StatusCategory,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.set_status_category(status_id, category, clock)
+        })
+    }
+
+    fn mark_status_accepted(
+        &mut self,
+        status_id: Id<Status>,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.mark_status_accepted(status_id, clock)
+        })
+    }
+
+    fn unmark_status_accepted(
+        &mut self,
+        status_id: Id<Status>,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.unmark_status_accepted(status_id, clock)
+        })
+    }
+
+    fn allow_transition(&mut self, from: Id<Status>, to: Id<Status>) -> TaskDomainResult<()> {
+        self.try_update(|net| net.allow_transition(from, to))
+    }
+
+    fn forbid_transition(&mut self, from: Id<Status>, to: Id<Status>) -> TaskDomainResult<()> {
+        self.try_update(|net| net.forbid_transition(from, to))
+    }
+
+    fn replace_schema(
+        &mut self,
+        schema: SchemaParts,
+        status_mapping: HashMap<Id<Status>, Id<Status>>,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.replace_schema(schema, status_mapping, clock)
+        })
+    }
+
+    fn change_propagation_policy(
+        &mut self,
+        policy: PropagationPolicy,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.change_propagation_policy(policy, clock)
+        })
+    }
+
+    fn require_single_parent(&mut self, enabled: bool) -> TaskDomainResult<()> {
+        self.try_update(|net| net.require_single_parent(enabled))
+    }
+
+    fn warn_on_orphaning(&mut self, enabled: bool) -> TaskDomainResult<()> {
+        self.try_update(|net| net.warn_on_orphaning(enabled))
+    }
+
+    fn estimate_task(
+        &mut self,
+        task_id: Id<Task>,
+        estimate: Option<Duration>,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.estimate_task(task_id, estimate))
+    }
+
+    fn add_task(&mut self, task_id: Id<Task>) -> TaskDomainResult<()> {
+        self.try_update(|net| net.add_task(task_id))
+    }
+
+    fn add_tasks(&mut self, task_ids: impl IntoIterator<Item = Id<Task>>) -> TaskDomainResult<()> {
+        self.try_update(|net| net.add_tasks(task_ids))
+    }
+
+    fn remove_task(&mut self, task_id: Id<Task>, _: &impl Clock) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| net.remove_task(task_id, clock))
+    }
+
+    fn soft_remove_task(&mut self, task_id: Id<Task>, _: &impl Clock) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| net.soft_remove_task(task_id, clock))
+    }
+
+    fn restore_task(&mut self, task_id: Id<Task>, _: &impl Clock) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| net.restore_task(task_id, clock))
+    }
+
+    fn new_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        relation_type: RelationType,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.new_relation(from, to, relation_type, clock)
+        })
+    }
+
+    fn new_relations(
+        &mut self,
+        relations: impl IntoIterator<Item = (Id<Task>, Id<Task>, RelationType)>,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| net.new_relations(relations, clock))
+    }
+
+    fn link_requirement(
+        &mut self,
+        task_id: Id<Task>,
+        required: TaskRef,
+        accepted: bool,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.link_requirement(task_id, required, accepted, clock)
+        })
+    }
+
+    fn unlink_requirement(
+        &mut self,
+        task_id: Id<Task>,
+        required: TaskRef,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.unlink_requirement(task_id, required, clock)
+        })
+    }
+
+    fn update_linked_requirement(
+        &mut self,
+        required: TaskRef,
+        accepted: bool,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.update_linked_requirement(required, accepted, clock)
+        })
+    }
+
+    fn set_milestone(
+        &mut self,
+        task_id: Id<Task>,
+        milestone: bool,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.set_milestone(task_id, milestone, clock)
+        })
+    }
+
+    fn archive_task(&mut self, task_id: Id<Task>, _: &impl Clock) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| net.archive_task(task_id, clock))
+    }
+
+    fn unarchive_task(&mut self, task_id: Id<Task>, _: &impl Clock) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| net.unarchive_task(task_id, clock))
+    }
+
+    fn set_relation_strength(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        strength: RelationStrength,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.set_relation_strength(from, to, strength, clock)
+        })
+    }
+
+    fn annotate_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        note: Option<String>,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.annotate_relation(from, to, note))
+    }
+
+    fn remove_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| net.remove_relation(from, to, clock))
+    }
+
+    fn change_task_status_with_reason(
+        &mut self,
+        task_id: Id<Task>,
+        status_id: Id<Status>,
+        reason: Option<String>,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| {
+            net.change_task_status_with_reason(task_id, status_id, reason, clock)
+        })
+    }
+
+    fn apply(&mut self, ops: Vec<NetOp>, _: &impl Clock) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| net.apply(ops, clock))
+    }
+
+    fn clone_subtree(
+        &mut self,
+        root: Id<Task>,
+        _: &impl Clock,
+    ) -> TaskDomainResult<HashMap<Id<Task>, Id<Task>>> {
+        stamped(self, |net, clock| net.clone_subtree(root, clock))
+    }
+
+    fn copy_tasks(
+        &mut self,
+        copies: &HashMap<Id<Task>, Id<Task>>,
+        _: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        stamped(self, |net, clock| net.copy_tasks(copies, clock))
+    }
+}
+
+/// Runs an operation on a timestamped net, handing it the clock of the net.
+fn stamped<C: Clock + Clone, R>(
+    net: &mut Timestamped<Entity<Net>, C>,
+    operation: impl FnOnce(&mut Entity<Net>, &C) -> TaskDomainResult<R>,
+) -> TaskDomainResult<R> {
+    let clock = net.clock().clone();
+    net.try_update(|net| operation(net, &clock))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use chrono::{DateTime, TimeDelta};
+    use shared_kernel::{ManualClock, SystemClock};
+
+    use super::*;
+    use crate::domain::{
+        error::TaskDomainError,
+        net::{NetQuery, RelationType},
+    };
+
+    fn net() -> (Timestamped<Entity<Net>, ManualClock>, ManualClock) {
+        let clock = ManualClock::new(DateTime::from_timestamp(1_700_000_000, 0).unwrap());
+        let net = Timestamped::new(
+            NetAggregateRoot::new(
+                StatusName::new("Todo").unwrap(),
+                StatusName::new("Done").unwrap(),
+            ),
+            clock.clone(),
+        );
+        (net, clock)
+    }
+
+    #[test]
+    fn test_operation_bumps_updated_at() {
+        let (mut net, clock) = net();
+        let created_at = net.created_at();
+        let later = created_at + TimeDelta::seconds(5);
+        let task_id = Id::new();
+
+        clock.set(later);
+        net.add_task(task_id).unwrap();
+
+        assert_eq!(net.updated_at(), later);
+        assert_eq!(net.created_at(), created_at);
+    }
+
+    #[test]
+    fn test_operation_uses_the_clock_of_the_net() {
+        let (mut net, clock) = net();
+        let (task1_id, task2_id) = (Id::new(), Id::new());
+        net.add_tasks([task1_id, task2_id]).unwrap();
+        let later = net.created_at() + TimeDelta::seconds(5);
+
+        clock.set(later);
+        net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.change_task_status(task1_id, net.accepted_status(), &SystemClock)
+            .unwrap();
+
+        assert_eq!(net.updated_at(), later);
+        let (_, _, relation) = net.relations().next().unwrap();
+        assert_eq!(relation.created_at(), later);
+        assert_eq!(net.history_of(task2_id).count(), 1);
+        assert!([task1_id, task2_id]
+            .into_iter()
+            .flat_map(|task| net.history_of(task))
+            .all(|change| change.at == later));
+    }
+
+    #[test]
+    fn test_failed_operation_keeps_updated_at() {
+        let (mut net, clock) = net();
+        let task_id = Id::new();
+        net.add_task(task_id).unwrap();
+        let updated_at = net.updated_at();
+
+        clock.set(updated_at + TimeDelta::seconds(5));
+        assert!(matches!(
+            net.add_task(task_id),
+            Err(TaskDomainError::TaskAlreadyInNet { .. })
+        ));
+        assert!(net
+            .new_relation(task_id, task_id, RelationType::Compose, &SystemClock)
+            .is_err());
+        assert!(net.remove_task(Id::new(), &SystemClock).is_err());
+
+        assert_eq!(net.updated_at(), updated_at);
+    }
+}
@@ -0,0 +1,353 @@
+use std::{collections::HashMap, time::Duration};
+
+use shared_kernel::{Clock, Entity, Id, Versioned};
+
+use super::{
+    Color, Net, NetAggregateRoot, NetOp, NetParts, PropagationPolicy, RelationStrength,
+    RelationType, SchemaParts, Status, StatusCategory, TaskDomainResult, WipLimit,
+};
+use crate::domain::{event::TaskDomainEvent, link::TaskRef, name::StatusName, task::Task};
+
+impl NetAggregateRoot for Versioned<Entity<Net>> {
+    fn new(default: StatusName, accepted: StatusName) -> Self {
+        Versioned::new(<Entity<Net> as NetAggregateRoot>::new(default, accepted))
+    }
+
+    fn with_statuses(default: StatusName, normal: Vec<StatusName>, accepted: StatusName) -> Self {
+        Versioned::new(<Entity<Net> as NetAggregateRoot>::with_statuses(
+            default, normal, accepted,
+        ))
+    }
+
+    fn from_parts(id: Id<Net>, parts: NetParts) -> TaskDomainResult<Self> {
+        Ok(Versioned::new(
+            <Entity<Net> as NetAggregateRoot>::from_parts(id, parts)?,
+        ))
+    }
+
+    fn replay(events: impl IntoIterator<Item = TaskDomainEvent>) -> TaskDomainResult<Self> {
+        Ok(Versioned::new(<Entity<Net> as NetAggregateRoot>::replay(
+            events,
+        )?))
+    }
+
+    fn apply_event(&mut self, event: &TaskDomainEvent) -> TaskDomainResult<()> {
+        self.try_update(|net| net.apply_event(event))
+    }
+
+    fn lock(&mut self) {
+        self.update(|net| net.lock());
+    }
+
+    fn unlock(&mut self) {
+        self.update(|net| net.unlock());
+    }
+
+    fn new_status(&mut self, status_name: StatusName) -> TaskDomainResult<()> {
+        self.try_update(|net| net.new_status(status_name))
+    }
+
+    fn remove_status(&mut self, status_id: Id<Status>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.try_update(|net| net.remove_status(status_id, clock))
+    }
+
+    fn change_status_name(
+        &mut self,
+        status_id: Id<Status>,
+        new_name: StatusName,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_status_name(status_id, new_name))
+    }
+
+    fn change_status_color(
+        &mut self,
+        status_id: Id<Status>,
+        color: Option<Color>,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_status_color(status_id, color))
+    }
+
+    fn change_status_description(
+        &mut self,
+        status_id: Id<Status>,
+        description: Option<String>,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_status_description(status_id, description))
+    }
+
+    fn change_status_wip_limit(
+        &mut self,
+        status_id: Id<Status>,
+        wip_limit: Option<WipLimit>,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_status_wip_limit(status_id, wip_limit))
+    }
+
+    fn move_status(&mut self, status_id: Id<Status>, new_index: usize) -> TaskDomainResult<()> {
+        self.try_update(|net| net.move_status(status_id, new_index))
+    }
+
+    fn change_default(&mut self, new_default: Id<Status>) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_default(new_default))
+    }
+
+    fn change_accepted(
+        &mut self,
+        new_accepted: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_accepted(new_accepted, clock))
+    }
+
+    fn set_status_category(
+        &mut self,
+        status_id: Id<Status>,
+        category: StatusCategory,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.set_status_category(status_id, category, clock))
+    }
+
+    fn mark_status_accepted(
+        &mut self,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.mark_status_accepted(status_id, clock))
+    }
+
+    fn unmark_status_accepted(
+        &mut self,
+        status_id: Id<Status>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.unmark_status_accepted(status_id, clock))
+    }
+
+    fn allow_transition(&mut self, from: Id<Status>, to: Id<Status>) -> TaskDomainResult<()> {
+        self.try_update(|net| net.allow_transition(from, to))
+    }
+
+    fn forbid_transition(&mut self, from: Id<Status>, to: Id<Status>) -> TaskDomainResult<()> {
+        self.try_update(|net| net.forbid_transition(from, to))
+    }
+
+    fn replace_schema(
+        &mut self,
+        schema: SchemaParts,
+        status_mapping: HashMap<Id<Status>, Id<Status>>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.replace_schema(schema, status_mapping, clock))
+    }
+
+    fn change_propagation_policy(
+        &mut self,
+        policy: PropagationPolicy,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_propagation_policy(policy, clock))
+    }
+
+    fn require_single_parent(&mut self, enabled: bool) -> TaskDomainResult<()> {
+        self.try_update(|net| net.require_single_parent(enabled))
+    }
+
+    fn warn_on_orphaning(&mut self, enabled: bool) -> TaskDomainResult<()> {
+        self.try_update(|net| net.warn_on_orphaning(enabled))
+    }
+
+    fn estimate_task(
+        &mut self,
+        task_id: Id<Task>,
+        estimate: Option<Duration>,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.estimate_task(task_id, estimate))
+    }
+
+    fn add_task(&mut self, task_id: Id<Task>) -> TaskDomainResult<()> {
+        self.try_update(|net| net.add_task(task_id))
+    }
+
+    fn add_tasks(&mut self, task_ids: impl IntoIterator<Item = Id<Task>>) -> TaskDomainResult<()> {
+        self.try_update(|net| net.add_tasks(task_ids))
+    }
+
+    fn remove_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.try_update(|net| net.remove_task(task_id, clock))
+    }
+
+    fn soft_remove_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.try_update(|net| net.soft_remove_task(task_id, clock))
+    }
+
+    fn restore_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.try_update(|net| net.restore_task(task_id, clock))
+    }
+
+    fn new_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        relation_type: RelationType,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.new_relation(from, to, relation_type, clock))
+    }
+
+    fn new_relations(
+        &mut self,
+        relations: impl IntoIterator<Item = (Id<Task>, Id<Task>, RelationType)>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.new_relations(relations, clock))
+    }
+
+    fn link_requirement(
+        &mut self,
+        task_id: Id<Task>,
+        required: TaskRef,
+        accepted: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.link_requirement(task_id, required, accepted, clock))
+    }
+
+    fn unlink_requirement(
+        &mut self,
+        task_id: Id<Task>,
+        required: TaskRef,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.unlink_requirement(task_id, required, clock))
+    }
+
+    fn update_linked_requirement(
+        &mut self,
+        required: TaskRef,
+        accepted: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.update_linked_requirement(required, accepted, clock))
+    }
+
+    fn set_milestone(
+        &mut self,
+        task_id: Id<Task>,
+        milestone: bool,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.set_milestone(task_id, milestone, clock))
+    }
+
+    fn archive_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.try_update(|net| net.archive_task(task_id, clock))
+    }
+
+    fn unarchive_task(&mut self, task_id: Id<Task>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.try_update(|net| net.unarchive_task(task_id, clock))
+    }
+
+    fn set_relation_strength(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        strength: RelationStrength,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.set_relation_strength(from, to, strength, clock))
+    }
+
+    fn annotate_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        note: Option<String>,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.annotate_relation(from, to, note))
+    }
+
+    fn remove_relation(
+        &mut self,
+        from: Id<Task>,
+        to: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.remove_relation(from, to, clock))
+    }
+
+    fn change_task_status_with_reason(
+        &mut self,
+        task_id: Id<Task>,
+        status_id: Id<Status>,
+        reason: Option<String>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.change_task_status_with_reason(task_id, status_id, reason, clock))
+    }
+
+    fn apply(&mut self, ops: Vec<NetOp>, clock: &impl Clock) -> TaskDomainResult<()> {
+        self.try_update(|net| net.apply(ops, clock))
+    }
+
+    fn clone_subtree(
+        &mut self,
+        root: Id<Task>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<HashMap<Id<Task>, Id<Task>>> {
+        self.try_update(|net| net.clone_subtree(root, clock))
+    }
+
+    fn copy_tasks(
+        &mut self,
+        copies: &HashMap<Id<Task>, Id<Task>>,
+        clock: &impl Clock,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|net| net.copy_tasks(copies, clock))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{SystemClock, Version};
+
+    use super::*;
+    use crate::domain::net::{NetQuery, RelationType};
+
+    fn net() -> Versioned<Entity<Net>> {
+        NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_operation_increments_version() {
+        let mut net = net();
+        let (task1_id, task2_id) = (Id::new(), Id::new());
+
+        net.add_tasks([task1_id, task2_id]).unwrap();
+        net.new_relation(task1_id, task2_id, RelationType::Compose, &SystemClock)
+            .unwrap();
+        net.lock();
+
+        assert_eq!(net.version(), Version::new(3));
+        assert_eq!(net.relations().count(), 1);
+    }
+
+    #[test]
+    fn test_failed_operation_keeps_version() {
+        let mut net = net();
+        let task_id = Id::new();
+        net.add_task(task_id).unwrap();
+
+        assert!(net.add_task(task_id).is_err());
+        assert!(net
+            .new_relation(task_id, task_id, RelationType::Compose, &SystemClock)
+            .is_err());
+        assert!(net.remove_task(Id::new(), &SystemClock).is_err());
+
+        assert_eq!(net.version(), Version::new(1));
+    }
+}
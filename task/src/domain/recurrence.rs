@@ -0,0 +1,341 @@
+use std::num::NonZeroU32;
+
+use chrono::{Days, Months};
+use shared_kernel::{Entity, Timestamp};
+
+use super::{
+    error::TaskDomainError,
+    net::Net,
+    task::{copy_label, Schedule, Task, TaskAggregateRoot},
+};
+
+type TaskDomainResult<T> = Result<T, TaskDomainError>;
+
+/// Represents the unit a recurrence repeats in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Frequency {
+    /// The task repeats every given number of days.
+    Daily,
+    /// The task repeats every given number of weeks.
+    Weekly,
+    /// The task repeats every given number of months, on the same day of the month where it
+    /// exists and on the last day of the month otherwise.
+    Monthly,
+}
+
+/// Represents when a series of recurring tasks ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RecurrenceEnd {
+    /// The series goes on forever.
+    #[default]
+    Never,
+    /// The series has this many occurrences left, counting the current one.
+    After(NonZeroU32),
+    /// The series ends with the last occurrence falling on or before this time.
+    Until(Timestamp),
+}
+
+/// Describes how a task repeats, in the spirit of an iCalendar RRULE.
+///
+/// Occurrences are counted from the schedule of the first one, so a series due on the 31st keeps
+/// coming back to the last day of shorter months instead of drifting to an earlier day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recurrence {
+    frequency: Frequency,
+    interval: NonZeroU32,
+    end: RecurrenceEnd,
+    #[cfg_attr(feature = "serde", serde(default))]
+    anchor: Option<Schedule>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    occurrence: u32,
+}
+
+impl Recurrence {
+    /// Creates a recurrence repeating every `interval` units of `frequency` until `end`.
+    pub fn new(frequency: Frequency, interval: NonZeroU32, end: RecurrenceEnd) -> Self {
+        Recurrence {
+            frequency,
+            interval,
+            end,
+            anchor: None,
+            occurrence: 0,
+        }
+    }
+
+    /// Returns the unit the recurrence repeats in.
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Returns how many units of the frequency lie between two occurrences.
+    pub fn interval(&self) -> NonZeroU32 {
+        self.interval
+    }
+
+    /// Returns when the series ends.
+    pub fn end(&self) -> RecurrenceEnd {
+        self.end
+    }
+
+    /// Returns the schedule of the occurrence the series is counted from, once the series has
+    /// moved past its first occurrence.
+    pub fn anchor(&self) -> Option<Schedule> {
+        self.anchor
+    }
+
+    /// Returns how many intervals the current occurrence lies after the anchor.
+    pub fn occurrence(&self) -> u32 {
+        self.occurrence
+    }
+
+    /// Returns the time `count` intervals after `anchor`, or `None` if it cannot be represented.
+    ///
+    /// Monthly recurrences keep the day of the month of the anchor, falling back to the last
+    /// day of months too short for it.
+    pub fn nth_after(&self, anchor: Timestamp, count: u32) -> Option<Timestamp> {
+        let intervals = self.interval.get().checked_mul(count)?;
+        match self.frequency {
+            Frequency::Daily => anchor.checked_add_days(Days::new(intervals.into())),
+            Frequency::Weekly => anchor.checked_add_days(Days::new(u64::from(intervals) * 7)),
+            Frequency::Monthly => anchor.checked_add_months(Months::new(intervals)),
+        }
+    }
+
+    /// Returns the recurrence of the next occurrence, `occurrence` intervals after `anchor` and
+    /// falling on `date`, or `None` if the series ends before it.
+    fn advance(&self, anchor: Schedule, occurrence: u32, date: Timestamp) -> Option<Recurrence> {
+        let end = match self.end {
+            RecurrenceEnd::Never => RecurrenceEnd::Never,
+            RecurrenceEnd::After(left) => RecurrenceEnd::After(NonZeroU32::new(left.get() - 1)?),
+            RecurrenceEnd::Until(until) if date > until => return None,
+            RecurrenceEnd::Until(until) => RecurrenceEnd::Until(until),
+        };
+
+        Some(Recurrence {
+            end,
+            anchor: Some(anchor),
+            occurrence,
+            ..*self
+        })
+    }
+}
+
+/// Materializes the task following a recurring task once it is accepted in a net.
+///
+/// The next occurrence is a fresh task with the name, description, priority, assignees and
+/// labels of the current one, scheduled one interval after the current occurrence as counted
+/// from the anchor of the series. A series starting with a task without a schedule is anchored
+/// on `accepted_at`. The new task is neither added to the net nor to any repository; that is up
+/// to the caller.
+///
+/// Returns `None` while the task is not accepted, when it does not recur, or once its series
+/// has ended.
+pub fn next_occurrence(
+    task: &Entity<Task>,
+    net: &Entity<Net>,
+    accepted_at: Timestamp,
+) -> TaskDomainResult<Option<Entity<Task>>> {
+    if net.data.status_of(task.id).is_none() {
+        return Err(TaskDomainError::TaskNotFoundInNet {
+            net: net.id,
+            task: task.id,
+        });
+    }
+    let Some(recurrence) = task.data.recurrence() else {
+        return Ok(None);
+    };
+    if !net.data.is_task_accepted(task.id) {
+        return Ok(None);
+    }
+
+    let anchor = recurrence
+        .anchor()
+        .or(task.data.schedule())
+        .unwrap_or(Schedule::due(accepted_at));
+    let Some(occurrence) = recurrence.occurrence().checked_add(1) else {
+        return Ok(None);
+    };
+    let next = |date| recurrence.nth_after(date, occurrence);
+    let schedule = match (anchor.start_date(), anchor.due_date()) {
+        (Some(start), Some(due)) => match next(start).zip(next(due)) {
+            Some((start, due)) => Some(Schedule::between(start, due)?),
+            None => None,
+        },
+        (Some(start), None) => next(start).map(Schedule::starting),
+        (None, Some(due)) => next(due).map(Schedule::due),
+        (None, None) => None,
+    };
+    let Some(schedule) = schedule else {
+        return Ok(None);
+    };
+    let date = schedule
+        .due_date()
+        .or(schedule.start_date())
+        .unwrap_or(accepted_at);
+    let Some(recurrence) = recurrence.advance(anchor, occurrence, date) else {
+        return Ok(None);
+    };
+
+    let mut occurrence: Entity<Task> =
+        TaskAggregateRoot::new(task.data.name().clone(), task.data.list());
+    if let Some(description) = task.data.description() {
+        occurrence.set_description(description.clone());
+    }
+    occurrence.schedule(schedule)?;
+    occurrence.set_priority(task.data.priority());
+    for user in task.data.assignees() {
+        occurrence.assign(*user)?;
+    }
+    for label in task.data.labels() {
+        copy_label(&mut occurrence, label.clone());
+    }
+    occurrence.set_recurrence(recurrence);
+
+    Ok(Some(occurrence))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::{Id, SystemClock};
+
+    use super::*;
+    use crate::domain::{
+        name::{StatusName, TaskName},
+        net::{NetAggregateRoot, NetQuery},
+    };
+
+    fn date(day: u32) -> Timestamp {
+        chrono::NaiveDate::from_ymd_opt(2025, 1, day)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+            .and_utc()
+    }
+
+    fn every(frequency: Frequency, interval: u32, end: RecurrenceEnd) -> Recurrence {
+        Recurrence::new(frequency, NonZeroU32::new(interval).unwrap(), end)
+    }
+
+    #[test]
+    fn test_nth_after() {
+        let start = date(31);
+
+        assert_eq!(
+            every(Frequency::Daily, 2, RecurrenceEnd::Never).nth_after(date(1), 1),
+            Some(date(3))
+        );
+        assert_eq!(
+            every(Frequency::Weekly, 1, RecurrenceEnd::Never).nth_after(date(1), 2),
+            Some(date(15))
+        );
+        let monthly = every(Frequency::Monthly, 1, RecurrenceEnd::Never);
+        for (count, month, day) in [(1, 2, 28), (2, 3, 31), (3, 4, 30)] {
+            assert_eq!(
+                monthly.nth_after(start, count).unwrap().date_naive(),
+                chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+            );
+        }
+        assert_eq!(
+            every(Frequency::Monthly, u32::MAX, RecurrenceEnd::Never).nth_after(start, 2),
+            None
+        );
+    }
+
+    #[test]
+    fn test_next_occurrence_keeps_month_end() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Close the books").unwrap(), Id::new());
+        task.schedule(Schedule::due(date(31))).unwrap();
+        task.set_recurrence(every(Frequency::Monthly, 1, RecurrenceEnd::Never));
+
+        let mut due_dates = Vec::new();
+        for _ in 0..3 {
+            net.add_task(task.id).unwrap();
+            net.change_task_status(task.id, net.accepted_status(), &SystemClock)
+                .unwrap();
+            task = next_occurrence(&task, &net, date(31)).unwrap().unwrap();
+            due_dates.push(
+                task.data
+                    .schedule()
+                    .unwrap()
+                    .due_date()
+                    .unwrap()
+                    .date_naive(),
+            );
+        }
+
+        let day = |month, day| chrono::NaiveDate::from_ymd_opt(2025, month, day).unwrap();
+        assert_eq!(due_dates, [day(2, 28), day(3, 31), day(4, 30)]);
+        assert_eq!(task.data.recurrence().unwrap().occurrence(), 3);
+        assert_eq!(
+            task.data.recurrence().unwrap().anchor(),
+            Some(Schedule::due(date(31)))
+        );
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Water plants").unwrap(), Id::new());
+        task.schedule(Schedule::between(date(1), date(2)).unwrap())
+            .unwrap();
+        task.set_recurrence(every(
+            Frequency::Weekly,
+            1,
+            RecurrenceEnd::After(NonZeroU32::new(2).unwrap()),
+        ));
+        assert!(next_occurrence(&task, &net, date(2)).is_err());
+
+        net.add_task(task.id).unwrap();
+        assert!(next_occurrence(&task, &net, date(2)).unwrap().is_none());
+
+        net.change_task_status(task.id, net.accepted_status(), &SystemClock)
+            .unwrap();
+        let next = next_occurrence(&task, &net, date(2)).unwrap().unwrap();
+        assert_ne!(next.id, task.id);
+        assert_eq!(next.data.name(), task.data.name());
+        assert_eq!(
+            next.data.schedule(),
+            Some(Schedule::between(date(8), date(9)).unwrap())
+        );
+        assert_eq!(
+            next.data.recurrence().unwrap().end(),
+            RecurrenceEnd::After(NonZeroU32::new(1).unwrap())
+        );
+
+        net.add_task(next.id).unwrap();
+        net.change_task_status(next.id, net.accepted_status(), &SystemClock)
+            .unwrap();
+        assert!(next_occurrence(&next, &net, date(9)).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_next_occurrence_until() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Stand-up").unwrap(), Id::new());
+        task.set_recurrence(every(Frequency::Daily, 1, RecurrenceEnd::Until(date(3))));
+        net.add_task(task.id).unwrap();
+        net.change_task_status(task.id, net.accepted_status(), &SystemClock)
+            .unwrap();
+
+        let next = next_occurrence(&task, &net, date(2)).unwrap().unwrap();
+        assert_eq!(next.data.schedule(), Some(Schedule::due(date(3))));
+        assert!(next_occurrence(&task, &net, date(3)).unwrap().is_none());
+    }
+}
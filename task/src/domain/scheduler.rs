@@ -0,0 +1,423 @@
+use std::{convert::Infallible, time::Duration};
+
+use chrono::TimeDelta;
+use shared_kernel::{AggregateRoot, Clock, Entity, EntityKind, Id, Timestamp};
+
+use super::{error::TaskDomainError, event::TaskDomainEvent, task::Task};
+
+type TaskDomainResult<T> = Result<T, TaskDomainError>;
+
+/// Represents the way a reminder reaches its user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Channel {
+    /// A notification inside the application.
+    InApp,
+    /// An email.
+    Email,
+    /// A push notification on a device.
+    Push,
+}
+
+/// Represents a reminder about a task, fired once at a given time.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "ReminderRecord", from = "ReminderRecord")
+)]
+pub struct Reminder {
+    task: Id<Task>,
+    remind_at: Timestamp,
+    channel: Channel,
+    lead: Option<Duration>,
+    fired: bool,
+    events: Vec<TaskDomainEvent>,
+}
+
+/// The versioned on-disk form of a reminder; pending events are not persisted.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+enum ReminderRecord {
+    #[serde(rename = "1")]
+    V1 {
+        task: Id<Task>,
+        remind_at: Timestamp,
+        channel: Channel,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        lead: Option<Duration>,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        fired: bool,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<Reminder> for ReminderRecord {
+    fn from(reminder: Reminder) -> Self {
+        ReminderRecord::V1 {
+            task: reminder.task,
+            remind_at: reminder.remind_at,
+            channel: reminder.channel,
+            lead: reminder.lead,
+            fired: reminder.fired,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ReminderRecord> for Reminder {
+    fn from(record: ReminderRecord) -> Self {
+        match record {
+            ReminderRecord::V1 {
+                task,
+                remind_at,
+                channel,
+                lead,
+                fired,
+            } => Reminder {
+                task,
+                remind_at,
+                channel,
+                lead,
+                fired,
+                events: Vec::new(),
+            },
+        }
+    }
+}
+
+impl EntityKind for Reminder {
+    const KIND: &'static str = "reminder";
+}
+
+impl Reminder {
+    /// Returns the task the reminder is about.
+    pub fn task(&self) -> Id<Task> {
+        self.task
+    }
+
+    /// Returns the time the reminder fires at.
+    pub fn remind_at(&self) -> Timestamp {
+        self.remind_at
+    }
+
+    /// Returns the way the reminder reaches its user.
+    pub fn channel(&self) -> Channel {
+        self.channel
+    }
+
+    /// Returns how long before the due date of its task the reminder fires, if it follows
+    /// the due date.
+    pub fn lead(&self) -> Option<Duration> {
+        self.lead
+    }
+
+    /// Checks whether the reminder has fired.
+    pub fn is_fired(&self) -> bool {
+        self.fired
+    }
+
+    /// Checks whether the reminder is waiting to fire at the given time.
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        !self.fired && self.remind_at <= now
+    }
+
+    /// Moves the reminder to a new time and makes it fire again, without touching how it is
+    /// scheduled.
+    fn move_to(&mut self, reminder: Id<Reminder>, remind_at: Timestamp) {
+        self.remind_at = remind_at;
+        self.fired = false;
+        self.events.push(TaskDomainEvent::ReminderRescheduled {
+            reminder,
+            remind_at,
+        });
+    }
+}
+
+/// Trait for aggregate root operations on a `Reminder`.
+pub trait ReminderAggregateRoot: Sized {
+    /// Creates a reminder about a task firing at a fixed time.
+    fn new(task: Id<Task>, remind_at: Timestamp, channel: Channel) -> Self;
+
+    /// Creates a reminder firing some time before the due date of a task, failing if the task
+    /// has no due date or the lead reaches out of the representable range.
+    ///
+    /// The reminder keeps following the due date through [`follow_due_date`].
+    ///
+    /// [`follow_due_date`]: ReminderAggregateRoot::follow_due_date
+    fn before_due(task: &Entity<Task>, lead: Duration, channel: Channel) -> TaskDomainResult<Self>;
+
+    /// Moves the reminder to a new time, making it fire again if it already fired. A reminder
+    /// following the due date of its task stops doing so.
+    fn reschedule(&mut self, remind_at: Timestamp);
+
+    /// Moves a reminder created by [`before_due`] along with the due date of its task, failing
+    /// if the task no longer has one or the lead no longer fits before it. Reminders firing at
+    /// a fixed time are left alone.
+    ///
+    /// [`before_due`]: ReminderAggregateRoot::before_due
+    fn follow_due_date(&mut self, task: &Entity<Task>) -> TaskDomainResult<()>;
+
+    /// Fires the reminder, failing if it already fired.
+    fn fire(&mut self) -> TaskDomainResult<()>;
+}
+
+/// Returns the time some lead before the due date of a task, failing if the task has no due
+/// date or the time cannot be represented.
+fn before_due_date_of(task: &Entity<Task>, lead: Duration) -> TaskDomainResult<Timestamp> {
+    let due_date = task
+        .data
+        .schedule()
+        .and_then(|schedule| schedule.due_date())
+        .ok_or(TaskDomainError::TaskWithoutDueDate { task: task.id })?;
+
+    TimeDelta::from_std(lead)
+        .ok()
+        .and_then(|lead| due_date.checked_sub_signed(lead))
+        .ok_or(TaskDomainError::ReminderLeadOutOfRange {
+            task: task.id,
+            lead,
+        })
+}
+
+impl ReminderAggregateRoot for Entity<Reminder> {
+    fn new(task: Id<Task>, remind_at: Timestamp, channel: Channel) -> Self {
+        let id = Id::new();
+        Entity {
+            id,
+            data: Reminder {
+                task,
+                remind_at,
+                channel,
+                lead: None,
+                fired: false,
+                events: vec![TaskDomainEvent::ReminderScheduled {
+                    reminder: id,
+                    task,
+                    remind_at,
+                    channel,
+                }],
+            },
+        }
+    }
+
+    fn before_due(task: &Entity<Task>, lead: Duration, channel: Channel) -> TaskDomainResult<Self> {
+        let remind_at = before_due_date_of(task, lead)?;
+
+        let mut reminder: Self = ReminderAggregateRoot::new(task.id, remind_at, channel);
+        reminder.data.lead = Some(lead);
+        Ok(reminder)
+    }
+
+    fn reschedule(&mut self, remind_at: Timestamp) {
+        self.data.lead = None;
+        self.data.move_to(self.id, remind_at);
+    }
+
+    fn follow_due_date(&mut self, task: &Entity<Task>) -> TaskDomainResult<()> {
+        let Some(lead) = self.data.lead else {
+            return Ok(());
+        };
+
+        let remind_at = before_due_date_of(task, lead)?;
+        if remind_at != self.data.remind_at {
+            self.data.move_to(self.id, remind_at);
+        }
+
+        Ok(())
+    }
+
+    fn fire(&mut self) -> TaskDomainResult<()> {
+        if self.data.fired {
+            return Err(TaskDomainError::ReminderAlreadyFired { reminder: self.id });
+        }
+
+        self.data.fired = true;
+        self.data.events.push(TaskDomainEvent::ReminderFired {
+            reminder: self.id,
+            task: self.data.task,
+            channel: self.data.channel,
+        });
+
+        Ok(())
+    }
+}
+
+impl AggregateRoot for Reminder {
+    type Event = TaskDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+/// A service firing reminders once they are due.
+///
+/// A runner calls [`poll`] periodically, or sleeps until [`next_due`] and polls then, and
+/// delivers the `ReminderFired` events it gets back through their channels.
+///
+/// [`poll`]: ReminderScheduler::poll
+/// [`next_due`]: ReminderScheduler::next_due
+pub trait ReminderScheduler {
+    /// The error raised by the underlying storage.
+    type Error;
+
+    /// Returns the earliest time a pending reminder fires at, if any reminder is pending.
+    fn next_due(&self) -> Result<Option<Timestamp>, Self::Error>;
+
+    /// Fires every reminder due at the current time, returning the pending events of the
+    /// reminders it fired.
+    fn poll(&mut self, clock: &impl Clock) -> Result<Vec<TaskDomainEvent>, Self::Error>;
+}
+
+/// A scheduler keeping reminders in memory, for tests and prototypes.
+#[derive(Debug, Default)]
+pub struct InMemoryReminderScheduler {
+    reminders: Vec<Entity<Reminder>>,
+}
+
+impl InMemoryReminderScheduler {
+    /// Creates a scheduler without reminders.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a reminder to the scheduler, replacing the one with the same id if any.
+    pub fn schedule(&mut self, reminder: Entity<Reminder>) {
+        self.cancel(reminder.id);
+        self.reminders.push(reminder);
+    }
+
+    /// Removes a reminder from the scheduler, returning it if it was there.
+    pub fn cancel(&mut self, reminder: Id<Reminder>) -> Option<Entity<Reminder>> {
+        let index = self.reminders.iter().position(|r| r.id == reminder)?;
+        Some(self.reminders.remove(index))
+    }
+
+    /// Lists the reminders of the scheduler, fired or not.
+    pub fn reminders(&self) -> &[Entity<Reminder>] {
+        &self.reminders
+    }
+}
+
+impl ReminderScheduler for InMemoryReminderScheduler {
+    type Error = Infallible;
+
+    fn next_due(&self) -> Result<Option<Timestamp>, Self::Error> {
+        Ok(self
+            .reminders
+            .iter()
+            .filter(|reminder| !reminder.data.fired)
+            .map(|reminder| reminder.data.remind_at)
+            .min())
+    }
+
+    fn poll(&mut self, clock: &impl Clock) -> Result<Vec<TaskDomainEvent>, Self::Error> {
+        let now = clock.now();
+        let mut events = Vec::new();
+        for reminder in &mut self.reminders {
+            if reminder.data.is_due(now) && reminder.fire().is_ok() {
+                events.extend(reminder.data.take_events());
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        name::TaskName,
+        task::{Schedule, TaskAggregateRoot},
+    };
+
+    #[test]
+    fn test_reminder_follows_due_date() {
+        let due = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let hour = Duration::from_secs(3600);
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Report").unwrap(), Id::new());
+        assert!(matches!(
+            Entity::<Reminder>::before_due(&task, hour, Channel::Email),
+            Err(TaskDomainError::TaskWithoutDueDate { .. })
+        ));
+
+        task.schedule(Schedule::due(due)).unwrap();
+        let mut reminder = Entity::<Reminder>::before_due(&task, hour, Channel::Email).unwrap();
+        assert_eq!(reminder.data.remind_at(), due - hour);
+        assert!(!reminder.data.is_due(due - hour * 2));
+        assert!(reminder.data.is_due(due));
+
+        reminder.fire().unwrap();
+        assert!(matches!(
+            reminder.fire(),
+            Err(TaskDomainError::ReminderAlreadyFired { .. })
+        ));
+        task.reschedule(Schedule::due(due + hour * 24)).unwrap();
+        reminder.follow_due_date(&task).unwrap();
+        assert_eq!(reminder.data.remind_at(), due + hour * 23);
+        assert!(!reminder.data.is_fired());
+
+        reminder.reschedule(due);
+        assert_eq!(reminder.data.lead(), None);
+        task.reschedule(Schedule::due(due + hour * 48)).unwrap();
+        reminder.follow_due_date(&task).unwrap();
+        assert_eq!(reminder.data.remind_at(), due);
+        assert_eq!(reminder.data.take_events().len(), 4);
+
+        assert!(matches!(
+            Entity::<Reminder>::before_due(&task, Duration::MAX, Channel::Email),
+            Err(TaskDomainError::ReminderLeadOutOfRange { .. })
+        ));
+        let mut reminder = Entity::<Reminder>::before_due(&task, hour, Channel::Email).unwrap();
+        let end_of_time = chrono::DateTime::<chrono::Utc>::MIN_UTC + hour;
+        task.reschedule(Schedule::due(end_of_time)).unwrap();
+        reminder.follow_due_date(&task).unwrap();
+        assert_eq!(
+            reminder.data.remind_at(),
+            chrono::DateTime::<chrono::Utc>::MIN_UTC
+        );
+        task.reschedule(Schedule::due(end_of_time - hour)).unwrap();
+        assert!(matches!(
+            reminder.follow_due_date(&task),
+            Err(TaskDomainError::ReminderLeadOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_scheduler_fires_due_reminders() {
+        let start = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let task = Id::new();
+        let soon: Entity<Reminder> = ReminderAggregateRoot::new(task, start, Channel::InApp);
+        let later: Entity<Reminder> =
+            ReminderAggregateRoot::new(task, start + chrono::Duration::hours(1), Channel::Push);
+        let (soon_id, later_id) = (soon.id, later.id);
+        let mut scheduler = InMemoryReminderScheduler::new();
+        scheduler.schedule(soon);
+        scheduler.schedule(later);
+        assert_eq!(scheduler.next_due().unwrap(), Some(start));
+
+        let events = scheduler.poll(&|| start).unwrap();
+        assert_eq!(
+            events.last(),
+            Some(&TaskDomainEvent::ReminderFired {
+                reminder: soon_id,
+                task,
+                channel: Channel::InApp,
+            })
+        );
+        assert!(scheduler.poll(&|| start).unwrap().is_empty());
+        assert_eq!(
+            scheduler.next_due().unwrap(),
+            Some(start + chrono::Duration::hours(1))
+        );
+
+        assert!(scheduler.cancel(later_id).is_some());
+        assert_eq!(scheduler.next_due().unwrap(), None);
+        assert_eq!(scheduler.reminders().len(), 1);
+    }
+}
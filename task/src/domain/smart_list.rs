@@ -0,0 +1,270 @@
+use shared_kernel::{AggregateRoot, Entity, EntityKind, Id, Specification, Timestamp, User};
+
+use super::{
+    error::TaskDomainError,
+    event::TaskDomainEvent,
+    name::{LabelName, ListTitle},
+    net::{Net, Status},
+    specification::{TaskCandidate, TaskWithStatus},
+    task::Task,
+};
+
+type TaskDomainResult<T> = Result<T, TaskDomainError>;
+
+/// A filter over tasks, built from predicates combined with `And`, `Or` and `Not`.
+///
+/// Unlike the specifications it is made of, a filter is plain data, so it can be saved and
+/// loaded with the smart list holding it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TaskFilter {
+    /// Satisfied by every task.
+    All,
+    /// Satisfied by tasks with a status in the net.
+    Status(Id<Status>),
+    /// Satisfied by tasks carrying a label.
+    Label(LabelName),
+    /// Satisfied by tasks assigned to a user.
+    Assignee(Id<User>),
+    /// Satisfied by tasks due before a time.
+    DueBefore(Timestamp),
+    /// Satisfied by tasks due at or after a time.
+    DueFrom(Timestamp),
+    /// Satisfied by tasks satisfying every filter.
+    And(Vec<TaskFilter>),
+    /// Satisfied by tasks satisfying any filter.
+    Or(Vec<TaskFilter>),
+    /// Satisfied by tasks not satisfying a filter.
+    Not(Box<TaskFilter>),
+}
+
+impl Specification<TaskCandidate<'_>> for TaskFilter {
+    fn is_satisfied_by(&self, candidate: &TaskCandidate<'_>) -> bool {
+        let task = &candidate.task.data;
+        let due_date = || task.schedule().and_then(|schedule| schedule.due_date());
+        match self {
+            TaskFilter::All => true,
+            TaskFilter::Status(status) => TaskWithStatus(*status).is_satisfied_by(candidate),
+            TaskFilter::Label(label) => task.labels().contains(label),
+            TaskFilter::Assignee(user) => task.assignees().contains(user),
+            TaskFilter::DueBefore(at) => due_date().is_some_and(|due| due < *at),
+            TaskFilter::DueFrom(at) => due_date().is_some_and(|due| due >= *at),
+            TaskFilter::And(filters) => filters.iter().all(|f| f.is_satisfied_by(candidate)),
+            TaskFilter::Or(filters) => filters.iter().any(|f| f.is_satisfied_by(candidate)),
+            TaskFilter::Not(filter) => !filter.is_satisfied_by(candidate),
+        }
+    }
+}
+
+/// Represents a saved search: a titled filter listing the tasks that match it.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "SmartListRecord", from = "SmartListRecord")
+)]
+pub struct SmartList {
+    title: ListTitle,
+    filter: TaskFilter,
+    events: Vec<TaskDomainEvent>,
+}
+
+/// The versioned on-disk form of a smart list; pending events are not persisted.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+enum SmartListRecord {
+    #[serde(rename = "1")]
+    V1 {
+        title: ListTitle,
+        filter: TaskFilter,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<SmartList> for SmartListRecord {
+    fn from(smart_list: SmartList) -> Self {
+        SmartListRecord::V1 {
+            title: smart_list.title,
+            filter: smart_list.filter,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<SmartListRecord> for SmartList {
+    fn from(record: SmartListRecord) -> Self {
+        match record {
+            SmartListRecord::V1 { title, filter } => SmartList {
+                title,
+                filter,
+                events: Vec::new(),
+            },
+        }
+    }
+}
+
+impl EntityKind for SmartList {
+    const KIND: &'static str = "smart_list";
+}
+
+impl SmartList {
+    /// Returns the title of the smart list.
+    pub fn title(&self) -> &ListTitle {
+        &self.title
+    }
+
+    /// Returns the filter tasks must satisfy to be listed.
+    pub fn filter(&self) -> &TaskFilter {
+        &self.filter
+    }
+
+    /// Lists the ids of the given tasks matching the filter, evaluated against a net, in the
+    /// order the tasks were given.
+    pub fn evaluate<'a>(
+        &self,
+        tasks: impl IntoIterator<Item = &'a Entity<Task>>,
+        net: &Entity<Net>,
+    ) -> Vec<Id<Task>> {
+        tasks
+            .into_iter()
+            .filter(|task| self.filter.is_satisfied_by(&TaskCandidate { task, net }))
+            .map(|task| task.id)
+            .collect()
+    }
+}
+
+/// Trait for aggregate root operations on a `SmartList`.
+pub trait SmartListAggregateRoot {
+    /// Creates a new smart list listing the tasks satisfying a filter.
+    fn new(title: ListTitle, filter: TaskFilter) -> Self;
+
+    /// Renames the smart list with a new title, validated and trimmed of surrounding
+    /// whitespace.
+    fn rename(&mut self, title: impl Into<String>) -> TaskDomainResult<()>;
+
+    /// Replaces the filter of the smart list.
+    fn change_filter(&mut self, filter: TaskFilter);
+}
+
+impl SmartListAggregateRoot for Entity<SmartList> {
+    fn new(title: ListTitle, filter: TaskFilter) -> Self {
+        let id = Id::new();
+        Entity {
+            id,
+            data: SmartList {
+                title: title.clone(),
+                filter: filter.clone(),
+                events: vec![TaskDomainEvent::SmartListCreated {
+                    smart_list: id,
+                    title,
+                    filter,
+                }],
+            },
+        }
+    }
+
+    fn rename(&mut self, title: impl Into<String>) -> TaskDomainResult<()> {
+        let title = ListTitle::new_trimmed(title)?;
+        self.data.title = title.clone();
+        self.data.events.push(TaskDomainEvent::SmartListRenamed {
+            smart_list: self.id,
+            title,
+        });
+
+        Ok(())
+    }
+
+    fn change_filter(&mut self, filter: TaskFilter) {
+        self.data.filter = filter.clone();
+        self.data
+            .events
+            .push(TaskDomainEvent::SmartListFilterChanged {
+                smart_list: self.id,
+                filter,
+            });
+    }
+}
+
+impl AggregateRoot for SmartList {
+    type Event = TaskDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        list::{Label, List, ListAggregateRoot},
+        name::{StatusName, TaskName},
+        net::{Color, NetAggregateRoot, NetQuery},
+        task::{Schedule, TaskAggregateRoot},
+    };
+
+    #[test]
+    fn test_evaluate() {
+        let mut net: Entity<Net> = NetAggregateRoot::new(
+            StatusName::new("Todo").unwrap(),
+            StatusName::new("Done").unwrap(),
+        );
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let me = Id::new();
+        let urgent = LabelName::new("urgent").unwrap();
+        let red = Color {
+            red: 255,
+            green: 0,
+            blue: 0,
+        };
+        list.define_label(Label::new(urgent.clone(), red)).unwrap();
+        let at = |secs| chrono::DateTime::from_timestamp(secs, 0).unwrap();
+
+        let mut mine: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Mine").unwrap(), list.id);
+        mine.assign(me).unwrap();
+        mine.schedule(Schedule::due(at(100))).unwrap();
+        let mut flagged: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Flagged").unwrap(), list.id);
+        flagged.add_label(&list, urgent.clone()).unwrap();
+        flagged.schedule(Schedule::due(at(300))).unwrap();
+        let done: Entity<Task> = TaskAggregateRoot::new(TaskName::new("Done").unwrap(), list.id);
+        for task in [&mine, &flagged, &done] {
+            net.add_task(task.id).unwrap();
+        }
+        let done_status = net.accepted_status();
+        net.change_task_status(done.id, done_status, &shared_kernel::SystemClock)
+            .unwrap();
+        let tasks = [&mine, &flagged, &done];
+
+        let mut smart_list: Entity<SmartList> = SmartListAggregateRoot::new(
+            ListTitle::new("My focus").unwrap(),
+            TaskFilter::Or(vec![
+                TaskFilter::Assignee(me),
+                TaskFilter::Label(urgent.clone()),
+            ]),
+        );
+        assert_eq!(smart_list.data.evaluate(tasks, &net), [mine.id, flagged.id]);
+
+        smart_list.change_filter(TaskFilter::And(vec![
+            TaskFilter::DueFrom(at(200)),
+            TaskFilter::Not(Box::new(TaskFilter::Assignee(me))),
+        ]));
+        assert_eq!(smart_list.data.evaluate(tasks, &net), [flagged.id]);
+
+        smart_list.change_filter(TaskFilter::Or(vec![
+            TaskFilter::Status(done_status),
+            TaskFilter::DueBefore(at(200)),
+        ]));
+        assert_eq!(smart_list.data.evaluate(tasks, &net), [mine.id, done.id]);
+
+        smart_list.change_filter(TaskFilter::All);
+        assert_eq!(smart_list.data.evaluate(tasks, &net).len(), 3);
+        assert!(smart_list.rename("  ").is_err());
+        smart_list.rename(" Everything ").unwrap();
+        assert_eq!(smart_list.data.title(), "Everything");
+        assert_eq!(smart_list.take_events().len(), 5);
+    }
+}
@@ -0,0 +1,55 @@
+use shared_kernel::{Entity, Id, Specification, Timestamp};
+
+use super::{
+    list::List,
+    net::{Net, Status},
+    task::Task,
+};
+
+/// A task together with the net it is evaluated against.
+#[derive(Debug, Clone, Copy)]
+pub struct TaskCandidate<'a> {
+    pub task: &'a Entity<Task>,
+    pub net: &'a Entity<Net>,
+}
+
+/// Specification satisfied by tasks categorized to a list.
+#[derive(Debug, Clone, Copy)]
+pub struct TaskInList(pub Id<List>);
+
+/// Specification satisfied by tasks that have a status in the net.
+#[derive(Debug, Clone, Copy)]
+pub struct TaskWithStatus(pub Id<Status>);
+
+/// Specification satisfied by tasks waiting on a related task that is not accepted yet.
+#[derive(Debug, Clone, Copy)]
+pub struct TaskBlocked;
+
+/// Specification satisfied by tasks past their due date at a given time without being accepted
+/// in the net.
+#[derive(Debug, Clone, Copy)]
+pub struct TaskOverdue(pub Timestamp);
+
+impl Specification<TaskCandidate<'_>> for TaskInList {
+    fn is_satisfied_by(&self, candidate: &TaskCandidate<'_>) -> bool {
+        candidate.task.data.list() == self.0
+    }
+}
+
+impl Specification<TaskCandidate<'_>> for TaskWithStatus {
+    fn is_satisfied_by(&self, candidate: &TaskCandidate<'_>) -> bool {
+        candidate.net.data.status_of(candidate.task.id) == Some(self.0)
+    }
+}
+
+impl Specification<TaskCandidate<'_>> for TaskBlocked {
+    fn is_satisfied_by(&self, candidate: &TaskCandidate<'_>) -> bool {
+        candidate.net.data.is_blocked(candidate.task.id)
+    }
+}
+
+impl Specification<TaskCandidate<'_>> for TaskOverdue {
+    fn is_satisfied_by(&self, candidate: &TaskCandidate<'_>) -> bool {
+        candidate.net.data.is_overdue(candidate.task, self.0)
+    }
+}
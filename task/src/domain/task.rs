@@ -1,39 +1,1501 @@
-use shared_kernel::{Entity, Id};
+use std::{cmp::Ordering, collections::HashMap, time::Duration};
 
-use super::list::List;
+use shared_kernel::{
+    AggregateRoot, Audited, Clock, Entity, EntityKind, Id, Timestamp, Timestamped, User,
+};
+
+use super::{
+    custom_field::{self, FieldDefinition, FieldValue},
+    error::TaskDomainError,
+    event::TaskDomainEvent,
+    list::List,
+    name::{LabelName, TaskDescription, TaskName},
+    recurrence::Recurrence,
+};
+
+type TaskDomainResult<T> = Result<T, TaskDomainError>;
 
 /// Represents a task.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "TaskRecord", from = "TaskRecord")
+)]
 pub struct Task {
-    pub name: String,
-    pub list: Id<List>,
+    name: TaskName,
+    description: Option<TaskDescription>,
+    schedule: Option<Schedule>,
+    priority: Priority,
+    assignees: Vec<Id<User>>,
+    labels: Vec<LabelName>,
+    work_log: Vec<Entity<WorkEntry>>,
+    recurrence: Option<Recurrence>,
+    fields: Vec<(Id<FieldDefinition>, FieldValue)>,
+    list: Id<List>,
+    events: Vec<TaskDomainEvent>,
+}
+
+/// Represents time someone spent working on a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorkEntry {
+    /// How long the work took.
+    pub duration: Duration,
+    /// When the work was done.
+    pub date: Timestamp,
+    /// Who did the work.
+    pub actor: Id<User>,
+}
+
+/// Represents how urgently a task should be worked on.
+///
+/// Priorities order from the most to the least urgent, so sorting in ascending order puts
+/// urgent tasks first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Priority {
+    /// The task needs attention before anything else.
+    Urgent,
+    /// The task should be picked up soon.
+    High,
+    /// The task has no particular urgency.
+    #[default]
+    Normal,
+    /// The task can wait until everything else is done.
+    Low,
+}
+
+/// Orders tasks from the most to the least urgent priority, breaking ties by id so the order
+/// is stable across runs.
+pub fn by_priority(a: &Entity<Task>, b: &Entity<Task>) -> Ordering {
+    a.data
+        .priority
+        .cmp(&b.data.priority)
+        .then_with(|| a.id.cmp(&b.id))
+}
+
+/// When work on a task is meant to start and by when it is due.
+///
+/// At least one of the dates is set, and the start never falls after the due date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "ScheduleRecord", try_from = "ScheduleRecord")
+)]
+pub struct Schedule {
+    start_date: Option<Timestamp>,
+    due_date: Option<Timestamp>,
+}
+
+impl Schedule {
+    /// Creates a schedule with only a due date.
+    pub fn due(due_date: Timestamp) -> Self {
+        Schedule {
+            start_date: None,
+            due_date: Some(due_date),
+        }
+    }
+
+    /// Creates a schedule with only a start date.
+    pub fn starting(start_date: Timestamp) -> Self {
+        Schedule {
+            start_date: Some(start_date),
+            due_date: None,
+        }
+    }
+
+    /// Creates a schedule with both dates, failing if the start falls after the due date.
+    pub fn between(start_date: Timestamp, due_date: Timestamp) -> TaskDomainResult<Self> {
+        if start_date > due_date {
+            return Err(TaskDomainError::InvertedSchedule {
+                start_date,
+                due_date,
+            });
+        }
+
+        Ok(Schedule {
+            start_date: Some(start_date),
+            due_date: Some(due_date),
+        })
+    }
+
+    /// Returns when work on the task is meant to start, if set.
+    pub fn start_date(&self) -> Option<Timestamp> {
+        self.start_date
+    }
+
+    /// Returns by when the task is due, if set.
+    pub fn due_date(&self) -> Option<Timestamp> {
+        self.due_date
+    }
+
+    /// Checks whether the due date lies before the given time.
+    pub fn is_past_due(&self, at: Timestamp) -> bool {
+        self.due_date.is_some_and(|due_date| due_date < at)
+    }
+}
+
+/// The on-disk form of a schedule, checked like a new schedule when it is read.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ScheduleRecord {
+    start_date: Option<Timestamp>,
+    due_date: Option<Timestamp>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Schedule> for ScheduleRecord {
+    fn from(schedule: Schedule) -> Self {
+        ScheduleRecord {
+            start_date: schedule.start_date,
+            due_date: schedule.due_date,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ScheduleRecord> for Schedule {
+    type Error = TaskDomainError;
+
+    fn try_from(record: ScheduleRecord) -> Result<Self, Self::Error> {
+        match (record.start_date, record.due_date) {
+            (Some(start_date), Some(due_date)) => Schedule::between(start_date, due_date),
+            (Some(start_date), None) => Ok(Schedule::starting(start_date)),
+            (None, Some(due_date)) => Ok(Schedule::due(due_date)),
+            (None, None) => Err(TaskDomainError::EmptySchedule),
+        }
+    }
+}
+
+/// The versioned on-disk form of a task; pending events are not persisted.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+enum TaskRecord {
+    #[serde(rename = "1")]
+    V1 {
+        name: TaskName,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        description: Option<TaskDescription>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        schedule: Option<Schedule>,
+        #[serde(default)]
+        priority: Priority,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        assignees: Vec<Id<User>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        labels: Vec<LabelName>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        work_log: Vec<Entity<WorkEntry>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        recurrence: Option<Recurrence>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        fields: Vec<(Id<FieldDefinition>, FieldValue)>,
+        list: Id<List>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<Task> for TaskRecord {
+    fn from(task: Task) -> Self {
+        TaskRecord::V1 {
+            name: task.name,
+            description: task.description,
+            schedule: task.schedule,
+            priority: task.priority,
+            assignees: task.assignees,
+            labels: task.labels,
+            work_log: task.work_log,
+            recurrence: task.recurrence,
+            fields: task.fields,
+            list: task.list,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<TaskRecord> for Task {
+    fn from(record: TaskRecord) -> Self {
+        match record {
+            TaskRecord::V1 {
+                name,
+                description,
+                schedule,
+                priority,
+                assignees,
+                labels,
+                work_log,
+                recurrence,
+                fields,
+                list,
+            } => Task {
+                name,
+                description,
+                schedule,
+                priority,
+                assignees,
+                labels,
+                work_log,
+                recurrence,
+                fields,
+                list,
+                events: Vec::new(),
+            },
+        }
+    }
+}
+
+impl EntityKind for Task {
+    const KIND: &'static str = "task";
+}
+
+impl Task {
+    /// Returns the name of the task.
+    pub fn name(&self) -> &TaskName {
+        &self.name
+    }
+
+    /// Returns the Markdown description of the task, if one is set.
+    pub fn description(&self) -> Option<&TaskDescription> {
+        self.description.as_ref()
+    }
+
+    /// Returns the schedule of the task, if it is scheduled.
+    pub fn schedule(&self) -> Option<Schedule> {
+        self.schedule
+    }
+
+    /// Returns the priority of the task.
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// Lists the users the task is assigned to, in the order they were assigned.
+    pub fn assignees(&self) -> &[Id<User>] {
+        &self.assignees
+    }
+
+    /// Checks whether the task is assigned to a user.
+    pub fn is_assigned_to(&self, user: Id<User>) -> bool {
+        self.assignees.contains(&user)
+    }
+
+    /// Lists the names of the labels the task carries, in the order they were added.
+    ///
+    /// The colors of the labels are kept by the label registry of the list.
+    pub fn labels(&self) -> &[LabelName] {
+        &self.labels
+    }
+
+    /// Checks whether the task carries a label.
+    pub fn has_label(&self, label: &LabelName) -> bool {
+        self.labels.contains(label)
+    }
+
+    /// Lists the work logged on the task, in the order it was logged.
+    pub fn work_log(&self) -> &[Entity<WorkEntry>] {
+        &self.work_log
+    }
+
+    /// Returns the total time logged on the task, saturating at [`Duration::MAX`].
+    pub fn logged_time(&self) -> Duration {
+        self.work_log.iter().fold(Duration::ZERO, |total, entry| {
+            total.saturating_add(entry.data.duration)
+        })
+    }
+
+    /// Returns how the task repeats, if it is recurring.
+    pub fn recurrence(&self) -> Option<Recurrence> {
+        self.recurrence
+    }
+
+    /// Lists the custom field values of the task, in the order they were first set.
+    pub fn fields(&self) -> &[(Id<FieldDefinition>, FieldValue)] {
+        &self.fields
+    }
+
+    /// Returns the value of a custom field of the task, if it is set.
+    pub fn field(&self, field: Id<FieldDefinition>) -> Option<&FieldValue> {
+        self.fields
+            .iter()
+            .find(|(id, _)| *id == field)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the list the task is categorized to.
+    pub fn list(&self) -> Id<List> {
+        self.list
+    }
 }
 
 /// Trait for aggregate root operations on a `Task`.
 pub trait TaskAggregateRoot {
-    /// Renames the task with a new name.
-    fn rename(&mut self, name: String);
+    /// Renames the task with a new name, validated and trimmed of surrounding whitespace.
+    fn rename(&mut self, name: impl Into<String>) -> TaskDomainResult<()>;
+
+    /// Sets the Markdown description of the task.
+    fn set_description(&mut self, description: TaskDescription);
+
+    /// Removes the description of the task.
+    fn clear_description(&mut self);
+
+    /// Schedules a task that has no schedule yet.
+    fn schedule(&mut self, schedule: Schedule) -> TaskDomainResult<()>;
+
+    /// Replaces the schedule of an already scheduled task.
+    fn reschedule(&mut self, schedule: Schedule) -> TaskDomainResult<()>;
+
+    /// Removes the schedule of the task.
+    fn clear_schedule(&mut self);
+
+    /// Changes the priority of the task.
+    fn set_priority(&mut self, priority: Priority);
+
+    /// Assigns the task to a user, failing if it is assigned to them already.
+    fn assign(&mut self, user: Id<User>) -> TaskDomainResult<()>;
+
+    /// Removes a user from the assignees of the task, failing if they are not among them.
+    fn unassign(&mut self, user: Id<User>) -> TaskDomainResult<()>;
+
+    /// Adds a label defined by the list of the task, doing nothing if it carries the label already.
+    ///
+    /// Labels the list no longer defines stay on the tasks carrying them until they are removed,
+    /// but they cannot be added again.
+    fn add_label(&mut self, list: &Entity<List>, label: LabelName) -> TaskDomainResult<()>;
+
+    /// Removes a label from the task, doing nothing if it does not carry the label.
+    fn remove_label(&mut self, label: &LabelName);
+
+    /// Logs time spent on the task, returning the id of the new work entry.
+    ///
+    /// Fails if the total time logged on the task would overflow a duration.
+    fn log_work(&mut self, entry: WorkEntry) -> TaskDomainResult<Id<WorkEntry>>;
+
+    /// Removes a work entry from the log of the task.
+    fn remove_work_entry(&mut self, entry: Id<WorkEntry>) -> TaskDomainResult<()>;
+
+    /// Makes the task recur, replacing any previous recurrence.
+    fn set_recurrence(&mut self, recurrence: Recurrence);
+
+    /// Stops the task from recurring.
+    fn clear_recurrence(&mut self);
+
+    /// Sets the value of a custom field of the list the task is categorized to, failing if
+    /// the value does not fit the type of the field.
+    fn set_field(
+        &mut self,
+        field: &Entity<FieldDefinition>,
+        value: FieldValue,
+    ) -> TaskDomainResult<()>;
+
+    /// Clears the value of a custom field of the task.
+    fn clear_field(&mut self, field: Id<FieldDefinition>);
 
     /// Creates a new task with the given name and list.
-    fn new(name: String, list: Id<List>) -> Self;
+    fn new(name: TaskName, list: Id<List>) -> Self;
 
-    /// Categorizes the task to a new list.
+    /// Categorizes the task to a new list, clearing the values of the custom fields of the
+    /// list it leaves.
     fn categorize_to(&mut self, list: Id<List>);
 }
 
 impl TaskAggregateRoot for Entity<Task> {
-    fn rename(&mut self, name: String) {
-        self.data.name = name;
+    fn rename(&mut self, name: impl Into<String>) -> TaskDomainResult<()> {
+        let name = TaskName::new_trimmed(name)?;
+        self.data.name = name.clone();
+        self.data.events.push(TaskDomainEvent::TaskRenamed {
+            task: self.id,
+            name,
+        });
+
+        Ok(())
     }
 
-    fn new(name: String, list: Id<List>) -> Self {
+    fn set_description(&mut self, description: TaskDescription) {
+        if self.data.description.as_ref() == Some(&description) {
+            return;
+        }
+
+        self.data.description = Some(description.clone());
+        self.data
+            .events
+            .push(TaskDomainEvent::TaskDescriptionChanged {
+                task: self.id,
+                description: Some(description),
+            });
+    }
+
+    fn clear_description(&mut self) {
+        if self.data.description.take().is_none() {
+            return;
+        }
+
+        self.data
+            .events
+            .push(TaskDomainEvent::TaskDescriptionChanged {
+                task: self.id,
+                description: None,
+            });
+    }
+
+    fn schedule(&mut self, schedule: Schedule) -> TaskDomainResult<()> {
+        if self.data.schedule.is_some() {
+            return Err(TaskDomainError::TaskAlreadyScheduled { task: self.id });
+        }
+
+        self.data.schedule = Some(schedule);
+        self.data.events.push(TaskDomainEvent::TaskScheduled {
+            task: self.id,
+            schedule: Some(schedule),
+        });
+
+        Ok(())
+    }
+
+    fn reschedule(&mut self, schedule: Schedule) -> TaskDomainResult<()> {
+        match self.data.schedule {
+            None => return Err(TaskDomainError::TaskNotScheduled { task: self.id }),
+            Some(current) if current == schedule => return Ok(()),
+            Some(_) => {}
+        }
+
+        self.data.schedule = Some(schedule);
+        self.data.events.push(TaskDomainEvent::TaskScheduled {
+            task: self.id,
+            schedule: Some(schedule),
+        });
+
+        Ok(())
+    }
+
+    fn clear_schedule(&mut self) {
+        if self.data.schedule.take().is_none() {
+            return;
+        }
+
+        self.data.events.push(TaskDomainEvent::TaskScheduled {
+            task: self.id,
+            schedule: None,
+        });
+    }
+
+    fn set_priority(&mut self, priority: Priority) {
+        if self.data.priority == priority {
+            return;
+        }
+
+        self.data.priority = priority;
+        self.data.events.push(TaskDomainEvent::TaskPrioritized {
+            task: self.id,
+            priority,
+        });
+    }
+
+    fn assign(&mut self, user: Id<User>) -> TaskDomainResult<()> {
+        if self.data.is_assigned_to(user) {
+            return Err(TaskDomainError::AlreadyAssigned {
+                task: self.id,
+                user,
+            });
+        }
+
+        self.data.assignees.push(user);
+        self.data.events.push(TaskDomainEvent::TaskAssigned {
+            task: self.id,
+            user,
+        });
+
+        Ok(())
+    }
+
+    fn unassign(&mut self, user: Id<User>) -> TaskDomainResult<()> {
+        let Some(index) = self.data.assignees.iter().position(|u| *u == user) else {
+            return Err(TaskDomainError::NotAssigned {
+                task: self.id,
+                user,
+            });
+        };
+
+        self.data.assignees.remove(index);
+        self.data.events.push(TaskDomainEvent::TaskUnassigned {
+            task: self.id,
+            user,
+        });
+
+        Ok(())
+    }
+
+    fn add_label(&mut self, list: &Entity<List>, label: LabelName) -> TaskDomainResult<()> {
+        if list.id != self.data.list {
+            return Err(TaskDomainError::TaskNotInList {
+                list: list.id,
+                task: self.id,
+            });
+        }
+        if list.data.label(&label).is_none() {
+            return Err(TaskDomainError::LabelNotDefined {
+                list: list.id,
+                name: label,
+            });
+        }
+        if self.data.has_label(&label) {
+            return Ok(());
+        }
+
+        self.data.labels.push(label.clone());
+        self.data.events.push(TaskDomainEvent::TaskLabeled {
+            task: self.id,
+            label,
+        });
+
+        Ok(())
+    }
+
+    fn remove_label(&mut self, label: &LabelName) {
+        let Some(index) = self.data.labels.iter().position(|l| l == label) else {
+            return;
+        };
+
+        self.data.labels.remove(index);
+        self.data.events.push(TaskDomainEvent::TaskUnlabeled {
+            task: self.id,
+            label: label.clone(),
+        });
+    }
+
+    fn log_work(&mut self, entry: WorkEntry) -> TaskDomainResult<Id<WorkEntry>> {
+        if entry.duration.is_zero() {
+            return Err(TaskDomainError::EmptyWorkEntry { task: self.id });
+        }
+        if self
+            .data
+            .logged_time()
+            .checked_add(entry.duration)
+            .is_none()
+        {
+            return Err(TaskDomainError::WorkLogOverflow { task: self.id });
+        }
+
+        let entry = Entity::from(entry);
+        self.data.events.push(TaskDomainEvent::WorkLogged {
+            task: self.id,
+            entry: entry.id,
+            work: entry.data.clone(),
+        });
+        let id = entry.id;
+        self.data.work_log.push(entry);
+
+        Ok(id)
+    }
+
+    fn remove_work_entry(&mut self, entry: Id<WorkEntry>) -> TaskDomainResult<()> {
+        let Some(index) = self.data.work_log.iter().position(|e| e.id == entry) else {
+            return Err(TaskDomainError::WorkEntryNotFound {
+                task: self.id,
+                entry,
+            });
+        };
+
+        self.data.work_log.remove(index);
+        self.data.events.push(TaskDomainEvent::WorkEntryRemoved {
+            task: self.id,
+            entry,
+        });
+
+        Ok(())
+    }
+
+    fn set_recurrence(&mut self, recurrence: Recurrence) {
+        if self.data.recurrence == Some(recurrence) {
+            return;
+        }
+
+        self.data.recurrence = Some(recurrence);
+        self.data
+            .events
+            .push(TaskDomainEvent::TaskRecurrenceChanged {
+                task: self.id,
+                recurrence: Some(recurrence),
+            });
+    }
+
+    fn clear_recurrence(&mut self) {
+        if self.data.recurrence.take().is_none() {
+            return;
+        }
+
+        self.data
+            .events
+            .push(TaskDomainEvent::TaskRecurrenceChanged {
+                task: self.id,
+                recurrence: None,
+            });
+    }
+
+    fn set_field(
+        &mut self,
+        field: &Entity<FieldDefinition>,
+        value: FieldValue,
+    ) -> TaskDomainResult<()> {
+        if field.data.list() != self.data.list {
+            return Err(TaskDomainError::FieldNotInList {
+                field: field.id,
+                list: self.data.list,
+            });
+        }
+        custom_field::validate(field, &value)?;
+
+        match self.data.fields.iter_mut().find(|(id, _)| *id == field.id) {
+            Some((_, current)) if *current == value => return Ok(()),
+            Some((_, current)) => *current = value.clone(),
+            None => self.data.fields.push((field.id, value.clone())),
+        }
+        self.data.events.push(TaskDomainEvent::TaskFieldSet {
+            task: self.id,
+            field: field.id,
+            value,
+        });
+
+        Ok(())
+    }
+
+    fn clear_field(&mut self, field: Id<FieldDefinition>) {
+        let Some(index) = self.data.fields.iter().position(|(id, _)| *id == field) else {
+            return;
+        };
+
+        self.data.fields.remove(index);
+        self.data.events.push(TaskDomainEvent::TaskFieldCleared {
+            task: self.id,
+            field,
+        });
+    }
+
+    fn new(name: TaskName, list: Id<List>) -> Self {
+        let id = Id::new();
         Entity {
-            id: Id::new(),
-            data: Task { name, list },
+            id,
+            data: Task {
+                name: name.clone(),
+                description: None,
+                schedule: None,
+                priority: Priority::default(),
+                assignees: Vec::new(),
+                labels: Vec::new(),
+                work_log: Vec::new(),
+                recurrence: None,
+                fields: Vec::new(),
+                list,
+                events: vec![TaskDomainEvent::TaskCreated {
+                    task: id,
+                    name,
+                    list,
+                }],
+            },
         }
     }
 
     fn categorize_to(&mut self, list: Id<List>) {
-        self.data.list = list;
+        if list != self.data.list {
+            for (field, _) in std::mem::take(&mut self.data.fields) {
+                self.data.events.push(TaskDomainEvent::TaskFieldCleared {
+                    task: self.id,
+                    field,
+                });
+            }
+        }
+        let from = std::mem::replace(&mut self.data.list, list);
+        self.data.events.push(TaskDomainEvent::TaskRecategorized {
+            task: self.id,
+            from,
+            to: list,
+        });
+    }
+}
+
+impl<C: Clock + Default> TaskAggregateRoot for Timestamped<Entity<Task>, C> {
+    fn rename(&mut self, name: impl Into<String>) -> TaskDomainResult<()> {
+        self.try_update(|task| task.rename(name))
+    }
+
+    fn set_description(&mut self, description: TaskDescription) {
+        self.update(|task| task.set_description(description));
+    }
+
+    fn clear_description(&mut self) {
+        self.update(|task| task.clear_description());
+    }
+
+    fn schedule(&mut self, schedule: Schedule) -> TaskDomainResult<()> {
+        self.try_update(|task| task.schedule(schedule))
+    }
+
+    fn reschedule(&mut self, schedule: Schedule) -> TaskDomainResult<()> {
+        self.try_update(|task| task.reschedule(schedule))
+    }
+
+    fn clear_schedule(&mut self) {
+        self.update(|task| task.clear_schedule());
+    }
+
+    fn set_priority(&mut self, priority: Priority) {
+        self.update(|task| task.set_priority(priority));
+    }
+
+    fn assign(&mut self, user: Id<User>) -> TaskDomainResult<()> {
+        self.try_update(|task| task.assign(user))
+    }
+
+    fn unassign(&mut self, user: Id<User>) -> TaskDomainResult<()> {
+        self.try_update(|task| task.unassign(user))
+    }
+
+    fn add_label(&mut self, list: &Entity<List>, label: LabelName) -> TaskDomainResult<()> {
+        self.try_update(|task| task.add_label(list, label))
+    }
+
+    fn remove_label(&mut self, label: &LabelName) {
+        self.update(|task| task.remove_label(label));
+    }
+
+    fn log_work(&mut self, entry: WorkEntry) -> TaskDomainResult<Id<WorkEntry>> {
+        self.try_update(|task| task.log_work(entry))
+    }
+
+    fn remove_work_entry(&mut self, entry: Id<WorkEntry>) -> TaskDomainResult<()> {
+        self.try_update(|task| task.remove_work_entry(entry))
+    }
+
+    fn set_recurrence(&mut self, recurrence: Recurrence) {
+        self.update(|task| task.set_recurrence(recurrence));
+    }
+
+    fn clear_recurrence(&mut self) {
+        self.update(|task| task.clear_recurrence());
+    }
+
+    fn set_field(
+        &mut self,
+        field: &Entity<FieldDefinition>,
+        value: FieldValue,
+    ) -> TaskDomainResult<()> {
+        self.try_update(|task| task.set_field(field, value))
+    }
+
+    fn clear_field(&mut self, field: Id<FieldDefinition>) {
+        self.update(|task| task.clear_field(field));
+    }
+
+    fn new(name: TaskName, list: Id<List>) -> Self {
+        Timestamped::new(
+            <Entity<Task> as TaskAggregateRoot>::new(name, list),
+            C::default(),
+        )
+    }
+
+    fn categorize_to(&mut self, list: Id<List>) {
+        self.update(|task| task.categorize_to(list));
+    }
+}
+
+/// Attributed variants of the [`TaskAggregateRoot`] operations, recording the user performing each
+/// one on an [`Audited`] task.
+pub trait AuditedTaskAggregateRoot {
+    /// Attributed variant of [`TaskAggregateRoot::rename`].
+    fn rename_by(&mut self, actor: Id<User>, name: impl Into<String>) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`TaskAggregateRoot::set_description`].
+    fn set_description_by(&mut self, actor: Id<User>, description: TaskDescription);
+
+    /// Attributed variant of [`TaskAggregateRoot::clear_description`].
+    fn clear_description_by(&mut self, actor: Id<User>);
+
+    /// Attributed variant of [`TaskAggregateRoot::schedule`].
+    fn schedule_by(&mut self, actor: Id<User>, schedule: Schedule) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`TaskAggregateRoot::reschedule`].
+    fn reschedule_by(&mut self, actor: Id<User>, schedule: Schedule) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`TaskAggregateRoot::clear_schedule`].
+    fn clear_schedule_by(&mut self, actor: Id<User>);
+
+    /// Attributed variant of [`TaskAggregateRoot::set_priority`].
+    fn set_priority_by(&mut self, actor: Id<User>, priority: Priority);
+
+    /// Attributed variant of [`TaskAggregateRoot::assign`].
+    fn assign_by(&mut self, actor: Id<User>, user: Id<User>) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`TaskAggregateRoot::unassign`].
+    fn unassign_by(&mut self, actor: Id<User>, user: Id<User>) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`TaskAggregateRoot::add_label`].
+    fn add_label_by(
+        &mut self,
+        actor: Id<User>,
+        list: &Entity<List>,
+        label: LabelName,
+    ) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`TaskAggregateRoot::remove_label`].
+    fn remove_label_by(&mut self, actor: Id<User>, label: &LabelName);
+
+    /// Attributed variant of [`TaskAggregateRoot::log_work`].
+    fn log_work_by(&mut self, actor: Id<User>, entry: WorkEntry)
+        -> TaskDomainResult<Id<WorkEntry>>;
+
+    /// Attributed variant of [`TaskAggregateRoot::remove_work_entry`].
+    fn remove_work_entry_by(
+        &mut self,
+        actor: Id<User>,
+        entry: Id<WorkEntry>,
+    ) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`TaskAggregateRoot::set_recurrence`].
+    fn set_recurrence_by(&mut self, actor: Id<User>, recurrence: Recurrence);
+
+    /// Attributed variant of [`TaskAggregateRoot::clear_recurrence`].
+    fn clear_recurrence_by(&mut self, actor: Id<User>);
+
+    /// Attributed variant of [`TaskAggregateRoot::set_field`].
+    fn set_field_by(
+        &mut self,
+        actor: Id<User>,
+        field: &Entity<FieldDefinition>,
+        value: FieldValue,
+    ) -> TaskDomainResult<()>;
+
+    /// Attributed variant of [`TaskAggregateRoot::clear_field`].
+    fn clear_field_by(&mut self, actor: Id<User>, field: Id<FieldDefinition>);
+
+    /// Attributed variant of [`TaskAggregateRoot::new`], created by `actor`.
+    fn new_by(actor: Id<User>, name: TaskName, list: Id<List>) -> Self;
+
+    /// Attributed variant of [`TaskAggregateRoot::categorize_to`].
+    fn categorize_to_by(&mut self, actor: Id<User>, list: Id<List>);
+}
+
+impl AuditedTaskAggregateRoot for Audited<Entity<Task>> {
+    fn rename_by(&mut self, actor: Id<User>, name: impl Into<String>) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |task| task.rename(name))
+    }
+
+    fn set_description_by(&mut self, actor: Id<User>, description: TaskDescription) {
+        self.update_by(actor, |task| task.set_description(description));
+    }
+
+    fn clear_description_by(&mut self, actor: Id<User>) {
+        self.update_by(actor, |task| task.clear_description());
+    }
+
+    fn schedule_by(&mut self, actor: Id<User>, schedule: Schedule) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |task| task.schedule(schedule))
+    }
+
+    fn reschedule_by(&mut self, actor: Id<User>, schedule: Schedule) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |task| task.reschedule(schedule))
+    }
+
+    fn clear_schedule_by(&mut self, actor: Id<User>) {
+        self.update_by(actor, |task| task.clear_schedule());
+    }
+
+    fn set_priority_by(&mut self, actor: Id<User>, priority: Priority) {
+        self.update_by(actor, |task| task.set_priority(priority));
+    }
+
+    fn assign_by(&mut self, actor: Id<User>, user: Id<User>) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |task| task.assign(user))
+    }
+
+    fn unassign_by(&mut self, actor: Id<User>, user: Id<User>) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |task| task.unassign(user))
+    }
+
+    fn add_label_by(
+        &mut self,
+        actor: Id<User>,
+        list: &Entity<List>,
+        label: LabelName,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |task| task.add_label(list, label))
+    }
+
+    fn remove_label_by(&mut self, actor: Id<User>, label: &LabelName) {
+        self.update_by(actor, |task| task.remove_label(label));
+    }
+
+    fn log_work_by(
+        &mut self,
+        actor: Id<User>,
+        entry: WorkEntry,
+    ) -> TaskDomainResult<Id<WorkEntry>> {
+        self.try_update_by(actor, |task| task.log_work(entry))
+    }
+
+    fn remove_work_entry_by(
+        &mut self,
+        actor: Id<User>,
+        entry: Id<WorkEntry>,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |task| task.remove_work_entry(entry))
+    }
+
+    fn set_recurrence_by(&mut self, actor: Id<User>, recurrence: Recurrence) {
+        self.update_by(actor, |task| task.set_recurrence(recurrence));
+    }
+
+    fn clear_recurrence_by(&mut self, actor: Id<User>) {
+        self.update_by(actor, |task| task.clear_recurrence());
+    }
+
+    fn set_field_by(
+        &mut self,
+        actor: Id<User>,
+        field: &Entity<FieldDefinition>,
+        value: FieldValue,
+    ) -> TaskDomainResult<()> {
+        self.try_update_by(actor, |task| task.set_field(field, value))
+    }
+
+    fn clear_field_by(&mut self, actor: Id<User>, field: Id<FieldDefinition>) {
+        self.update_by(actor, |task| task.clear_field(field));
+    }
+
+    fn new_by(actor: Id<User>, name: TaskName, list: Id<List>) -> Self {
+        Audited::new(<Entity<Task> as TaskAggregateRoot>::new(name, list), actor)
+    }
+
+    fn categorize_to_by(&mut self, actor: Id<User>, list: Id<List>) {
+        self.update_by(actor, |task| task.categorize_to(list));
+    }
+}
+
+/// Puts a label carried by another task on a task, without checking it against the registry of
+/// the list, so copies keep labels the list no longer defines.
+pub(super) fn copy_label(task: &mut Entity<Task>, label: LabelName) {
+    if task.data.has_label(&label) {
+        return;
+    }
+
+    task.data.labels.push(label.clone());
+    task.data.events.push(TaskDomainEvent::TaskLabeled {
+        task: task.id,
+        label,
+    });
+}
+
+/// Copies a task under a new id, leaving out its work log.
+///
+/// The copy is recorded as created with everything the original carries, so it can be
+/// replayed like any other task.
+pub fn clone_task(task: &Entity<Task>) -> Entity<Task> {
+    clone_task_as(task, Id::new())
+}
+
+/// Copies a task under the given id, as [`clone_task`] does.
+///
+/// Pairs with [`NetAggregateRoot::clone_subtree`](crate::domain::net::NetAggregateRoot::clone_subtree),
+/// which picks the ids of the copies in the net.
+pub fn clone_task_as(task: &Entity<Task>, id: Id<Task>) -> Entity<Task> {
+    clone_task_into(task, id, task.data.list, &HashMap::new())
+}
+
+/// Copies a task under the given id into a list, moving the values of its custom fields to
+/// the fields they are mapped to.
+pub(crate) fn clone_task_into(
+    task: &Entity<Task>,
+    id: Id<Task>,
+    list: Id<List>,
+    fields: &HashMap<Id<FieldDefinition>, Id<FieldDefinition>>,
+) -> Entity<Task> {
+    let source = &task.data;
+    let mut clone: Entity<Task> = Entity {
+        id,
+        data: Task {
+            name: source.name.clone(),
+            description: None,
+            schedule: None,
+            priority: Priority::default(),
+            assignees: Vec::new(),
+            labels: Vec::new(),
+            work_log: Vec::new(),
+            recurrence: None,
+            fields: Vec::new(),
+            list,
+            events: vec![TaskDomainEvent::TaskCreated {
+                task: id,
+                name: source.name.clone(),
+                list,
+            }],
+        },
+    };
+
+    if let Some(description) = &source.description {
+        clone.set_description(description.clone());
+    }
+    if let Some(schedule) = source.schedule {
+        clone.data.schedule = Some(schedule);
+        clone.data.events.push(TaskDomainEvent::TaskScheduled {
+            task: id,
+            schedule: Some(schedule),
+        });
+    }
+    clone.set_priority(source.priority);
+    for user in &source.assignees {
+        clone.data.assignees.push(*user);
+        clone.data.events.push(TaskDomainEvent::TaskAssigned {
+            task: id,
+            user: *user,
+        });
+    }
+    for label in &source.labels {
+        copy_label(&mut clone, label.clone());
+    }
+    if let Some(recurrence) = source.recurrence {
+        clone.set_recurrence(recurrence);
+    }
+    for (field, value) in &source.fields {
+        let field = fields.get(field).copied().unwrap_or(*field);
+        clone.data.fields.push((field, value.clone()));
+        clone.data.events.push(TaskDomainEvent::TaskFieldSet {
+            task: id,
+            field,
+            value: value.clone(),
+        });
+    }
+
+    clone
+}
+
+impl AggregateRoot for Task {
+    type Event = TaskDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accessors_follow_mutations() {
+        let list_id = Id::new();
+        let mut task = Entity::new(TaskName::new("Draft").unwrap(), list_id);
+        assert_eq!(task.data.name(), "Draft");
+        assert_eq!(task.data.list(), list_id);
+
+        let other_list_id = Id::new();
+        task.rename(TaskName::new("Final").unwrap()).unwrap();
+        task.categorize_to(other_list_id);
+
+        assert_eq!(task.data.name(), "Final");
+        assert_eq!(task.data.list(), other_list_id);
+    }
+
+    #[test]
+    fn test_rename_validates_name() {
+        let mut task = Entity::new(TaskName::new("Draft").unwrap(), Id::new());
+        task.take_events();
+
+        task.rename(" Final\t").unwrap();
+        assert_eq!(task.data.name(), "Final");
+        assert!(matches!(
+            task.rename("   "),
+            Err(TaskDomainError::InvalidName { .. })
+        ));
+        assert_eq!(task.data.name(), "Final");
+        assert_eq!(task.take_events().len(), 1);
+    }
+
+    #[test]
+    fn test_description() {
+        let mut task = Entity::new(TaskName::new("Draft").unwrap(), Id::new());
+        task.take_events();
+        assert_eq!(task.data.description(), None);
+
+        let description = TaskDescription::new("Cover the **happy path** first.").unwrap();
+        task.set_description(description.clone());
+        task.set_description(description.clone());
+        assert_eq!(task.data.description(), Some(&description));
+        assert_eq!(
+            task.take_events(),
+            vec![TaskDomainEvent::TaskDescriptionChanged {
+                task: task.id,
+                description: Some(description),
+            }]
+        );
+
+        task.clear_description();
+        task.clear_description();
+        assert_eq!(task.data.description(), None);
+        assert_eq!(task.take_events().len(), 1);
+    }
+
+    #[test]
+    fn test_schedule() {
+        let mut task = Entity::new(TaskName::new("Draft").unwrap(), Id::new());
+        let start = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let due = start + chrono::Duration::days(3);
+
+        assert!(matches!(
+            Schedule::between(due, start),
+            Err(TaskDomainError::InvertedSchedule { .. })
+        ));
+        assert!(matches!(
+            task.reschedule(Schedule::due(due)),
+            Err(TaskDomainError::TaskNotScheduled { .. })
+        ));
+
+        task.schedule(Schedule::starting(start)).unwrap();
+        assert!(matches!(
+            task.schedule(Schedule::due(due)),
+            Err(TaskDomainError::TaskAlreadyScheduled { .. })
+        ));
+        assert!(!task.data.schedule().unwrap().is_past_due(due));
+
+        let schedule = Schedule::between(start, due).unwrap();
+        task.reschedule(schedule).unwrap();
+        assert_eq!(task.data.schedule(), Some(schedule));
+        assert_eq!(schedule.start_date(), Some(start));
+        assert!(schedule.is_past_due(due + chrono::Duration::seconds(1)));
+
+        task.take_events();
+        task.clear_schedule();
+        task.clear_schedule();
+        assert_eq!(task.data.schedule(), None);
+        assert_eq!(
+            task.take_events(),
+            vec![TaskDomainEvent::TaskScheduled {
+                task: task.id,
+                schedule: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_priority() {
+        let list_id = Id::new();
+        let mut tasks: Vec<Entity<Task>> = ["Low", "Urgent", "Normal"]
+            .into_iter()
+            .map(|name| Entity::new(TaskName::new(name).unwrap(), list_id))
+            .collect();
+        assert_eq!(tasks[0].data.priority(), Priority::Normal);
+
+        tasks[0].set_priority(Priority::Low);
+        tasks[1].set_priority(Priority::Urgent);
+        tasks[2].set_priority(Priority::Normal);
+        assert!(tasks[2]
+            .take_events()
+            .iter()
+            .all(|event| !matches!(event, TaskDomainEvent::TaskPrioritized { .. })));
+
+        tasks.sort_by(by_priority);
+        let names: Vec<_> = tasks.iter().map(|task| task.data.name().as_str()).collect();
+        assert_eq!(names, ["Urgent", "Normal", "Low"]);
+    }
+
+    #[test]
+    fn test_assignees() {
+        let mut task = Entity::new(TaskName::new("Draft").unwrap(), Id::new());
+        let alice = Id::new();
+        let bob = Id::new();
+
+        task.assign(alice).unwrap();
+        task.assign(bob).unwrap();
+        assert!(matches!(
+            task.assign(alice),
+            Err(TaskDomainError::AlreadyAssigned { user, .. }) if user == alice
+        ));
+        assert_eq!(task.data.assignees(), [alice, bob]);
+
+        task.unassign(alice).unwrap();
+        assert!(matches!(
+            task.unassign(alice),
+            Err(TaskDomainError::NotAssigned { .. })
+        ));
+        assert!(!task.data.is_assigned_to(alice));
+        assert!(task.data.is_assigned_to(bob));
+    }
+
+    #[test]
+    fn test_labels() {
+        use crate::domain::{
+            list::{Label, ListAggregateRoot},
+            name::ListTitle,
+            net::Color,
+        };
+
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let bug = LabelName::new("bug").unwrap();
+        let docs = LabelName::new("docs").unwrap();
+        let color = Color {
+            red: 255,
+            green: 0,
+            blue: 0,
+        };
+        list.define_label(Label::new(bug.clone(), color)).unwrap();
+        list.define_label(Label::new(docs.clone(), color)).unwrap();
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Draft").unwrap(), list.id);
+        task.take_events();
+
+        task.add_label(&list, bug.clone()).unwrap();
+        task.add_label(&list, docs.clone()).unwrap();
+        task.add_label(&list, bug.clone()).unwrap();
+        assert_eq!(task.data.labels(), [bug.clone(), docs.clone()]);
+
+        task.remove_label(&bug);
+        task.remove_label(&bug);
+        assert!(!task.data.has_label(&bug));
+        assert!(task.data.has_label(&docs));
+        assert_eq!(task.take_events().len(), 3);
+
+        let other: Entity<List> = ListAggregateRoot::new(ListTitle::new("Other").unwrap());
+        assert!(matches!(
+            task.add_label(&other, bug.clone()),
+            Err(TaskDomainError::TaskNotInList { .. })
+        ));
+
+        list.undefine_label(&docs).unwrap();
+        list.undefine_label(&bug).unwrap();
+        assert!(task.data.has_label(&docs));
+        assert!(matches!(
+            task.add_label(&list, bug.clone()),
+            Err(TaskDomainError::LabelNotDefined { .. })
+        ));
+        assert!(!task.data.has_label(&bug));
+        assert!(task.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_work_log() {
+        let mut task = Entity::new(TaskName::new("Draft").unwrap(), Id::new());
+        let date = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let actor = Id::new();
+        let work = |minutes: u64| WorkEntry {
+            duration: Duration::from_secs(minutes * 60),
+            date,
+            actor,
+        };
+
+        assert!(matches!(
+            task.log_work(work(0)),
+            Err(TaskDomainError::EmptyWorkEntry { .. })
+        ));
+        let first = task.log_work(work(30)).unwrap();
+        task.log_work(work(45)).unwrap();
+        assert_eq!(task.data.logged_time(), Duration::from_secs(75 * 60));
+
+        task.remove_work_entry(first).unwrap();
+        assert!(matches!(
+            task.remove_work_entry(first),
+            Err(TaskDomainError::WorkEntryNotFound { .. })
+        ));
+        assert_eq!(task.data.work_log().len(), 1);
+        assert_eq!(task.data.logged_time(), Duration::from_secs(45 * 60));
+
+        let forever = WorkEntry {
+            duration: Duration::MAX,
+            ..work(1)
+        };
+        assert!(matches!(
+            task.log_work(forever.clone()),
+            Err(TaskDomainError::WorkLogOverflow { .. })
+        ));
+        assert_eq!(task.data.work_log().len(), 1);
+
+        // Logs loaded from storage are not checked, so totals saturate instead.
+        task.data.work_log.push(Entity::from(forever));
+        assert_eq!(task.data.logged_time(), Duration::MAX);
+    }
+
+    #[test]
+    fn test_custom_fields() {
+        use crate::domain::{
+            custom_field::{FieldKind, FieldType, FiniteNumber},
+            list::ListAggregateRoot,
+            name::{FieldName, ListTitle},
+        };
+
+        let number = |value| FieldValue::Number(FiniteNumber::new(value).unwrap());
+
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let points = list
+            .define_field(FieldName::new("Points").unwrap(), FieldType::Number)
+            .unwrap();
+        let size = list
+            .define_field(
+                FieldName::new("Size").unwrap(),
+                FieldType::Enum(vec!["S".to_owned(), "L".to_owned()]),
+            )
+            .unwrap();
+        let points = list.data.field(points).unwrap().clone();
+        let size = list.data.field(size).unwrap().clone();
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Draft").unwrap(), list.id);
+
+        task.set_field(&points, number(3.0)).unwrap();
+        task.set_field(&size, FieldValue::Enum("L".to_owned()))
+            .unwrap();
+        assert!(matches!(
+            task.set_field(&points, FieldValue::Text("three".to_owned())),
+            Err(TaskDomainError::FieldTypeMismatch {
+                expected: FieldKind::Number,
+                found: FieldKind::Text,
+                ..
+            })
+        ));
+        assert!(matches!(
+            FiniteNumber::new(f64::NAN),
+            Err(TaskDomainError::NonFiniteNumber { .. })
+        ));
+        assert!(matches!(
+            task.set_field(&size, FieldValue::Enum("XL".to_owned())),
+            Err(TaskDomainError::InvalidFieldValue { .. })
+        ));
+        assert_eq!(task.data.field(points.id), Some(&number(3.0)));
+
+        task.clear_field(size.id);
+        assert_eq!(task.data.field(size.id), None);
+        assert_eq!(task.data.fields().len(), 1);
+
+        task.take_events();
+        task.categorize_to(Id::new());
+        assert!(task.data.fields().is_empty());
+        assert_eq!(
+            task.take_events()[0],
+            TaskDomainEvent::TaskFieldCleared {
+                task: task.id,
+                field: points.id,
+            }
+        );
+        assert!(matches!(
+            task.set_field(&points, number(5.0)),
+            Err(TaskDomainError::FieldNotInList { .. })
+        ));
+    }
+
+    #[test]
+    fn test_clone_task() {
+        use crate::domain::{
+            list::{Label, ListAggregateRoot},
+            name::ListTitle,
+            net::Color,
+        };
+
+        let phase = LabelName::new("phase").unwrap();
+        let mut list: Entity<List> = ListAggregateRoot::new(ListTitle::new("Inbox").unwrap());
+        let color = Color {
+            red: 0,
+            green: 0,
+            blue: 255,
+        };
+        list.define_label(Label::new(phase.clone(), color)).unwrap();
+        let mut task: Entity<Task> =
+            TaskAggregateRoot::new(TaskName::new("Plan phase").unwrap(), list.id);
+        task.set_description(TaskDescription::new("Kick-off and scope").unwrap());
+        task.set_priority(Priority::High);
+        task.assign(Id::new()).unwrap();
+        task.add_label(&list, phase).unwrap();
+        task.log_work(WorkEntry {
+            duration: Duration::from_secs(60),
+            date: chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            actor: Id::new(),
+        })
+        .unwrap();
+
+        let mut clone = clone_task(&task);
+        assert_ne!(clone.id, task.id);
+        assert_eq!(clone.data.name(), task.data.name());
+        assert_eq!(clone.data.description(), task.data.description());
+        assert_eq!(clone.data.priority(), Priority::High);
+        assert_eq!(clone.data.assignees(), task.data.assignees());
+        assert_eq!(clone.data.labels(), task.data.labels());
+        assert!(clone.data.work_log().is_empty());
+        assert!(matches!(
+            clone.take_events().first(),
+            Some(TaskDomainEvent::TaskCreated { task, .. }) if *task == clone.id
+        ));
+
+        let id = Id::new();
+        assert_eq!(clone_task_as(&task, id).id, id);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let task = Entity::new(TaskName::new("Draft").unwrap(), Id::new());
+        let json = serde_json::to_value(&task).unwrap();
+
+        assert_eq!(json["data"]["version"], "1");
+        assert_eq!(json["data"]["name"], "Draft");
+
+        let mut restored: Entity<Task> = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.id, task.id);
+        assert_eq!(restored.data.name(), task.data.name());
+        assert_eq!(restored.data.list(), task.data.list());
+        assert_eq!(restored.data.description(), None);
+        assert!(restored.data.take_events().is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_validates_schedule() {
+        let start = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let due = start + chrono::TimeDelta::days(1);
+        let mut task = Entity::new(TaskName::new("Draft").unwrap(), Id::new());
+        task.schedule(Schedule::between(start, due).unwrap())
+            .unwrap();
+        let json = serde_json::to_value(&task).unwrap();
+        let restored: Entity<Task> = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(restored.data.schedule(), task.data.schedule());
+
+        let mut inverted = json.clone();
+        inverted["data"]["schedule"]["start_date"] = serde_json::json!(due);
+        inverted["data"]["schedule"]["due_date"] = serde_json::json!(start);
+        assert!(serde_json::from_value::<Entity<Task>>(inverted).is_err());
+
+        let mut empty = json;
+        empty["data"]["schedule"] = serde_json::json!({"start_date": null, "due_date": null});
+        assert!(serde_json::from_value::<Entity<Task>>(empty).is_err());
+    }
+
+    #[test]
+    fn test_timestamped_task_mutation() {
+        let start = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let later = start + chrono::TimeDelta::seconds(1);
+        let clock = shared_kernel::ManualClock::new(start);
+        let mut task = Timestamped::new(
+            <Entity<Task> as TaskAggregateRoot>::new(TaskName::new("Task").unwrap(), Id::new()),
+            clock.clone(),
+        );
+
+        clock.set(later);
+        task.set_priority(Priority::High);
+        assert_eq!(task.updated_at(), later);
+
+        clock.set(later + chrono::TimeDelta::seconds(1));
+        assert!(task.rename("   ").is_err());
+        assert_eq!(task.updated_at(), later);
+        assert_eq!(task.created_at(), start);
+        assert_eq!(task.data.priority(), Priority::High);
+    }
+
+    #[test]
+    fn test_audited_task_mutation() {
+        let (creator, editor) = (Id::new(), Id::new());
+        let mut task: Audited<Entity<Task>> =
+            AuditedTaskAggregateRoot::new_by(creator, TaskName::new("Task").unwrap(), Id::new());
+
+        task.assign_by(editor, editor).unwrap();
+        assert_eq!(task.last_modified_by(), editor);
+        assert!(task.assign_by(creator, editor).is_err());
+        assert_eq!(task.last_modified_by(), editor);
+        assert_eq!(task.created_by(), creator);
     }
 }
@@ -0,0 +1,306 @@
+use std::time::Duration;
+
+use shared_kernel::{AggregateRoot, Entity, EntityKind, Id};
+
+use super::{
+    error::TaskDomainError,
+    event::TaskDomainEvent,
+    list::List,
+    name::{LabelName, TaskDescription, TaskName},
+    task::{Task, TaskAggregateRoot},
+};
+
+type TaskDomainResult<T> = Result<T, TaskDomainError>;
+
+/// The placeholder in a name pattern replaced by the number of the instance.
+pub const INSTANCE_PLACEHOLDER: &str = "{n}";
+
+/// Represents a reusable shape for tasks created over and over again.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "TaskTemplateRecord", from = "TaskTemplateRecord")
+)]
+pub struct TaskTemplate {
+    name_pattern: TaskName,
+    defaults: TaskTemplateDefaults,
+    instances: u32,
+    events: Vec<TaskDomainEvent>,
+}
+
+/// The values every task instantiated from a template starts with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaskTemplateDefaults {
+    /// The description of new tasks.
+    pub description: Option<TaskDescription>,
+    /// The labels new tasks carry.
+    pub labels: Vec<LabelName>,
+    /// The checklist appended to the description of new tasks as Markdown task items.
+    pub checklist: Vec<TaskName>,
+    /// The estimate to give new tasks once they are added to a net.
+    pub estimate: Option<Duration>,
+}
+
+/// The versioned on-disk form of a task template; pending events are not persisted.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+enum TaskTemplateRecord {
+    #[serde(rename = "1")]
+    V1 {
+        name_pattern: TaskName,
+        defaults: TaskTemplateDefaults,
+        instances: u32,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<TaskTemplate> for TaskTemplateRecord {
+    fn from(template: TaskTemplate) -> Self {
+        TaskTemplateRecord::V1 {
+            name_pattern: template.name_pattern,
+            defaults: template.defaults,
+            instances: template.instances,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<TaskTemplateRecord> for TaskTemplate {
+    fn from(record: TaskTemplateRecord) -> Self {
+        match record {
+            TaskTemplateRecord::V1 {
+                name_pattern,
+                defaults,
+                instances,
+            } => TaskTemplate {
+                name_pattern,
+                defaults,
+                instances,
+                events: Vec::new(),
+            },
+        }
+    }
+}
+
+impl EntityKind for TaskTemplate {
+    const KIND: &'static str = "task_template";
+}
+
+impl TaskTemplate {
+    /// Returns the pattern the names of new tasks are made from.
+    ///
+    /// Every [`INSTANCE_PLACEHOLDER`] in the pattern is replaced by the number of the instance.
+    pub fn name_pattern(&self) -> &TaskName {
+        &self.name_pattern
+    }
+
+    /// Returns the values new tasks start with.
+    pub fn defaults(&self) -> &TaskTemplateDefaults {
+        &self.defaults
+    }
+
+    /// Returns how many tasks were instantiated from the template.
+    pub fn instances(&self) -> u32 {
+        self.instances
+    }
+
+    /// Renders the description of new tasks, with the checklist appended as task items.
+    fn description(&self) -> TaskDomainResult<Option<TaskDescription>> {
+        if self.defaults.checklist.is_empty() {
+            return Ok(self.defaults.description.clone());
+        }
+
+        let checklist = self
+            .defaults
+            .checklist
+            .iter()
+            .map(|item| format!("- [ ] {item}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let description = match &self.defaults.description {
+            Some(description) => format!("{description}\n\n{checklist}"),
+            None => checklist,
+        };
+
+        TaskDescription::new(description).map(Some)
+    }
+}
+
+/// Trait for aggregate root operations on a `TaskTemplate`.
+pub trait TaskTemplateAggregateRoot {
+    /// Creates a new template making tasks named after a pattern.
+    fn new(name_pattern: TaskName) -> Self;
+
+    /// Changes the pattern the names of new tasks are made from.
+    fn change_name_pattern(&mut self, name_pattern: TaskName);
+
+    /// Replaces the values new tasks start with.
+    fn change_defaults(&mut self, defaults: TaskTemplateDefaults);
+
+    /// Creates a task in a list from the template, failing if the list does not define one of
+    /// its default labels.
+    ///
+    /// The estimate of the template is not part of the task; apply it with
+    /// `NetAggregateRoot::estimate_task` once the task is added to a net.
+    fn instantiate(&mut self, list: &Entity<List>) -> TaskDomainResult<Entity<Task>>;
+}
+
+impl TaskTemplateAggregateRoot for Entity<TaskTemplate> {
+    fn new(name_pattern: TaskName) -> Self {
+        let id = Id::new();
+        Entity {
+            id,
+            data: TaskTemplate {
+                name_pattern: name_pattern.clone(),
+                defaults: TaskTemplateDefaults::default(),
+                instances: 0,
+                events: vec![TaskDomainEvent::TaskTemplateCreated {
+                    template: id,
+                    name_pattern,
+                }],
+            },
+        }
+    }
+
+    fn change_name_pattern(&mut self, name_pattern: TaskName) {
+        self.data.name_pattern = name_pattern.clone();
+        self.data
+            .events
+            .push(TaskDomainEvent::TaskTemplateNamePatternChanged {
+                template: self.id,
+                name_pattern,
+            });
+    }
+
+    fn change_defaults(&mut self, defaults: TaskTemplateDefaults) {
+        self.data.defaults = defaults.clone();
+        self.data
+            .events
+            .push(TaskDomainEvent::TaskTemplateDefaultsChanged {
+                template: self.id,
+                defaults,
+            });
+    }
+
+    fn instantiate(&mut self, list: &Entity<List>) -> TaskDomainResult<Entity<Task>> {
+        let number = self.data.instances + 1;
+        let name = TaskName::new(
+            self.data
+                .name_pattern
+                .as_str()
+                .replace(INSTANCE_PLACEHOLDER, &number.to_string()),
+        )?;
+        let description = self.data.description()?;
+
+        let mut task: Entity<Task> = TaskAggregateRoot::new(name, list.id);
+        if let Some(description) = description {
+            task.set_description(description);
+        }
+        for label in &self.data.defaults.labels {
+            task.add_label(list, label.clone())?;
+        }
+
+        self.data.instances = number;
+        self.data
+            .events
+            .push(TaskDomainEvent::TaskTemplateInstantiated {
+                template: self.id,
+                task: task.id,
+            });
+
+        Ok(task)
+    }
+}
+
+impl AggregateRoot for TaskTemplate {
+    type Event = TaskDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        list::{Label, ListAggregateRoot},
+        name::ListTitle,
+        net::Color,
+    };
+
+    fn inbox() -> Entity<List> {
+        ListAggregateRoot::new(ListTitle::new("Inbox").unwrap())
+    }
+
+    #[test]
+    fn test_instantiate() {
+        let mut template: Entity<TaskTemplate> =
+            TaskTemplateAggregateRoot::new(TaskName::new("Release {n}").unwrap());
+        template.change_defaults(TaskTemplateDefaults {
+            description: Some(TaskDescription::new("Ship it.").unwrap()),
+            labels: vec![LabelName::new("release").unwrap()],
+            checklist: vec![
+                TaskName::new("Tag").unwrap(),
+                TaskName::new("Publish").unwrap(),
+            ],
+            estimate: Some(Duration::from_secs(3600)),
+        });
+        let mut list = inbox();
+        assert!(matches!(
+            template.instantiate(&list),
+            Err(TaskDomainError::LabelNotDefined { .. })
+        ));
+        assert_eq!(template.data.instances(), 0);
+        let color = Color {
+            red: 0,
+            green: 128,
+            blue: 0,
+        };
+        list.define_label(Label::new(LabelName::new("release").unwrap(), color))
+            .unwrap();
+
+        let first = template.instantiate(&list).unwrap();
+        let second = template.instantiate(&list).unwrap();
+        assert_eq!(first.data.name(), "Release 1");
+        assert_eq!(second.data.name(), "Release 2");
+        assert_eq!(second.data.list(), list.id);
+        assert_eq!(
+            first.data.description().unwrap(),
+            "Ship it.\n\n- [ ] Tag\n- [ ] Publish"
+        );
+        assert_eq!(first.data.labels(), [LabelName::new("release").unwrap()]);
+        assert_eq!(template.data.instances(), 2);
+        assert!(template
+            .take_events()
+            .contains(&TaskDomainEvent::TaskTemplateInstantiated {
+                template: template.id,
+                task: second.id,
+            }));
+    }
+
+    #[test]
+    fn test_instantiate_invalid_description() {
+        let mut template: Entity<TaskTemplate> =
+            TaskTemplateAggregateRoot::new(TaskName::new("Chore {n}").unwrap());
+        template.change_defaults(TaskTemplateDefaults {
+            description: Some(TaskDescription::new("x".repeat(TaskDescription::MAX_LEN)).unwrap()),
+            checklist: vec![TaskName::new("Tidy up").unwrap()],
+            ..TaskTemplateDefaults::default()
+        });
+
+        assert!(matches!(
+            template.instantiate(&inbox()),
+            Err(TaskDomainError::InvalidName { .. })
+        ));
+        assert_eq!(template.data.instances(), 0);
+
+        template.change_defaults(TaskTemplateDefaults::default());
+        template.change_name_pattern(TaskName::new("Chore").unwrap());
+        assert_eq!(template.instantiate(&inbox()).unwrap().data.name(), "Chore");
+    }
+}
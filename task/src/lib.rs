@@ -1,4 +1,4 @@
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
 /// The `domain` module contains the core domain logic for the task management system.
-mod domain;
+pub mod domain;
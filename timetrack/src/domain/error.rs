@@ -0,0 +1,24 @@
+use shared_kernel::Id;
+use task::domain::task::Task;
+use thiserror::Error;
+
+use super::timesheet::Timesheet;
+
+/// Represents errors that can occur in the time-tracking domain.
+#[derive(Debug, Error)]
+pub enum TimetrackDomainError {
+    /// Error indicating that a timer was started while another one is running.
+    #[error("timesheet {timesheet:?} already has a timer running on task {task:?}")]
+    TimerAlreadyRunning {
+        timesheet: Id<Timesheet>,
+        task: Id<Task>,
+    },
+
+    /// Error indicating that no timer is running to be stopped.
+    #[error("timesheet {timesheet:?} has no timer running")]
+    NoTimerRunning { timesheet: Id<Timesheet> },
+
+    /// Error indicating that a timer was stopped before it started.
+    #[error("timer of timesheet {timesheet:?} stopped before it started")]
+    TimerStoppedBeforeStart { timesheet: Id<Timesheet> },
+}
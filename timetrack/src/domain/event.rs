@@ -0,0 +1,33 @@
+use shared_kernel::{Id, Timestamp, User};
+use task::domain::task::Task;
+
+use super::timesheet::{TimeEntry, Timesheet};
+
+/// Represents a domain event raised by an aggregate of the time-tracking context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimetrackDomainEvent {
+    /// A timesheet was opened for a user.
+    TimesheetCreated {
+        timesheet: Id<Timesheet>,
+        user: Id<User>,
+    },
+    /// A timer was started on a task.
+    TimerStarted {
+        timesheet: Id<Timesheet>,
+        task: Id<Task>,
+        at: Timestamp,
+    },
+    /// The running timer was stopped and its time recorded.
+    TimerStopped {
+        timesheet: Id<Timesheet>,
+        entry: Id<TimeEntry>,
+        task: Id<Task>,
+        started_at: Timestamp,
+        stopped_at: Timestamp,
+    },
+    /// The running timer was discarded without recording its time.
+    TimerDiscarded {
+        timesheet: Id<Timesheet>,
+        task: Id<Task>,
+    },
+}
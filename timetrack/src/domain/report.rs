@@ -0,0 +1,109 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use chrono::{Days, NaiveDate, NaiveTime};
+use shared_kernel::{Entity, Id, User};
+use task::domain::task::Task;
+
+use super::timesheet::{TimeEntry, Timesheet};
+
+/// Lists every recorded entry of the timesheets with the user who tracked it.
+///
+/// Running timers are not part of any report until they are stopped.
+fn entries<'a>(
+    timesheets: impl IntoIterator<Item = &'a Entity<Timesheet>>,
+) -> impl Iterator<Item = (Id<User>, &'a TimeEntry)> {
+    timesheets.into_iter().flat_map(|timesheet| {
+        timesheet
+            .data
+            .entries()
+            .iter()
+            .map(move |entry| (timesheet.data.user(), &entry.data))
+    })
+}
+
+/// Sums the time recorded in the timesheets per task.
+pub fn time_per_task<'a>(
+    timesheets: impl IntoIterator<Item = &'a Entity<Timesheet>>,
+) -> BTreeMap<Id<Task>, Duration> {
+    let mut report = BTreeMap::new();
+    for (_, entry) in entries(timesheets) {
+        *report.entry(entry.task()).or_default() += entry.duration();
+    }
+    report
+}
+
+/// Sums the time recorded in the timesheets per user.
+pub fn time_per_user<'a>(
+    timesheets: impl IntoIterator<Item = &'a Entity<Timesheet>>,
+) -> BTreeMap<Id<User>, Duration> {
+    let mut report = BTreeMap::new();
+    for (user, entry) in entries(timesheets) {
+        *report.entry(user).or_default() += entry.duration();
+    }
+    report
+}
+
+/// Sums the time recorded in the timesheets per UTC day, splitting entries running past
+/// midnight between the days they span.
+pub fn time_per_day<'a>(
+    timesheets: impl IntoIterator<Item = &'a Entity<Timesheet>>,
+) -> BTreeMap<NaiveDate, Duration> {
+    let mut report = BTreeMap::<_, Duration>::new();
+    for (_, entry) in entries(timesheets) {
+        let mut from = entry.started_at();
+        while from < entry.stopped_at() {
+            let day = from.date_naive();
+            let midnight = day
+                .checked_add_days(Days::new(1))
+                .map(|next| next.and_time(NaiveTime::MIN).and_utc());
+            let until = midnight.map_or(entry.stopped_at(), |m| m.min(entry.stopped_at()));
+            *report.entry(day).or_default() += (until - from).to_std().unwrap_or_default();
+            from = until;
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::domain::timesheet::TimesheetAggregateRoot;
+
+    #[test]
+    fn test_reports() {
+        let evening = chrono::DateTime::from_timestamp(1_700_000_000, 0)
+            .unwrap()
+            .date_naive()
+            .and_hms_opt(23, 0, 0)
+            .unwrap()
+            .and_utc();
+        let hours = |n| chrono::Duration::hours(n);
+        let (design, build) = (Id::new(), Id::new());
+        let (alice, bob) = (Id::new(), Id::new());
+
+        let mut first: Entity<Timesheet> = TimesheetAggregateRoot::new(alice);
+        first.start(design, &|| evening).unwrap();
+        first.stop(&|| evening + hours(2)).unwrap();
+        first.start(build, &|| evening + hours(3)).unwrap();
+        let mut second: Entity<Timesheet> = TimesheetAggregateRoot::new(bob);
+        second.start(build, &|| evening).unwrap();
+        second.stop(&|| evening + hours(1)).unwrap();
+
+        let hour = Duration::from_secs(3600);
+        let timesheets = [&first, &second];
+        assert_eq!(
+            time_per_task(timesheets),
+            BTreeMap::from([(design, hour * 2), (build, hour)])
+        );
+        assert_eq!(
+            time_per_user(timesheets),
+            BTreeMap::from([(alice, hour * 2), (bob, hour)])
+        );
+        let day = evening.date_naive();
+        assert_eq!(
+            time_per_day(timesheets),
+            BTreeMap::from([(day, hour * 2), (day.succ_opt().unwrap(), hour)])
+        );
+    }
+}
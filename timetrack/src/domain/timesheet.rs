@@ -0,0 +1,296 @@
+use std::time::Duration;
+
+use shared_kernel::{AggregateRoot, Clock, Entity, EntityKind, Id, Timestamp, User};
+use task::domain::task::Task;
+
+use super::{error::TimetrackDomainError, event::TimetrackDomainEvent};
+
+type TimetrackDomainResult<T> = Result<T, TimetrackDomainError>;
+
+/// Represents a timer running on a task since a given time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunningTimer {
+    task: Id<Task>,
+    started_at: Timestamp,
+}
+
+impl RunningTimer {
+    /// Returns the task the timer runs on.
+    pub fn task(&self) -> Id<Task> {
+        self.task
+    }
+
+    /// Returns when the timer was started.
+    pub fn started_at(&self) -> Timestamp {
+        self.started_at
+    }
+}
+
+/// Represents a stretch of time a user spent on a task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeEntry {
+    task: Id<Task>,
+    started_at: Timestamp,
+    stopped_at: Timestamp,
+}
+
+impl EntityKind for TimeEntry {
+    const KIND: &'static str = "time_entry";
+}
+
+impl TimeEntry {
+    /// Returns the task the time was spent on.
+    pub fn task(&self) -> Id<Task> {
+        self.task
+    }
+
+    /// Returns when the timer was started.
+    pub fn started_at(&self) -> Timestamp {
+        self.started_at
+    }
+
+    /// Returns when the timer was stopped.
+    pub fn stopped_at(&self) -> Timestamp {
+        self.stopped_at
+    }
+
+    /// Returns the time spent.
+    pub fn duration(&self) -> Duration {
+        (self.stopped_at - self.started_at)
+            .to_std()
+            .unwrap_or_default()
+    }
+}
+
+/// Represents the time a user tracked on tasks: the recorded entries and at most one running
+/// timer.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "TimesheetRecord", from = "TimesheetRecord")
+)]
+pub struct Timesheet {
+    user: Id<User>,
+    running: Option<RunningTimer>,
+    entries: Vec<Entity<TimeEntry>>,
+    events: Vec<TimetrackDomainEvent>,
+}
+
+/// The versioned on-disk form of a timesheet; pending events are not persisted.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+enum TimesheetRecord {
+    #[serde(rename = "1")]
+    V1 {
+        user: Id<User>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        running: Option<RunningTimer>,
+        entries: Vec<Entity<TimeEntry>>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<Timesheet> for TimesheetRecord {
+    fn from(timesheet: Timesheet) -> Self {
+        TimesheetRecord::V1 {
+            user: timesheet.user,
+            running: timesheet.running,
+            entries: timesheet.entries,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<TimesheetRecord> for Timesheet {
+    fn from(record: TimesheetRecord) -> Self {
+        match record {
+            TimesheetRecord::V1 {
+                user,
+                running,
+                entries,
+            } => Timesheet {
+                user,
+                running,
+                entries,
+                events: Vec::new(),
+            },
+        }
+    }
+}
+
+impl EntityKind for Timesheet {
+    const KIND: &'static str = "timesheet";
+}
+
+impl Timesheet {
+    /// Returns the user tracking the time.
+    pub fn user(&self) -> Id<User> {
+        self.user
+    }
+
+    /// Returns the running timer, if any.
+    pub fn running(&self) -> Option<RunningTimer> {
+        self.running
+    }
+
+    /// Lists the recorded entries in the order they were stopped.
+    pub fn entries(&self) -> &[Entity<TimeEntry>] {
+        &self.entries
+    }
+}
+
+/// Trait for aggregate root operations on a `Timesheet`.
+///
+/// A user has a single timesheet, so holding at most one running timer per timesheet keeps
+/// users from tracking two tasks at once.
+pub trait TimesheetAggregateRoot {
+    /// Opens an empty timesheet for a user.
+    fn new(user: Id<User>) -> Self;
+
+    /// Starts a timer on a task, failing if a timer is already running.
+    fn start(&mut self, task: Id<Task>, clock: &impl Clock) -> TimetrackDomainResult<()>;
+
+    /// Stops the running timer and records its time, returning the id of the new entry.
+    fn stop(&mut self, clock: &impl Clock) -> TimetrackDomainResult<Id<TimeEntry>>;
+
+    /// Stops the running timer without recording its time.
+    fn discard(&mut self) -> TimetrackDomainResult<()>;
+}
+
+impl TimesheetAggregateRoot for Entity<Timesheet> {
+    fn new(user: Id<User>) -> Self {
+        let id = Id::new();
+        Entity {
+            id,
+            data: Timesheet {
+                user,
+                running: None,
+                entries: Vec::new(),
+                events: vec![TimetrackDomainEvent::TimesheetCreated {
+                    timesheet: id,
+                    user,
+                }],
+            },
+        }
+    }
+
+    fn start(&mut self, task: Id<Task>, clock: &impl Clock) -> TimetrackDomainResult<()> {
+        if let Some(running) = self.data.running {
+            return Err(TimetrackDomainError::TimerAlreadyRunning {
+                timesheet: self.id,
+                task: running.task,
+            });
+        }
+
+        let at = clock.now();
+        self.data.running = Some(RunningTimer {
+            task,
+            started_at: at,
+        });
+        self.data.events.push(TimetrackDomainEvent::TimerStarted {
+            timesheet: self.id,
+            task,
+            at,
+        });
+
+        Ok(())
+    }
+
+    fn stop(&mut self, clock: &impl Clock) -> TimetrackDomainResult<Id<TimeEntry>> {
+        let running = self
+            .data
+            .running
+            .ok_or(TimetrackDomainError::NoTimerRunning { timesheet: self.id })?;
+        let stopped_at = clock.now();
+        if stopped_at < running.started_at {
+            return Err(TimetrackDomainError::TimerStoppedBeforeStart { timesheet: self.id });
+        }
+
+        let entry = Entity::from(TimeEntry {
+            task: running.task,
+            started_at: running.started_at,
+            stopped_at,
+        });
+        let id = entry.id;
+        self.data.running = None;
+        self.data.entries.push(entry);
+        self.data.events.push(TimetrackDomainEvent::TimerStopped {
+            timesheet: self.id,
+            entry: id,
+            task: running.task,
+            started_at: running.started_at,
+            stopped_at,
+        });
+
+        Ok(id)
+    }
+
+    fn discard(&mut self) -> TimetrackDomainResult<()> {
+        let running = self
+            .data
+            .running
+            .take()
+            .ok_or(TimetrackDomainError::NoTimerRunning { timesheet: self.id })?;
+
+        self.data.events.push(TimetrackDomainEvent::TimerDiscarded {
+            timesheet: self.id,
+            task: running.task,
+        });
+
+        Ok(())
+    }
+}
+
+impl AggregateRoot for Timesheet {
+    type Event = TimetrackDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_running_timer() {
+        let start = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let (first, second) = (Id::new(), Id::new());
+        let mut timesheet: Entity<Timesheet> = TimesheetAggregateRoot::new(Id::new());
+        assert!(matches!(
+            timesheet.stop(&|| start),
+            Err(TimetrackDomainError::NoTimerRunning { .. })
+        ));
+
+        timesheet.start(first, &|| start).unwrap();
+        assert!(matches!(
+            timesheet.start(second, &|| start),
+            Err(TimetrackDomainError::TimerAlreadyRunning { task, .. }) if task == first
+        ));
+        assert!(matches!(
+            timesheet.stop(&|| start - chrono::Duration::minutes(1)),
+            Err(TimetrackDomainError::TimerStoppedBeforeStart { .. })
+        ));
+        let entry = timesheet
+            .stop(&|| start + chrono::Duration::minutes(25))
+            .unwrap();
+        assert_eq!(timesheet.data.entries()[0].id, entry);
+        assert_eq!(
+            timesheet.data.entries()[0].data.duration(),
+            Duration::from_secs(25 * 60)
+        );
+
+        timesheet.start(second, &|| start).unwrap();
+        assert_eq!(timesheet.data.running().map(|r| r.task()), Some(second));
+        timesheet.discard().unwrap();
+        assert!(timesheet.data.running().is_none());
+        assert_eq!(timesheet.data.entries().len(), 1);
+        assert_eq!(timesheet.data.take_events().len(), 5);
+    }
+}
@@ -0,0 +1,5 @@
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+/// The `domain` module contains the core domain logic for tracking the time users spend on
+/// tasks.
+pub mod domain;
@@ -0,0 +1,127 @@
+use shared_kernel::{Entity, Id, User};
+
+use super::{
+    error::WorkspaceDomainError,
+    workspace::{Role, Workspace},
+};
+
+type WorkspaceDomainResult<T> = Result<T, WorkspaceDomainError>;
+
+/// An action a user may attempt within a workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    /// Look at lists, nets, tasks and comments.
+    View,
+    /// Comment on tasks and react to comments.
+    Comment,
+    /// Move tasks between statuses.
+    ChangeStatus,
+    /// Create and edit tasks.
+    EditTask,
+    /// Add and remove tasks and relations of nets.
+    EditNet,
+    /// Change the statuses, workflow and policies of nets.
+    ManageSchema,
+    /// Create, archive and reorganize lists and projects.
+    ManageLists,
+    /// Invite and remove members and change their roles.
+    ManageMembers,
+    /// Rename or otherwise administer the workspace itself.
+    ManageWorkspace,
+}
+
+/// Decides whether a user may perform an action in a workspace.
+///
+/// Application services consult a policy before invoking aggregate methods; the aggregates
+/// themselves do not check permissions.
+pub trait PermissionPolicy {
+    /// Checks whether the user may perform the action in the workspace.
+    fn is_allowed(&self, workspace: &Entity<Workspace>, user: Id<User>, action: Action) -> bool;
+
+    /// Fails with [`WorkspaceDomainError::Forbidden`] unless the user may perform the action.
+    fn authorize(
+        &self,
+        workspace: &Entity<Workspace>,
+        user: Id<User>,
+        action: Action,
+    ) -> WorkspaceDomainResult<()> {
+        if !self.is_allowed(workspace, user, action) {
+            return Err(WorkspaceDomainError::Forbidden {
+                workspace: workspace.id,
+                user,
+                action,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The default policy, granting actions by the role of the user in the workspace.
+///
+/// Guests may view and comment, members may also work on tasks and nets, admins may manage
+/// schemas, lists and members, and owners may do everything. Users outside the workspace
+/// may do nothing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoleBasedPolicy;
+
+impl RoleBasedPolicy {
+    /// Returns the least privileged role allowed to perform an action.
+    pub fn required_role(action: Action) -> Role {
+        match action {
+            Action::View | Action::Comment => Role::Guest,
+            Action::ChangeStatus | Action::EditTask | Action::EditNet => Role::Member,
+            Action::ManageSchema | Action::ManageLists | Action::ManageMembers => Role::Admin,
+            Action::ManageWorkspace => Role::Owner,
+        }
+    }
+}
+
+impl PermissionPolicy for RoleBasedPolicy {
+    fn is_allowed(&self, workspace: &Entity<Workspace>, user: Id<User>, action: Action) -> bool {
+        workspace
+            .data
+            .role_of(user)
+            .is_some_and(|role| role <= Self::required_role(action))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::domain::workspace::{WorkspaceAggregateRoot, WorkspaceName};
+
+    #[test]
+    fn test_role_based_policy() {
+        let owner = Id::new();
+        let guest = Id::new();
+        let member = Id::new();
+        let mut workspace: Entity<Workspace> =
+            WorkspaceAggregateRoot::new(WorkspaceName::new("Acme").unwrap(), owner);
+        for (user, role) in [(guest, Role::Guest), (member, Role::Member)] {
+            workspace.invite(user, role).unwrap();
+            workspace.accept_invitation(user).unwrap();
+        }
+        let policy = RoleBasedPolicy;
+
+        assert!(policy.is_allowed(&workspace, guest, Action::Comment));
+        assert!(!policy.is_allowed(&workspace, guest, Action::ChangeStatus));
+        assert!(policy.is_allowed(&workspace, member, Action::EditNet));
+        assert!(!policy.is_allowed(&workspace, member, Action::ManageSchema));
+        assert!(policy.is_allowed(&workspace, owner, Action::ManageWorkspace));
+        assert!(!policy.is_allowed(&workspace, Id::new(), Action::View));
+
+        assert!(policy
+            .authorize(&workspace, owner, Action::ManageMembers)
+            .is_ok());
+        assert!(matches!(
+            policy.authorize(&workspace, member, Action::ManageMembers),
+            Err(WorkspaceDomainError::Forbidden {
+                action: Action::ManageMembers,
+                ..
+            })
+        ));
+    }
+}
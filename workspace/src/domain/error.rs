@@ -0,0 +1,55 @@
+use shared_kernel::{Id, NameViolation, User};
+use thiserror::Error;
+
+use super::{authz::Action, workspace::Workspace};
+
+/// Represents errors that can occur in the workspace domain.
+#[derive(Debug, Error)]
+pub enum WorkspaceDomainError {
+    /// Error indicating that a workspace name failed validation.
+    #[error("invalid workspace name: {reason}")]
+    InvalidName { reason: NameViolation },
+
+    /// Error indicating that a user is already a member of a workspace.
+    #[error("user {user:?} is already a member of workspace {workspace:?}")]
+    AlreadyMember {
+        workspace: Id<Workspace>,
+        user: Id<User>,
+    },
+
+    /// Error indicating that a user is not a member of a workspace.
+    #[error("user {user:?} is not a member of workspace {workspace:?}")]
+    NotMember {
+        workspace: Id<Workspace>,
+        user: Id<User>,
+    },
+
+    /// Error indicating that a user already has a pending invitation to a workspace.
+    #[error("user {user:?} is already invited to workspace {workspace:?}")]
+    AlreadyInvited {
+        workspace: Id<Workspace>,
+        user: Id<User>,
+    },
+
+    /// Error indicating that a user has no pending invitation to a workspace.
+    #[error("user {user:?} is not invited to workspace {workspace:?}")]
+    NotInvited {
+        workspace: Id<Workspace>,
+        user: Id<User>,
+    },
+
+    /// Error indicating that a change would leave a workspace without an owner.
+    #[error("user {user:?} is the last owner of workspace {workspace:?}")]
+    LastOwner {
+        workspace: Id<Workspace>,
+        user: Id<User>,
+    },
+
+    /// Error indicating that a user may not perform an action in a workspace.
+    #[error("user {user:?} may not {action:?} in workspace {workspace:?}")]
+    Forbidden {
+        workspace: Id<Workspace>,
+        user: Id<User>,
+        action: Action,
+    },
+}
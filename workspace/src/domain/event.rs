@@ -0,0 +1,47 @@
+use shared_kernel::{Id, User};
+
+use super::workspace::{Role, Workspace, WorkspaceName};
+
+/// Represents a domain event raised by an aggregate of the workspace context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkspaceDomainEvent {
+    /// A workspace was created by its first owner.
+    WorkspaceCreated {
+        workspace: Id<Workspace>,
+        name: WorkspaceName,
+        owner: Id<User>,
+    },
+    /// A workspace was renamed.
+    WorkspaceRenamed {
+        workspace: Id<Workspace>,
+        name: WorkspaceName,
+    },
+    /// A user was invited to a workspace with a role.
+    MemberInvited {
+        workspace: Id<Workspace>,
+        user: Id<User>,
+        role: Role,
+    },
+    /// A pending invitation to a workspace was revoked.
+    InvitationRevoked {
+        workspace: Id<Workspace>,
+        user: Id<User>,
+    },
+    /// An invited user joined a workspace with the role they were invited with.
+    MemberJoined {
+        workspace: Id<Workspace>,
+        user: Id<User>,
+        role: Role,
+    },
+    /// A member was removed from a workspace.
+    MemberRemoved {
+        workspace: Id<Workspace>,
+        user: Id<User>,
+    },
+    /// The role of a member of a workspace changed.
+    MemberRoleChanged {
+        workspace: Id<Workspace>,
+        user: Id<User>,
+        role: Role,
+    },
+}
@@ -0,0 +1,4 @@
+pub mod authz;
+pub mod error;
+pub mod event;
+pub mod workspace;
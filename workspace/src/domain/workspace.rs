@@ -0,0 +1,399 @@
+use shared_kernel::{AggregateRoot, Entity, EntityKind, Id, User};
+
+use super::{error::WorkspaceDomainError, event::WorkspaceDomainEvent};
+
+type WorkspaceDomainResult<T> = Result<T, WorkspaceDomainError>;
+
+shared_kernel::validated_text!(
+    /// The validated name of a workspace.
+    WorkspaceName,
+    128,
+    WorkspaceDomainError,
+    |reason| WorkspaceDomainError::InvalidName { reason }
+);
+
+/// The role of a member in a workspace, from the most to the least privileged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Role {
+    /// Owns the workspace; every workspace keeps at least one owner.
+    Owner,
+    /// Manages members and settings of the workspace.
+    Admin,
+    /// Works on the content of the workspace.
+    Member,
+    /// Sees what is shared with them.
+    Guest,
+}
+
+/// Represents a workspace: the tenant that users share lists, nets and projects in.
+///
+/// Users join a workspace by accepting an invitation, which sets the role they start with.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "WorkspaceRecord", from = "WorkspaceRecord")
+)]
+pub struct Workspace {
+    name: WorkspaceName,
+    members: Vec<(Id<User>, Role)>,
+    invitations: Vec<(Id<User>, Role)>,
+    events: Vec<WorkspaceDomainEvent>,
+}
+
+/// The versioned on-disk form of a workspace; pending events are not persisted.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "version")]
+enum WorkspaceRecord {
+    #[serde(rename = "1")]
+    V1 {
+        name: WorkspaceName,
+        members: Vec<(Id<User>, Role)>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        invitations: Vec<(Id<User>, Role)>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl From<Workspace> for WorkspaceRecord {
+    fn from(workspace: Workspace) -> Self {
+        WorkspaceRecord::V1 {
+            name: workspace.name,
+            members: workspace.members,
+            invitations: workspace.invitations,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<WorkspaceRecord> for Workspace {
+    fn from(record: WorkspaceRecord) -> Self {
+        match record {
+            WorkspaceRecord::V1 {
+                name,
+                members,
+                invitations,
+            } => Workspace {
+                name,
+                members,
+                invitations,
+                events: Vec::new(),
+            },
+        }
+    }
+}
+
+impl EntityKind for Workspace {
+    const KIND: &'static str = "workspace";
+}
+
+impl Workspace {
+    /// Returns the name of the workspace.
+    pub fn name(&self) -> &WorkspaceName {
+        &self.name
+    }
+
+    /// Lists the members of the workspace with their roles, in the order they joined.
+    pub fn members(&self) -> &[(Id<User>, Role)] {
+        &self.members
+    }
+
+    /// Lists the pending invitations to the workspace with the roles they grant.
+    pub fn invitations(&self) -> &[(Id<User>, Role)] {
+        &self.invitations
+    }
+
+    /// Returns the role of a user in the workspace, if they are a member.
+    pub fn role_of(&self, user: Id<User>) -> Option<Role> {
+        self.members
+            .iter()
+            .find(|(member, _)| *member == user)
+            .map(|(_, role)| *role)
+    }
+
+    /// Checks whether the user is the only owner of the workspace.
+    fn is_last_owner(&self, user: Id<User>) -> bool {
+        self.role_of(user) == Some(Role::Owner)
+            && self
+                .members
+                .iter()
+                .filter(|(_, role)| *role == Role::Owner)
+                .count()
+                == 1
+    }
+}
+
+/// Trait for aggregate root operations on a `Workspace`.
+pub trait WorkspaceAggregateRoot {
+    /// Creates a new workspace owned by the user creating it.
+    fn new(name: WorkspaceName, owner: Id<User>) -> Self;
+
+    /// Renames the workspace.
+    fn rename(&mut self, name: WorkspaceName);
+
+    /// Invites a user to the workspace with the role they will join with.
+    fn invite(&mut self, user: Id<User>, role: Role) -> WorkspaceDomainResult<()>;
+
+    /// Revokes the pending invitation of a user.
+    fn revoke_invitation(&mut self, user: Id<User>) -> WorkspaceDomainResult<()>;
+
+    /// Makes an invited user a member with the role of their invitation.
+    fn accept_invitation(&mut self, user: Id<User>) -> WorkspaceDomainResult<()>;
+
+    /// Removes a member from the workspace, failing for its last owner.
+    fn remove_member(&mut self, user: Id<User>) -> WorkspaceDomainResult<()>;
+
+    /// Changes the role of a member, failing if that would demote the last owner.
+    fn change_role(&mut self, user: Id<User>, role: Role) -> WorkspaceDomainResult<()>;
+}
+
+impl WorkspaceAggregateRoot for Entity<Workspace> {
+    fn new(name: WorkspaceName, owner: Id<User>) -> Self {
+        let id = Id::new();
+        Entity {
+            id,
+            data: Workspace {
+                name: name.clone(),
+                members: vec![(owner, Role::Owner)],
+                invitations: Vec::new(),
+                events: vec![WorkspaceDomainEvent::WorkspaceCreated {
+                    workspace: id,
+                    name,
+                    owner,
+                }],
+            },
+        }
+    }
+
+    fn rename(&mut self, name: WorkspaceName) {
+        self.data.name = name.clone();
+        self.data
+            .events
+            .push(WorkspaceDomainEvent::WorkspaceRenamed {
+                workspace: self.id,
+                name,
+            });
+    }
+
+    fn invite(&mut self, user: Id<User>, role: Role) -> WorkspaceDomainResult<()> {
+        if self.data.role_of(user).is_some() {
+            return Err(WorkspaceDomainError::AlreadyMember {
+                workspace: self.id,
+                user,
+            });
+        }
+        if self.data.invitations.iter().any(|(u, _)| *u == user) {
+            return Err(WorkspaceDomainError::AlreadyInvited {
+                workspace: self.id,
+                user,
+            });
+        }
+
+        self.data.invitations.push((user, role));
+        self.data.events.push(WorkspaceDomainEvent::MemberInvited {
+            workspace: self.id,
+            user,
+            role,
+        });
+
+        Ok(())
+    }
+
+    fn revoke_invitation(&mut self, user: Id<User>) -> WorkspaceDomainResult<()> {
+        let Some(index) = self.data.invitations.iter().position(|(u, _)| *u == user) else {
+            return Err(WorkspaceDomainError::NotInvited {
+                workspace: self.id,
+                user,
+            });
+        };
+
+        self.data.invitations.remove(index);
+        self.data
+            .events
+            .push(WorkspaceDomainEvent::InvitationRevoked {
+                workspace: self.id,
+                user,
+            });
+
+        Ok(())
+    }
+
+    fn accept_invitation(&mut self, user: Id<User>) -> WorkspaceDomainResult<()> {
+        let Some(index) = self.data.invitations.iter().position(|(u, _)| *u == user) else {
+            return Err(WorkspaceDomainError::NotInvited {
+                workspace: self.id,
+                user,
+            });
+        };
+
+        let (user, role) = self.data.invitations.remove(index);
+        self.data.members.push((user, role));
+        self.data.events.push(WorkspaceDomainEvent::MemberJoined {
+            workspace: self.id,
+            user,
+            role,
+        });
+
+        Ok(())
+    }
+
+    fn remove_member(&mut self, user: Id<User>) -> WorkspaceDomainResult<()> {
+        let Some(index) = self.data.members.iter().position(|(u, _)| *u == user) else {
+            return Err(WorkspaceDomainError::NotMember {
+                workspace: self.id,
+                user,
+            });
+        };
+        if self.data.is_last_owner(user) {
+            return Err(WorkspaceDomainError::LastOwner {
+                workspace: self.id,
+                user,
+            });
+        }
+
+        self.data.members.remove(index);
+        self.data.events.push(WorkspaceDomainEvent::MemberRemoved {
+            workspace: self.id,
+            user,
+        });
+
+        Ok(())
+    }
+
+    fn change_role(&mut self, user: Id<User>, role: Role) -> WorkspaceDomainResult<()> {
+        let Some(current) = self.data.role_of(user) else {
+            return Err(WorkspaceDomainError::NotMember {
+                workspace: self.id,
+                user,
+            });
+        };
+        if current == role {
+            return Ok(());
+        }
+        if self.data.is_last_owner(user) {
+            return Err(WorkspaceDomainError::LastOwner {
+                workspace: self.id,
+                user,
+            });
+        }
+
+        for (member, member_role) in &mut self.data.members {
+            if *member == user {
+                *member_role = role;
+            }
+        }
+        self.data
+            .events
+            .push(WorkspaceDomainEvent::MemberRoleChanged {
+                workspace: self.id,
+                user,
+                role,
+            });
+
+        Ok(())
+    }
+}
+
+impl AggregateRoot for Workspace {
+    type Event = WorkspaceDomainEvent;
+
+    fn take_events(&mut self) -> Vec<Self::Event> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use shared_kernel::NameViolation;
+
+    use super::*;
+
+    fn workspace(owner: Id<User>) -> Entity<Workspace> {
+        WorkspaceAggregateRoot::new(WorkspaceName::new("Acme").unwrap(), owner)
+    }
+
+    #[test]
+    fn test_name() {
+        assert!(matches!(
+            WorkspaceName::new(""),
+            Err(WorkspaceDomainError::InvalidName {
+                reason: NameViolation::Empty
+            })
+        ));
+
+        let mut workspace = workspace(Id::new());
+        workspace.rename(WorkspaceName::new("Acme Inc.").unwrap());
+        assert_eq!(workspace.data.name(), &"Acme Inc.");
+    }
+
+    #[test]
+    fn test_invitations() {
+        let owner = Id::new();
+        let guest = Id::new();
+        let mut workspace = workspace(owner);
+
+        assert!(matches!(
+            workspace.invite(owner, Role::Admin),
+            Err(WorkspaceDomainError::AlreadyMember { .. })
+        ));
+        workspace.invite(guest, Role::Guest).unwrap();
+        assert!(matches!(
+            workspace.invite(guest, Role::Member),
+            Err(WorkspaceDomainError::AlreadyInvited { .. })
+        ));
+        assert_eq!(workspace.data.role_of(guest), None);
+
+        workspace.revoke_invitation(guest).unwrap();
+        assert!(matches!(
+            workspace.accept_invitation(guest),
+            Err(WorkspaceDomainError::NotInvited { .. })
+        ));
+        workspace.invite(guest, Role::Guest).unwrap();
+        workspace.accept_invitation(guest).unwrap();
+        assert_eq!(workspace.data.role_of(guest), Some(Role::Guest));
+        assert!(workspace.data.invitations().is_empty());
+    }
+
+    #[test]
+    fn test_last_owner_stays() {
+        let owner = Id::new();
+        let admin = Id::new();
+        let mut workspace = workspace(owner);
+        workspace.invite(admin, Role::Admin).unwrap();
+        workspace.accept_invitation(admin).unwrap();
+
+        assert!(matches!(
+            workspace.remove_member(owner),
+            Err(WorkspaceDomainError::LastOwner { .. })
+        ));
+        assert!(matches!(
+            workspace.change_role(owner, Role::Member),
+            Err(WorkspaceDomainError::LastOwner { .. })
+        ));
+
+        workspace.change_role(admin, Role::Owner).unwrap();
+        workspace.change_role(owner, Role::Member).unwrap();
+        workspace.remove_member(owner).unwrap();
+        assert_eq!(workspace.data.members(), [(admin, Role::Owner)]);
+        assert!(matches!(
+            workspace.remove_member(owner),
+            Err(WorkspaceDomainError::NotMember { .. })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let owner = Id::new();
+        let mut workspace = workspace(owner);
+        workspace.invite(Id::new(), Role::Member).unwrap();
+
+        let json = serde_json::to_value(&workspace).unwrap();
+        let restored: Entity<Workspace> = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.data.members(), [(owner, Role::Owner)]);
+        assert_eq!(restored.data.invitations(), workspace.data.invitations());
+    }
+}